@@ -0,0 +1,750 @@
+//! Time-integrated coverage analysis
+//!
+//! Sweeps a set of satellites over a time interval and accumulates, for each
+//! cell of a lat/lon grid, the percentage of time it is covered, the longest
+//! gap, the mean gap between accesses and the number of accesses. The sweep is plain
+//! Rust so it can run in a background task or headless from the command line.
+
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task, block_on};
+use chrono::{DateTime, Duration, Utc};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::analysis::{SnapshotPropagator, TleSnapshot};
+use crate::core::coordinates::{Coordinates, visible_above_mask_ecef_dvec};
+use crate::core::paths::export_dir;
+use crate::orbital::{Dut1, SimulationTime};
+use crate::satellite::components::{NoradId, Satellite, TleComponent};
+use crate::tle::fetcher::{clean_tle_lines, parse_tle_pairs};
+use crate::tle::parser::parse_tle_epoch_to_utc;
+
+/// Geographic region sampled by the coverage grid (degrees)
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoverageRegion {
+    pub lat_min_deg: f64,
+    pub lat_max_deg: f64,
+    pub lon_min_deg: f64,
+    pub lon_max_deg: f64,
+    pub cell_size_deg: f64,
+}
+
+impl Default for CoverageRegion {
+    fn default() -> Self {
+        Self {
+            lat_min_deg: -90.0,
+            lat_max_deg: 90.0,
+            lon_min_deg: -180.0,
+            lon_max_deg: 180.0,
+            cell_size_deg: 5.0,
+        }
+    }
+}
+
+impl CoverageRegion {
+    fn cell_size(&self) -> f64 {
+        self.cell_size_deg.max(0.1)
+    }
+
+    /// True when the bounds enclose no area
+    pub fn is_empty(&self) -> bool {
+        self.lat_min_deg >= self.lat_max_deg || self.lon_min_deg >= self.lon_max_deg
+    }
+
+    /// Number of (rows, columns) in the grid
+    pub fn dimensions(&self) -> (usize, usize) {
+        let size = self.cell_size();
+        let rows = ((self.lat_max_deg - self.lat_min_deg) / size)
+            .ceil()
+            .max(1.0) as usize;
+        let cols = ((self.lon_max_deg - self.lon_min_deg) / size)
+            .ceil()
+            .max(1.0) as usize;
+        (rows, cols)
+    }
+
+    /// Cell centers in row-major order (south to north, west to east)
+    pub fn cells(&self) -> Vec<CoverageCell> {
+        let (rows, cols) = self.dimensions();
+        let size = self.cell_size();
+        let mut cells = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            let lat_deg = (self.lat_min_deg + (row as f64 + 0.5) * size).min(self.lat_max_deg);
+            for col in 0..cols {
+                let lon_deg = (self.lon_min_deg + (col as f64 + 0.5) * size).min(self.lon_max_deg);
+                let coords = Coordinates {
+                    latitude: lat_deg.to_radians(),
+                    longitude: lon_deg.to_radians(),
+                };
+                cells.push(CoverageCell {
+                    lat_deg,
+                    lon_deg,
+                    ecef_km: coords.get_point_on_sphere_ecef_km_dvec(),
+                });
+            }
+        }
+        cells
+    }
+
+    /// Index of the cell containing a geographic point, if it lies in the region
+    pub fn cell_index(&self, lat_deg: f64, lon_deg: f64) -> Option<usize> {
+        if lat_deg < self.lat_min_deg
+            || lat_deg > self.lat_max_deg
+            || lon_deg < self.lon_min_deg
+            || lon_deg > self.lon_max_deg
+        {
+            return None;
+        }
+        let (rows, cols) = self.dimensions();
+        let size = self.cell_size();
+        let row = (((lat_deg - self.lat_min_deg) / size) as usize).min(rows - 1);
+        let col = (((lon_deg - self.lon_min_deg) / size) as usize).min(cols - 1);
+        Some(row * cols + col)
+    }
+}
+
+/// Grid cell center on the Earth surface
+#[derive(Clone, Debug)]
+pub struct CoverageCell {
+    pub lat_deg: f64,
+    pub lon_deg: f64,
+    pub ecef_km: DVec3,
+}
+
+/// Accumulated coverage statistics for one grid cell
+#[derive(Clone, Debug, Default)]
+pub struct CellStats {
+    /// Number of samples in which the cell was covered
+    pub covered_samples: u32,
+    /// Number of distinct access intervals
+    pub access_count: u32,
+    /// Longest uncovered interval, including the leading and trailing gaps
+    pub max_gap_s: f64,
+    between_gap_sum_s: f64,
+    between_gap_count: u32,
+    in_access: bool,
+    current_gap_s: f64,
+}
+
+impl CellStats {
+    /// Record one sample of `step_s` seconds
+    pub fn record(&mut self, covered: bool, step_s: f64) {
+        if covered {
+            self.covered_samples += 1;
+            if !self.in_access {
+                // Gap ends here; only gaps between two accesses count toward the mean
+                if self.access_count > 0 {
+                    self.between_gap_sum_s += self.current_gap_s;
+                    self.between_gap_count += 1;
+                }
+                self.max_gap_s = self.max_gap_s.max(self.current_gap_s);
+                self.current_gap_s = 0.0;
+                self.access_count += 1;
+                self.in_access = true;
+            }
+        } else {
+            self.in_access = false;
+            self.current_gap_s += step_s;
+        }
+    }
+
+    /// Close the trailing gap once the sweep is done
+    pub fn finish(&mut self) {
+        if !self.in_access {
+            self.max_gap_s = self.max_gap_s.max(self.current_gap_s);
+        }
+    }
+
+    /// Percentage of samples in which the cell was covered
+    pub fn percent_covered(&self, samples: u32) -> f64 {
+        if samples == 0 {
+            return 0.0;
+        }
+        f64::from(self.covered_samples) / f64::from(samples) * 100.0
+    }
+
+    /// Mean time between the end of one access and the start of the next
+    pub fn mean_gap_s(&self) -> Option<f64> {
+        (self.between_gap_count > 0)
+            .then(|| self.between_gap_sum_s / f64::from(self.between_gap_count))
+    }
+}
+
+/// Per-cell statistic shown on the globe
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CoverageMetric {
+    #[default]
+    PercentCovered,
+    MaxGap,
+    MeanGap,
+    AccessCount,
+}
+
+impl CoverageMetric {
+    pub const ALL: [CoverageMetric; 4] = [
+        CoverageMetric::PercentCovered,
+        CoverageMetric::MaxGap,
+        CoverageMetric::MeanGap,
+        CoverageMetric::AccessCount,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CoverageMetric::PercentCovered => "% time covered",
+            CoverageMetric::MaxGap => "Max gap (min)",
+            CoverageMetric::MeanGap => "Mean gap (min)",
+            CoverageMetric::AccessCount => "Access count",
+        }
+    }
+
+    /// Value of this metric for a cell, None when undefined (e.g. fewer than two accesses)
+    pub fn value(self, stats: &CellStats, samples: u32) -> Option<f64> {
+        match self {
+            CoverageMetric::PercentCovered => Some(stats.percent_covered(samples)),
+            CoverageMetric::MaxGap => Some(stats.max_gap_s / 60.0),
+            CoverageMetric::MeanGap => stats.mean_gap_s().map(|s| s / 60.0),
+            CoverageMetric::AccessCount => Some(f64::from(stats.access_count)),
+        }
+    }
+}
+
+/// Inputs for a coverage sweep
+#[derive(Clone, Debug)]
+pub struct CoverageRequest {
+    pub region: CoverageRegion,
+    pub start_utc: DateTime<Utc>,
+    pub duration: Duration,
+    pub step_s: f64,
+    pub min_elevation_deg: f64,
+    pub dut1_seconds: f64,
+}
+
+/// Output of a coverage sweep
+#[derive(Clone, Debug)]
+pub struct CoverageResult {
+    pub region: CoverageRegion,
+    pub start_utc: DateTime<Utc>,
+    pub end_utc: DateTime<Utc>,
+    pub min_elevation_deg: f64,
+    pub samples: u32,
+    pub satellite_count: usize,
+    pub cells: Vec<CoverageCell>,
+    pub stats: Vec<CellStats>,
+}
+
+impl CoverageResult {
+    /// Metric value at an ECEF point, None outside the region or when undefined
+    pub fn value_at_ecef(&self, ecef_km: DVec3, metric: CoverageMetric) -> Option<f64> {
+        let (lat_deg, lon_deg) = Coordinates::from(ecef_km).as_degrees();
+        let index = self
+            .region
+            .cell_index(f64::from(lat_deg), f64::from(lon_deg))?;
+        self.stats
+            .get(index)
+            .and_then(|stats| metric.value(stats, self.samples))
+    }
+
+    /// Area-unweighted mean of the percent-covered metric
+    pub fn mean_percent_covered(&self) -> f64 {
+        if self.stats.is_empty() {
+            return 0.0;
+        }
+        let total: f64 = self
+            .stats
+            .iter()
+            .map(|stats| stats.percent_covered(self.samples))
+            .sum();
+        total / self.stats.len() as f64
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "{} sats, {} cells, {} samples over {:.1} h above {:.0}°, mean coverage {:.1}%",
+            self.satellite_count,
+            self.cells.len(),
+            self.samples,
+            (self.end_utc - self.start_utc).num_seconds() as f64 / 3600.0,
+            self.min_elevation_deg,
+            self.mean_percent_covered()
+        )
+    }
+
+    /// Per-cell statistics as CSV (one row per grid cell)
+    pub fn to_csv(&self) -> String {
+        let mut out =
+            String::from("lat_deg,lon_deg,percent_covered,max_gap_s,mean_gap_s,access_count\n");
+        for (cell, stats) in self.cells.iter().zip(&self.stats) {
+            let mean_gap = stats
+                .mean_gap_s()
+                .map(|s| format!("{s:.1}"))
+                .unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "{:.3},{:.3},{:.2},{:.1},{},{}",
+                cell.lat_deg,
+                cell.lon_deg,
+                stats.percent_covered(self.samples),
+                stats.max_gap_s,
+                mean_gap,
+                stats.access_count
+            );
+        }
+        out
+    }
+
+    pub fn write_csv(&self, path: &Path) -> Result<(), anyhow::Error> {
+        fs::write(path, self.to_csv())?;
+        Ok(())
+    }
+}
+
+/// Sweep the satellites over the requested interval and accumulate per-cell stats
+pub fn run_coverage(request: &CoverageRequest, snapshots: &[TleSnapshot]) -> CoverageResult {
+    let propagators = SnapshotPropagator::build_all(snapshots);
+    let cells = request.region.cells();
+    let mut stats = vec![CellStats::default(); cells.len()];
+
+    let step_s = request.step_s.max(1.0);
+    let total_s = request.duration.num_milliseconds() as f64 / 1000.0;
+    let samples = (total_s / step_s).floor().max(1.0) as u32;
+
+    let mut positions: Vec<DVec3> = Vec::with_capacity(propagators.len());
+    for sample in 0..samples {
+        let offset_ms = (f64::from(sample) * step_s * 1000.0) as i64;
+        let utc = request.start_utc + Duration::milliseconds(offset_ms);
+
+        positions.clear();
+        positions.extend(
+            propagators
+                .iter()
+                .filter_map(|p| p.ecef_km(utc, request.dut1_seconds)),
+        );
+
+        for (cell, cell_stats) in cells.iter().zip(stats.iter_mut()) {
+            let covered = positions.iter().any(|&sat| {
+                visible_above_mask_ecef_dvec(cell.ecef_km, sat, request.min_elevation_deg)
+            });
+            cell_stats.record(covered, step_s);
+        }
+    }
+
+    for cell_stats in &mut stats {
+        cell_stats.finish();
+    }
+
+    CoverageResult {
+        region: request.region.clone(),
+        start_utc: request.start_utc,
+        end_utc: request.start_utc + request.duration,
+        min_elevation_deg: request.min_elevation_deg,
+        samples,
+        satellite_count: propagators.len(),
+        cells,
+        stats,
+    }
+}
+
+/// Configuration resource for interactive coverage runs
+#[derive(Resource, Clone, Debug)]
+pub struct CoverageConfig {
+    pub region: CoverageRegion,
+    /// Analysis window length, starting at the current simulation time
+    pub duration_hours: f32,
+    /// Sampling step in seconds
+    pub step_seconds: f32,
+    /// Minimum elevation for a cell to count as covered
+    pub min_elevation_deg: f32,
+    /// Statistic shown on the globe overlay
+    pub metric: CoverageMetric,
+}
+
+impl Default for CoverageConfig {
+    fn default() -> Self {
+        Self {
+            region: CoverageRegion::default(),
+            duration_hours: 24.0,
+            step_seconds: 60.0,
+            min_elevation_deg: 10.0,
+            metric: CoverageMetric::PercentCovered,
+        }
+    }
+}
+
+impl CoverageConfig {
+    pub fn request(&self, start_utc: DateTime<Utc>, dut1_seconds: f64) -> CoverageRequest {
+        CoverageRequest {
+            region: self.region.clone(),
+            start_utc,
+            duration: Duration::seconds((f64::from(self.duration_hours) * 3600.0) as i64),
+            step_s: f64::from(self.step_seconds),
+            min_elevation_deg: f64::from(self.min_elevation_deg),
+            dut1_seconds,
+        }
+    }
+}
+
+/// Runtime state for coverage runs
+#[derive(Resource, Default)]
+pub struct CoverageState {
+    /// Set by the UI to start a new run
+    pub run_requested: bool,
+    /// Set by the UI to write the latest result to CSV
+    pub export_requested: bool,
+    /// In-flight sweep
+    pub pending_task: Option<Task<CoverageResult>>,
+    /// Most recent completed sweep
+    pub result: Option<CoverageResult>,
+    /// Human-readable status line for the UI
+    pub status: Option<String>,
+}
+
+/// Plugin for coverage analysis
+pub struct CoveragePlugin;
+
+impl Plugin for CoveragePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CoverageConfig>()
+            .init_resource::<CoverageState>()
+            .add_systems(
+                Update,
+                (
+                    start_coverage_analysis,
+                    poll_coverage_task,
+                    export_coverage_csv,
+                )
+                    .chain(),
+            );
+    }
+}
+
+fn start_coverage_analysis(
+    config: Res<CoverageConfig>,
+    mut state: ResMut<CoverageState>,
+    sim_time: Res<SimulationTime>,
+    dut1: Res<Dut1>,
+    satellites: Query<(&NoradId, &TleComponent), With<Satellite>>,
+) {
+    if !state.run_requested {
+        return;
+    }
+    state.run_requested = false;
+
+    if state.pending_task.is_some() {
+        state.status = Some("Analysis already running".to_string());
+        return;
+    }
+    if config.region.is_empty() {
+        state.status = Some("Region is empty; check the lat/lon bounds".to_string());
+        return;
+    }
+
+    let snapshots: Vec<TleSnapshot> = satellites
+        .iter()
+        .map(|(norad, tle)| TleSnapshot::from_tle(norad.0, &tle.0))
+        .collect();
+    if snapshots.is_empty() {
+        state.status = Some("No satellites with TLEs loaded".to_string());
+        return;
+    }
+
+    let request = config.request(sim_time.current_utc, **dut1);
+    let (rows, cols) = request.region.dimensions();
+    println!(
+        "[COVERAGE] start sats={} cells={} window={}h step={}s mask={}deg",
+        snapshots.len(),
+        rows * cols,
+        config.duration_hours,
+        config.step_seconds,
+        config.min_elevation_deg
+    );
+    state.status = Some(format!(
+        "Running: {} sats over {} cells...",
+        snapshots.len(),
+        rows * cols
+    ));
+
+    let task = AsyncComputeTaskPool::get().spawn(async move { run_coverage(&request, &snapshots) });
+    state.pending_task = Some(task);
+}
+
+fn poll_coverage_task(mut state: ResMut<CoverageState>) {
+    // Avoid touching the resource mutably until the task is done so change
+    // detection only fires when a new result lands.
+    if !state
+        .pending_task
+        .as_ref()
+        .is_some_and(|task| task.is_finished())
+    {
+        return;
+    }
+    if let Some(task) = state.pending_task.take() {
+        let result = block_on(task);
+        println!("[COVERAGE] done: {}", result.summary());
+        state.status = Some(result.summary());
+        state.result = Some(result);
+    }
+}
+
+fn export_coverage_csv(mut state: ResMut<CoverageState>) {
+    if !state.export_requested {
+        return;
+    }
+    state.export_requested = false;
+
+    let status = match &state.result {
+        None => "Nothing to export yet; run an analysis first".to_string(),
+        Some(result) => {
+            let file_name = format!("coverage_{}.csv", result.start_utc.format("%Y%m%dT%H%M%SZ"));
+            match export_dir()
+                .map(|dir| dir.join(file_name))
+                .and_then(|path| result.write_csv(&path).map(|()| path))
+            {
+                Ok(path) => {
+                    println!("[COVERAGE] exported {}", path.display());
+                    format!("Exported {}", path.display())
+                }
+                Err(e) => {
+                    eprintln!("[COVERAGE] export failed: {e}");
+                    format!("Export failed: {e}")
+                }
+            }
+        }
+    };
+    state.status = Some(status);
+}
+
+/// Options for a headless coverage run (`--coverage <tle-file>`)
+#[derive(Debug)]
+pub struct HeadlessCoverageArgs {
+    pub tle_path: PathBuf,
+    pub out_path: PathBuf,
+    pub request: CoverageRequest,
+}
+
+/// Parse headless coverage arguments; returns None when `--coverage` is absent.
+///
+/// Usage: `--coverage <tle-file> [--out coverage.csv] [--start RFC3339]
+/// [--hours 24] [--step 60] [--cell 5] [--min-el 10]
+/// [--region lat_min,lat_max,lon_min,lon_max]`
+pub fn parse_headless_args(args: &[String]) -> Option<Result<HeadlessCoverageArgs, anyhow::Error>> {
+    let pos = args.iter().position(|a| a == "--coverage")?;
+    Some(parse_headless_options(&args[pos + 1..]))
+}
+
+fn parse_headless_options(args: &[String]) -> Result<HeadlessCoverageArgs, anyhow::Error> {
+    let mut iter = args.iter();
+    let tle_path = iter
+        .next()
+        .filter(|a| !a.starts_with("--"))
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow::anyhow!("--coverage requires a TLE file path"))?;
+
+    let defaults = CoverageConfig::default();
+    let mut out_path = PathBuf::from("coverage.csv");
+    let mut start_utc = Utc::now();
+    let mut hours = f64::from(defaults.duration_hours);
+    let mut step_s = f64::from(defaults.step_seconds);
+    let mut min_elevation_deg = f64::from(defaults.min_elevation_deg);
+    let mut region = defaults.region;
+
+    while let Some(flag) = iter.next() {
+        let value = iter
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("{flag} requires a value"))?;
+        match flag.as_str() {
+            "--out" => out_path = PathBuf::from(value),
+            "--start" => {
+                start_utc = DateTime::parse_from_rfc3339(value)?.with_timezone(&Utc);
+            }
+            "--hours" => hours = value.parse()?,
+            "--step" => step_s = value.parse()?,
+            "--cell" => region.cell_size_deg = value.parse()?,
+            "--min-el" => min_elevation_deg = value.parse()?,
+            "--region" => {
+                let parts: Vec<f64> = value
+                    .split(',')
+                    .map(|p| p.trim().parse::<f64>())
+                    .collect::<Result<_, _>>()?;
+                let [lat_min, lat_max, lon_min, lon_max] = parts[..] else {
+                    anyhow::bail!("--region expects lat_min,lat_max,lon_min,lon_max");
+                };
+                region.lat_min_deg = lat_min;
+                region.lat_max_deg = lat_max;
+                region.lon_min_deg = lon_min;
+                region.lon_max_deg = lon_max;
+            }
+            other => anyhow::bail!("Unknown coverage option: {other}"),
+        }
+    }
+
+    if region.is_empty() {
+        anyhow::bail!("Coverage region is empty");
+    }
+
+    Ok(HeadlessCoverageArgs {
+        tle_path,
+        out_path,
+        request: CoverageRequest {
+            region,
+            start_utc,
+            duration: Duration::seconds((hours * 3600.0) as i64),
+            step_s,
+            min_elevation_deg,
+            dut1_seconds: 0.0,
+        },
+    })
+}
+
+/// Run a coverage sweep over the TLEs in a file and write the CSV, without a window
+pub fn run_headless(args: HeadlessCoverageArgs) -> Result<(), anyhow::Error> {
+    let body = fs::read_to_string(&args.tle_path)?;
+    let lines = clean_tle_lines(&body);
+    let snapshots: Vec<TleSnapshot> = parse_tle_pairs(&lines)
+        .into_iter()
+        .filter_map(|entry| {
+            let epoch_utc = parse_tle_epoch_to_utc(&entry.line1)?;
+            Some(TleSnapshot {
+                norad: entry.norad,
+                line1: entry.line1,
+                line2: entry.line2,
+                epoch_utc,
+            })
+        })
+        .collect();
+    if snapshots.is_empty() {
+        anyhow::bail!("No TLEs found in {}", args.tle_path.display());
+    }
+
+    println!(
+        "[COVERAGE] headless run: {} TLEs from {}",
+        snapshots.len(),
+        args.tle_path.display()
+    );
+    let result = run_coverage(&args.request, &snapshots);
+    result.write_csv(&args.out_path)?;
+    println!("[COVERAGE] {}", result.summary());
+    println!("[COVERAGE] wrote {}", args.out_path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record_pattern(pattern: &str, step_s: f64) -> CellStats {
+        let mut stats = CellStats::default();
+        for c in pattern.chars() {
+            stats.record(c == '#', step_s);
+        }
+        stats.finish();
+        stats
+    }
+
+    #[test]
+    fn test_cell_stats_accesses_and_gaps() {
+        // 2 leading gap, access, 3 gap, access, 1 trailing gap
+        let stats = record_pattern("..##...#.", 60.0);
+        assert_eq!(stats.access_count, 2);
+        assert_eq!(stats.covered_samples, 3);
+        assert!((stats.max_gap_s - 180.0).abs() < 1e-9);
+        assert!((stats.mean_gap_s().unwrap() - 180.0).abs() < 1e-9);
+        assert!((stats.percent_covered(9) - 100.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cell_stats_never_covered() {
+        let stats = record_pattern("......", 30.0);
+        assert_eq!(stats.access_count, 0);
+        assert!((stats.max_gap_s - 180.0).abs() < 1e-9);
+        assert!(stats.mean_gap_s().is_none());
+        assert_eq!(stats.percent_covered(6), 0.0);
+    }
+
+    #[test]
+    fn test_cell_stats_trailing_gap_counts_toward_max() {
+        let stats = record_pattern("#.#.....", 10.0);
+        assert_eq!(stats.access_count, 2);
+        assert!((stats.max_gap_s - 50.0).abs() < 1e-9);
+        assert!((stats.mean_gap_s().unwrap() - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_region_grid_and_lookup() {
+        let region = CoverageRegion {
+            lat_min_deg: 0.0,
+            lat_max_deg: 10.0,
+            lon_min_deg: 20.0,
+            lon_max_deg: 40.0,
+            cell_size_deg: 5.0,
+        };
+        assert_eq!(region.dimensions(), (2, 4));
+
+        let cells = region.cells();
+        assert_eq!(cells.len(), 8);
+        assert!((cells[0].lat_deg - 2.5).abs() < 1e-9);
+        assert!((cells[0].lon_deg - 22.5).abs() < 1e-9);
+        assert!((cells[7].lat_deg - 7.5).abs() < 1e-9);
+        assert!((cells[7].lon_deg - 37.5).abs() < 1e-9);
+
+        assert_eq!(region.cell_index(2.0, 21.0), Some(0));
+        assert_eq!(region.cell_index(9.0, 39.0), Some(7));
+        assert_eq!(region.cell_index(10.0, 40.0), Some(7));
+        assert_eq!(region.cell_index(-1.0, 30.0), None);
+    }
+
+    #[test]
+    fn test_csv_header_and_rows() {
+        let region = CoverageRegion {
+            lat_min_deg: 0.0,
+            lat_max_deg: 5.0,
+            lon_min_deg: 0.0,
+            lon_max_deg: 10.0,
+            cell_size_deg: 5.0,
+        };
+        let cells = region.cells();
+        let stats = vec![record_pattern("#..#", 60.0), record_pattern("....", 60.0)];
+        let result = CoverageResult {
+            region,
+            start_utc: Utc::now(),
+            end_utc: Utc::now(),
+            min_elevation_deg: 0.0,
+            samples: 4,
+            satellite_count: 1,
+            cells,
+            stats,
+        };
+
+        let csv = result.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("lat_deg,lon_deg,percent_covered"));
+        assert_eq!(lines[1], "2.500,2.500,50.00,120.0,120.0,2");
+        assert_eq!(lines[2], "2.500,7.500,0.00,240.0,,0");
+    }
+
+    #[test]
+    fn test_parse_headless_args() {
+        let args: Vec<String> = [
+            "--coverage",
+            "sats.tle",
+            "--hours",
+            "6",
+            "--region",
+            "-10,10,0,30",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        let parsed = parse_headless_args(&args).unwrap().unwrap();
+        assert_eq!(parsed.tle_path, PathBuf::from("sats.tle"));
+        assert_eq!(parsed.request.duration, Duration::hours(6));
+        assert_eq!(parsed.request.region.lat_min_deg, -10.0);
+        assert_eq!(parsed.request.region.lon_max_deg, 30.0);
+
+        assert!(parse_headless_args(&["--other".to_string()]).is_none());
+        let missing = vec!["--coverage".to_string()];
+        assert!(parse_headless_args(&missing).unwrap().is_err());
+    }
+}
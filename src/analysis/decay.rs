@@ -0,0 +1,178 @@
+//! Re-entry prediction for loaded satellites
+//!
+//! Whenever a low satellite's TLE changes, its element history is read from
+//! the disk cache in a background task and the resulting decay prediction is
+//! attached to the entity as a `ReentryPrediction`.
+
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task, block_on};
+use chrono::{DateTime, Utc};
+
+use crate::analysis::TleSnapshot;
+use crate::core::space::EARTH_RADIUS_KM_F64;
+use crate::orbital::decay::{
+    DecayPrediction, MAX_DECAY_ALTITUDE_KM, predict_decay, semi_major_axis_km,
+};
+use crate::satellite::components::{NoradId, Satellite, TleComponent};
+use crate::satellite::resources::NoradIndex;
+use crate::tle::TleCacheConfig;
+use crate::tle::cache::TleCache;
+use crate::tle::parser::parse_tle_mean_elements;
+
+/// Predicted re-entry for a satellite low enough to decay
+#[derive(Component, Clone, Debug)]
+pub struct ReentryPrediction(pub DecayPrediction);
+
+/// Configuration for re-entry flagging
+#[derive(Resource, Clone, Debug)]
+pub struct DecayConfig {
+    /// Satellites whose earliest re-entry falls within this many days are flagged
+    pub flag_within_days: f32,
+}
+
+impl Default for DecayConfig {
+    fn default() -> Self {
+        Self {
+            flag_within_days: 30.0,
+        }
+    }
+}
+
+impl DecayConfig {
+    /// Whether a prediction falls inside the flagging window at `utc`
+    pub fn is_flagged(&self, prediction: &DecayPrediction, utc: DateTime<Utc>) -> bool {
+        let earliest_days = (prediction.earliest_utc - utc).num_seconds() as f64 / 86_400.0;
+        earliest_days <= f64::from(self.flag_within_days)
+    }
+}
+
+/// Predictions from one background task, by NORAD ID
+type DecayBatch = Vec<(u32, Option<DecayPrediction>)>;
+
+/// Runtime state for background decay predictions
+#[derive(Resource, Default)]
+pub struct DecayState {
+    /// Satellites whose TLE changed since the last task started
+    pub queued: Vec<TleSnapshot>,
+    pub pending_task: Option<Task<DecayBatch>>,
+}
+
+/// Plugin for re-entry prediction
+pub struct DecayPlugin;
+
+impl Plugin for DecayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DecayConfig>()
+            .init_resource::<DecayState>()
+            .add_systems(
+                Update,
+                (queue_decay_predictions, start_decay_task, poll_decay_task).chain(),
+            );
+    }
+}
+
+/// Whether a TLE describes an orbit low enough to be assessed
+fn is_low_orbit(snapshot: &TleSnapshot) -> bool {
+    parse_tle_mean_elements(&snapshot.line1, &snapshot.line2).is_some_and(|elements| {
+        semi_major_axis_km(elements.mean_motion_rev_per_day) - EARTH_RADIUS_KM_F64
+            <= MAX_DECAY_ALTITUDE_KM
+    })
+}
+
+#[allow(clippy::type_complexity)]
+fn queue_decay_predictions(
+    mut state: ResMut<DecayState>,
+    changed: Query<(Entity, &NoradId, &TleComponent), (With<Satellite>, Changed<TleComponent>)>,
+    mut commands: Commands,
+) {
+    for (entity, norad, tle) in &changed {
+        let snapshot = TleSnapshot::from_tle(norad.0, &tle.0);
+        if is_low_orbit(&snapshot) {
+            state.queued.retain(|queued| queued.norad != norad.0);
+            state.queued.push(snapshot);
+        } else {
+            commands.entity(entity).remove::<ReentryPrediction>();
+        }
+    }
+}
+
+fn start_decay_task(mut state: ResMut<DecayState>, cache_config: Res<TleCacheConfig>) {
+    if state.pending_task.is_some() || state.queued.is_empty() {
+        return;
+    }
+    let snapshots = std::mem::take(&mut state.queued);
+    let use_history = cache_config.enabled;
+    let expiration_days = cache_config.expiration_days;
+
+    let task = AsyncComputeTaskPool::get().spawn(async move {
+        let cache = if use_history {
+            TleCache::new(expiration_days)
+                .map_err(|e| eprintln!("[DECAY] history unavailable: {e}"))
+                .ok()
+        } else {
+            None
+        };
+        snapshots
+            .iter()
+            .map(|snap| {
+                let prediction =
+                    parse_tle_mean_elements(&snap.line1, &snap.line2).and_then(|latest| {
+                        let mut history: Vec<_> = cache
+                            .as_ref()
+                            .and_then(|cache| cache.read_history(snap.norad).ok())
+                            .unwrap_or_default()
+                            .iter()
+                            .filter_map(|h| parse_tle_mean_elements(&h.line1, &h.line2))
+                            .collect();
+                        if !history.iter().any(|h| h.epoch_utc == latest.epoch_utc) {
+                            history.push(latest);
+                        }
+                        predict_decay(&history, &latest)
+                    });
+                (snap.norad, prediction)
+            })
+            .collect()
+    });
+    state.pending_task = Some(task);
+}
+
+fn poll_decay_task(
+    mut state: ResMut<DecayState>,
+    norad_index: Res<NoradIndex>,
+    mut commands: Commands,
+) {
+    // Only touch the resource mutably once the task is done
+    if !state
+        .pending_task
+        .as_ref()
+        .is_some_and(|task| task.is_finished())
+    {
+        return;
+    }
+    let Some(task) = state.pending_task.take() else {
+        return;
+    };
+
+    let results = block_on(task);
+    let mut predicted = 0;
+    for (norad, prediction) in results {
+        let Some(&entity) = norad_index.map.get(&norad) else {
+            continue;
+        };
+        let Ok(mut ec) = commands.get_entity(entity) else {
+            continue;
+        };
+        match prediction {
+            Some(prediction) => {
+                predicted += 1;
+                ec.insert(ReentryPrediction(prediction));
+            }
+            None => {
+                ec.remove::<ReentryPrediction>();
+            }
+        }
+    }
+    if predicted > 0 {
+        println!("[DECAY] {predicted} satellites with a predicted re-entry");
+    }
+}
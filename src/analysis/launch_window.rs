@@ -0,0 +1,233 @@
+//! Launch window calculator
+//!
+//! Lists the daily instantaneous launch opportunities from a ground site (the
+//! picked analysis site or the pad of the selected launch) into a target plane
+//! (the detail satellite's, or an inclination and RAAN entered by hand).
+
+use bevy::prelude::*;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::analysis::AnalysisSite;
+use crate::analysis::radio::detail_target_norad;
+use crate::launch_library::LaunchLibraryData;
+use crate::orbital::launch_window::{LaunchOpportunity, TargetPlane, launch_opportunities};
+use crate::orbital::{Dut1, SimulationTime};
+use crate::satellite::SelectedSatellite;
+use crate::satellite::components::{NoradId, Satellite, SatelliteFlags, TleComponent};
+use crate::satellite::resources::NoradIndex;
+use crate::tle::parser::parse_tle_mean_elements;
+use crate::ui::state::LaunchLibraryUiState;
+
+/// Where the vehicle launches from
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LaunchWindowSite {
+    /// Ground point picked on the globe
+    #[default]
+    AnalysisSite,
+    /// Pad of the selected launch (click a pad marker or a launch in the list)
+    SelectedPad,
+}
+
+impl LaunchWindowSite {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::AnalysisSite => "Picked site",
+            Self::SelectedPad => "Selected launch pad",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::AnalysisSite => Self::SelectedPad,
+            Self::SelectedPad => Self::AnalysisSite,
+        }
+    }
+}
+
+/// Which orbit plane to launch into
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LaunchWindowTarget {
+    /// Plane of the tracked (otherwise last clicked) satellite
+    #[default]
+    SelectedSatellite,
+    /// Inclination and RAAN from the sliders, fixed in inertial space
+    Plane,
+}
+
+impl LaunchWindowTarget {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::SelectedSatellite => "Selected satellite",
+            Self::Plane => "Inclination / RAAN",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::SelectedSatellite => Self::Plane,
+            Self::Plane => Self::SelectedSatellite,
+        }
+    }
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct LaunchWindowConfig {
+    pub site: LaunchWindowSite,
+    pub target: LaunchWindowTarget,
+    pub inclination_deg: f32,
+    pub raan_deg: f32,
+    pub days: f32,
+}
+
+impl Default for LaunchWindowConfig {
+    fn default() -> Self {
+        Self {
+            site: LaunchWindowSite::default(),
+            target: LaunchWindowTarget::default(),
+            inclination_deg: 51.6,
+            raan_deg: 0.0,
+            days: 3.0,
+        }
+    }
+}
+
+/// Opportunities found by the last run
+#[derive(Clone, Debug)]
+pub struct LaunchWindowReport {
+    pub site_label: String,
+    pub target_label: String,
+    pub start_utc: DateTime<Utc>,
+    pub end_utc: DateTime<Utc>,
+    pub opportunities: Vec<LaunchOpportunity>,
+}
+
+impl LaunchWindowReport {
+    pub fn summary(&self) -> String {
+        format!(
+            "{} into {}: {} opportunities {} to {}",
+            self.site_label,
+            self.target_label,
+            self.opportunities.len(),
+            self.start_utc.format("%Y-%m-%d"),
+            self.end_utc.format("%Y-%m-%d")
+        )
+    }
+}
+
+/// Runtime state for the launch window calculator
+#[derive(Resource, Default)]
+pub struct LaunchWindowState {
+    pub run_requested: bool,
+    pub report: Option<LaunchWindowReport>,
+    pub status: Option<String>,
+}
+
+/// Plugin for the launch window calculator
+pub struct LaunchWindowPlugin;
+
+impl Plugin for LaunchWindowPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LaunchWindowConfig>()
+            .init_resource::<LaunchWindowState>()
+            .add_systems(Update, run_launch_window);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_launch_window(
+    mut state: ResMut<LaunchWindowState>,
+    config: Res<LaunchWindowConfig>,
+    site: Res<AnalysisSite>,
+    launch_data: Res<LaunchLibraryData>,
+    launch_ui: Res<LaunchLibraryUiState>,
+    sim_time: Res<SimulationTime>,
+    dut1: Res<Dut1>,
+    selected: Res<SelectedSatellite>,
+    norad_index: Res<NoradIndex>,
+    satellites: Query<(&NoradId, &SatelliteFlags, Option<&TleComponent>), With<Satellite>>,
+) {
+    if !state.run_requested {
+        return;
+    }
+    state.run_requested = false;
+
+    let ground = match config.site {
+        LaunchWindowSite::AnalysisSite => site
+            .lat_lon_deg
+            .map(|(lat, lon)| (lat, lon, format!("Site {lat:.2}, {lon:.2}"))),
+        LaunchWindowSite::SelectedPad => launch_ui
+            .selection
+            .and_then(|selection| selection.launch(&launch_data))
+            .and_then(|launch| {
+                let label = launch.pad_name.clone().unwrap_or_else(|| "Pad".to_string());
+                Some((launch.pad_lat?, launch.pad_lon?, label))
+            }),
+    };
+    let Some((lat, lon, site_label)) = ground else {
+        state.status = Some(match config.site {
+            LaunchWindowSite::AnalysisSite => "Pick a site on the globe first".to_string(),
+            LaunchWindowSite::SelectedPad => "Select a launch with a known pad".to_string(),
+        });
+        return;
+    };
+
+    let target = match config.target {
+        LaunchWindowTarget::SelectedSatellite => detail_target_norad(
+            &selected,
+            &norad_index,
+            satellites
+                .iter()
+                .map(|(norad, flags, _)| (norad.0, flags.is_clicked)),
+        )
+        .and_then(|norad| {
+            satellites
+                .iter()
+                .find(|(n, ..)| n.0 == norad)
+                .and_then(|(_, _, tle)| tle)
+                .and_then(|tle| parse_tle_mean_elements(&tle.0.line1, &tle.0.line2))
+                .map(|elements| {
+                    (
+                        TargetPlane::from_elements(&elements),
+                        format!("NORAD {norad}"),
+                    )
+                })
+        }),
+        LaunchWindowTarget::Plane => Some((
+            TargetPlane {
+                inclination_deg: f64::from(config.inclination_deg),
+                raan_deg: f64::from(config.raan_deg),
+                raan_epoch_utc: sim_time.current_utc,
+                raan_rate_deg_per_day: 0.0,
+            },
+            format!(
+                "i {:.1} RAAN {:.1}",
+                config.inclination_deg, config.raan_deg
+            ),
+        )),
+    };
+    let Some((plane, target_label)) = target else {
+        state.status = Some("Select or track a satellite with a TLE".to_string());
+        return;
+    };
+
+    let days = f64::from(config.days.max(1.0));
+    let start_utc = sim_time.current_utc;
+    let opportunities = launch_opportunities(lat, lon, &plane, start_utc, days, dut1.0);
+    let report = LaunchWindowReport {
+        site_label,
+        target_label,
+        start_utc,
+        end_utc: start_utc + Duration::seconds((days * 86_400.0) as i64),
+        opportunities,
+    };
+    println!("[LAUNCH WINDOW] {}", report.summary());
+    state.status = Some(if report.opportunities.is_empty() {
+        format!(
+            "Inclination {:.1} deg can't be reached directly from latitude {lat:.1}",
+            plane.inclination_deg
+        )
+    } else {
+        report.summary()
+    });
+    state.report = Some(report);
+}
@@ -0,0 +1,180 @@
+//! Beta angle and eclipse statistics for thermal and power analysis
+//!
+//! For the detail satellite (tracked, otherwise last clicked) the orbit plane is
+//! carried over a date range from its TLE and compared with the Sun to give
+//! beta angle, eclipse minutes per orbit and eclipse seasons.
+
+use bevy::prelude::*;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::analysis::radio::detail_target_norad;
+use crate::orbital::SimulationTime;
+use crate::orbital::beta::{EclipseSeason, LightingSample, eclipse_seasons, lighting_profile};
+use crate::satellite::SelectedSatellite;
+use crate::satellite::components::{NoradId, Satellite, SatelliteFlags, TleComponent};
+use crate::satellite::resources::NoradIndex;
+use crate::tle::parser::parse_tle_mean_elements;
+
+/// Roughly this many samples are taken over the date range
+const TARGET_SAMPLES: f64 = 400.0;
+
+/// Date range for the lighting profile
+#[derive(Resource, Clone, Debug)]
+pub struct LightingConfig {
+    pub days: f32,
+}
+
+impl Default for LightingConfig {
+    fn default() -> Self {
+        Self { days: 365.0 }
+    }
+}
+
+/// Beta angle and eclipse history of one satellite
+#[derive(Clone, Debug)]
+pub struct LightingProfile {
+    pub norad: u32,
+    pub start_utc: DateTime<Utc>,
+    pub end_utc: DateTime<Utc>,
+    pub period_min: f64,
+    pub samples: Vec<LightingSample>,
+    pub seasons: Vec<EclipseSeason>,
+}
+
+impl LightingProfile {
+    pub fn beta_range_deg(&self) -> (f64, f64) {
+        self.samples
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(lo, hi), s| {
+                (lo.min(s.beta_deg), hi.max(s.beta_deg))
+            })
+    }
+
+    pub fn max_eclipse_minutes(&self) -> f64 {
+        self.samples
+            .iter()
+            .map(|s| s.eclipse_minutes)
+            .fold(0.0, f64::max)
+    }
+
+    /// Share of the whole range spent in shadow
+    pub fn mean_eclipse_fraction(&self) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        self.samples.iter().map(|s| s.eclipse_fraction).sum::<f64>() / self.samples.len() as f64
+    }
+
+    pub fn summary(&self) -> String {
+        let (beta_min, beta_max) = self.beta_range_deg();
+        format!(
+            "Beta {beta_min:+.1} to {beta_max:+.1} deg  eclipse max {:.1} min/orbit ({:.0}% sunlit)",
+            self.max_eclipse_minutes(),
+            100.0 * (1.0 - self.mean_eclipse_fraction())
+        )
+    }
+
+    /// One line per eclipse season
+    pub fn seasons_text(&self) -> String {
+        if self.seasons.is_empty() {
+            return "No eclipses in range (full sun)".to_string();
+        }
+        self.seasons
+            .iter()
+            .map(|season| {
+                if season.start_utc == self.start_utc && season.end_utc == self.end_utc {
+                    format!(
+                        "Eclipses every orbit, up to {:.1} min",
+                        season.max_eclipse_minutes
+                    )
+                } else {
+                    format!(
+                        "{} to {}  up to {:.1} min",
+                        season.start_utc.format("%Y-%m-%d"),
+                        season.end_utc.format("%Y-%m-%d"),
+                        season.max_eclipse_minutes
+                    )
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Runtime state for the lighting analysis
+#[derive(Resource, Default)]
+pub struct LightingState {
+    pub run_requested: bool,
+    pub profile: Option<LightingProfile>,
+    pub status: Option<String>,
+}
+
+/// Plugin for beta angle and eclipse statistics
+pub struct LightingPlugin;
+
+impl Plugin for LightingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LightingConfig>()
+            .init_resource::<LightingState>()
+            .add_systems(Update, run_lighting_analysis);
+    }
+}
+
+fn run_lighting_analysis(
+    mut state: ResMut<LightingState>,
+    config: Res<LightingConfig>,
+    sim_time: Res<SimulationTime>,
+    selected: Res<SelectedSatellite>,
+    norad_index: Res<NoradIndex>,
+    satellites: Query<(&NoradId, &SatelliteFlags, Option<&TleComponent>), With<Satellite>>,
+) {
+    if !state.run_requested {
+        return;
+    }
+    state.run_requested = false;
+
+    let target = detail_target_norad(
+        &selected,
+        &norad_index,
+        satellites
+            .iter()
+            .map(|(norad, flags, _)| (norad.0, flags.is_clicked)),
+    );
+    let Some((norad, elements)) = target.and_then(|norad| {
+        satellites
+            .iter()
+            .find(|(n, ..)| n.0 == norad)
+            .and_then(|(_, _, tle)| tle)
+            .and_then(|tle| parse_tle_mean_elements(&tle.0.line1, &tle.0.line2))
+            .map(|elements| (norad, elements))
+    }) else {
+        state.status = Some("Select or track a satellite with a TLE".to_string());
+        return;
+    };
+
+    let days = f64::from(config.days.max(1.0));
+    let step_hours = (days * 24.0 / TARGET_SAMPLES).max(1.0);
+    let start_utc = sim_time.current_utc;
+    let samples = lighting_profile(&elements, start_utc, days, step_hours);
+    let profile = LightingProfile {
+        norad,
+        start_utc,
+        end_utc: samples.last().map_or(start_utc, |s| s.utc),
+        period_min: 1440.0 / elements.mean_motion_rev_per_day,
+        seasons: eclipse_seasons(&samples),
+        samples,
+    };
+    println!(
+        "[LIGHTING] norad={norad} {} days: {} ({} eclipse seasons)",
+        days,
+        profile.summary(),
+        profile.seasons.len()
+    );
+    state.status = Some(format!(
+        "{} to {}: {}",
+        start_utc.format("%Y-%m-%d"),
+        (start_utc + Duration::seconds((days * 86_400.0) as i64)).format("%Y-%m-%d"),
+        profile.summary()
+    ));
+    state.profile = Some(profile);
+}
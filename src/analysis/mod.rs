@@ -0,0 +1,108 @@
+//! Analysis module
+//!
+//! Longer-running orbital analyses (coverage statistics, ground-point revisit,
+//! re-entry prediction, radio passes, beta angle and eclipses, TLE fitting,
+//! launch windows) that run over loaded satellites in a background task.
+
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use chrono::{DateTime, Utc};
+
+pub mod coverage;
+pub mod decay;
+pub mod launch_window;
+pub mod lighting;
+pub mod radio;
+pub mod revisit;
+pub mod tle_fit;
+
+use crate::orbital::{constants_from_tle, propagate_ecef_km, propagate_ecef_state_km};
+use crate::tle::TleData;
+pub use coverage::{CoverageConfig, CoverageMetric, CoveragePlugin, CoverageState};
+pub use decay::{DecayConfig, DecayPlugin, ReentryPrediction};
+pub use launch_window::{LaunchWindowConfig, LaunchWindowPlugin, LaunchWindowState};
+pub use lighting::{LightingConfig, LightingPlugin, LightingState};
+pub use radio::{RadioConfig, RadioPlugin, RadioState, TransmitterDb};
+pub use revisit::{RevisitConfig, RevisitPlugin, RevisitState, RevisitTargets};
+
+/// TLE snapshot that can be moved into a background task
+#[derive(Clone, Debug)]
+pub struct TleSnapshot {
+    pub norad: u32,
+    pub line1: String,
+    pub line2: String,
+    pub epoch_utc: DateTime<Utc>,
+}
+
+impl TleSnapshot {
+    pub fn from_tle(norad: u32, tle: &TleData) -> Self {
+        Self {
+            norad,
+            line1: tle.line1.clone(),
+            line2: tle.line2.clone(),
+            epoch_utc: tle.epoch_utc,
+        }
+    }
+}
+
+/// SGP4 model rebuilt from a snapshot
+pub struct SnapshotPropagator {
+    pub norad: u32,
+    pub epoch_utc: DateTime<Utc>,
+    pub constants: sgp4::Constants,
+}
+
+impl SnapshotPropagator {
+    /// Build propagators for all snapshots, skipping ones SGP4 rejects
+    pub fn build_all(snapshots: &[TleSnapshot]) -> Vec<Self> {
+        snapshots
+            .iter()
+            .filter_map(|snap| match constants_from_tle(&snap.line1, &snap.line2) {
+                Ok(constants) => Some(Self {
+                    norad: snap.norad,
+                    epoch_utc: snap.epoch_utc,
+                    constants,
+                }),
+                Err(e) => {
+                    eprintln!("[ANALYSIS] norad={} skipped: {e}", snap.norad);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// ECEF position (km) at the given instant
+    pub fn ecef_km(&self, utc: DateTime<Utc>, dut1_seconds: f64) -> Option<DVec3> {
+        propagate_ecef_km(&self.constants, self.epoch_utc, utc, dut1_seconds)
+    }
+
+    /// ECEF position (km) and velocity (km/s) at the given instant
+    pub fn ecef_state_km(&self, utc: DateTime<Utc>, dut1_seconds: f64) -> Option<(DVec3, DVec3)> {
+        propagate_ecef_state_km(&self.constants, self.epoch_utc, utc, dut1_seconds)
+    }
+}
+
+/// Ground point picked on the globe for point analyses
+#[derive(Resource, Default, Clone, Debug)]
+pub struct AnalysisSite {
+    /// Picked location (lat, lon in degrees), None until the user clicks the globe
+    pub lat_lon_deg: Option<(f64, f64)>,
+    /// When set, the next globe click moves the site
+    pub picking: bool,
+}
+
+/// Plugin for background analyses
+pub struct AnalysisPlugin;
+
+impl Plugin for AnalysisPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AnalysisSite>().add_plugins((
+            CoveragePlugin,
+            RevisitPlugin,
+            DecayPlugin,
+            RadioPlugin,
+            LightingPlugin,
+            LaunchWindowPlugin,
+        ));
+    }
+}
@@ -0,0 +1,471 @@
+//! Radio pass planning with Doppler-corrected frequencies
+//!
+//! Transmitter frequencies come from a local JSON file in the SatNOGS DB
+//! transmitter format (`transmitters.json` in the app data directory, or
+//! `--transmitters <file>`). For the detail satellite and the analysis site the
+//! next pass is sampled in a background task into a Doppler curve.
+
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task, block_on};
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::analysis::revisit::{find_intervals, site_ecef_km};
+use crate::analysis::{AnalysisSite, SnapshotPropagator, TleSnapshot};
+use crate::core::coordinates::elevation_deg_ecef_dvec;
+use crate::core::paths::data_dir;
+use crate::orbital::doppler::{downlink_hz, range_and_rate_km, uplink_hz};
+use crate::orbital::{Dut1, SimulationTime};
+use crate::satellite::SelectedSatellite;
+use crate::satellite::components::{NoradId, Satellite, SatelliteFlags, TleComponent};
+use crate::satellite::resources::NoradIndex;
+
+/// How far ahead to look for the next pass
+const PASS_SEARCH_HOURS: i64 = 24;
+/// Coarse step for finding the pass before rise/set refinement
+const PASS_SEARCH_STEP_S: f64 = 30.0;
+
+/// One transmitter entry in SatNOGS DB format (unused fields are ignored)
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct Transmitter {
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub alive: bool,
+    /// "active", "inactive" or "invalid"
+    #[serde(default)]
+    pub status: String,
+    pub norad_cat_id: Option<u32>,
+    pub uplink_low: Option<u64>,
+    pub uplink_high: Option<u64>,
+    pub downlink_low: Option<u64>,
+    pub downlink_high: Option<u64>,
+    pub mode: Option<String>,
+    /// Inverting transponder
+    #[serde(default)]
+    pub invert: bool,
+}
+
+impl Transmitter {
+    /// Centre of a low/high pair, or the single frequency when only one is set
+    fn center_hz(low: Option<u64>, high: Option<u64>) -> Option<f64> {
+        match (low, high) {
+            (Some(low), Some(high)) => Some(0.5 * (low as f64 + high as f64)),
+            (Some(freq), None) | (None, Some(freq)) => Some(freq as f64),
+            (None, None) => None,
+        }
+    }
+
+    pub fn downlink_center_hz(&self) -> Option<f64> {
+        Self::center_hz(self.downlink_low, self.downlink_high)
+    }
+
+    pub fn uplink_center_hz(&self) -> Option<f64> {
+        Self::center_hz(self.uplink_low, self.uplink_high)
+    }
+
+    pub fn label(&self) -> String {
+        match (&self.mode, self.invert) {
+            (Some(mode), true) => format!("{} ({mode}, inverting)", self.description),
+            (Some(mode), false) => format!("{} ({mode})", self.description),
+            (None, true) => format!("{} (inverting)", self.description),
+            (None, false) => self.description.clone(),
+        }
+    }
+}
+
+/// Transmitters grouped by NORAD ID
+#[derive(Resource, Default, Debug)]
+pub struct TransmitterDb {
+    pub by_norad: HashMap<u32, Vec<Transmitter>>,
+    /// File the database was read from
+    pub source: Option<PathBuf>,
+    pub error: Option<String>,
+}
+
+impl TransmitterDb {
+    /// Parse a SatNOGS DB transmitter export, keeping alive, non-invalid entries with a frequency
+    pub fn parse(body: &str) -> Result<Self, anyhow::Error> {
+        let transmitters: Vec<Transmitter> = serde_json::from_str(body)?;
+        let mut by_norad: HashMap<u32, Vec<Transmitter>> = HashMap::new();
+        for transmitter in transmitters {
+            let usable = transmitter.alive
+                && transmitter.status != "invalid"
+                && (transmitter.downlink_center_hz().is_some()
+                    || transmitter.uplink_center_hz().is_some());
+            if let Some(norad) = transmitter.norad_cat_id
+                && usable
+            {
+                by_norad.entry(norad).or_default().push(transmitter);
+            }
+        }
+        for list in by_norad.values_mut() {
+            // Active entries first, then by downlink frequency
+            list.sort_by(|a, b| {
+                (a.status != "active")
+                    .cmp(&(b.status != "active"))
+                    .then(a.downlink_low.cmp(&b.downlink_low))
+            });
+        }
+        Ok(Self {
+            by_norad,
+            source: None,
+            error: None,
+        })
+    }
+
+    pub fn for_norad(&self, norad: u32) -> &[Transmitter] {
+        self.by_norad.get(&norad).map_or(&[][..], Vec::as_slice)
+    }
+
+    /// Transmitter at `index`, wrapping around the satellite's list
+    pub fn pick(&self, norad: u32, index: usize) -> Option<(usize, &Transmitter)> {
+        let list = self.for_norad(norad);
+        if list.is_empty() {
+            return None;
+        }
+        let index = index % list.len();
+        Some((index, &list[index]))
+    }
+}
+
+/// Configuration resource for radio pass planning
+#[derive(Resource, Clone, Debug, Default)]
+pub struct RadioConfig {
+    /// Transmitter database; defaults to `transmitters.json` in the data directory
+    pub database_path: Option<PathBuf>,
+    /// Chosen transmitter of the detail satellite (wraps around its list)
+    pub transmitter_index: usize,
+    pub min_elevation_deg: f32,
+}
+
+/// Doppler state at one instant of a pass
+#[derive(Clone, Debug, PartialEq)]
+pub struct DopplerSample {
+    pub utc: DateTime<Utc>,
+    pub elevation_deg: f64,
+    pub range_km: f64,
+    pub range_rate_km_s: f64,
+    pub downlink_hz: Option<f64>,
+    pub uplink_hz: Option<f64>,
+}
+
+/// Doppler curve over one pass of a satellite over the analysis site
+#[derive(Clone, Debug)]
+pub struct DopplerPass {
+    pub norad: u32,
+    pub transmitter: Option<String>,
+    pub downlink_nominal_hz: Option<f64>,
+    pub aos_utc: DateTime<Utc>,
+    pub los_utc: DateTime<Utc>,
+    pub max_elevation_deg: f64,
+    pub samples: Vec<DopplerSample>,
+}
+
+impl DopplerPass {
+    /// Downlink shift range (Hz) over the pass, or None without a downlink
+    pub fn downlink_shift_range_hz(&self) -> Option<(f64, f64)> {
+        let nominal = self.downlink_nominal_hz?;
+        self.samples
+            .iter()
+            .filter_map(|s| s.downlink_hz)
+            .map(|hz| hz - nominal)
+            .fold(None, |range, shift| match range {
+                None => Some((shift, shift)),
+                Some((lo, hi)) => Some((f64::min(lo, shift), f64::max(hi, shift))),
+            })
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "AOS {}  LOS {}  max el {:.0} deg",
+            self.aos_utc.format("%m-%d %H:%M:%S"),
+            self.los_utc.format("%H:%M:%S"),
+            self.max_elevation_deg
+        )
+    }
+}
+
+/// Parameters for a pass search, copied into the background task
+#[derive(Clone, Debug)]
+pub struct RadioPassRequest {
+    pub lat_deg: f64,
+    pub lon_deg: f64,
+    pub start_utc: DateTime<Utc>,
+    pub min_elevation_deg: f64,
+    pub sample_step_s: i64,
+    pub dut1_seconds: f64,
+    pub transmitter: Option<Transmitter>,
+}
+
+/// Find the current or next pass and sample its Doppler curve
+pub fn plan_doppler_pass(
+    request: &RadioPassRequest,
+    snapshot: &TleSnapshot,
+) -> Option<DopplerPass> {
+    let propagator = SnapshotPropagator::build_all(std::slice::from_ref(snapshot)).pop()?;
+    let site = site_ecef_km(request.lat_deg, request.lon_deg);
+    let elevation = |utc: DateTime<Utc>| {
+        propagator
+            .ecef_km(utc, request.dut1_seconds)
+            .map_or(-90.0, |sat| elevation_deg_ecef_dvec(site, sat))
+    };
+    let (aos_utc, los_utc, _) = find_intervals(
+        elevation,
+        request.start_utc,
+        request.start_utc + Duration::hours(PASS_SEARCH_HOURS),
+        PASS_SEARCH_STEP_S,
+        request.min_elevation_deg,
+    )
+    .into_iter()
+    .next()?;
+
+    let transmitter = request.transmitter.as_ref();
+    let downlink_nominal_hz = transmitter.and_then(Transmitter::downlink_center_hz);
+    let uplink_nominal_hz = transmitter.and_then(Transmitter::uplink_center_hz);
+    let step = Duration::seconds(request.sample_step_s.max(1));
+    let mut samples = Vec::new();
+    let mut utc = aos_utc;
+    loop {
+        if let Some((position, velocity)) = propagator.ecef_state_km(utc, request.dut1_seconds) {
+            let (range_km, range_rate_km_s) = range_and_rate_km(site, position, velocity);
+            samples.push(DopplerSample {
+                utc,
+                elevation_deg: elevation_deg_ecef_dvec(site, position),
+                range_km,
+                range_rate_km_s,
+                downlink_hz: downlink_nominal_hz.map(|hz| downlink_hz(hz, range_rate_km_s)),
+                uplink_hz: uplink_nominal_hz.map(|hz| uplink_hz(hz, range_rate_km_s)),
+            });
+        }
+        if utc >= los_utc {
+            break;
+        }
+        utc = (utc + step).min(los_utc);
+    }
+    let max_elevation_deg = samples
+        .iter()
+        .map(|s| s.elevation_deg)
+        .fold(request.min_elevation_deg, f64::max);
+
+    Some(DopplerPass {
+        norad: snapshot.norad,
+        transmitter: transmitter.map(Transmitter::label),
+        downlink_nominal_hz,
+        aos_utc,
+        los_utc,
+        max_elevation_deg,
+        samples,
+    })
+}
+
+/// Runtime state for radio pass planning
+#[derive(Resource, Default)]
+pub struct RadioState {
+    pub run_requested: bool,
+    pub pending_task: Option<Task<Option<DopplerPass>>>,
+    pub pass: Option<DopplerPass>,
+    pub status: Option<String>,
+}
+
+/// Plugin for transmitter lookup and Doppler pass planning
+pub struct RadioPlugin;
+
+impl Plugin for RadioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RadioConfig>()
+            .init_resource::<RadioState>()
+            .init_resource::<TransmitterDb>()
+            .add_systems(Startup, load_transmitter_db)
+            .add_systems(Update, (start_radio_pass, poll_radio_pass).chain());
+    }
+}
+
+fn load_transmitter_db(config: Res<RadioConfig>, mut db: ResMut<TransmitterDb>) {
+    let path = match config.database_path.clone() {
+        Some(path) => path,
+        None => match data_dir() {
+            Ok(dir) => dir.join("transmitters.json"),
+            Err(e) => {
+                db.error = Some(e.to_string());
+                return;
+            }
+        },
+    };
+    let loaded = fs::read_to_string(&path)
+        .map_err(anyhow::Error::from)
+        .and_then(|body| TransmitterDb::parse(&body));
+    match loaded {
+        Ok(loaded) => {
+            println!(
+                "[RADIO] {} satellites with transmitters from {}",
+                loaded.by_norad.len(),
+                path.display()
+            );
+            *db = TransmitterDb {
+                source: Some(path),
+                ..loaded
+            };
+        }
+        Err(e) => {
+            println!("[RADIO] no transmitter database at {}: {e}", path.display());
+            db.error = Some(format!("No transmitters loaded from {}", path.display()));
+            db.source = Some(path);
+        }
+    }
+}
+
+/// Satellite shown in the details panel: tracked first, otherwise the last clicked
+pub fn detail_target_norad(
+    selected: &SelectedSatellite,
+    norad_index: &NoradIndex,
+    clicked: impl IntoIterator<Item = (u32, bool)>,
+) -> Option<u32> {
+    selected
+        .tracking
+        .filter(|norad| norad_index.map.contains_key(norad))
+        .or_else(|| {
+            clicked
+                .into_iter()
+                .find_map(|(norad, is_clicked)| is_clicked.then_some(norad))
+        })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn start_radio_pass(
+    mut state: ResMut<RadioState>,
+    config: Res<RadioConfig>,
+    db: Res<TransmitterDb>,
+    site: Res<AnalysisSite>,
+    sim_time: Res<SimulationTime>,
+    dut1: Res<Dut1>,
+    selected: Res<SelectedSatellite>,
+    norad_index: Res<NoradIndex>,
+    satellites: Query<(&NoradId, &SatelliteFlags, Option<&TleComponent>), With<Satellite>>,
+) {
+    if !state.run_requested {
+        return;
+    }
+    state.run_requested = false;
+
+    if state.pending_task.is_some() {
+        state.status = Some("Pass search already running".to_string());
+        return;
+    }
+    let Some((lat_deg, lon_deg)) = site.lat_lon_deg else {
+        state.status = Some("Pick a site in Revisit Analysis first".to_string());
+        return;
+    };
+    let target = detail_target_norad(
+        &selected,
+        &norad_index,
+        satellites
+            .iter()
+            .map(|(norad, flags, _)| (norad.0, flags.is_clicked)),
+    );
+    let Some(snapshot) = target.and_then(|norad| {
+        satellites
+            .iter()
+            .find(|(n, ..)| n.0 == norad)
+            .and_then(|(n, _, tle)| tle.map(|tle| TleSnapshot::from_tle(n.0, &tle.0)))
+    }) else {
+        state.status = Some("Select or track a satellite with a TLE".to_string());
+        return;
+    };
+
+    let request = RadioPassRequest {
+        lat_deg,
+        lon_deg,
+        start_utc: sim_time.current_utc,
+        min_elevation_deg: f64::from(config.min_elevation_deg),
+        sample_step_s: 10,
+        dut1_seconds: **dut1,
+        transmitter: db
+            .pick(snapshot.norad, config.transmitter_index)
+            .map(|(_, transmitter)| transmitter.clone()),
+    };
+    println!(
+        "[RADIO] pass search norad={} site=({lat_deg:.3},{lon_deg:.3})",
+        snapshot.norad
+    );
+    state.status = Some("Searching for the next pass...".to_string());
+    let task =
+        AsyncComputeTaskPool::get().spawn(async move { plan_doppler_pass(&request, &snapshot) });
+    state.pending_task = Some(task);
+}
+
+fn poll_radio_pass(mut state: ResMut<RadioState>) {
+    // Only touch the resource mutably once the task is done
+    if !state
+        .pending_task
+        .as_ref()
+        .is_some_and(|task| task.is_finished())
+    {
+        return;
+    }
+    let Some(task) = state.pending_task.take() else {
+        return;
+    };
+    match block_on(task) {
+        Some(pass) => {
+            println!("[RADIO] norad={} {}", pass.norad, pass.summary());
+            state.status = Some(pass.summary());
+            state.pass = Some(pass);
+        }
+        None => {
+            state.status = Some(format!("No pass within {PASS_SEARCH_HOURS} h"));
+            state.pass = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_DB: &str = r#"[
+        {"uuid": "a", "description": "Mode V/U FM", "alive": true, "type": "Transceiver",
+         "uplink_low": 145990000, "uplink_high": null, "downlink_low": 437800000,
+         "downlink_high": null, "mode": "FM", "invert": false, "baud": null,
+         "norad_cat_id": 25544, "status": "active"},
+        {"uuid": "b", "description": "APRS", "alive": true, "type": "Transmitter",
+         "uplink_low": null, "uplink_high": null, "downlink_low": 145825000,
+         "downlink_high": null, "mode": "AFSK", "norad_cat_id": 25544, "status": "inactive"},
+        {"uuid": "c", "description": "Linear", "alive": true, "uplink_low": 435120000,
+         "uplink_high": 435160000, "downlink_low": 145880000, "downlink_high": 145920000,
+         "mode": "SSB", "invert": true, "norad_cat_id": 7530, "status": "active"},
+        {"uuid": "d", "description": "Dead beacon", "alive": false, "downlink_low": 145000000,
+         "norad_cat_id": 7530, "status": "active"},
+        {"uuid": "e", "description": "No satellite", "alive": true, "downlink_low": 145000000,
+         "norad_cat_id": null, "status": "active"}
+    ]"#;
+
+    #[test]
+    fn test_parse_satnogs_transmitters() {
+        let db = TransmitterDb::parse(SAMPLE_DB).expect("valid JSON");
+        assert_eq!(db.by_norad.len(), 2);
+        let iss = db.for_norad(25544);
+        assert_eq!(iss.len(), 2);
+        // Active transmitters sort first
+        assert_eq!(iss[0].description, "Mode V/U FM");
+        assert_eq!(iss[0].uplink_center_hz(), Some(145_990_000.0));
+        assert_eq!(iss[0].label(), "Mode V/U FM (FM)");
+
+        let linear = db.for_norad(7530);
+        assert_eq!(linear.len(), 1);
+        assert!(linear[0].invert);
+        assert_eq!(linear[0].label(), "Linear (SSB, inverting)");
+        assert_eq!(linear[0].downlink_center_hz(), Some(145_900_000.0));
+
+        // Picking wraps around the list
+        assert_eq!(
+            db.pick(25544, 3).map(|(i, t)| (i, t.description.as_str())),
+            Some((1, "APRS"))
+        );
+        assert!(db.pick(1, 0).is_none());
+        assert!(TransmitterDb::parse("{not json").is_err());
+    }
+}
@@ -0,0 +1,625 @@
+//! Revisit and gap analysis for a single ground point
+//!
+//! Finds every access interval (satellite at or above an elevation mask) over a
+//! time window, refines rise/set times by bisection, merges the accesses of all
+//! satellites and reports the gaps between them.
+
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task, block_on};
+use chrono::{DateTime, Duration, Utc};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::analysis::{AnalysisSite, SnapshotPropagator, TleSnapshot};
+use crate::core::coordinates::elevation_deg_ecef_dvec;
+use crate::core::paths::export_dir;
+use crate::core::space::ecef_to_bevy_km;
+use crate::orbital::{Dut1, SimulationTime};
+use crate::satellite::SelectedSatellite;
+use crate::satellite::components::{NoradId, Satellite, SatelliteGroupUrl, TleComponent};
+
+/// Rise/set times are refined to this precision
+const CROSSING_TOLERANCE_S: f64 = 1.0;
+
+/// One satellite pass above the elevation mask
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessInterval {
+    pub norad: u32,
+    pub start_utc: DateTime<Utc>,
+    pub end_utc: DateTime<Utc>,
+    pub max_elevation_deg: f64,
+}
+
+impl AccessInterval {
+    pub fn duration_s(&self) -> f64 {
+        seconds_between(self.start_utc, self.end_utc)
+    }
+}
+
+/// Interval with no satellite above the mask
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gap {
+    pub start_utc: DateTime<Utc>,
+    pub end_utc: DateTime<Utc>,
+}
+
+impl Gap {
+    pub fn duration_s(&self) -> f64 {
+        seconds_between(self.start_utc, self.end_utc)
+    }
+}
+
+fn seconds_between(start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+    (end - start).num_milliseconds() as f64 / 1000.0
+}
+
+fn offset_utc(start: DateTime<Utc>, seconds: f64) -> DateTime<Utc> {
+    start + Duration::milliseconds((seconds * 1000.0).round() as i64)
+}
+
+/// Find the intervals where `elevation_deg(t) >= mask` inside `[start, end]`.
+///
+/// The function is sampled every `step_s` seconds and each crossing is refined
+/// by bisection. Passes shorter than one step may be missed. Returns
+/// `(start, end, max_elevation_deg)` triples.
+pub fn find_intervals(
+    elevation_deg: impl Fn(DateTime<Utc>) -> f64,
+    start_utc: DateTime<Utc>,
+    end_utc: DateTime<Utc>,
+    step_s: f64,
+    min_elevation_deg: f64,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>, f64)> {
+    let total_s = seconds_between(start_utc, end_utc);
+    if total_s <= 0.0 {
+        return Vec::new();
+    }
+    let step_s = step_s.max(CROSSING_TOLERANCE_S);
+    let above = |el: f64| el >= min_elevation_deg;
+
+    // Bisect between an offset on one side of the mask and one on the other
+    let refine = |mut lo: f64, mut hi: f64, lo_above: bool| {
+        while hi - lo > CROSSING_TOLERANCE_S {
+            let mid = 0.5 * (lo + hi);
+            if above(elevation_deg(offset_utc(start_utc, mid))) == lo_above {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        // Report the side that is above the mask
+        if lo_above { lo } else { hi }
+    };
+
+    let mut intervals = Vec::new();
+    let mut prev_t = 0.0;
+    let first_el = elevation_deg(start_utc);
+    let mut open: Option<(f64, f64)> = above(first_el).then_some((0.0, first_el));
+
+    loop {
+        let t = (prev_t + step_s).min(total_s);
+        let el = elevation_deg(offset_utc(start_utc, t));
+        match (open, above(el)) {
+            (None, true) => {
+                let rise = refine(prev_t, t, false);
+                open = Some((rise, el));
+            }
+            (Some((rise, max_el)), false) => {
+                let set = refine(prev_t, t, true);
+                intervals.push((
+                    offset_utc(start_utc, rise),
+                    offset_utc(start_utc, set),
+                    max_el,
+                ));
+                open = None;
+            }
+            (Some((rise, max_el)), true) => open = Some((rise, max_el.max(el))),
+            (None, false) => {}
+        }
+        prev_t = t;
+        if t >= total_s {
+            break;
+        }
+    }
+
+    if let Some((rise, max_el)) = open {
+        intervals.push((offset_utc(start_utc, rise), end_utc, max_el));
+    }
+    intervals
+}
+
+/// Union of access intervals, sorted by start
+pub fn merge_accesses(accesses: &[AccessInterval]) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut spans: Vec<(DateTime<Utc>, DateTime<Utc>)> =
+        accesses.iter().map(|a| (a.start_utc, a.end_utc)).collect();
+    spans.sort_by_key(|&(start, _)| start);
+
+    let mut merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::with_capacity(spans.len());
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Gaps between merged accesses, including the leading and trailing gaps of the window
+pub fn find_gaps(
+    merged: &[(DateTime<Utc>, DateTime<Utc>)],
+    start_utc: DateTime<Utc>,
+    end_utc: DateTime<Utc>,
+) -> Vec<Gap> {
+    let mut gaps = Vec::new();
+    let mut cursor = start_utc;
+    for &(start, end) in merged {
+        if start > cursor {
+            gaps.push(Gap {
+                start_utc: cursor,
+                end_utc: start,
+            });
+        }
+        cursor = cursor.max(end);
+    }
+    if end_utc > cursor {
+        gaps.push(Gap {
+            start_utc: cursor,
+            end_utc,
+        });
+    }
+    gaps
+}
+
+/// Inputs for a revisit analysis
+#[derive(Clone, Debug)]
+pub struct RevisitRequest {
+    pub lat_deg: f64,
+    pub lon_deg: f64,
+    pub start_utc: DateTime<Utc>,
+    pub duration: Duration,
+    pub step_s: f64,
+    pub min_elevation_deg: f64,
+    pub dut1_seconds: f64,
+}
+
+/// Access and gap report for one ground point
+#[derive(Clone, Debug)]
+pub struct RevisitReport {
+    pub lat_deg: f64,
+    pub lon_deg: f64,
+    pub start_utc: DateTime<Utc>,
+    pub end_utc: DateTime<Utc>,
+    pub min_elevation_deg: f64,
+    pub satellite_count: usize,
+    /// Per-satellite passes, sorted by start time
+    pub accesses: Vec<AccessInterval>,
+    /// Union of all passes
+    pub merged: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+    pub gaps: Vec<Gap>,
+}
+
+impl RevisitReport {
+    pub fn window_s(&self) -> f64 {
+        seconds_between(self.start_utc, self.end_utc)
+    }
+
+    pub fn percent_covered(&self) -> f64 {
+        let window_s = self.window_s();
+        if window_s <= 0.0 {
+            return 0.0;
+        }
+        let covered: f64 = self
+            .merged
+            .iter()
+            .map(|&(start, end)| seconds_between(start, end))
+            .sum();
+        covered / window_s * 100.0
+    }
+
+    pub fn longest_gap(&self) -> Option<&Gap> {
+        self.gaps
+            .iter()
+            .max_by(|a, b| a.duration_s().total_cmp(&b.duration_s()))
+    }
+
+    /// Mean time between the end of one access and the start of the next
+    ///
+    /// Same definition as the coverage heatmap's mean gap: the leading and
+    /// trailing gaps of the window don't count.
+    pub fn mean_gap_s(&self) -> Option<f64> {
+        if self.merged.len() < 2 {
+            return None;
+        }
+        let total: f64 = self
+            .merged
+            .windows(2)
+            .map(|pair| seconds_between(pair[0].1, pair[1].0))
+            .sum();
+        Some(total / (self.merged.len() - 1) as f64)
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "{} passes from {} sats at {:.2}, {:.2} above {:.0}°, {:.1}% covered",
+            self.accesses.len(),
+            self.satellite_count,
+            self.lat_deg,
+            self.lon_deg,
+            self.min_elevation_deg,
+            self.percent_covered()
+        )
+    }
+
+    /// Accesses and gaps as CSV, one row each
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("kind,norad,start_utc,end_utc,duration_s,max_elevation_deg\n");
+        for access in &self.accesses {
+            let _ = writeln!(
+                out,
+                "access,{},{},{},{:.0},{:.1}",
+                access.norad,
+                access.start_utc.to_rfc3339(),
+                access.end_utc.to_rfc3339(),
+                access.duration_s(),
+                access.max_elevation_deg
+            );
+        }
+        for gap in &self.gaps {
+            let _ = writeln!(
+                out,
+                "gap,,{},{},{:.0},",
+                gap.start_utc.to_rfc3339(),
+                gap.end_utc.to_rfc3339(),
+                gap.duration_s()
+            );
+        }
+        out
+    }
+
+    pub fn write_csv(&self, path: &Path) -> Result<(), anyhow::Error> {
+        fs::write(path, self.to_csv())?;
+        Ok(())
+    }
+}
+
+/// Compute every access of the satellites to the ground point over the window
+pub fn run_revisit(request: &RevisitRequest, snapshots: &[TleSnapshot]) -> RevisitReport {
+    let site = site_ecef_km(request.lat_deg, request.lon_deg);
+    let end_utc = request.start_utc + request.duration;
+    let propagators = SnapshotPropagator::build_all(snapshots);
+
+    let mut accesses: Vec<AccessInterval> = Vec::new();
+    for propagator in &propagators {
+        let elevation = |utc: DateTime<Utc>| {
+            propagator
+                .ecef_km(utc, request.dut1_seconds)
+                .map_or(-90.0, |sat| elevation_deg_ecef_dvec(site, sat))
+        };
+        accesses.extend(
+            find_intervals(
+                elevation,
+                request.start_utc,
+                end_utc,
+                request.step_s,
+                request.min_elevation_deg,
+            )
+            .into_iter()
+            .map(|(start_utc, end_utc, max_elevation_deg)| AccessInterval {
+                norad: propagator.norad,
+                start_utc,
+                end_utc,
+                max_elevation_deg,
+            }),
+        );
+    }
+    accesses.sort_by_key(|a| a.start_utc);
+
+    let merged = merge_accesses(&accesses);
+    let gaps = find_gaps(&merged, request.start_utc, end_utc);
+
+    RevisitReport {
+        lat_deg: request.lat_deg,
+        lon_deg: request.lon_deg,
+        start_utc: request.start_utc,
+        end_utc,
+        min_elevation_deg: request.min_elevation_deg,
+        satellite_count: propagators.len(),
+        accesses,
+        merged,
+        gaps,
+    }
+}
+
+pub(crate) fn site_ecef_km(lat_deg: f64, lon_deg: f64) -> DVec3 {
+    crate::core::coordinates::Coordinates {
+        latitude: lat_deg.to_radians(),
+        longitude: lon_deg.to_radians(),
+    }
+    .get_point_on_sphere_ecef_km_dvec()
+}
+
+/// Which satellites feed the analysis
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum RevisitTargets {
+    /// Every loaded satellite
+    #[default]
+    AllLoaded,
+    /// The selected (or tracked) satellite
+    Selected,
+    /// Satellites loaded from `RevisitConfig::group`
+    Group,
+}
+
+/// Configuration resource for revisit runs
+#[derive(Resource, Clone, Debug)]
+pub struct RevisitConfig {
+    pub targets: RevisitTargets,
+    /// Group URL used with `RevisitTargets::Group`
+    pub group: Option<String>,
+    /// Analysis window length, starting at the current simulation time
+    pub duration_hours: f32,
+    /// Sampling step in seconds before rise/set refinement
+    pub step_seconds: f32,
+    pub min_elevation_deg: f32,
+}
+
+impl Default for RevisitConfig {
+    fn default() -> Self {
+        Self {
+            targets: RevisitTargets::AllLoaded,
+            group: None,
+            duration_hours: 24.0,
+            step_seconds: 30.0,
+            min_elevation_deg: 10.0,
+        }
+    }
+}
+
+/// Runtime state for revisit runs
+#[derive(Resource, Default)]
+pub struct RevisitState {
+    pub run_requested: bool,
+    pub export_requested: bool,
+    pub pending_task: Option<Task<RevisitReport>>,
+    pub report: Option<RevisitReport>,
+    pub status: Option<String>,
+}
+
+/// Plugin for ground-point revisit analysis
+pub struct RevisitPlugin;
+
+impl Plugin for RevisitPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RevisitConfig>()
+            .init_resource::<RevisitState>()
+            .add_systems(
+                Update,
+                (
+                    start_revisit_analysis,
+                    poll_revisit_task,
+                    export_revisit_csv,
+                    draw_analysis_site,
+                )
+                    .chain(),
+            );
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn start_revisit_analysis(
+    config: Res<RevisitConfig>,
+    site: Res<AnalysisSite>,
+    mut state: ResMut<RevisitState>,
+    sim_time: Res<SimulationTime>,
+    dut1: Res<Dut1>,
+    selected: Res<SelectedSatellite>,
+    satellites: Query<(&NoradId, &TleComponent, Option<&SatelliteGroupUrl>), With<Satellite>>,
+) {
+    if !state.run_requested {
+        return;
+    }
+    state.run_requested = false;
+
+    if state.pending_task.is_some() {
+        state.status = Some("Analysis already running".to_string());
+        return;
+    }
+    let Some((lat_deg, lon_deg)) = site.lat_lon_deg else {
+        state.status = Some("Pick a site on the globe first".to_string());
+        return;
+    };
+
+    let selected_norad = selected.selected.or(selected.tracking);
+    let snapshots: Vec<TleSnapshot> = satellites
+        .iter()
+        .filter(|(norad, _, group)| match config.targets {
+            RevisitTargets::AllLoaded => true,
+            RevisitTargets::Selected => selected_norad == Some(norad.0),
+            RevisitTargets::Group => {
+                group.is_some_and(|g| config.group.as_deref() == Some(g.0.as_str()))
+            }
+        })
+        .map(|(norad, tle, _)| TleSnapshot::from_tle(norad.0, &tle.0))
+        .collect();
+    if snapshots.is_empty() {
+        state.status = Some(match config.targets {
+            RevisitTargets::AllLoaded => "No satellites with TLEs loaded".to_string(),
+            RevisitTargets::Selected => "No satellite selected".to_string(),
+            RevisitTargets::Group => "No loaded satellites in the chosen group".to_string(),
+        });
+        return;
+    }
+
+    let request = RevisitRequest {
+        lat_deg,
+        lon_deg,
+        start_utc: sim_time.current_utc,
+        duration: Duration::seconds((f64::from(config.duration_hours) * 3600.0) as i64),
+        step_s: f64::from(config.step_seconds),
+        min_elevation_deg: f64::from(config.min_elevation_deg),
+        dut1_seconds: **dut1,
+    };
+    println!(
+        "[REVISIT] start site=({lat_deg:.3},{lon_deg:.3}) sats={} window={}h mask={}deg",
+        snapshots.len(),
+        config.duration_hours,
+        config.min_elevation_deg
+    );
+    state.status = Some(format!("Running: {} sats...", snapshots.len()));
+
+    let task = AsyncComputeTaskPool::get().spawn(async move { run_revisit(&request, &snapshots) });
+    state.pending_task = Some(task);
+}
+
+fn poll_revisit_task(mut state: ResMut<RevisitState>) {
+    // Only touch the resource mutably once the task is done
+    if !state
+        .pending_task
+        .as_ref()
+        .is_some_and(|task| task.is_finished())
+    {
+        return;
+    }
+    if let Some(task) = state.pending_task.take() {
+        let report = block_on(task);
+        println!("[REVISIT] done: {}", report.summary());
+        state.status = Some(report.summary());
+        state.report = Some(report);
+    }
+}
+
+fn export_revisit_csv(mut state: ResMut<RevisitState>) {
+    if !state.export_requested {
+        return;
+    }
+    state.export_requested = false;
+
+    let status = match &state.report {
+        None => "Nothing to export yet; run an analysis first".to_string(),
+        Some(report) => {
+            let file_name = format!("revisit_{}.csv", report.start_utc.format("%Y%m%dT%H%M%SZ"));
+            match export_dir()
+                .map(|dir| dir.join(file_name))
+                .and_then(|path| report.write_csv(&path).map(|()| path))
+            {
+                Ok(path) => {
+                    println!("[REVISIT] exported {}", path.display());
+                    format!("Exported {}", path.display())
+                }
+                Err(e) => {
+                    eprintln!("[REVISIT] export failed: {e}");
+                    format!("Export failed: {e}")
+                }
+            }
+        }
+    };
+    state.status = Some(status);
+}
+
+/// Mark the picked ground point on the globe
+fn draw_analysis_site(site: Res<AnalysisSite>, mut gizmos: Gizmos) {
+    let Some((lat_deg, lon_deg)) = site.lat_lon_deg else {
+        return;
+    };
+    let position = ecef_to_bevy_km(site_ecef_km(lat_deg, lon_deg) * 1.002);
+    gizmos.sphere(
+        Isometry3d::from_translation(position),
+        40.0,
+        Color::srgb(1.0, 0.85, 0.2),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn t0() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    fn access(norad: u32, start_s: i64, end_s: i64) -> AccessInterval {
+        AccessInterval {
+            norad,
+            start_utc: t0() + Duration::seconds(start_s),
+            end_utc: t0() + Duration::seconds(end_s),
+            max_elevation_deg: 45.0,
+        }
+    }
+
+    #[test]
+    fn test_find_intervals_refines_crossings() {
+        // Triangle wave: above 10 deg between t = 1000 s and t = 2000 s
+        let elevation = |utc: DateTime<Utc>| {
+            let t = seconds_between(t0(), utc);
+            60.0 - (t - 1500.0).abs() / 10.0
+        };
+        let intervals = find_intervals(elevation, t0(), t0() + Duration::hours(1), 60.0, 10.0);
+        assert_eq!(intervals.len(), 1);
+        let (start, end, max_el) = intervals[0];
+        assert!((seconds_between(t0(), start) - 1000.0).abs() <= CROSSING_TOLERANCE_S);
+        assert!((seconds_between(t0(), end) - 2000.0).abs() <= CROSSING_TOLERANCE_S);
+        assert!(max_el > 55.0 && max_el <= 60.0);
+    }
+
+    #[test]
+    fn test_find_intervals_truncates_at_window_edges() {
+        let always = |_| 30.0;
+        let end = t0() + Duration::minutes(10);
+        let intervals = find_intervals(always, t0(), end, 60.0, 10.0);
+        assert_eq!(intervals, vec![(t0(), end, 30.0)]);
+
+        let never = |_| -5.0;
+        assert!(find_intervals(never, t0(), end, 60.0, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_merge_and_gaps() {
+        let accesses = vec![
+            access(1, 100, 200),
+            access(2, 150, 300),
+            access(1, 600, 700),
+        ];
+        let merged = merge_accesses(&accesses);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].1, t0() + Duration::seconds(300));
+
+        let gaps = find_gaps(&merged, t0(), t0() + Duration::seconds(1000));
+        let durations: Vec<f64> = gaps.iter().map(Gap::duration_s).collect();
+        assert_eq!(durations, vec![100.0, 300.0, 300.0]);
+    }
+
+    #[test]
+    fn test_report_statistics() {
+        let accesses = vec![
+            access(1, 0, 100),
+            access(2, 400, 500),
+            access(3, 1000, 1100),
+        ];
+        let merged = merge_accesses(&accesses);
+        let end = t0() + Duration::seconds(1200);
+        let gaps = find_gaps(&merged, t0(), end);
+        let report = RevisitReport {
+            lat_deg: 0.0,
+            lon_deg: 0.0,
+            start_utc: t0(),
+            end_utc: end,
+            min_elevation_deg: 10.0,
+            satellite_count: 3,
+            accesses,
+            merged,
+            gaps,
+        };
+
+        assert!((report.percent_covered() - 25.0).abs() < 1e-9);
+        assert_eq!(report.longest_gap().unwrap().duration_s(), 500.0);
+        // Gaps of 300 s and 500 s between passes; the window edges don't count
+        assert!((report.mean_gap_s().unwrap() - 400.0).abs() < 1e-9);
+
+        let csv = report.to_csv();
+        assert!(csv.starts_with("kind,norad,start_utc"));
+        assert_eq!(csv.lines().filter(|l| l.starts_with("access,")).count(), 3);
+        assert_eq!(csv.lines().filter(|l| l.starts_with("gap,")).count(), 3);
+    }
+}
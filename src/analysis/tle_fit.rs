@@ -0,0 +1,669 @@
+//! TLE fitting from state vectors (differential correction)
+//!
+//! Fits SGP4 mean elements to an ephemeris with Levenberg-Marquardt least
+//! squares on position residuals. Every candidate is evaluated through
+//! formatted TLE lines, so the fitted set is exactly what SGP4 will load.
+
+use bevy::math::DVec3;
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::core::coordinates::{EARTH_ROTATION_RAD_S, eci_to_ecef_km, gmst_rad_with_dut1};
+use crate::orbital::decay::MU_EARTH_KM3_S2;
+use crate::orbital::timescale::{TimeScale, scale_to_utc};
+use crate::orbital::{constants_from_tle, minutes_since_epoch};
+use crate::tle::parser::parse_tle_epoch_to_utc;
+
+/// Finite-difference steps, chosen above the TLE text precision of each field
+const JACOBIAN_STEPS: [f64; 7] = [1.0e-5, 1.0e-5, 1.0e-3, 1.0e-3, 1.0e-3, 1.0e-3, 1.0e-6];
+/// Stop once an accepted step improves the RMS by less than this fraction
+const CONVERGENCE_TOLERANCE: f64 = 1.0e-6;
+
+/// Frame of the input ephemeris
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum EphemerisFrame {
+    /// True equator, mean equinox (SGP4's native frame)
+    #[default]
+    Teme,
+    /// Earth-fixed, converted to TEME with GMST
+    Ecef,
+}
+
+/// Position and velocity at an instant, in TEME km and km/s
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StateVector {
+    pub epoch_utc: DateTime<Utc>,
+    pub position_km: DVec3,
+    pub velocity_km_s: DVec3,
+}
+
+impl StateVector {
+    /// Convert an Earth-fixed state to TEME
+    pub fn from_ecef(epoch_utc: DateTime<Utc>, position_km: DVec3, velocity_km_s: DVec3) -> Self {
+        let gmst = gmst_rad_with_dut1(epoch_utc, 0.0);
+        let omega = DVec3::Z * EARTH_ROTATION_RAD_S;
+        let inertial_velocity = velocity_km_s + omega.cross(position_km);
+        Self {
+            epoch_utc,
+            position_km: eci_to_ecef_km(position_km, -gmst),
+            velocity_km_s: eci_to_ecef_km(inertial_velocity, -gmst),
+        }
+    }
+}
+
+/// Parse `utc,x,y,z,vx,vy,vz` rows (km, km/s); a header and `#` comments are skipped
+///
+/// Times are read in `time_scale` (e.g. TDB or GPS for ephemeris products) and converted to UTC.
+pub fn parse_ephemeris_csv(
+    body: &str,
+    frame: EphemerisFrame,
+    time_scale: TimeScale,
+) -> Result<Vec<StateVector>, anyhow::Error> {
+    let mut states = Vec::new();
+    for (index, line) in body.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("utc") {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [time, values @ ..] = fields.as_slice() else {
+            continue;
+        };
+        let values: Vec<f64> = values
+            .iter()
+            .map(|v| v.parse::<f64>())
+            .collect::<Result<_, _>>()
+            .map_err(|e| anyhow::anyhow!("line {}: {e}", index + 1))?;
+        let [x, y, z, vx, vy, vz] = values[..] else {
+            anyhow::bail!("line {}: expected utc and 6 values", index + 1);
+        };
+        let reading = DateTime::parse_from_rfc3339(time)
+            .map_err(|e| anyhow::anyhow!("line {}: {e}", index + 1))?
+            .naive_utc();
+        let epoch_utc = scale_to_utc(reading, time_scale);
+        let (position, velocity) = (DVec3::new(x, y, z), DVec3::new(vx, vy, vz));
+        states.push(match frame {
+            EphemerisFrame::Teme => StateVector {
+                epoch_utc,
+                position_km: position,
+                velocity_km_s: velocity,
+            },
+            EphemerisFrame::Ecef => StateVector::from_ecef(epoch_utc, position, velocity),
+        });
+    }
+    states.sort_by_key(|s| s.epoch_utc);
+    Ok(states)
+}
+
+/// SGP4 mean elements being fitted
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FitElements {
+    pub mean_motion_rev_per_day: f64,
+    pub eccentricity: f64,
+    pub inclination_deg: f64,
+    pub raan_deg: f64,
+    pub arg_perigee_deg: f64,
+    pub mean_anomaly_deg: f64,
+    /// B* drag term in inverse Earth radii
+    pub bstar: f64,
+}
+
+impl FitElements {
+    /// Osculating two-body elements of a state vector, used as the first guess
+    pub fn osculating(state: &StateVector) -> Self {
+        let r = state.position_km;
+        let v = state.velocity_km_s;
+        let h = r.cross(v);
+        let node = DVec3::Z.cross(h);
+        let e_vec = ((v.length_squared() - MU_EARTH_KM3_S2 / r.length()) * r - r.dot(v) * v)
+            / MU_EARTH_KM3_S2;
+        let eccentricity = e_vec.length();
+        let energy = 0.5 * v.length_squared() - MU_EARTH_KM3_S2 / r.length();
+        let a = -MU_EARTH_KM3_S2 / (2.0 * energy);
+
+        let angle = |u: DVec3, w: DVec3| u.angle_between(w).to_degrees();
+        let inclination_deg = (h.z / h.length()).clamp(-1.0, 1.0).acos().to_degrees();
+        let mut raan_deg = if node.length() > 1.0e-9 {
+            angle(DVec3::X, node)
+        } else {
+            0.0
+        };
+        if node.y < 0.0 {
+            raan_deg = 360.0 - raan_deg;
+        }
+        // Near-circular or equatorial orbits fall back to the node / x-axis reference
+        let reference = if node.length() > 1.0e-9 {
+            node
+        } else {
+            DVec3::X
+        };
+        let periapsis = if eccentricity > 1.0e-9 {
+            e_vec
+        } else {
+            reference
+        };
+        let mut arg_perigee_deg = angle(reference, periapsis);
+        if periapsis.z < 0.0 && node.length() > 1.0e-9 {
+            arg_perigee_deg = 360.0 - arg_perigee_deg;
+        }
+        let mut true_anomaly = angle(periapsis, r).to_radians();
+        if r.dot(v) < 0.0 {
+            true_anomaly = std::f64::consts::TAU - true_anomaly;
+        }
+        let eccentric_anomaly = 2.0
+            * ((1.0 - eccentricity).sqrt() * (0.5 * true_anomaly).sin())
+                .atan2((1.0 + eccentricity).sqrt() * (0.5 * true_anomaly).cos());
+        let mean_anomaly = eccentric_anomaly - eccentricity * eccentric_anomaly.sin();
+
+        let n_rad_s = (MU_EARTH_KM3_S2 / a.powi(3)).sqrt();
+        Self {
+            mean_motion_rev_per_day: n_rad_s * 86_400.0 / std::f64::consts::TAU,
+            eccentricity,
+            inclination_deg,
+            raan_deg,
+            arg_perigee_deg,
+            mean_anomaly_deg: mean_anomaly.to_degrees().rem_euclid(360.0),
+            bstar: 0.0,
+        }
+    }
+
+    fn to_params(self) -> [f64; 7] {
+        [
+            self.mean_motion_rev_per_day,
+            self.eccentricity,
+            self.inclination_deg,
+            self.raan_deg,
+            self.arg_perigee_deg,
+            self.mean_anomaly_deg,
+            self.bstar,
+        ]
+    }
+
+    /// Build elements from a parameter vector, keeping each field in range
+    fn from_params(p: [f64; 7]) -> Self {
+        Self {
+            mean_motion_rev_per_day: p[0].max(0.05),
+            eccentricity: p[1].clamp(0.0, 0.99),
+            inclination_deg: p[2].clamp(0.0, 180.0),
+            raan_deg: p[3].rem_euclid(360.0),
+            arg_perigee_deg: p[4].rem_euclid(360.0),
+            mean_anomaly_deg: p[5].rem_euclid(360.0),
+            bstar: p[6],
+        }
+    }
+
+    /// Format as a TLE line pair with checksums
+    pub fn to_tle_lines(self, norad: u32, epoch_utc: DateTime<Utc>) -> (String, String) {
+        let day_fraction = f64::from(epoch_utc.num_seconds_from_midnight())
+            + f64::from(epoch_utc.nanosecond()) / 1.0e9;
+        let day_of_year = f64::from(epoch_utc.ordinal()) + day_fraction / 86_400.0;
+        let line1 = format!(
+            "1 {norad:05}U          {:02}{day_of_year:012.8}  .00000000  00000-0 {} 0  999",
+            epoch_utc.year().rem_euclid(100),
+            format_implied_exponent(self.bstar),
+        );
+        let line2 = format!(
+            "2 {norad:05} {:8.4} {:8.4} {:07} {:8.4} {:8.4} {:11.8}    0",
+            self.inclination_deg,
+            self.raan_deg,
+            (self.eccentricity * 1.0e7).round() as u32,
+            self.arg_perigee_deg,
+            self.mean_anomaly_deg,
+            self.mean_motion_rev_per_day,
+        );
+        (with_checksum(line1), with_checksum(line2))
+    }
+}
+
+/// Format a value in the TLE "implied decimal point" form, e.g. ` 34123-4`
+fn format_implied_exponent(value: f64) -> String {
+    if value == 0.0 {
+        return " 00000-0".to_string();
+    }
+    let sign = if value < 0.0 { '-' } else { ' ' };
+    let mut exponent = value.abs().log10().floor() as i32 + 1;
+    let mut mantissa = (value.abs() / 10f64.powi(exponent) * 1.0e5).round() as u32;
+    if mantissa >= 100_000 {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    let exponent_sign = if exponent < 0 { '-' } else { '+' };
+    format!(
+        "{sign}{mantissa:05}{exponent_sign}{}",
+        exponent.unsigned_abs().min(9)
+    )
+}
+
+/// TLE checksum: sum of digits plus one per minus sign, modulo 10
+fn tle_checksum(line: &str) -> u32 {
+    line.chars()
+        .take(68)
+        .map(|c| match c {
+            '-' => 1,
+            _ => c.to_digit(10).unwrap_or(0),
+        })
+        .sum::<u32>()
+        % 10
+}
+
+fn with_checksum(line: String) -> String {
+    let checksum = tle_checksum(&line);
+    format!("{line}{checksum}")
+}
+
+/// Settings for a fit
+#[derive(Clone, Debug)]
+pub struct TleFitOptions {
+    pub norad: u32,
+    /// Also solve for B*; otherwise B* stays at zero
+    pub fit_bstar: bool,
+    pub max_iterations: usize,
+}
+
+impl Default for TleFitOptions {
+    fn default() -> Self {
+        Self {
+            norad: 99_999,
+            fit_bstar: false,
+            max_iterations: 25,
+        }
+    }
+}
+
+/// Fitted TLE with residual statistics
+#[derive(Clone, Debug)]
+pub struct TleFit {
+    pub line1: String,
+    pub line2: String,
+    pub epoch_utc: DateTime<Utc>,
+    pub elements: FitElements,
+    pub iterations: usize,
+    pub rms_km: f64,
+    pub max_residual_km: f64,
+    /// Position residual magnitude at each ephemeris point
+    pub residuals_km: Vec<f64>,
+}
+
+impl TleFit {
+    pub fn summary(&self) -> String {
+        format!(
+            "{} points, RMS {:.3} km, max {:.3} km after {} iterations",
+            self.residuals_km.len(),
+            self.rms_km,
+            self.max_residual_km,
+            self.iterations
+        )
+    }
+}
+
+/// Position residuals (km) of a candidate, or None if SGP4 rejects it
+fn residuals(
+    elements: &FitElements,
+    norad: u32,
+    epoch_utc: DateTime<Utc>,
+    ephemeris: &[StateVector],
+) -> Option<Vec<DVec3>> {
+    let (line1, line2) = elements.to_tle_lines(norad, epoch_utc);
+    let constants = constants_from_tle(&line1, &line2).ok()?;
+    // Propagate from the epoch as written, since the text rounds it
+    let tle_epoch = parse_tle_epoch_to_utc(&line1)?;
+    ephemeris
+        .iter()
+        .map(|state| {
+            let mins = minutes_since_epoch(state.epoch_utc, tle_epoch);
+            let prediction = constants.propagate(sgp4::MinutesSinceEpoch(mins)).ok()?;
+            Some(state.position_km - DVec3::from_array(prediction.position))
+        })
+        .collect()
+}
+
+fn sum_of_squares(residuals: &[DVec3]) -> f64 {
+    residuals.iter().map(|r| r.length_squared()).sum()
+}
+
+/// Solve a small dense linear system with partial pivoting
+fn solve_linear(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1.0e-300 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        for row in col + 1..n {
+            let factor = a[row][col] / a[col][col];
+            let pivot_row = a[col].clone();
+            for (value, pivot) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * pivot;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let tail: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - tail) / a[row][row];
+    }
+    Some(x)
+}
+
+/// Fit SGP4 mean elements to an ephemeris (TEME), with the epoch at the last point
+pub fn fit_tle(
+    ephemeris: &[StateVector],
+    options: &TleFitOptions,
+) -> Result<TleFit, anyhow::Error> {
+    if ephemeris.len() < 4 {
+        anyhow::bail!("Need at least 4 state vectors, got {}", ephemeris.len());
+    }
+    let last = ephemeris[ephemeris.len() - 1];
+    let epoch_utc = last.epoch_utc;
+    let norad = options.norad;
+    let solved = if options.fit_bstar { 7 } else { 6 };
+
+    let mut params = FitElements::osculating(&last).to_params();
+    let mut current = residuals(
+        &FitElements::from_params(params),
+        norad,
+        epoch_utc,
+        ephemeris,
+    )
+    .ok_or_else(|| anyhow::anyhow!("SGP4 rejected the initial guess"))?;
+    let mut cost = sum_of_squares(&current);
+    let mut damping = 1.0e-3;
+    let mut iterations = 0;
+
+    while iterations < options.max_iterations {
+        iterations += 1;
+
+        // Forward-difference Jacobian of the stacked position residuals
+        let mut columns = Vec::with_capacity(solved);
+        for (index, step) in JACOBIAN_STEPS.iter().enumerate().take(solved) {
+            let mut perturbed = params;
+            perturbed[index] += step;
+            let shifted = residuals(
+                &FitElements::from_params(perturbed),
+                norad,
+                epoch_utc,
+                ephemeris,
+            )
+            .ok_or_else(|| anyhow::anyhow!("SGP4 failed while building the Jacobian"))?;
+            // d(residual)/d(param); the model enters the residual with a minus sign
+            let column: Vec<DVec3> = shifted
+                .iter()
+                .zip(&current)
+                .map(|(s, c)| (*s - *c) / *step)
+                .collect();
+            columns.push(column);
+        }
+        let mut normal = vec![vec![0.0; solved]; solved];
+        let mut gradient = vec![0.0; solved];
+        for i in 0..solved {
+            for j in 0..solved {
+                normal[i][j] = columns[i]
+                    .iter()
+                    .zip(&columns[j])
+                    .map(|(a, b)| a.dot(*b))
+                    .sum();
+            }
+            gradient[i] = -columns[i]
+                .iter()
+                .zip(&current)
+                .map(|(a, r)| a.dot(*r))
+                .sum::<f64>();
+        }
+
+        // Levenberg-Marquardt: raise the damping until a step lowers the cost
+        let mut improved = false;
+        for _ in 0..10 {
+            let mut damped = normal.clone();
+            for (i, row) in damped.iter_mut().enumerate() {
+                row[i] += damping * normal[i][i].max(1.0e-12);
+            }
+            let Some(delta) = solve_linear(damped, gradient.clone()) else {
+                damping *= 10.0;
+                continue;
+            };
+            let mut candidate = params;
+            for (value, change) in candidate.iter_mut().zip(&delta) {
+                *value += change;
+            }
+            let candidate = FitElements::from_params(candidate).to_params();
+            if let Some(trial) = residuals(
+                &FitElements::from_params(candidate),
+                norad,
+                epoch_utc,
+                ephemeris,
+            ) {
+                let trial_cost = sum_of_squares(&trial);
+                if trial_cost < cost {
+                    let relative_gain = (cost - trial_cost) / cost.max(1.0e-30);
+                    params = candidate;
+                    current = trial;
+                    cost = trial_cost;
+                    damping = (damping / 10.0).max(1.0e-9);
+                    improved = relative_gain > CONVERGENCE_TOLERANCE;
+                    break;
+                }
+            }
+            damping *= 10.0;
+        }
+        if !improved {
+            break;
+        }
+    }
+
+    let elements = FitElements::from_params(params);
+    let (line1, line2) = elements.to_tle_lines(norad, epoch_utc);
+    let residuals_km: Vec<f64> = current.iter().map(|r| r.length()).collect();
+    let rms_km = (cost / residuals_km.len() as f64).sqrt();
+    let max_residual_km = residuals_km.iter().copied().fold(0.0, f64::max);
+    Ok(TleFit {
+        line1,
+        line2,
+        epoch_utc,
+        elements,
+        iterations,
+        rms_km,
+        max_residual_km,
+        residuals_km,
+    })
+}
+
+/// Command-line options for `--fit-tle`
+pub struct HeadlessFitArgs {
+    pub ephemeris_path: PathBuf,
+    pub out_path: PathBuf,
+    pub frame: EphemerisFrame,
+    pub time_scale: TimeScale,
+    pub name: String,
+    pub options: TleFitOptions,
+}
+
+/// Parse `--fit-tle <ephemeris.csv> [--out fit.tle] [--norad N] [--name NAME] [--frame teme|ecef]
+/// [--time-scale utc|tai|tt|gps|tdb] [--bstar]`
+///
+/// Returns None when `--fit-tle` is absent.
+pub fn parse_headless_args(args: &[String]) -> Option<Result<HeadlessFitArgs, anyhow::Error>> {
+    let pos = args.iter().position(|a| a == "--fit-tle")?;
+    Some(parse_headless_options(&args[pos + 1..]))
+}
+
+fn parse_headless_options(args: &[String]) -> Result<HeadlessFitArgs, anyhow::Error> {
+    let mut iter = args.iter();
+    let ephemeris_path = iter
+        .next()
+        .filter(|a| !a.starts_with("--"))
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow::anyhow!("--fit-tle requires an ephemeris CSV path"))?;
+
+    let mut out_path = PathBuf::from("fit.tle");
+    let mut frame = EphemerisFrame::Teme;
+    let mut time_scale = TimeScale::Utc;
+    let mut name = "FITTED OBJECT".to_string();
+    let mut options = TleFitOptions::default();
+
+    while let Some(flag) = iter.next() {
+        if flag == "--bstar" {
+            options.fit_bstar = true;
+            continue;
+        }
+        let value = iter
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("{flag} requires a value"))?;
+        match flag.as_str() {
+            "--out" => out_path = PathBuf::from(value),
+            "--norad" => options.norad = value.parse()?,
+            "--name" => name = value.clone(),
+            "--frame" => {
+                frame = match value.as_str() {
+                    "teme" => EphemerisFrame::Teme,
+                    "ecef" => EphemerisFrame::Ecef,
+                    other => anyhow::bail!("Unknown frame {other}; use teme or ecef"),
+                }
+            }
+            "--time-scale" => {
+                time_scale = TimeScale::parse(value).ok_or_else(|| {
+                    anyhow::anyhow!("Unknown time scale {value}; use utc, tai, tt, gps or tdb")
+                })?
+            }
+            "--iterations" => options.max_iterations = value.parse()?,
+            other => anyhow::bail!("Unknown fit option: {other}"),
+        }
+    }
+    if options.norad > 99_999 {
+        anyhow::bail!("--norad must fit in 5 digits");
+    }
+
+    Ok(HeadlessFitArgs {
+        ephemeris_path,
+        out_path,
+        frame,
+        time_scale,
+        name,
+        options,
+    })
+}
+
+/// Fit a TLE to an ephemeris file and write a three-line TLE, without a window
+pub fn run_headless(args: HeadlessFitArgs) -> Result<(), anyhow::Error> {
+    let body = fs::read_to_string(&args.ephemeris_path)?;
+    let ephemeris = parse_ephemeris_csv(&body, args.frame, args.time_scale)?;
+    println!(
+        "[TLE FIT] {} state vectors from {} ({})",
+        ephemeris.len(),
+        args.ephemeris_path.display(),
+        args.time_scale.label()
+    );
+
+    let fit = fit_tle(&ephemeris, &args.options)?;
+    println!("[TLE FIT] {}", fit.summary());
+    println!(
+        "[TLE FIT] epoch {}: n {:.8} rev/day, e {:.7}, i {:.4} deg, B* {:.4e}",
+        fit.epoch_utc.to_rfc3339(),
+        fit.elements.mean_motion_rev_per_day,
+        fit.elements.eccentricity,
+        fit.elements.inclination_deg,
+        fit.elements.bstar
+    );
+    println!("{}\n{}", fit.line1, fit.line2);
+
+    fs::write(
+        &args.out_path,
+        format!("{}\n{}\n{}\n", args.name, fit.line1, fit.line2),
+    )?;
+    println!(
+        "[TLE FIT] wrote {} (load it with --load-tle)",
+        args.out_path.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tle::parser::parse_tle_mean_elements;
+    use chrono::TimeZone;
+
+    const ISS_LINE1: &str = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    const ISS_LINE2: &str = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+    #[test]
+    fn test_checksum_matches_published_tle() {
+        assert_eq!(tle_checksum(ISS_LINE1), 7);
+        assert_eq!(tle_checksum(ISS_LINE2), 7);
+    }
+
+    #[test]
+    fn test_formatted_lines_parse_back() {
+        let elements = FitElements {
+            mean_motion_rev_per_day: 15.5,
+            eccentricity: 0.0012345,
+            inclination_deg: 97.4,
+            raan_deg: 12.3456,
+            arg_perigee_deg: 300.0,
+            mean_anomaly_deg: 60.25,
+            bstar: 3.4123e-5,
+        };
+        let epoch = Utc.with_ymd_and_hms(2025, 2, 14, 6, 0, 0).unwrap();
+        let (line1, line2) = elements.to_tle_lines(12345, epoch);
+        assert_eq!(line1.len(), 69);
+        assert_eq!(line2.len(), 69);
+        assert_eq!(line1[68..].parse::<u32>().unwrap(), tle_checksum(&line1));
+
+        let parsed = parse_tle_mean_elements(&line1, &line2).expect("parsable");
+        assert_eq!(parsed.epoch_utc, epoch);
+        assert!((parsed.mean_motion_rev_per_day - 15.5).abs() < 1e-8);
+        assert!((parsed.eccentricity - 0.0012345).abs() < 1e-9);
+        assert!((parsed.raan_deg - 12.3456).abs() < 1e-9);
+        assert!((parsed.bstar - 3.4123e-5).abs() < 1e-10);
+        assert_eq!(format_implied_exponent(-0.11606e-4), "-11606-4");
+    }
+
+    #[test]
+    fn test_ephemeris_csv_parsing() {
+        let body = "utc,x,y,z,vx,vy,vz\n\
+                    # comment\n\
+                    2025-01-01T00:01:00Z,7000,0,0,0,7.5,0\n\
+                    2025-01-01T00:00:00Z,0,7000,0,-7.5,0,0\n";
+        let states = parse_ephemeris_csv(body, EphemerisFrame::Teme, TimeScale::Utc).unwrap();
+        assert_eq!(states.len(), 2);
+        assert!(states[0].epoch_utc < states[1].epoch_utc);
+        assert!(
+            parse_ephemeris_csv(
+                "2025-01-01T00:00:00Z,1,2",
+                EphemerisFrame::Teme,
+                TimeScale::Utc
+            )
+            .is_err()
+        );
+        // GPS time runs 18 s ahead of UTC
+        let gps = parse_ephemeris_csv(body, EphemerisFrame::Teme, TimeScale::Gps).unwrap();
+        assert_eq!((states[0].epoch_utc - gps[0].epoch_utc).num_seconds(), 18);
+    }
+
+    #[test]
+    fn test_fit_recovers_sgp4_trajectory() {
+        let constants = constants_from_tle(ISS_LINE1, ISS_LINE2).unwrap();
+        let epoch = parse_tle_epoch_to_utc(ISS_LINE1).unwrap();
+        let ephemeris: Vec<StateVector> = (0..=36)
+            .map(|i| {
+                let utc = epoch + chrono::Duration::minutes(i * 5);
+                let prediction = constants
+                    .propagate(sgp4::MinutesSinceEpoch(minutes_since_epoch(utc, epoch)))
+                    .unwrap();
+                StateVector {
+                    epoch_utc: utc,
+                    position_km: DVec3::from_array(prediction.position),
+                    velocity_km_s: DVec3::from_array(prediction.velocity),
+                }
+            })
+            .collect();
+
+        let fit = fit_tle(&ephemeris, &TleFitOptions::default()).expect("fit converges");
+        assert!(fit.rms_km < 1.0, "{}", fit.summary());
+        assert!((fit.elements.inclination_deg - 51.64).abs() < 0.05);
+        assert!(constants_from_tle(&fit.line1, &fit.line2).is_ok());
+    }
+}
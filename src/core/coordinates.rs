@@ -8,7 +8,7 @@
 //! - Import from `crate::core::coordinates::`*
 //! - Legacy paths via `crate::coord` and `crate::orbital::coordinates` are temporarily re-exported as shims.
 
-use bevy::math::{DVec3, Vec3};
+use bevy::math::{DMat4, DVec3, DVec4, Vec3};
 use chrono::{DateTime, Datelike, Timelike, Utc};
 use std::f64::consts::PI;
 
@@ -218,6 +218,100 @@ pub fn hemisphere_prefilter_ecef_dvec(
     city_ecef_km.dot(sat_ecef_km) > earth_radius_km * earth_radius_km
 }
 
+/// Elevation (degrees) of a satellite above the local horizon of a ground point.
+/// The local vertical is the geocentric radial direction (spherical Earth).
+pub fn elevation_deg_ecef_dvec(ground_ecef_km: DVec3, sat_ecef_km: DVec3) -> f64 {
+    let up = ground_ecef_km.normalize_or_zero();
+    let los = sat_ecef_km - ground_ecef_km;
+    let range = los.length();
+    if range == 0.0 || up == DVec3::ZERO {
+        return -90.0;
+    }
+    // atan2 keeps precision near the zenith where asin flattens out
+    let vertical = los.dot(up);
+    let horizontal = (los - up * vertical).length();
+    vertical.atan2(horizontal).to_degrees()
+}
+
+/// True if the satellite is at or above `min_elevation_deg` as seen from the ground point.
+pub fn visible_above_mask_ecef_dvec(
+    ground_ecef_km: DVec3,
+    sat_ecef_km: DVec3,
+    min_elevation_deg: f64,
+) -> bool {
+    // A non-negative mask implies the satellite is above the tangent plane.
+    if min_elevation_deg >= 0.0
+        && !hemisphere_prefilter_ecef_dvec(ground_ecef_km, sat_ecef_km, EARTH_RADIUS_KM_F64)
+    {
+        return false;
+    }
+    elevation_deg_ecef_dvec(ground_ecef_km, sat_ecef_km) >= min_elevation_deg
+}
+
+/// Local east/north/up unit vectors at a ground point (spherical Earth).
+pub fn enu_basis_ecef_dvec(ground_ecef_km: DVec3) -> (DVec3, DVec3, DVec3) {
+    let up = ground_ecef_km.normalize_or_zero();
+    // At the poles east is undefined; pick +Y so the basis stays orthonormal.
+    let east = DVec3::Z.cross(up).try_normalize().unwrap_or(DVec3::Y);
+    let north = up.cross(east);
+    (east, north, up)
+}
+
+/// Dilution of precision for a ground receiver.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dop {
+    pub gdop: f64,
+    pub pdop: f64,
+    pub hdop: f64,
+}
+
+/// DOP from unit line-of-sight vectors expressed in local ENU.
+/// Returns None with fewer than 4 satellites or a singular geometry.
+pub fn dop_from_enu_directions(directions: &[DVec3]) -> Option<Dop> {
+    if directions.len() < 4 {
+        return None;
+    }
+    // Normal matrix H^T H, with geometry rows [-e, -n, -u, 1]
+    let mut normal = [[0.0_f64; 4]; 4];
+    for dir in directions {
+        let row = [-dir.x, -dir.y, -dir.z, 1.0];
+        for (i, col) in normal.iter_mut().enumerate() {
+            for (j, value) in col.iter_mut().enumerate() {
+                *value += row[i] * row[j];
+            }
+        }
+    }
+    let normal = DMat4::from_cols_array_2d(&normal);
+    if normal.determinant().abs() < 1e-9 {
+        return None;
+    }
+    let q = normal.inverse();
+    let diag = DVec4::new(q.x_axis.x, q.y_axis.y, q.z_axis.z, q.w_axis.w);
+    Some(Dop {
+        gdop: (diag.x + diag.y + diag.z + diag.w).sqrt(),
+        pdop: (diag.x + diag.y + diag.z).sqrt(),
+        hdop: (diag.x + diag.y).sqrt(),
+    })
+}
+
+/// DOP at a ground point using the satellites at or above the elevation mask.
+pub fn dop_ecef_dvec(
+    ground_ecef_km: DVec3,
+    satellites_ecef_km: &[DVec3],
+    min_elevation_deg: f64,
+) -> Option<Dop> {
+    let (east, north, up) = enu_basis_ecef_dvec(ground_ecef_km);
+    let directions: Vec<DVec3> = satellites_ecef_km
+        .iter()
+        .filter(|&&sat| visible_above_mask_ecef_dvec(ground_ecef_km, sat, min_elevation_deg))
+        .map(|&sat| {
+            let los = (sat - ground_ecef_km).normalize();
+            DVec3::new(los.dot(east), los.dot(north), los.dot(up))
+        })
+        .collect();
+    dop_from_enu_directions(&directions)
+}
+
 // ========================= Orbital/Earth-frame transformations =========================
 
 /// Compute the Julian Date (UTC) for a given timestamp.
@@ -281,6 +375,26 @@ pub fn eci_to_ecef_km(eci: DVec3, gmst: f64) -> DVec3 {
     DVec3::new(x, y, eci.z)
 }
 
+/// Carry an ECEF position recorded at sidereal angle `gmst_then` to the ECEF frame at
+/// `gmst_now`, holding it fixed in inertial space (used to draw inertial-frame trails)
+pub fn ecef_held_inertial_km(ecef_km: DVec3, gmst_then: f64, gmst_now: f64) -> DVec3 {
+    eci_to_ecef_km(ecef_km, gmst_now - gmst_then)
+}
+
+/// Yaw step about ECEF Z that keeps a camera fixed in inertial space while sidereal time
+/// goes from `gmst_then` to `gmst_now` (the shortest turn, so GMST wrapping at 2π is harmless)
+pub fn inertial_yaw_step(gmst_then: f64, gmst_now: f64) -> f64 {
+    -((gmst_now - gmst_then + PI).rem_euclid(std::f64::consts::TAU) - PI)
+}
+
+/// Earth rotation rate about Z (rad/s)
+pub const EARTH_ROTATION_RAD_S: f64 = 7.292_115_855_3e-5;
+
+/// Rotate an ECI (TEME) velocity into ECEF, removing the Earth's rotation
+pub fn eci_velocity_to_ecef_km_s(velocity_eci: DVec3, position_ecef_km: DVec3, gmst: f64) -> DVec3 {
+    eci_to_ecef_km(velocity_eci, gmst) - (DVec3::Z * EARTH_ROTATION_RAD_S).cross(position_ecef_km)
+}
+
 /// Greenwich Mean Sidereal Time (radians) allowing explicit DUT1 (UT1-UTC) seconds.
 /// If `dut1_seconds` is 0, this matches the GMST computed without DUT1.
 pub fn gmst_rad_with_dut1(t: DateTime<Utc>, dut1_seconds: f64) -> f64 {
@@ -802,6 +916,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_elevation_deg_zenith_and_horizon() {
+        let ground = DVec3::new(EARTH_RADIUS_KM_F64, 0.0, 0.0);
+
+        let zenith = DVec3::new(EARTH_RADIUS_KM_F64 + 500.0, 0.0, 0.0);
+        assert!((elevation_deg_ecef_dvec(ground, zenith) - 90.0).abs() < 1e-9);
+
+        let horizon = DVec3::new(EARTH_RADIUS_KM_F64, 1000.0, 0.0);
+        assert!(elevation_deg_ecef_dvec(ground, horizon).abs() < 1e-9);
+
+        let below = DVec3::new(EARTH_RADIUS_KM_F64 - 100.0, 1000.0, 0.0);
+        assert!(elevation_deg_ecef_dvec(ground, below) < 0.0);
+    }
+
+    #[test]
+    fn test_visible_above_mask() {
+        let ground = DVec3::new(EARTH_RADIUS_KM_F64, 0.0, 0.0);
+        // 45 degrees up: equal radial and tangential offsets
+        let sat = DVec3::new(EARTH_RADIUS_KM_F64 + 1000.0, 1000.0, 0.0);
+
+        assert!(visible_above_mask_ecef_dvec(ground, sat, 0.0));
+        assert!(visible_above_mask_ecef_dvec(ground, sat, 44.9));
+        assert!(!visible_above_mask_ecef_dvec(ground, sat, 45.1));
+
+        let far_side = DVec3::new(-EARTH_RADIUS_KM_F64 - 500.0, 0.0, 0.0);
+        assert!(!visible_above_mask_ecef_dvec(ground, far_side, 0.0));
+    }
+
+    #[test]
+    fn test_dop_from_enu_directions_known_geometry() {
+        // Zenith plus four horizon satellites at the cardinal points:
+        // Q_ee = Q_nn = 1/2, Q_uu = 5/4, Q_tt = 1/4
+        let directions = [DVec3::Z, DVec3::X, DVec3::NEG_X, DVec3::Y, DVec3::NEG_Y];
+        let dop = dop_from_enu_directions(&directions).unwrap();
+        assert!((dop.hdop - 1.0).abs() < 1e-9);
+        assert!((dop.pdop - 1.5).abs() < 1e-9);
+        assert!((dop.gdop - 2.5_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_dop_requires_four_satellites() {
+        assert!(dop_from_enu_directions(&[DVec3::Z, DVec3::X, DVec3::Y]).is_none());
+        // Four identical directions are singular
+        assert!(dop_from_enu_directions(&[DVec3::Z; 4]).is_none());
+    }
+
+    #[test]
+    fn test_dop_ecef_applies_elevation_mask() {
+        let ground = DVec3::new(EARTH_RADIUS_KM_F64, 0.0, 0.0);
+        let (east, north, up) = enu_basis_ecef_dvec(ground);
+        let alt = 20_000.0;
+        let sats: Vec<DVec3> = [up, up + east, up - east, up + north, up - north]
+            .iter()
+            .map(|dir| ground + dir.normalize() * alt)
+            .collect();
+
+        let dop = dop_ecef_dvec(ground, &sats, 10.0).unwrap();
+        assert!(dop.gdop >= dop.pdop && dop.pdop >= dop.hdop && dop.hdop > 0.0);
+        // The four slanted satellites sit at 45 degrees and drop out above that mask
+        assert!(dop_ecef_dvec(ground, &sats, 50.0).is_none());
+    }
+
     // ---- Orbital/ECEF/Bevy transform tests (from former crate::orbital::coordinates) ----
 
     #[test]
@@ -1095,4 +1271,79 @@ mod tests {
         let bevy_length = bevy_diagonal.length() as f64;
         assert!((ecef_length - bevy_length).abs() < 1e-3);
     }
+
+    #[test]
+    fn test_ecef_held_inertial_closes_orbit() {
+        // Circular equatorial orbit sampled over one period while the Earth turns
+        let radius = 7000.0;
+        let period_s = 5828.5;
+        let gmst0 = 1.2;
+        let gmst_at = |t: f64| gmst0 + EARTH_ROTATION_RAD_S * t;
+        let gmst_now = gmst_at(period_s);
+        let held: Vec<DVec3> = (0..=8)
+            .map(|k| {
+                let t = period_s * k as f64 / 8.0;
+                let angle = std::f64::consts::TAU * t / period_s;
+                let eci = DVec3::new(radius * angle.cos(), radius * angle.sin(), 0.0);
+                let held =
+                    ecef_held_inertial_km(eci_to_ecef_km(eci, gmst_at(t)), gmst_at(t), gmst_now);
+                assert!((held - eci_to_ecef_km(eci, gmst_now)).length() < 1e-9);
+                held
+            })
+            .collect();
+        // The recorded ECEF track does not close, the inertial one does
+        assert!((held[0] - held[8]).length() < 1e-9);
+    }
+
+    #[test]
+    fn test_inertial_yaw_step_matches_rotating_the_scene() {
+        use bevy::prelude::Transform;
+
+        let camera_at = |yaw: f64, pitch: f64| {
+            let radius = 30_000.0_f64;
+            let pos = BVec3::new(
+                (radius * pitch.cos() * yaw.sin()) as f32,
+                (radius * pitch.sin()) as f32,
+                (radius * pitch.cos() * yaw.cos()) as f32,
+            );
+            Transform::from_translation(pos).looking_at(BVec3::ZERO, BVec3::Y)
+        };
+        let view = |camera: &Transform, world: BVec3| {
+            camera.compute_affine().inverse().transform_point3(world)
+        };
+
+        // A ground site and a satellite, both given in ECEF as the world stores them
+        let site = Coordinates::from_degrees(30.0, 50.0)
+            .unwrap()
+            .get_point_on_sphere_ecef_km_dvec();
+        let sat = DVec3::new(4000.0, -5000.0, 2500.0);
+        let (yaw0, pitch) = (0.4, 0.3);
+        let gmst0 = 6.1;
+
+        // Step sidereal time across the 2π wrap, holding the camera as the inertial view does
+        let mut yaw = yaw0;
+        let mut gmst_then = gmst0;
+        for k in 1..=6 {
+            let gmst = (gmst0 + 0.05 * f64::from(k)).rem_euclid(std::f64::consts::TAU);
+            yaw += inertial_yaw_step(gmst_then, gmst);
+            gmst_then = gmst;
+
+            // Same picture as a fixed camera looking at the scene turned by GMST
+            let held = camera_at(yaw, pitch);
+            let fixed = camera_at(yaw0 + gmst0, pitch);
+            for ecef in [site, sat] {
+                let turned = ecef_to_bevy_km(eci_to_ecef_km(ecef, -gmst));
+                let seen_held = view(&held, ecef_to_bevy_km(ecef));
+                let seen_turned = view(&fixed, turned);
+                assert!((seen_held - seen_turned).length() < 0.05);
+            }
+        }
+
+        // World positions stay ECEF, so picks and tracking read them unchanged
+        let (lat, lon) = Coordinates::from(ecef_to_bevy_km(site)).as_degrees();
+        assert!((lat - 30.0).abs() < 1e-3 && (lon - 50.0).abs() < 1e-3);
+        let sat_bevy = ecef_to_bevy_km(sat).normalize();
+        let track_yaw = f64::from(sat_bevy.x.atan2(sat_bevy.z));
+        assert!((track_yaw - sat.y.atan2(sat.x)).abs() < 1e-6);
+    }
 }
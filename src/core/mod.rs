@@ -1,3 +1,4 @@
 //! Core module for foundational types and utilities
 pub mod coordinates;
+pub mod paths;
 pub mod space;
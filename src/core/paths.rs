@@ -0,0 +1,26 @@
+//! Filesystem locations for user data and exports.
+
+use directories::ProjectDirs;
+use std::fs;
+use std::path::PathBuf;
+
+/// App data directory for user-provided files (transmitter database, ...)
+///
+/// Resolves the platform data directory:
+/// - macOS: ~/Library/Application Support/bevyearth/
+/// - Linux: ~/.local/share/bevyearth/
+/// - Windows: %APPDATA%\bevyearth\data\
+pub fn data_dir() -> Result<PathBuf, anyhow::Error> {
+    let proj_dirs = ProjectDirs::from("", "", "bevyearth")
+        .ok_or_else(|| anyhow::anyhow!("Failed to resolve data directory"))?;
+    Ok(proj_dirs.data_dir().to_path_buf())
+}
+
+/// Directory for exported reports (CSV, calendars, ...)
+///
+/// This is `exports/` inside [`data_dir`], created if it does not exist.
+pub fn export_dir() -> Result<PathBuf, anyhow::Error> {
+    let dir = data_dir()?.join("exports");
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
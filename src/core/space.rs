@@ -11,6 +11,10 @@ pub const EARTH_RADIUS_KM_F64: f64 = EARTH_RADIUS_KM as f64;
 #[derive(Component, Copy, Clone, Debug, Deref, DerefMut)]
 pub struct WorldEcefKm(pub DVec3);
 
+/// Earth-relative velocity in ECEF (km/s, f64).
+#[derive(Component, Copy, Clone, Debug, Deref, DerefMut)]
+pub struct WorldVelocityEcefKmS(pub DVec3);
+
 /// Convert standard ECEF km (f64) to Bevy render km (f32).
 /// Mapping: Bevy (x,y,z) = (ECEF.y, ECEF.z, ECEF.x)
 pub fn ecef_to_bevy_km(ecef_km: DVec3) -> Vec3 {
@@ -26,3 +30,32 @@ pub fn bevy_to_ecef_km(bevy_km: Vec3) -> DVec3 {
         f64::from(bevy_km.y),
     )
 }
+
+/// Frame the scene is viewed in
+///
+/// World coordinates are always ECEF. In `Inertial` the main camera is turned
+/// by -GMST to stay fixed against the stars, which shows the Earth turning by
+/// GMST beneath it without moving any entity, and trails are drawn where they
+/// were in inertial space so orbits trace closed ellipses.
+#[derive(Resource, Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RenderFrame {
+    #[default]
+    Ecef,
+    Inertial,
+}
+
+impl RenderFrame {
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Ecef => Self::Inertial,
+            Self::Inertial => Self::Ecef,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Ecef => "Earth-fixed",
+            Self::Inertial => "Inertial",
+        }
+    }
+}
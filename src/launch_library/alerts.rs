@@ -0,0 +1,230 @@
+//! Launch countdown and NET slip alerts
+//!
+//! A countdown alert is raised when the simulation clock passes T-60, T-10 or
+//! T-0 of a listed launch. Steps longer than a few minutes are taken to be jumps
+//! (scrubbing, jump to date) and pass no marks. A slip alert is raised when a
+//! refresh moves the NET of a launch seen before.
+
+use bevy::prelude::*;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+use crate::launch_library::types::LaunchSummary;
+
+/// Minutes before NET at which countdown alerts are raised
+pub const COUNTDOWN_MARKS_MINUTES: [i64; 3] = [60, 10, 0];
+/// Clock steps longer than this pass no countdown marks
+const MAX_COUNTDOWN_STEP_MINUTES: i64 = 5;
+/// NET changes smaller than this are not reported
+const MIN_SLIP_SECONDS: i64 = 60;
+/// Raised alerts kept for display
+const MAX_ALERTS: usize = 20;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LaunchAlertKind {
+    /// The clock passed T-`minutes`
+    Countdown { minutes: i64 },
+    /// A refresh moved the NET
+    NetSlip {
+        previous: DateTime<Utc>,
+        current: DateTime<Utc>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LaunchAlert {
+    pub launch_name: String,
+    pub kind: LaunchAlertKind,
+}
+
+impl LaunchAlert {
+    pub fn message(&self) -> String {
+        match self.kind {
+            LaunchAlertKind::Countdown { minutes: 0 } => format!("T-0: {}", self.launch_name),
+            LaunchAlertKind::Countdown { minutes } => {
+                format!("T-{minutes} min: {}", self.launch_name)
+            }
+            LaunchAlertKind::NetSlip { previous, current } => {
+                let delta = current - previous;
+                format!(
+                    "NET {} {}: {} now {}",
+                    if delta > Duration::zero() {
+                        "slipped"
+                    } else {
+                        "moved up"
+                    },
+                    format_span(delta.abs()),
+                    self.launch_name,
+                    current.format("%Y-%m-%d %H:%M UTC")
+                )
+            }
+        }
+    }
+}
+
+/// Slip size, e.g. "45m", "2h 15m" or "3d 4h"
+fn format_span(span: Duration) -> String {
+    let minutes = span.num_minutes();
+    match (minutes / 1440, minutes % 1440 / 60, minutes % 60) {
+        (0, 0, m) => format!("{m}m"),
+        (0, h, 0) => format!("{h}h"),
+        (0, h, m) => format!("{h}h {m}m"),
+        (d, 0, _) => format!("{d}d"),
+        (d, h, _) => format!("{d}d {h}h"),
+    }
+}
+
+/// Stable key for a launch across refreshes
+fn launch_key(launch: &LaunchSummary) -> String {
+    launch
+        .uuid
+        .clone()
+        .or_else(|| launch.id.map(|id| id.to_string()))
+        .unwrap_or_else(|| launch.name.clone())
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct LaunchAlertTracker {
+    /// Last NET seen for each launch
+    known_nets: HashMap<String, DateTime<Utc>>,
+    /// Clock at the previous countdown check
+    last_clock: Option<DateTime<Utc>>,
+    /// Recent alerts, oldest first
+    pub alerts: Vec<LaunchAlert>,
+    /// Alerts raised since startup, for spotting new ones
+    pub raised: usize,
+}
+
+impl LaunchAlertTracker {
+    /// Record the NETs of a refreshed list and return the launches that moved
+    pub fn check_nets(&mut self, launches: &[LaunchSummary]) -> Vec<LaunchAlert> {
+        let mut alerts = Vec::new();
+        for launch in launches {
+            let Some(net) = launch.net_utc else { continue };
+            let previous = self.known_nets.insert(launch_key(launch), net);
+            if let Some(previous) = previous
+                && (net - previous).num_seconds().abs() >= MIN_SLIP_SECONDS
+            {
+                alerts.push(LaunchAlert {
+                    launch_name: launch.name.clone(),
+                    kind: LaunchAlertKind::NetSlip {
+                        previous,
+                        current: net,
+                    },
+                });
+            }
+        }
+        alerts
+    }
+
+    /// Countdown marks passed since the previous check
+    pub fn check_countdowns(
+        &mut self,
+        launches: &[LaunchSummary],
+        now: DateTime<Utc>,
+    ) -> Vec<LaunchAlert> {
+        let Some(last) = self.last_clock.replace(now) else {
+            return Vec::new();
+        };
+        if now <= last || now - last > Duration::minutes(MAX_COUNTDOWN_STEP_MINUTES) {
+            return Vec::new();
+        }
+
+        let mut alerts = Vec::new();
+        for launch in launches {
+            let Some(net) = launch.net_utc else { continue };
+            for minutes in COUNTDOWN_MARKS_MINUTES {
+                let mark = net - Duration::minutes(minutes);
+                if last < mark && mark <= now {
+                    alerts.push(LaunchAlert {
+                        launch_name: launch.name.clone(),
+                        kind: LaunchAlertKind::Countdown { minutes },
+                    });
+                }
+            }
+        }
+        alerts
+    }
+
+    pub fn push(&mut self, alert: LaunchAlert) {
+        self.alerts.push(alert);
+        if self.alerts.len() > MAX_ALERTS {
+            self.alerts.remove(0);
+        }
+        self.raised += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launch_library::types::test_launch;
+    use chrono::TimeZone;
+
+    fn launch(uuid: &str, net: DateTime<Utc>) -> LaunchSummary {
+        LaunchSummary {
+            uuid: Some(uuid.to_string()),
+            ..test_launch("Falcon 9 Block 5 | Starlink Group 6-40", Some(net))
+        }
+    }
+
+    #[test]
+    fn test_countdown_marks_fire_once_when_passed() {
+        let net = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let launches = vec![launch("a", net)];
+        let mut tracker = LaunchAlertTracker::default();
+
+        // The first check only sets the clock
+        let start = net - Duration::minutes(61);
+        assert!(tracker.check_countdowns(&launches, start).is_empty());
+        let mut fired = Vec::new();
+        let mut now = start;
+        while now < net + Duration::minutes(5) {
+            now += Duration::seconds(30);
+            fired.extend(tracker.check_countdowns(&launches, now));
+        }
+        let minutes: Vec<i64> = fired
+            .iter()
+            .map(|alert| match alert.kind {
+                LaunchAlertKind::Countdown { minutes } => minutes,
+                LaunchAlertKind::NetSlip { .. } => -1,
+            })
+            .collect();
+        assert_eq!(minutes, vec![60, 10, 0]);
+        assert_eq!(
+            fired[2].message(),
+            "T-0: Falcon 9 Block 5 | Starlink Group 6-40"
+        );
+
+        // Jumping back and across the NET is not a countdown
+        tracker.check_countdowns(&launches, net - Duration::hours(2));
+        assert!(
+            tracker
+                .check_countdowns(&launches, net + Duration::hours(1))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_net_slips_between_refreshes() {
+        let net = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let mut tracker = LaunchAlertTracker::default();
+        assert!(tracker.check_nets(&[launch("a", net)]).is_empty());
+        // Under a minute is noise, and a new launch has nothing to compare with
+        assert!(
+            tracker
+                .check_nets(&[launch("a", net + Duration::seconds(30)), launch("b", net)])
+                .is_empty()
+        );
+
+        let slipped = net + Duration::seconds(30) + Duration::hours(2) + Duration::minutes(15);
+        let alerts = tracker.check_nets(&[launch("a", slipped)]);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(
+            alerts[0].message(),
+            "NET slipped 2h 15m: Falcon 9 Block 5 | Starlink Group 6-40 now 2024-03-10 14:15 UTC"
+        );
+        let alerts = tracker.check_nets(&[launch("a", slipped - Duration::days(1))]);
+        assert!(alerts[0].message().starts_with("NET moved up 1d: "));
+    }
+}
@@ -0,0 +1,119 @@
+//! LL2 rate limiting and retry backoff
+//!
+//! The free Launch Library tier allows a handful of requests per hour. A 429
+//! response is honoured through its `Retry-After` header; any other failure
+//! backs off exponentially with jitter instead of retrying every interval.
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+
+/// Wait after the first failure, doubled for each further one
+pub const BACKOFF_BASE_SECONDS: i64 = 60;
+/// Longest wait between retries
+pub const BACKOFF_MAX_SECONDS: i64 = 6 * 3600;
+/// Wait after a 429 that came without a usable `Retry-After`
+pub const RATE_LIMIT_DEFAULT_SECONDS: i64 = 15 * 60;
+
+/// HTTP 429 from LL2, with the server's requested wait if it sent one
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimited {
+    pub retry_after: Option<Duration>,
+}
+
+impl fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.retry_after {
+            Some(wait) => write!(f, "Rate limited (retry after {}s)", wait.num_seconds()),
+            None => write!(f, "Rate limited"),
+        }
+    }
+}
+
+impl std::error::Error for RateLimited {}
+
+/// Parse a `Retry-After` value: delay seconds or an HTTP date
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<i64>() {
+        return Some(Duration::seconds(seconds.max(0)));
+    }
+    let at = DateTime::parse_from_rfc2822(value)
+        .ok()?
+        .with_timezone(&Utc);
+    Some((at - now).max(Duration::zero()))
+}
+
+/// Delay before retry number `failures` (1 for the first failure)
+///
+/// The exponential delay is capped, then the upper half is scaled by `jitter`
+/// (0..1) so several clients don't retry in lockstep.
+pub fn backoff_delay(failures: u32, jitter: f64) -> Duration {
+    let exponent = failures.saturating_sub(1).min(20);
+    let full = (BACKOFF_BASE_SECONDS << exponent).min(BACKOFF_MAX_SECONDS);
+    let half = full / 2;
+    Duration::seconds(half + (half as f64 * jitter.clamp(0.0, 1.0)).round() as i64)
+}
+
+/// Random fraction in 0..1 for [`backoff_delay`]
+pub fn jitter_fraction() -> f64 {
+    // Each RandomState is seeded differently, which is plenty for spreading retries
+    let bits = RandomState::new().build_hasher().finish() >> 11;
+    bits as f64 / (1u64 << 53) as f64
+}
+
+/// Short wait description such as `45s`, `12m` or `3h05m`
+pub fn format_wait(wait: Duration) -> String {
+    let seconds = wait.num_seconds().max(0);
+    if seconds < 60 {
+        format!("{seconds}s")
+    } else if seconds < 3600 {
+        format!("{}m", (seconds + 30) / 60)
+    } else {
+        format!("{}h{:02}m", seconds / 3600, (seconds % 3600) / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 0, 0).unwrap();
+        assert_eq!(parse_retry_after("120", now), Some(Duration::seconds(120)));
+        assert_eq!(parse_retry_after(" 0 ", now), Some(Duration::zero()));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now),
+            Some(Duration::minutes(28))
+        );
+        // A date in the past means retry now
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 06:00:00 GMT", now),
+            Some(Duration::zero())
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        assert_eq!(backoff_delay(1, 0.0), Duration::seconds(30));
+        assert_eq!(backoff_delay(1, 1.0), Duration::seconds(60));
+        assert_eq!(backoff_delay(3, 1.0), Duration::seconds(240));
+        assert_eq!(
+            backoff_delay(40, 1.0),
+            Duration::seconds(BACKOFF_MAX_SECONDS)
+        );
+        assert_eq!(
+            backoff_delay(40, 0.0),
+            Duration::seconds(BACKOFF_MAX_SECONDS / 2)
+        );
+        for _ in 0..100 {
+            let jitter = jitter_fraction();
+            assert!((0.0..1.0).contains(&jitter));
+        }
+        assert_eq!(format_wait(Duration::seconds(3900)), "1h05m");
+    }
+}
@@ -0,0 +1,187 @@
+//! Launch Library disk cache
+//!
+//! The last good LL2 response for each feed is kept on disk with the time it
+//! was fetched, so a restart can show launches and events straight away
+//! instead of spending a request from the rate-limited free tier.
+
+use chrono::{DateTime, Utc};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::launch_library::types::LaunchLibraryFeed;
+
+/// Raw response body as fetched, stored as JSON on disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResponse {
+    pub fetched_at: DateTime<Utc>,
+    pub url: String,
+    pub body: String,
+}
+
+impl CachedResponse {
+    /// Whether this response answers a request for `url` from the current config
+    ///
+    /// The endpoint and query must be the same. Date bounds (`*__gte`, `*__lte`)
+    /// move with the clock, so only the length of the range they span is compared.
+    pub fn matches_request(&self, url: &str) -> bool {
+        let (Ok(cached), Ok(current)) = (reqwest::Url::parse(&self.url), reqwest::Url::parse(url))
+        else {
+            return false;
+        };
+        cached.scheme() == current.scheme()
+            && cached.host_str() == current.host_str()
+            && cached.port_or_known_default() == current.port_or_known_default()
+            && cached.path() == current.path()
+            && query_shape(&cached) == query_shape(&current)
+    }
+}
+
+/// Sorted query pairs with date bounds blanked, and the minutes between the bounds
+fn query_shape(url: &reqwest::Url) -> (Vec<(String, String)>, Option<i64>) {
+    let mut pairs = Vec::new();
+    let (mut start, mut end) = (None, None);
+    for (key, value) in url.query_pairs() {
+        let date = DateTime::parse_from_rfc3339(&value).ok();
+        if key.ends_with("__gte") && date.is_some() {
+            start = date;
+        } else if key.ends_with("__lte") && date.is_some() {
+            end = date;
+        } else {
+            pairs.push((key.into_owned(), value.into_owned()));
+            continue;
+        }
+        pairs.push((key.into_owned(), String::new()));
+    }
+    pairs.sort();
+    let span = start
+        .zip(end)
+        .map(|(start, end)| (end - start).num_minutes());
+    (pairs, span)
+}
+
+/// Launch Library disk cache manager
+pub struct LaunchLibraryCache {
+    cache_dir: PathBuf,
+}
+
+impl LaunchLibraryCache {
+    /// Create a cache in the platform cache directory (`.../bevyearth/launch_library/`)
+    pub fn new() -> Result<Self, anyhow::Error> {
+        let proj_dirs = ProjectDirs::from("", "", "bevyearth")
+            .ok_or_else(|| anyhow::anyhow!("Failed to resolve cache directory"))?;
+        Self::new_in_dir(proj_dirs.cache_dir().join("launch_library"))
+    }
+
+    /// Create a cache rooted at a specific directory
+    pub fn new_in_dir(cache_dir: PathBuf) -> Result<Self, anyhow::Error> {
+        fs::create_dir_all(&cache_dir)?;
+        Ok(Self { cache_dir })
+    }
+
+    /// Read the last saved response for a feed
+    ///
+    /// Returns Ok(None) if nothing has been saved yet.
+    pub fn read(&self, feed: LaunchLibraryFeed) -> Result<Option<CachedResponse>, anyhow::Error> {
+        let path = self.cache_path(feed);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = fs::read_to_string(&path)?;
+        Ok(Some(serde_json::from_str(&contents)?))
+    }
+
+    /// Save a response that parsed successfully, replacing the previous one
+    pub fn write(
+        &self,
+        feed: LaunchLibraryFeed,
+        entry: &CachedResponse,
+    ) -> Result<(), anyhow::Error> {
+        fs::write(self.cache_path(feed), serde_json::to_string(entry)?)?;
+        Ok(())
+    }
+
+    fn cache_path(&self, feed: LaunchLibraryFeed) -> PathBuf {
+        let name = match feed {
+            LaunchLibraryFeed::Launches => "launches.json",
+            LaunchLibraryFeed::Events => "events.json",
+            LaunchLibraryFeed::Recent => "recent.json",
+            LaunchLibraryFeed::Previous => "previous.json",
+            LaunchLibraryFeed::Stations => "stations.json",
+        };
+        self.cache_dir.join(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn unique_temp_dir(test_name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        std::env::temp_dir().join(format!(
+            "bevyearth-ll-cache-{}-{}-{}",
+            test_name,
+            std::process::id(),
+            nanos
+        ))
+    }
+
+    #[test]
+    fn test_cache_round_trip_per_feed() {
+        let cache = LaunchLibraryCache::new_in_dir(unique_temp_dir("round_trip"))
+            .expect("Failed to create cache");
+        assert!(cache.read(LaunchLibraryFeed::Launches).unwrap().is_none());
+
+        let entry = CachedResponse {
+            fetched_at: Utc::now(),
+            url: "https://ll.thespacedevs.com/2.3.0/launches/".to_string(),
+            body: r#"{"results":[{"name":"Test Launch"}]}"#.to_string(),
+        };
+        cache
+            .write(LaunchLibraryFeed::Launches, &entry)
+            .expect("Failed to write cache");
+
+        let cached = cache
+            .read(LaunchLibraryFeed::Launches)
+            .unwrap()
+            .expect("Cache entry not found");
+        assert_eq!(cached.body, entry.body);
+        assert_eq!(cached.fetched_at, entry.fetched_at);
+        // Feeds are stored separately
+        assert!(cache.read(LaunchLibraryFeed::Events).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_cached_response_matches_current_request() {
+        let cached = CachedResponse {
+            fetched_at: Utc::now(),
+            url: "https://ll.thespacedevs.com/2.3.0/launches/?net__gte=2025-03-01T10%3A00%3A00%2B00%3A00\
+                  &net__lte=2025-03-31T10%3A00%3A00%2B00%3A00&ordering=net&limit=10&mode=detailed"
+                .to_string(),
+            body: String::new(),
+        };
+        // A later request over the same 30 days is the same query
+        assert!(cached.matches_request(
+            "https://ll.thespacedevs.com/2.3.0/launches/?net__gte=2025-03-02T08%3A00%3A00%2B00%3A00\
+             &net__lte=2025-04-01T08%3A00%3A00%2B00%3A00&ordering=net&limit=10&mode=detailed"
+        ));
+        // Another endpoint, page size or window is not
+        for url in [
+            "https://lldev.thespacedevs.com/2.3.0/launches/?net__gte=2025-03-02T08%3A00%3A00%2B00%3A00\
+             &net__lte=2025-04-01T08%3A00%3A00%2B00%3A00&ordering=net&limit=10&mode=detailed",
+            "https://ll.thespacedevs.com/2.3.0/launches/?net__gte=2025-03-02T08%3A00%3A00%2B00%3A00\
+             &net__lte=2025-04-01T08%3A00%3A00%2B00%3A00&ordering=net&limit=25&mode=detailed",
+            "https://ll.thespacedevs.com/2.3.0/launches/?net__gte=2025-03-02T08%3A00%3A00%2B00%3A00\
+             &net__lte=2025-03-16T08%3A00%3A00%2B00%3A00&ordering=net&limit=10&mode=detailed",
+            "not a url",
+        ] {
+            assert!(!cached.matches_request(url), "{url}");
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! iCalendar (RFC 5545) export of upcoming launches and events
+//!
+//! Each launch and event becomes a VEVENT with a stable UID from its LL2 id, so
+//! a calendar subscribed to the exported file updates entries in place when a
+//! NET moves instead of duplicating them.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::launch_library::types::{EventSummary, LaunchSummary};
+
+/// File name used for exports to the export directory
+pub const CALENDAR_FILE_NAME: &str = "launches.ics";
+/// Calendar entries get this length; LL2 summaries carry no window end
+const ENTRY_MINUTES: i64 = 60;
+/// Content lines longer than this many octets are folded
+const FOLD_OCTETS: usize = 75;
+
+/// Calendar with the launches and events at or after `now`
+pub fn build_calendar(
+    launches: &[LaunchSummary],
+    events: &[EventSummary],
+    now: DateTime<Utc>,
+) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//bevyearth//Launch Library//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        "X-WR-CALNAME:Launches & Events".to_string(),
+    ];
+
+    for launch in launches {
+        let Some(net) = launch.net_utc.filter(|net| *net >= now) else {
+            continue;
+        };
+        let uid = launch
+            .uuid
+            .clone()
+            .or_else(|| launch.id.map(|id| id.to_string()))
+            .unwrap_or_else(|| launch.name.clone());
+        let location = [
+            launch.pad_name.as_deref(),
+            launch.pad_location_name.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", ");
+        let description = [
+            ("Provider", launch.provider_name.as_deref()),
+            ("Mission", launch.mission_name.as_deref()),
+            ("Orbit", launch.orbit_name.as_deref()),
+            ("Status", launch.status_name.as_deref()),
+        ]
+        .into_iter()
+        .filter_map(|(label, value)| value.map(|value| format!("{label}: {value}")))
+        .collect::<Vec<_>>()
+        .join("\n");
+        // LL2 marks a confirmed date as "Go for Launch"; anything else may still move
+        let status = if launch.status_name.as_deref() == Some("Go for Launch") {
+            "CONFIRMED"
+        } else {
+            "TENTATIVE"
+        };
+        push_event(
+            &mut lines,
+            now,
+            CalendarEntry {
+                uid: format!("launch-{uid}"),
+                start: net,
+                summary: format!("Launch: {}", launch.name),
+                location,
+                description,
+                status,
+            },
+        );
+    }
+
+    for event in events {
+        let Some(date) = event.date_utc.filter(|date| *date >= now) else {
+            continue;
+        };
+        let uid = event
+            .id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| event.name.clone());
+        let description = [event.type_name.as_deref(), event.description.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join("\n");
+        push_event(
+            &mut lines,
+            now,
+            CalendarEntry {
+                uid: format!("event-{uid}"),
+                start: date,
+                summary: format!("Event: {}", event.name),
+                location: event.location.clone().unwrap_or_default(),
+                description,
+                status: "TENTATIVE",
+            },
+        );
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+    lines.iter().map(|line| fold_line(line)).collect()
+}
+
+/// One VEVENT; text fields are unescaped
+struct CalendarEntry {
+    uid: String,
+    start: DateTime<Utc>,
+    summary: String,
+    location: String,
+    description: String,
+    status: &'static str,
+}
+
+fn push_event(lines: &mut Vec<String>, stamp: DateTime<Utc>, entry: CalendarEntry) {
+    lines.push("BEGIN:VEVENT".to_string());
+    lines.push(format!("UID:{}@ll2.bevyearth", escape_text(&entry.uid)));
+    lines.push(format!("DTSTAMP:{}", format_utc(stamp)));
+    lines.push(format!("DTSTART:{}", format_utc(entry.start)));
+    lines.push(format!(
+        "DTEND:{}",
+        format_utc(entry.start + Duration::minutes(ENTRY_MINUTES))
+    ));
+    lines.push(format!("SUMMARY:{}", escape_text(&entry.summary)));
+    if !entry.location.is_empty() {
+        lines.push(format!("LOCATION:{}", escape_text(&entry.location)));
+    }
+    if !entry.description.is_empty() {
+        lines.push(format!("DESCRIPTION:{}", escape_text(&entry.description)));
+    }
+    lines.push(format!("STATUS:{}", entry.status));
+    lines.push("END:VEVENT".to_string());
+}
+
+fn format_utc(utc: DateTime<Utc>) -> String {
+    utc.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value: backslash, semicolon, comma and newline
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Fold a content line into CRLF-terminated pieces of at most 75 octets
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 8);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > FOLD_OCTETS {
+            folded.push_str("\r\n ");
+            // The leading space counts towards the continuation line
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launch_library::types::test_launch;
+    use chrono::TimeZone;
+
+    fn launch(name: &str, net: DateTime<Utc>) -> LaunchSummary {
+        LaunchSummary {
+            pad_name: Some("Space Launch Complex 40".to_string()),
+            pad_location_name: Some("Cape Canaveral SFS, FL, USA".to_string()),
+            provider_name: Some("SpaceX".to_string()),
+            orbit_name: Some("Low Earth Orbit".to_string()),
+            status_name: Some("Go for Launch".to_string()),
+            uuid: Some("f2a5e0e6-0000-4000-8000-000000000001".to_string()),
+            ..test_launch(name, Some(net))
+        }
+    }
+
+    #[test]
+    fn test_calendar_lists_upcoming_entries() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let launches = vec![
+            launch(
+                "Falcon 9 Block 5 | Starlink Group 6-40",
+                now + Duration::hours(5),
+            ),
+            launch("Already flown", now - Duration::hours(1)),
+        ];
+        let events = vec![EventSummary {
+            id: Some(812),
+            name: "Crew-8 Docking".to_string(),
+            date_utc: Some(now + Duration::days(2)),
+            location: Some("International Space Station".to_string()),
+            type_name: Some("Docking".to_string()),
+            description: None,
+        }];
+        let ics = build_calendar(&launches, &events, now);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("UID:launch-f2a5e0e6-0000-4000-8000-000000000001@ll2.bevyearth\r\n"));
+        assert!(ics.contains("DTSTART:20240310T170000Z\r\nDTEND:20240310T180000Z\r\n"));
+        assert!(ics.contains("SUMMARY:Launch: Falcon 9 Block 5 | Starlink Group 6-40\r\n"));
+        assert!(
+            ics.contains("LOCATION:Space Launch Complex 40\\, Cape Canaveral SFS\\, FL\\, USA")
+        );
+        assert!(ics.contains("DESCRIPTION:Provider: SpaceX\\nOrbit: Low Earth Orbit\\nStatus: Go"));
+        assert!(ics.contains("STATUS:CONFIRMED\r\n"));
+        assert!(ics.contains("UID:event-812@ll2.bevyearth\r\n"));
+        assert!(!ics.contains("Already flown"));
+    }
+
+    #[test]
+    fn test_long_lines_fold_at_75_octets() {
+        let line = format!("DESCRIPTION:{}", "é".repeat(60));
+        let folded = fold_line(&line);
+        let pieces: Vec<&str> = folded.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(pieces.len() > 1);
+        assert!(pieces.iter().all(|piece| piece.len() <= FOLD_OCTETS));
+        assert!(pieces[1..].iter().all(|piece| piece.starts_with(' ')));
+        let unfolded: String = pieces
+            .iter()
+            .enumerate()
+            .map(|(i, piece)| if i == 0 { *piece } else { &piece[1..] })
+            .collect();
+        assert_eq!(unfolded, line);
+        assert_eq!(escape_text("a;b,c\\d\ne"), "a\\;b\\,c\\\\d\\ne");
+    }
+}
@@ -0,0 +1,169 @@
+//! Payload TLEs for recently launched missions
+//!
+//! LL2 gives a launch its international designator after liftoff, and CelesTrak
+//! lists the objects under it once they are cataloged, which can take from hours
+//! to days. Recent successful launches are tracked here and their `INTDES` group
+//! is requested every few hours until objects show up.
+
+use bevy::prelude::*;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::launch_library::types::LaunchSummary;
+
+/// How far back recent launches are listed and tracked
+pub const RECENT_LAUNCH_DAYS: i64 = 7;
+/// How often the recent launch list is refreshed from LL2
+pub const RECENT_REFRESH_HOURS: i64 = 2;
+/// How often an uncataloged launch is looked up again (CelesTrak asks for no more than this)
+pub const INTDES_RETRY_HOURS: i64 = 2;
+
+/// A launch whose payloads are being loaded as a group
+#[derive(Clone, Debug)]
+pub struct Deployment {
+    /// International designator, e.g. `2024-123`
+    pub cospar_id: String,
+    /// Group name: the mission, or the launch when LL2 has no mission
+    pub name: String,
+    pub launched_at: DateTime<Utc>,
+    pub last_request: Option<DateTime<Utc>>,
+    /// At least one object from the group has been added
+    pub loaded: bool,
+}
+
+impl Deployment {
+    pub fn group_url(&self) -> String {
+        intdes_group_url(&self.cospar_id)
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct DeploymentTracker {
+    pub deployments: Vec<Deployment>,
+}
+
+impl DeploymentTracker {
+    /// Track newly seen launches that put payloads up, and drop ones past the window
+    pub fn update(&mut self, launches: &[LaunchSummary], now: DateTime<Utc>) {
+        let window = Duration::days(RECENT_LAUNCH_DAYS);
+        self.deployments.retain(|d| now - d.launched_at <= window);
+
+        for launch in launches {
+            let (Some(cospar_id), Some(launched_at)) =
+                (launch.cospar_id.as_deref(), launch.net_utc)
+            else {
+                continue;
+            };
+            if launched_at > now
+                || now - launched_at > window
+                || !payloads_expected(launch.status_name.as_deref())
+                || self.deployments.iter().any(|d| d.cospar_id == cospar_id)
+            {
+                continue;
+            }
+            self.deployments.push(Deployment {
+                cospar_id: cospar_id.to_string(),
+                name: launch
+                    .mission_name
+                    .clone()
+                    .unwrap_or_else(|| launch.name.clone()),
+                launched_at,
+                last_request: None,
+                loaded: false,
+            });
+        }
+    }
+
+    /// Deployments to look up now; they are marked as requested
+    pub fn take_due(&mut self, now: DateTime<Utc>) -> Vec<Deployment> {
+        let retry = Duration::hours(INTDES_RETRY_HOURS);
+        self.deployments
+            .iter_mut()
+            .filter(|d| !d.loaded && d.last_request.is_none_or(|t| now - t >= retry))
+            .map(|d| {
+                d.last_request = Some(now);
+                d.clone()
+            })
+            .collect()
+    }
+}
+
+/// CelesTrak query for every object from one launch
+pub fn intdes_group_url(cospar_id: &str) -> String {
+    format!("https://celestrak.org/NORAD/elements/gp.php?INTDES={cospar_id}&FORMAT=TLE")
+}
+
+/// Whether a launch with this LL2 status name left anything in orbit
+fn payloads_expected(status_name: Option<&str>) -> bool {
+    matches!(
+        status_name,
+        Some("Launch Successful") | Some("Launch was a Partial Failure")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launch_library::types::test_launch;
+    use chrono::TimeZone;
+
+    fn launch(cospar: Option<&str>, net: DateTime<Utc>, status: &str) -> LaunchSummary {
+        LaunchSummary {
+            mission_name: Some("Starlink Group 10-1".to_string()),
+            status_name: Some(status.to_string()),
+            cospar_id: cospar.map(str::to_string),
+            ..test_launch("Falcon 9 Block 5 | Starlink Group 10-1", Some(net))
+        }
+    }
+
+    #[test]
+    fn test_tracks_recent_successful_launches_once() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let launches = vec![
+            launch(
+                Some("2024-050"),
+                now - Duration::hours(5),
+                "Launch Successful",
+            ),
+            launch(None, now - Duration::hours(1), "Launch Successful"),
+            launch(Some("2024-049"), now - Duration::days(1), "Launch Failure"),
+            launch(
+                Some("2024-030"),
+                now - Duration::days(20),
+                "Launch Successful",
+            ),
+        ];
+        let mut tracker = DeploymentTracker::default();
+        tracker.update(&launches, now);
+        tracker.update(&launches, now);
+        assert_eq!(tracker.deployments.len(), 1);
+        assert_eq!(tracker.deployments[0].name, "Starlink Group 10-1");
+        assert_eq!(
+            tracker.deployments[0].group_url(),
+            "https://celestrak.org/NORAD/elements/gp.php?INTDES=2024-050&FORMAT=TLE"
+        );
+
+        // Dropped once it falls out of the window
+        tracker.update(&[], now + Duration::days(RECENT_LAUNCH_DAYS));
+        assert!(tracker.deployments.is_empty());
+    }
+
+    #[test]
+    fn test_take_due_waits_between_lookups() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let mut tracker = DeploymentTracker::default();
+        tracker.update(
+            &[launch(
+                Some("2024-050"),
+                now - Duration::hours(5),
+                "Launch Successful",
+            )],
+            now,
+        );
+        assert_eq!(tracker.take_due(now).len(), 1);
+        assert!(tracker.take_due(now + Duration::minutes(30)).is_empty());
+        assert_eq!(tracker.take_due(now + Duration::hours(2)).len(), 1);
+
+        tracker.deployments[0].loaded = true;
+        assert!(tracker.take_due(now + Duration::hours(6)).is_empty());
+    }
+}
@@ -1,7 +1,11 @@
 //! Launch Library fetcher worker.
 
+use crate::launch_library::backoff::{RateLimited, parse_retry_after};
+use crate::launch_library::cache::{CachedResponse, LaunchLibraryCache};
 use crate::launch_library::types::{
-    EventSummary, LaunchLibraryCommand, LaunchLibraryFeed, LaunchLibraryResult, LaunchSummary,
+    CrewMember, DockingEvent, EventSummary, LandingAttempt, LaunchDetail, LaunchLibraryCommand,
+    LaunchLibraryFeed, LaunchLibraryResult, LaunchPage, LaunchSummary, SpaceStation, StationData,
+    Webcast,
 };
 use anyhow::Result;
 use chrono::{DateTime, Utc};
@@ -20,20 +24,98 @@ pub fn start_launch_library_worker() -> crate::launch_library::types::LaunchLibr
         let rt = tokio::runtime::Runtime::new().expect("tokio runtime");
         rt.block_on(async move {
             let client = reqwest::Client::new();
+            let cache = LaunchLibraryCache::new()
+                .map_err(|err| eprintln!("[LAUNCH LIBRARY] Disk cache unavailable: {err}"))
+                .ok();
 
             while let Ok(cmd) = cmd_rx.recv() {
-                let (feed, result) = match cmd {
-                    LaunchLibraryCommand::FetchLaunches { url } => {
-                        let res = fetch_launches(&client, &url)
+                // `feed` is None for a launch detail request, which reports errors by UUID
+                let (feed, detail_uuid, result) = match cmd {
+                    LaunchLibraryCommand::FetchLaunches {
+                        url,
+                        api_token,
+                        more,
+                    } => {
+                        let res = fetch_body(&client, &url, api_token.as_deref())
                             .await
-                            .map(LaunchLibraryResult::Launches);
-                        (LaunchLibraryFeed::Launches, res)
+                            .and_then(|body| {
+                                let page = parse_launch_page(&body)?;
+                                if more {
+                                    return Ok(LaunchLibraryResult::MoreLaunches(page));
+                                }
+                                // Only the first page is cached; later pages are fetched on request
+                                save_response(
+                                    cache.as_ref(),
+                                    LaunchLibraryFeed::Launches,
+                                    url,
+                                    body,
+                                );
+                                Ok(LaunchLibraryResult::Launches(page))
+                            });
+                        (Some(LaunchLibraryFeed::Launches), None, res)
                     }
-                    LaunchLibraryCommand::FetchEvents { url } => {
-                        let res = fetch_events(&client, &url)
+                    LaunchLibraryCommand::FetchEvents { url, api_token } => {
+                        let res = fetch_body(&client, &url, api_token.as_deref())
                             .await
-                            .map(LaunchLibraryResult::Events);
-                        (LaunchLibraryFeed::Events, res)
+                            .and_then(|body| {
+                                let events = parse_events(&body)?;
+                                save_response(cache.as_ref(), LaunchLibraryFeed::Events, url, body);
+                                Ok(LaunchLibraryResult::Events(events))
+                            });
+                        (Some(LaunchLibraryFeed::Events), None, res)
+                    }
+                    LaunchLibraryCommand::FetchRecentLaunches { url, api_token } => {
+                        let res = fetch_body(&client, &url, api_token.as_deref())
+                            .await
+                            .and_then(|body| {
+                                let page = parse_launch_page(&body)?;
+                                save_response(cache.as_ref(), LaunchLibraryFeed::Recent, url, body);
+                                Ok(LaunchLibraryResult::RecentLaunches(page.launches))
+                            });
+                        (Some(LaunchLibraryFeed::Recent), None, res)
+                    }
+                    LaunchLibraryCommand::FetchPreviousLaunches { url, api_token } => {
+                        // Past launches follow the clock, so they aren't cached
+                        let res = fetch_body(&client, &url, api_token.as_deref())
+                            .await
+                            .and_then(|body| parse_launch_page(&body))
+                            .map(|page| LaunchLibraryResult::PreviousLaunches(page.launches));
+                        (Some(LaunchLibraryFeed::Previous), None, res)
+                    }
+                    LaunchLibraryCommand::FetchStations {
+                        stations_url,
+                        expeditions_url,
+                        docking_url,
+                        api_token,
+                    } => {
+                        let res = fetch_station_bodies(
+                            &client,
+                            [&stations_url, &expeditions_url, &docking_url].map(String::as_str),
+                            api_token.as_deref(),
+                        )
+                        .await
+                        .and_then(|body| {
+                            let data = parse_station_data(&body, Utc::now())?;
+                            save_response(
+                                cache.as_ref(),
+                                LaunchLibraryFeed::Stations,
+                                stations_url,
+                                body,
+                            );
+                            Ok(LaunchLibraryResult::Stations(data))
+                        });
+                        (Some(LaunchLibraryFeed::Stations), None, res)
+                    }
+                    LaunchLibraryCommand::FetchLaunchDetail {
+                        uuid,
+                        url,
+                        api_token,
+                    } => {
+                        let res = fetch_body(&client, &url, api_token.as_deref())
+                            .await
+                            .and_then(|body| parse_launch_detail(&body))
+                            .map(|detail| LaunchLibraryResult::LaunchDetail(Box::new(detail)));
+                        (None, Some(uuid), res)
                     }
                 };
 
@@ -44,11 +126,28 @@ pub fn start_launch_library_worker() -> crate::launch_library::types::LaunchLibr
                 match result {
                     Ok(msg) => send(msg),
                     Err(err) => {
-                        eprintln!("[LAUNCH LIBRARY] {feed:?} fetch failed: {err}");
-                        send(LaunchLibraryResult::Error {
-                            feed,
-                            error: err.to_string(),
-                        });
+                        if let Some(limited) = err.downcast_ref::<RateLimited>() {
+                            let what =
+                                feed.map_or("Launch detail".to_string(), |f| format!("{f:?}"));
+                            eprintln!("[LAUNCH LIBRARY] {what} fetch: {limited}");
+                            send(LaunchLibraryResult::RateLimited {
+                                feed,
+                                retry_after: limited.retry_after,
+                            });
+                        } else if let Some(feed) = feed {
+                            eprintln!("[LAUNCH LIBRARY] {feed:?} fetch failed: {err}");
+                            send(LaunchLibraryResult::Error {
+                                feed,
+                                error: err.to_string(),
+                            });
+                        } else {
+                            let uuid = detail_uuid.unwrap_or_default();
+                            eprintln!("[LAUNCH LIBRARY] Launch {uuid} detail fetch failed: {err}");
+                            send(LaunchLibraryResult::DetailError {
+                                uuid,
+                                error: err.to_string(),
+                            });
+                        }
                     }
                 }
             }
@@ -61,19 +160,43 @@ pub fn start_launch_library_worker() -> crate::launch_library::types::LaunchLibr
     }
 }
 
-async fn fetch_launches(client: &reqwest::Client, url: &str) -> Result<Vec<LaunchSummary>> {
-    let body = fetch_body(client, url).await?;
-    parse_launches(&body)
-}
-
-async fn fetch_events(client: &reqwest::Client, url: &str) -> Result<Vec<EventSummary>> {
-    let body = fetch_body(client, url).await?;
-    parse_events(&body)
+/// Keep a response that parsed, so the next start can show it without a request
+fn save_response(
+    cache: Option<&LaunchLibraryCache>,
+    feed: LaunchLibraryFeed,
+    url: String,
+    body: String,
+) {
+    let Some(cache) = cache else { return };
+    let entry = CachedResponse {
+        fetched_at: Utc::now(),
+        url,
+        body,
+    };
+    if let Err(err) = cache.write(feed, &entry) {
+        eprintln!("[LAUNCH LIBRARY] Failed to cache {feed:?}: {err}");
+    }
 }
 
-async fn fetch_body(client: &reqwest::Client, url: &str) -> Result<String> {
-    let resp = client.get(url).send().await?;
+async fn fetch_body(
+    client: &reqwest::Client,
+    url: &str,
+    api_token: Option<&str>,
+) -> Result<String> {
+    let mut request = client.get(url);
+    if let Some(token) = api_token {
+        request = request.header(reqwest::header::AUTHORIZATION, format!("Token {token}"));
+    }
+    let resp = request.send().await?;
     let status = resp.status();
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| parse_retry_after(value, Utc::now()));
+        return Err(RateLimited { retry_after }.into());
+    }
     let body = resp.text().await?;
     if !status.is_success() {
         anyhow::bail!("HTTP {} for {}", status, url);
@@ -81,15 +204,43 @@ async fn fetch_body(client: &reqwest::Client, url: &str) -> Result<String> {
     Ok(body)
 }
 
-fn parse_launches(body: &str) -> Result<Vec<LaunchSummary>> {
+/// Fetch stations, expeditions and docking events into one body for caching
+async fn fetch_station_bodies(
+    client: &reqwest::Client,
+    [stations_url, expeditions_url, docking_url]: [&str; 3],
+    api_token: Option<&str>,
+) -> Result<String> {
+    let mut combined = serde_json::Map::new();
+    for (key, url) in [
+        ("space_stations", stations_url),
+        ("expeditions", expeditions_url),
+        ("docking_events", docking_url),
+    ] {
+        let body = fetch_body(client, url, api_token).await?;
+        combined.insert(key.to_string(), serde_json::from_str(&body)?);
+    }
+    Ok(Value::Object(combined).to_string())
+}
+
+/// Parse a `/launches/` response with its paging links
+pub fn parse_launch_page(body: &str) -> Result<LaunchPage> {
     let value: Value = serde_json::from_str(body)?;
-    let items = extract_items(&value);
+    Ok(LaunchPage {
+        launches: parse_launch_items(&value),
+        next: get_string(&value, "next"),
+        total: value.get("count").and_then(|v| v.as_u64()),
+    })
+}
+
+fn parse_launch_items(value: &Value) -> Vec<LaunchSummary> {
+    let items = extract_items(value);
     let mut launches = Vec::with_capacity(items.len());
 
     for item in items {
         let name = get_string(item, "name").unwrap_or_else(|| "Unnamed Launch".to_string());
         let net_utc = get_string_ref(item, "net").and_then(parse_datetime);
         let id = get_i64(item, "id");
+        let uuid = get_string(item, "id").filter(|_| id.is_none());
 
         let pad = item.get("pad");
         let pad_id = pad.and_then(|p| get_i64(p, "id"));
@@ -97,6 +248,7 @@ fn parse_launches(body: &str) -> Result<Vec<LaunchSummary>> {
         let pad_lat = pad.and_then(|p| get_f64(p, "latitude"));
         let pad_lon = pad.and_then(|p| get_f64(p, "longitude"));
         let pad_location_name = pad.and_then(|p| p.get("location")).and_then(extract_name);
+        let pad_country = pad.and_then(pad_country);
         let launch_location_name = item.get("location").and_then(extract_name);
 
         let provider_name = item
@@ -123,16 +275,37 @@ fn parse_launches(body: &str) -> Result<Vec<LaunchSummary>> {
             pad_lat,
             pad_lon,
             pad_location_name: pad_location_name.or(launch_location_name),
+            pad_country,
             provider_name,
             mission_name,
             orbit_name,
+            status_name: item.get("status").and_then(extract_name),
+            cospar_id: get_string(item, "launch_designator").filter(|d| !d.is_empty()),
+            uuid,
         });
     }
 
-    Ok(launches)
+    launches
 }
 
-fn parse_events(body: &str) -> Result<Vec<EventSummary>> {
+/// Country of a pad: 2.3 has a `country` object, 2.2 a `country_code` on the pad or location
+fn pad_country(pad: &Value) -> Option<String> {
+    let from_object = |holder: &Value| {
+        holder
+            .get("country")
+            .filter(|c| c.is_object())
+            .and_then(|c| get_string(c, "alpha_3_code").or_else(|| get_string(c, "name")))
+    };
+    from_object(pad)
+        .or_else(|| get_string(pad, "country_code"))
+        .or_else(|| pad.get("location").and_then(from_object))
+        .or_else(|| {
+            pad.get("location")
+                .and_then(|l| get_string(l, "country_code"))
+        })
+}
+
+pub fn parse_events(body: &str) -> Result<Vec<EventSummary>> {
     let value: Value = serde_json::from_str(body)?;
     let items = extract_items(&value);
     let mut events = Vec::with_capacity(items.len());
@@ -160,6 +333,275 @@ fn parse_events(body: &str) -> Result<Vec<EventSummary>> {
     Ok(events)
 }
 
+/// Parse the combined station body; crews come from expeditions running at `now`
+pub fn parse_station_data(body: &str, now: DateTime<Utc>) -> Result<StationData> {
+    let value: Value = serde_json::from_str(body)?;
+    let Some(station_list) = value.get("space_stations") else {
+        anyhow::bail!("Station data without a space_stations list");
+    };
+
+    let mut stations = Vec::new();
+    for item in extract_items(station_list) {
+        let status = item.get("status").and_then(extract_name);
+        // Only stations in orbit can have crews or visitors
+        if status.as_deref().is_some_and(|s| s != "Active") {
+            continue;
+        }
+        let mut station = SpaceStation {
+            id: get_i64(item, "id"),
+            name: get_string(item, "name").unwrap_or_else(|| "Unnamed Station".to_string()),
+            status,
+            orbit: item.get("orbit").and_then(extract_name),
+            expeditions: Vec::new(),
+            crew: Vec::new(),
+        };
+        for expedition in as_list(item.get("active_expeditions")) {
+            add_expedition(&mut station, expedition, now);
+        }
+        stations.push(station);
+    }
+
+    // The expeditions feed carries the crews when stations list expeditions without them
+    for expedition in value
+        .get("expeditions")
+        .map(extract_items)
+        .unwrap_or_default()
+    {
+        let target = expedition.get("spacestation");
+        let target_id = target.and_then(|s| get_i64(s, "id"));
+        let target_name = target.and_then(extract_name);
+        if let Some(station) = stations.iter_mut().find(|station| {
+            (target_id.is_some() && station.id == target_id)
+                || target_name.as_deref() == Some(station.name.as_str())
+        }) {
+            add_expedition(station, expedition, now);
+        }
+    }
+
+    let docking_events = value
+        .get("docking_events")
+        .map(extract_items)
+        .unwrap_or_default()
+        .into_iter()
+        .map(parse_docking_event)
+        .collect();
+
+    Ok(StationData {
+        stations,
+        docking_events,
+    })
+}
+
+/// Add an expedition and its crew if it is running at `now` and not yet listed
+fn add_expedition(station: &mut SpaceStation, expedition: &Value, now: DateTime<Utc>) {
+    let start = get_string_ref(expedition, "start").and_then(parse_datetime);
+    let end = get_string_ref(expedition, "end").and_then(parse_datetime);
+    if start.is_some_and(|start| start > now) || end.is_some_and(|end| end <= now) {
+        return;
+    }
+    let name = get_string(expedition, "name").unwrap_or_else(|| "Expedition".to_string());
+    if station.expeditions.contains(&name) {
+        return;
+    }
+    station.expeditions.push(name);
+
+    for member in as_list(expedition.get("crew")) {
+        let astronaut = member.get("astronaut");
+        let Some(name) = astronaut.and_then(|a| get_string(a, "name")) else {
+            continue;
+        };
+        if station.crew.iter().any(|crew| crew.name == name) {
+            continue;
+        }
+        station.crew.push(CrewMember {
+            name,
+            // 2.2 and 2.3 give the role as `{"role": "Commander"}`
+            role: member
+                .get("role")
+                .and_then(|r| get_string(r, "role").or_else(|| extract_name(r))),
+            agency: astronaut
+                .and_then(|a| a.get("agency"))
+                .and_then(|a| get_string(a, "abbrev").or_else(|| extract_name(a))),
+        });
+    }
+}
+
+/// 2.3 names the visitor `flight_vehicle_chaser` and the station `space_station_target`;
+/// 2.2 used `flight_vehicle` and `space_station`
+fn parse_docking_event(item: &Value) -> DockingEvent {
+    let vehicle = item
+        .get("flight_vehicle_chaser")
+        .or_else(|| item.get("flight_vehicle"))
+        .and_then(|f| f.get("spacecraft"))
+        .and_then(|s| get_string(s, "name"))
+        .or_else(|| {
+            item.get("payload_flight_chaser")
+                .and_then(|f| f.get("payload"))
+                .and_then(|p| get_string(p, "name"))
+        })
+        .unwrap_or_else(|| "Spacecraft".to_string());
+    let location = item.get("docking_location").filter(|l| l.is_object());
+    let station = item
+        .get("space_station_target")
+        .or_else(|| item.get("space_station"))
+        .or_else(|| location.and_then(|l| l.get("spacestation")))
+        .and_then(extract_name);
+
+    DockingEvent {
+        id: get_i64(item, "id"),
+        vehicle,
+        station,
+        port: location.and_then(extract_name),
+        docking_utc: get_string_ref(item, "docking").and_then(parse_datetime),
+        departure_utc: get_string_ref(item, "departure").and_then(parse_datetime),
+    }
+}
+
+/// Parse a single launch from `/launches/{id}/`
+pub fn parse_launch_detail(body: &str) -> Result<LaunchDetail> {
+    let item: Value = serde_json::from_str(body)?;
+    let Some(uuid) = get_string(&item, "id") else {
+        anyhow::bail!("Launch detail without an id");
+    };
+
+    let rocket = item.get("rocket");
+    let configuration = rocket.and_then(|r| r.get("configuration"));
+    // 2.3 lists families as objects; 2.2 had a single `family` string
+    let rocket_family = configuration.and_then(|c| {
+        c.get("families")
+            .and_then(|f| f.as_array())
+            .and_then(|f| f.first())
+            .and_then(extract_name)
+            .or_else(|| get_string(c, "family"))
+    });
+    let rocket_name =
+        configuration.and_then(|c| get_string(c, "full_name").or_else(|| get_string(c, "name")));
+    let rocket_variant = configuration
+        .and_then(|c| get_string(c, "variant"))
+        .filter(|v| !v.is_empty());
+
+    let mission = item.get("mission");
+    let mission_type = mission.and_then(|m| m.get("type")).and_then(extract_name);
+    let mission_description = mission.and_then(|m| get_string(m, "description"));
+
+    let webcasts = item
+        .get("vid_urls")
+        .and_then(|v| v.as_array())
+        .map(|vids| {
+            vids.iter()
+                .filter_map(|vid| {
+                    Some(Webcast {
+                        title: get_string(vid, "title"),
+                        url: get_string(vid, "url")?,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut landings = Vec::new();
+    for stage in rocket
+        .map(|r| as_list(r.get("launcher_stage")))
+        .unwrap_or_default()
+    {
+        let vehicle = stage
+            .get("launcher")
+            .and_then(|l| get_string(l, "serial_number"))
+            .unwrap_or_else(|| "Booster".to_string());
+        if let Some(landing) = stage.get("landing").and_then(|l| parse_landing(l, vehicle)) {
+            landings.push(landing);
+        }
+    }
+    for stage in rocket
+        .map(|r| as_list(r.get("spacecraft_stage")))
+        .unwrap_or_default()
+    {
+        let vehicle = stage
+            .get("spacecraft")
+            .and_then(|s| get_string(s, "name"))
+            .unwrap_or_else(|| "Spacecraft".to_string());
+        if let Some(landing) = stage.get("landing").and_then(|l| parse_landing(l, vehicle)) {
+            landings.push(landing);
+        }
+    }
+
+    Ok(LaunchDetail {
+        uuid,
+        rocket_name,
+        rocket_family,
+        rocket_variant,
+        mission_type,
+        mission_description,
+        window_start: get_string_ref(&item, "window_start").and_then(parse_datetime),
+        window_end: get_string_ref(&item, "window_end").and_then(parse_datetime),
+        status: item.get("status").and_then(extract_name),
+        probability: get_i64(&item, "probability"),
+        webcast_live: item
+            .get("webcast_live")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        webcasts,
+        landings,
+        payload_norad_ids: rocket.map(payload_norad_ids).unwrap_or_default(),
+    })
+}
+
+/// NORAD ids from `rocket.payloads`, given on the payload flight or the payload itself
+fn payload_norad_ids(rocket: &Value) -> Vec<u32> {
+    let mut ids: Vec<u32> = as_list(rocket.get("payloads"))
+        .into_iter()
+        .filter_map(|flight| {
+            get_i64(flight, "norad_id")
+                .or_else(|| flight.get("payload").and_then(|p| get_i64(p, "norad_id")))
+        })
+        .filter_map(|id| u32::try_from(id).ok())
+        .filter(|id| *id > 0)
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+fn parse_landing(landing: &Value, vehicle: String) -> Option<LandingAttempt> {
+    if !landing.is_object() {
+        return None;
+    }
+    // 2.3 names the site `landing_location`; 2.2 used `location`
+    let location = landing
+        .get("landing_location")
+        .or_else(|| landing.get("location"))
+        .filter(|l| l.is_object());
+    let coordinate = |key: &str| {
+        location.and_then(|l| {
+            get_f64(l, key).or_else(|| l.get("location").and_then(|inner| get_f64(inner, key)))
+        })
+    };
+    Some(LandingAttempt {
+        vehicle,
+        attempt: landing
+            .get("attempt")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        success: landing.get("success").and_then(|v| v.as_bool()),
+        landing_type: landing
+            .get("type")
+            .and_then(|t| get_string(t, "abbrev").or_else(|| extract_name(t))),
+        location_name: location.and_then(|l| get_string(l, "name")),
+        location_abbrev: location.and_then(|l| get_string(l, "abbrev")),
+        lat: coordinate("latitude"),
+        lon: coordinate("longitude"),
+    })
+}
+
+/// A field that may hold one object or a list of them
+fn as_list(value: Option<&Value>) -> Vec<&Value> {
+    match value {
+        Some(Value::Array(items)) => items.iter().collect(),
+        Some(item @ Value::Object(_)) => vec![item],
+        _ => Vec::new(),
+    }
+}
+
 fn extract_items(value: &Value) -> Vec<&Value> {
     if let Some(array) = value.as_array() {
         return array.iter().collect();
@@ -215,3 +657,143 @@ fn parse_datetime(value: &str) -> Option<DateTime<Utc>> {
         .map(|dt| dt.with_timezone(&Utc))
         .ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_launch_detail() {
+        let body = r#"{
+            "id": "f059a2f9-1111-2222-3333-444455556666",
+            "name": "Falcon 9 Block 5 | Starlink Group 6-1",
+            "status": {"id": 1, "name": "Go for Launch", "abbrev": "Go"},
+            "window_start": "2025-03-01T10:00:00Z",
+            "window_end": "2025-03-01T14:00:00Z",
+            "probability": 90,
+            "webcast_live": false,
+            "vid_urls": [{"title": "Live webcast", "url": "https://example.com/watch"}, {"title": "No url"}],
+            "mission": {"name": "Starlink Group 6-1", "description": "A batch of Starlink satellites.", "type": "Communications"},
+            "rocket": {
+                "configuration": {"name": "Falcon 9", "full_name": "Falcon 9 Block 5", "variant": "Block 5", "families": [{"id": 1, "name": "Falcon"}]},
+                "launcher_stage": [{
+                    "launcher": {"serial_number": "B1062"},
+                    "landing": {
+                        "attempt": true,
+                        "success": null,
+                        "description": "Droneship landing.",
+                        "type": {"name": "Autonomous Spaceport Drone Ship", "abbrev": "ASDS"},
+                        "landing_location": {"name": "A Shortfall of Gravitas", "abbrev": "ASOG", "latitude": 29.5, "longitude": -76.8}
+                    }
+                }],
+                "spacecraft_stage": null,
+                "payloads": [{"id": 1, "norad_id": 58002, "payload": {"name": "Starlink"}},
+                             {"id": 2, "payload": {"name": "Starlink", "norad_id": 58001}},
+                             {"id": 3, "payload": {"name": "Unknown"}}]
+            }
+        }"#;
+        let detail = parse_launch_detail(body).expect("detail should parse");
+        assert_eq!(detail.uuid, "f059a2f9-1111-2222-3333-444455556666");
+        assert_eq!(detail.rocket_name.as_deref(), Some("Falcon 9 Block 5"));
+        assert_eq!(detail.rocket_family.as_deref(), Some("Falcon"));
+        assert_eq!(detail.rocket_variant.as_deref(), Some("Block 5"));
+        assert_eq!(detail.mission_type.as_deref(), Some("Communications"));
+        assert_eq!(detail.status.as_deref(), Some("Go for Launch"));
+        assert_eq!(detail.probability, Some(90));
+        assert!(detail.window_end > detail.window_start);
+        assert_eq!(detail.webcasts.len(), 1);
+        assert_eq!(detail.payload_norad_ids, vec![58001, 58002]);
+
+        let landing = &detail.landings[0];
+        assert_eq!(detail.landings.len(), 1);
+        assert_eq!(landing.vehicle, "B1062");
+        assert!(landing.attempt);
+        assert_eq!(landing.success, None);
+        assert_eq!(landing.landing_type.as_deref(), Some("ASDS"));
+        assert_eq!(landing.location_abbrev.as_deref(), Some("ASOG"));
+        assert_eq!((landing.lat, landing.lon), (Some(29.5), Some(-76.8)));
+    }
+
+    #[test]
+    fn test_parse_station_data() {
+        let body = r#"{
+            "space_stations": {"results": [
+                {"id": 4, "name": "International Space Station", "status": {"name": "Active"},
+                 "orbit": "Low Earth Orbit",
+                 "active_expeditions": [{"id": 150, "name": "Expedition 72",
+                    "start": "2024-09-23T00:00:00Z", "end": null,
+                    "crew": [{"role": {"role": "Commander"},
+                              "astronaut": {"name": "Sunita Williams", "agency": {"name": "NASA", "abbrev": "NASA"}}}]}]},
+                {"id": 1, "name": "Mir", "status": {"name": "De-Orbited"}},
+                {"id": 18, "name": "Tiangong", "status": {"name": "Active"}, "orbit": {"name": "Low Earth Orbit"}}
+            ]},
+            "expeditions": {"results": [
+                {"id": 160, "name": "Shenzhou 19", "start": "2024-10-30T00:00:00Z", "end": null,
+                 "spacestation": {"id": 18, "name": "Tiangong"},
+                 "crew": [{"role": {"role": "Commander"}, "astronaut": {"name": "Cai Xuzhe", "agency": {"abbrev": "CNSA"}}}]},
+                {"id": 149, "name": "Expedition 71", "start": "2024-04-06T00:00:00Z", "end": "2024-09-23T00:00:00Z",
+                 "spacestation": {"id": 4, "name": "International Space Station"}, "crew": []},
+                {"id": 150, "name": "Expedition 72", "start": "2024-09-23T00:00:00Z", "end": null,
+                 "spacestation": {"id": 4}, "crew": []}
+            ]},
+            "docking_events": {"results": [
+                {"id": 301, "docking": "2024-09-29T21:30:00Z", "departure": "2025-03-18T05:05:00Z",
+                 "flight_vehicle_chaser": {"spacecraft": {"name": "Crew Dragon Freedom"}},
+                 "space_station_target": {"id": 4, "name": "International Space Station"},
+                 "docking_location": {"name": "Harmony Zenith"}},
+                {"id": 302, "docking": "2024-11-17T00:00:00Z", "departure": null,
+                 "flight_vehicle": {"spacecraft": {"name": "Tianzhou 8"}},
+                 "docking_location": {"name": "Tianhe Aft", "spacestation": {"id": 18, "name": "Tiangong"}}}
+            ]}
+        }"#;
+        let now = DateTime::parse_from_rfc3339("2024-12-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let data = parse_station_data(body, now).expect("stations should parse");
+
+        let names: Vec<&str> = data.stations.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["International Space Station", "Tiangong"]);
+        let iss = &data.stations[0];
+        assert_eq!(iss.expeditions, vec!["Expedition 72"]);
+        assert_eq!(iss.crew.len(), 1);
+        assert_eq!(iss.crew[0].role.as_deref(), Some("Commander"));
+        assert_eq!(iss.crew[0].agency.as_deref(), Some("NASA"));
+        let tiangong = &data.stations[1];
+        assert_eq!(tiangong.orbit.as_deref(), Some("Low Earth Orbit"));
+        assert_eq!(tiangong.expeditions, vec!["Shenzhou 19"]);
+        assert_eq!(tiangong.crew[0].name, "Cai Xuzhe");
+
+        assert_eq!(data.docking_events.len(), 2);
+        let dragon = &data.docking_events[0];
+        assert_eq!(dragon.vehicle, "Crew Dragon Freedom");
+        assert_eq!(
+            dragon.station.as_deref(),
+            Some("International Space Station")
+        );
+        assert_eq!(dragon.port.as_deref(), Some("Harmony Zenith"));
+        assert!(dragon.departure_utc > dragon.docking_utc);
+        let tianzhou = &data.docking_events[1];
+        assert_eq!(tianzhou.station.as_deref(), Some("Tiangong"));
+        assert_eq!(tianzhou.departure_utc, None);
+    }
+
+    #[test]
+    fn test_launch_uuid_kept_for_detail_requests() {
+        let body = r#"{"count": 42, "next": "https://ll.thespacedevs.com/2.3.0/launches/?limit=10&offset=10",
+            "results": [{"id": "abc-123", "name": "Test", "net": "2025-03-01T10:00:00Z",
+            "status": {"name": "Go for Launch"},
+            "pad": {"name": "LC-39A", "country": {"name": "United States of America", "alpha_3_code": "USA"}}}]}"#;
+        let page = parse_launch_page(body).expect("launches should parse");
+        let launches = &page.launches;
+        assert_eq!(launches[0].id, None);
+        assert_eq!(launches[0].uuid.as_deref(), Some("abc-123"));
+        assert_eq!(launches[0].pad_country.as_deref(), Some("USA"));
+        assert_eq!(launches[0].status_name.as_deref(), Some("Go for Launch"));
+        assert_eq!(page.total, Some(42));
+        assert!(
+            page.next
+                .as_deref()
+                .is_some_and(|n| n.ends_with("offset=10"))
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! Launch list filters
+//!
+//! Filters run over the launches already loaded (every fetched page), so changing
+//! them costs no requests. The launch list and the pad markers both read
+//! [`LaunchFilter`].
+
+use bevy::prelude::*;
+
+use crate::launch_library::types::LaunchSummary;
+
+/// A field launches can be narrowed by
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaunchFilterField {
+    Provider,
+    Country,
+    Orbit,
+    Status,
+}
+
+impl LaunchFilterField {
+    pub fn label(self) -> &'static str {
+        match self {
+            LaunchFilterField::Provider => "Provider",
+            LaunchFilterField::Country => "Country",
+            LaunchFilterField::Orbit => "Orbit",
+            LaunchFilterField::Status => "Status",
+        }
+    }
+
+    /// The launch's value for this field
+    pub fn value(self, launch: &LaunchSummary) -> Option<&str> {
+        match self {
+            LaunchFilterField::Provider => launch.provider_name.as_deref(),
+            LaunchFilterField::Country => launch.pad_country.as_deref(),
+            LaunchFilterField::Orbit => launch.orbit_name.as_deref(),
+            LaunchFilterField::Status => launch.status_name.as_deref(),
+        }
+    }
+}
+
+#[derive(Resource, Debug, Default, Clone)]
+pub struct LaunchFilter {
+    /// Case-insensitive text matched against launch, mission, provider and pad names
+    pub search: String,
+    pub provider: Option<String>,
+    pub country: Option<String>,
+    pub orbit: Option<String>,
+    pub status: Option<String>,
+}
+
+impl LaunchFilter {
+    pub fn field(&self, field: LaunchFilterField) -> Option<&str> {
+        match field {
+            LaunchFilterField::Provider => self.provider.as_deref(),
+            LaunchFilterField::Country => self.country.as_deref(),
+            LaunchFilterField::Orbit => self.orbit.as_deref(),
+            LaunchFilterField::Status => self.status.as_deref(),
+        }
+    }
+
+    fn field_mut(&mut self, field: LaunchFilterField) -> &mut Option<String> {
+        match field {
+            LaunchFilterField::Provider => &mut self.provider,
+            LaunchFilterField::Country => &mut self.country,
+            LaunchFilterField::Orbit => &mut self.orbit,
+            LaunchFilterField::Status => &mut self.status,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.search.trim().is_empty()
+            || self.provider.is_some()
+            || self.country.is_some()
+            || self.orbit.is_some()
+            || self.status.is_some()
+    }
+
+    pub fn matches(&self, launch: &LaunchSummary) -> bool {
+        let fields = [
+            LaunchFilterField::Provider,
+            LaunchFilterField::Country,
+            LaunchFilterField::Orbit,
+            LaunchFilterField::Status,
+        ];
+        let fields_match = fields.into_iter().all(|field| match self.field(field) {
+            Some(wanted) => field.value(launch) == Some(wanted),
+            None => true,
+        });
+        fields_match && self.matches_search(launch)
+    }
+
+    fn matches_search(&self, launch: &LaunchSummary) -> bool {
+        let needle = self.search.trim().to_lowercase();
+        if needle.is_empty() {
+            return true;
+        }
+        [
+            Some(launch.name.as_str()),
+            launch.mission_name.as_deref(),
+            launch.provider_name.as_deref(),
+            launch.pad_name.as_deref(),
+            launch.pad_location_name.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|text| text.to_lowercase().contains(&needle))
+    }
+
+    /// Step `field` to the next value seen in `launches`, back to "all" after the last
+    pub fn cycle<'a>(
+        &mut self,
+        field: LaunchFilterField,
+        launches: impl IntoIterator<Item = &'a LaunchSummary>,
+    ) {
+        let mut values: Vec<&str> = launches
+            .into_iter()
+            .filter_map(|l| field.value(l))
+            .collect();
+        values.sort_unstable();
+        values.dedup();
+
+        let next = match self.field(field) {
+            None => values.first(),
+            Some(current) => values
+                .iter()
+                .position(|v| *v == current)
+                .and_then(|index| values.get(index + 1)),
+        };
+        *self.field_mut(field) = next.map(|v| (*v).to_string());
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Indices into `launches` that pass the filter, in list order
+pub fn filtered_indices(launches: &[LaunchSummary], filter: &LaunchFilter) -> Vec<usize> {
+    launches
+        .iter()
+        .enumerate()
+        .filter(|(_, launch)| filter.matches(launch))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launch_library::types::test_launch;
+
+    fn launch(name: &str, provider: &str, orbit: &str, status: &str) -> LaunchSummary {
+        LaunchSummary {
+            pad_name: Some("SLC-40".to_string()),
+            pad_location_name: Some("Cape Canaveral".to_string()),
+            pad_country: Some("USA".to_string()),
+            provider_name: Some(provider.to_string()),
+            orbit_name: Some(orbit.to_string()),
+            status_name: Some(status.to_string()),
+            ..test_launch(name, None)
+        }
+    }
+
+    #[test]
+    fn test_filter_fields_and_search() {
+        let launches = vec![
+            launch("Falcon 9 | Starlink", "SpaceX", "LEO", "Go"),
+            launch("Electron | Kinéis", "Rocket Lab", "LEO", "TBD"),
+            launch("Falcon Heavy | GOES", "SpaceX", "GTO", "Go"),
+        ];
+        let mut filter = LaunchFilter::default();
+        assert_eq!(filtered_indices(&launches, &filter), vec![0, 1, 2]);
+
+        filter.orbit = Some("LEO".to_string());
+        assert_eq!(filtered_indices(&launches, &filter), vec![0, 1]);
+        filter.search = "  starLINK ".to_string();
+        assert_eq!(filtered_indices(&launches, &filter), vec![0]);
+        // Search also covers pad names
+        filter.search = "slc-40".to_string();
+        assert_eq!(filtered_indices(&launches, &filter), vec![0, 1]);
+        assert!(filter.is_active());
+
+        filter.clear();
+        assert!(!filter.is_active());
+    }
+
+    #[test]
+    fn test_cycle_steps_through_values_then_all() {
+        let launches = vec![
+            launch("A", "SpaceX", "LEO", "Go"),
+            launch("B", "Rocket Lab", "LEO", "Go"),
+            launch("C", "SpaceX", "GTO", "Go"),
+        ];
+        let mut filter = LaunchFilter::default();
+        filter.cycle(LaunchFilterField::Provider, &launches);
+        assert_eq!(filter.provider.as_deref(), Some("Rocket Lab"));
+        filter.cycle(LaunchFilterField::Provider, &launches);
+        assert_eq!(filter.provider.as_deref(), Some("SpaceX"));
+        filter.cycle(LaunchFilterField::Provider, &launches);
+        assert_eq!(filter.provider, None);
+    }
+}
@@ -0,0 +1,98 @@
+//! Past launches around the simulation clock
+//!
+//! Next to the upcoming list (from real now), a second list is taken from LL2
+//! `/launches/previous/` for a window centered on the simulated day. The anchor
+//! is that day; the list is refetched once the clock moves a quarter window
+//! away from it in either direction.
+
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+
+/// Minimum time between past launch refetches while the clock is being scrubbed
+pub const HISTORY_REFETCH_MIN_SECONDS: i64 = 60;
+
+/// Midnight UTC of the simulated day
+pub fn sim_day(sim_utc: DateTime<Utc>) -> DateTime<Utc> {
+    sim_utc.date_naive().and_time(NaiveTime::MIN).and_utc()
+}
+
+/// Simulated day to center the past launch list on, or None when the whole
+/// window around it is still in the future
+pub fn history_anchor(
+    sim_utc: DateTime<Utc>,
+    now: DateTime<Utc>,
+    window_days: i64,
+) -> Option<DateTime<Utc>> {
+    let day = sim_day(sim_utc);
+    let (start, _) = history_range(day, window_days);
+    (start < now).then_some(day)
+}
+
+/// Whether the past launch list should be refetched for a new anchor
+pub fn anchor_moved(
+    current: Option<DateTime<Utc>>,
+    desired: Option<DateTime<Utc>>,
+    window_days: i64,
+) -> bool {
+    match (current, desired) {
+        (None, None) => false,
+        (Some(current), Some(desired)) => {
+            // Wait for a quarter window (at least a day) either way so small scrubs don't refetch
+            let slack = Duration::days((window_days / 4).max(1));
+            (desired - current).abs() >= slack
+        }
+        _ => true,
+    }
+}
+
+/// NET range listed for an anchor: the window centered on the simulated day
+pub fn history_range(anchor: DateTime<Utc>, window_days: i64) -> (DateTime<Utc>, DateTime<Utc>) {
+    let half = Duration::hours(window_days.max(1) * 12);
+    (anchor - half, anchor + half)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_history_anchor_while_window_reaches_the_past() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+        let today = Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap();
+        // No dead zone: the clock at real now already lists the last half window
+        assert_eq!(history_anchor(now, now, 30), Some(today));
+        assert_eq!(
+            history_anchor(now - Duration::hours(20), now, 30),
+            Some(today - Duration::days(1))
+        );
+        assert_eq!(
+            history_anchor(now + Duration::days(3), now, 30),
+            Some(today + Duration::days(3))
+        );
+        assert_eq!(history_anchor(now + Duration::days(16), now, 30), None);
+        let sim = Utc.with_ymd_and_hms(2019, 5, 24, 2, 30, 0).unwrap();
+        assert_eq!(
+            history_anchor(sim, now, 30),
+            Some(Utc.with_ymd_and_hms(2019, 5, 24, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_anchor_moved_and_range() {
+        let a = Utc.with_ymd_and_hms(2019, 5, 24, 0, 0, 0).unwrap();
+        assert!(!anchor_moved(None, None, 30));
+        assert!(anchor_moved(None, Some(a), 30));
+        assert!(anchor_moved(Some(a), None, 30));
+        assert!(!anchor_moved(Some(a), Some(a + Duration::days(6)), 30));
+        assert!(anchor_moved(Some(a), Some(a + Duration::days(7)), 30));
+        assert!(!anchor_moved(Some(a), Some(a - Duration::days(6)), 30));
+        assert!(anchor_moved(Some(a), Some(a - Duration::days(7)), 30));
+        // Short windows still need a full day of movement
+        assert!(!anchor_moved(Some(a), Some(a), 2));
+        assert!(anchor_moved(Some(a), Some(a + Duration::days(1)), 2));
+
+        let (start, end) = history_range(a, 30);
+        assert_eq!(start, a - Duration::days(15));
+        assert_eq!(end, a + Duration::days(15));
+    }
+}
@@ -1,14 +1,26 @@
-//! Launch Library 2 integration (launches + events).
+//! Launch Library 2 integration (launches, events and space stations).
 
 use bevy::prelude::*;
 
+pub mod alerts;
+pub mod backoff;
+pub mod cache;
+pub mod calendar;
+pub mod deployments;
 pub mod fetcher;
+pub mod filter;
+pub mod history;
+pub mod stations;
 pub mod systems;
 pub mod types;
 
+pub use alerts::LaunchAlertTracker;
+pub use deployments::DeploymentTracker;
+pub use filter::{LaunchFilter, LaunchFilterField, filtered_indices};
 pub use systems::{apply_launch_library_results, poll_launch_library};
 pub use types::{
-    EventSummary, LaunchLibraryConfig, LaunchLibraryData, LaunchLibraryState, LaunchSummary,
+    EventSummary, LaunchDetail, LaunchLibraryConfig, LaunchLibraryData, LaunchLibraryState,
+    LaunchSummary,
 };
 
 /// Plugin for Launch Library data management.
@@ -19,10 +31,20 @@ impl Plugin for LaunchLibraryPlugin {
         app.init_resource::<LaunchLibraryConfig>()
             .init_resource::<LaunchLibraryState>()
             .init_resource::<LaunchLibraryData>()
+            .init_resource::<LaunchFilter>()
+            .init_resource::<DeploymentTracker>()
+            .init_resource::<LaunchAlertTracker>()
             .add_systems(Startup, systems::setup_launch_library_worker)
             .add_systems(
                 Update,
-                (poll_launch_library, apply_launch_library_results).chain(),
+                (
+                    poll_launch_library,
+                    apply_launch_library_results,
+                    systems::track_deployments,
+                    systems::raise_launch_alerts,
+                    systems::export_launch_calendar,
+                )
+                    .chain(),
             );
     }
 }
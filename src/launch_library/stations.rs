@@ -0,0 +1,237 @@
+//! Space station crews, docked vehicles and docking events
+//!
+//! Stations come with their running expeditions, and docking events give each
+//! visiting vehicle's arrival and planned departure. Upcoming dockings and
+//! undockings are merged into the event list next to the `/events/` entries,
+//! skipping ones that feed already announces.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::launch_library::types::{DockingEvent, EventSummary, SpaceStation};
+
+/// How often stations, expeditions and docking events are refreshed from LL2
+pub const STATION_REFRESH_HOURS: i64 = 6;
+/// A feed event this close to a docking move is taken to announce it
+const DUPLICATE_EVENT_MINUTES: i64 = 60;
+
+/// NORAD id of a crewed station, for highlighting it in the 3D view
+pub fn station_norad_id(name: &str) -> Option<u32> {
+    let name = name.to_ascii_lowercase();
+    if name.contains("international space station") || name == "iss" {
+        Some(25544)
+    } else if name.contains("tiangong") {
+        // The Tianhe core module carries the station's catalog entry
+        Some(48274)
+    } else {
+        None
+    }
+}
+
+/// Whether a docking event names this station
+fn at_station(event: &DockingEvent, station: &SpaceStation) -> bool {
+    event
+        .station
+        .as_deref()
+        .is_some_and(|name| name.eq_ignore_ascii_case(&station.name))
+}
+
+/// Vehicles docked to `station` at `now`
+pub fn docked_vehicles<'a>(
+    station: &SpaceStation,
+    events: &'a [DockingEvent],
+    now: DateTime<Utc>,
+) -> Vec<&'a DockingEvent> {
+    events
+        .iter()
+        .filter(|event| at_station(event, station))
+        .filter(|event| event.docking_utc.is_some_and(|docking| docking <= now))
+        .filter(|event| event.departure_utc.is_none_or(|departure| departure > now))
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DockingMove {
+    Docking,
+    Undocking,
+}
+
+impl DockingMove {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Docking => "Docking",
+            Self::Undocking => "Undocking",
+        }
+    }
+}
+
+/// A docking or undocking at a given time
+#[derive(Clone, Copy, Debug)]
+pub struct ScheduledMove<'a> {
+    pub utc: DateTime<Utc>,
+    pub kind: DockingMove,
+    pub event: &'a DockingEvent,
+}
+
+/// Dockings and undockings after `now` up to `until`, in time order
+pub fn upcoming_moves(
+    events: &[DockingEvent],
+    now: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> Vec<ScheduledMove<'_>> {
+    let mut moves: Vec<ScheduledMove> = events
+        .iter()
+        .flat_map(|event| {
+            [
+                (event.docking_utc, DockingMove::Docking),
+                (event.departure_utc, DockingMove::Undocking),
+            ]
+            .into_iter()
+            .filter_map(move |(utc, kind)| {
+                Some(ScheduledMove {
+                    utc: utc?,
+                    kind,
+                    event,
+                })
+            })
+        })
+        .filter(|scheduled| scheduled.utc > now && scheduled.utc <= until)
+        .collect();
+    moves.sort_by_key(|scheduled| scheduled.utc);
+    moves
+}
+
+/// Feed events plus upcoming docking moves the feed doesn't list, by date
+pub fn merge_docking_events(
+    feed: &[EventSummary],
+    dockings: &[DockingEvent],
+    now: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> Vec<EventSummary> {
+    let mut events = feed.to_vec();
+    for scheduled in upcoming_moves(dockings, now, until) {
+        let announced = feed.iter().any(|event| {
+            event
+                .type_name
+                .as_deref()
+                .is_some_and(|t| t.to_ascii_lowercase().contains("docking"))
+                && event.date_utc.is_some_and(|date| {
+                    (date - scheduled.utc).abs() <= Duration::minutes(DUPLICATE_EVENT_MINUTES)
+                })
+        });
+        if announced {
+            continue;
+        }
+        let event = scheduled.event;
+        events.push(EventSummary {
+            id: None,
+            name: format!("{} {}", event.vehicle, scheduled.kind.label()),
+            date_utc: Some(scheduled.utc),
+            location: event.station.clone(),
+            type_name: Some(scheduled.kind.label().to_string()),
+            description: event.port.as_ref().map(|port| format!("Port: {port}")),
+        });
+    }
+    // Undated feed events stay at the end
+    events.sort_by_key(|event| (event.date_utc.is_none(), event.date_utc));
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn docking(
+        vehicle: &str,
+        docking_utc: Option<DateTime<Utc>>,
+        departure_utc: Option<DateTime<Utc>>,
+    ) -> DockingEvent {
+        DockingEvent {
+            id: None,
+            vehicle: vehicle.to_string(),
+            station: Some("International Space Station".to_string()),
+            port: Some("Harmony Forward".to_string()),
+            docking_utc,
+            departure_utc,
+        }
+    }
+
+    #[test]
+    fn test_docked_vehicles_and_station_ids() {
+        let now = Utc.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap();
+        let iss = SpaceStation {
+            id: Some(4),
+            name: "International Space Station".to_string(),
+            status: Some("Active".to_string()),
+            orbit: None,
+            expeditions: Vec::new(),
+            crew: Vec::new(),
+        };
+        let events = vec![
+            docking("Crew Dragon Freedom", Some(now - Duration::days(60)), None),
+            docking(
+                "Soyuz MS-25",
+                Some(now - Duration::days(200)),
+                Some(now - Duration::days(70)),
+            ),
+            docking("Cygnus NG-22", Some(now + Duration::days(3)), None),
+        ];
+        let docked: Vec<&str> = docked_vehicles(&iss, &events, now)
+            .iter()
+            .map(|event| event.vehicle.as_str())
+            .collect();
+        assert_eq!(docked, vec!["Crew Dragon Freedom"]);
+
+        assert_eq!(station_norad_id("International Space Station"), Some(25544));
+        assert_eq!(station_norad_id("Tiangong space station"), Some(48274));
+        assert_eq!(station_norad_id("Mir"), None);
+    }
+
+    #[test]
+    fn test_docking_moves_merged_into_events() {
+        let now = Utc.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap();
+        let dockings = vec![
+            docking(
+                "Crew Dragon Freedom",
+                Some(now - Duration::days(60)),
+                Some(now + Duration::days(5)),
+            ),
+            docking("Cygnus NG-22", Some(now + Duration::days(2)), None),
+            docking("Progress MS-30", Some(now + Duration::days(40)), None),
+        ];
+        let feed = vec![
+            EventSummary {
+                id: Some(900),
+                name: "Cygnus NG-22 Docking".to_string(),
+                date_utc: Some(now + Duration::days(2) + Duration::minutes(20)),
+                location: None,
+                type_name: Some("Docking".to_string()),
+                description: None,
+            },
+            EventSummary {
+                id: Some(901),
+                name: "Spacewalk".to_string(),
+                date_utc: Some(now + Duration::days(1)),
+                location: None,
+                type_name: Some("EVA".to_string()),
+                description: None,
+            },
+        ];
+
+        let merged = merge_docking_events(&feed, &dockings, now, now + Duration::days(14));
+        let names: Vec<&str> = merged.iter().map(|event| event.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "Spacewalk",
+                "Cygnus NG-22 Docking",
+                "Crew Dragon Freedom Undocking"
+            ]
+        );
+        assert_eq!(merged[2].type_name.as_deref(), Some("Undocking"));
+        assert_eq!(
+            merged[2].description.as_deref(),
+            Some("Port: Harmony Forward")
+        );
+    }
+}
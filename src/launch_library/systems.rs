@@ -1,73 +1,398 @@
 //! Launch Library systems (polling + apply).
 
-use crate::launch_library::fetcher::start_launch_library_worker;
+use crate::core::paths::export_dir;
+use crate::launch_library::alerts::LaunchAlertTracker;
+use crate::launch_library::backoff::{
+    RATE_LIMIT_DEFAULT_SECONDS, backoff_delay, format_wait, jitter_fraction,
+};
+use crate::launch_library::cache::LaunchLibraryCache;
+use crate::launch_library::calendar::{CALENDAR_FILE_NAME, build_calendar};
+use crate::launch_library::deployments::{
+    DeploymentTracker, RECENT_LAUNCH_DAYS, RECENT_REFRESH_HOURS,
+};
+use crate::launch_library::fetcher::{
+    parse_events, parse_launch_page, parse_station_data, start_launch_library_worker,
+};
+use crate::launch_library::history::{
+    HISTORY_REFETCH_MIN_SECONDS, anchor_moved, history_anchor, history_range,
+};
+use crate::launch_library::stations::{STATION_REFRESH_HOURS, merge_docking_events};
 use crate::launch_library::types::{
     LaunchLibraryChannels, LaunchLibraryCommand, LaunchLibraryConfig, LaunchLibraryData,
     LaunchLibraryFeed, LaunchLibraryResult, LaunchLibraryState,
 };
+use crate::orbital::SimulationTime;
+use crate::satellite::components::SatelliteGroupUrl;
+use crate::satellite::resources::{GroupRegistry, SatelliteGroup};
+use crate::tle::{FetchChannels, FetchCommand};
+use crate::ui::groups::create_default_group_colors;
 use bevy::prelude::*;
 use chrono::{DateTime, Duration, Utc};
 
-pub fn setup_launch_library_worker(mut commands: Commands) {
+pub fn setup_launch_library_worker(
+    mut commands: Commands,
+    config: Res<LaunchLibraryConfig>,
+    mut data: ResMut<LaunchLibraryData>,
+    mut state: ResMut<LaunchLibraryState>,
+) {
+    match LaunchLibraryCache::new() {
+        Ok(cache) => {
+            load_cached_feeds(&cache, &config, &mut data, &mut state);
+            merge_events(&mut data, config.window_days);
+        }
+        Err(err) => eprintln!("[LAUNCH LIBRARY] Disk cache unavailable: {err}"),
+    }
     let channels = start_launch_library_worker();
     println!("[INIT] Launch Library worker started");
     commands.insert_resource(channels);
 }
 
+/// Show the last saved responses right away, marked as cached until a refresh lands
+///
+/// The saved fetch time counts as the last request, so a restart inside the refresh
+/// interval doesn't spend another request. Responses to another endpoint or query
+/// (`--ll2-dev`, `--ll2-url`, a different page size or window) are ignored.
+fn load_cached_feeds(
+    cache: &LaunchLibraryCache,
+    config: &LaunchLibraryConfig,
+    data: &mut LaunchLibraryData,
+    state: &mut LaunchLibraryState,
+) {
+    let now = Utc::now();
+    let read_current = |feed: LaunchLibraryFeed, expected: anyhow::Result<String>| {
+        let cached = match cache.read(feed) {
+            Ok(cached) => cached?,
+            Err(err) => {
+                eprintln!("[LAUNCH LIBRARY] Failed to read {feed:?} cache: {err}");
+                return None;
+            }
+        };
+        if !expected.is_ok_and(|url| cached.matches_request(&url)) {
+            println!(
+                "[LAUNCH LIBRARY] Ignoring {feed:?} cache from another endpoint or query: {}",
+                cached.url
+            );
+            return None;
+        }
+        Some(cached)
+    };
+
+    if let Some(cached) = read_current(LaunchLibraryFeed::Launches, build_launches_url(config, now))
+    {
+        match parse_launch_page(&cached.body) {
+            Ok(page) => {
+                println!(
+                    "[LAUNCH LIBRARY] {} cached launches from {}",
+                    page.launches.len(),
+                    cached.fetched_at.to_rfc3339()
+                );
+                data.launches = page.launches;
+                data.launches_next = page.next;
+                data.launches_total = page.total;
+                state.last_launch_update = Some(cached.fetched_at);
+                state.last_launch_request = Some(cached.fetched_at);
+                state.launches_from_cache = true;
+            }
+            Err(err) => eprintln!("[LAUNCH LIBRARY] Ignoring unreadable launch cache: {err}"),
+        }
+    }
+    if let Some(cached) = read_current(LaunchLibraryFeed::Events, build_events_url(config, now)) {
+        match parse_events(&cached.body) {
+            Ok(events) => {
+                println!(
+                    "[LAUNCH LIBRARY] {} cached events from {}",
+                    events.len(),
+                    cached.fetched_at.to_rfc3339()
+                );
+                data.feed_events = events;
+                state.last_event_update = Some(cached.fetched_at);
+                state.last_event_request = Some(cached.fetched_at);
+                state.events_from_cache = true;
+            }
+            Err(err) => eprintln!("[LAUNCH LIBRARY] Ignoring unreadable event cache: {err}"),
+        }
+    }
+    if let Some(cached) = read_current(
+        LaunchLibraryFeed::Recent,
+        build_recent_launches_url(config, now),
+    ) {
+        match parse_launch_page(&cached.body) {
+            Ok(page) => {
+                data.recent_launches = page.launches;
+                state.last_recent_request = Some(cached.fetched_at);
+            }
+            Err(err) => eprintln!("[LAUNCH LIBRARY] Ignoring unreadable recent cache: {err}"),
+        }
+    }
+    // The station cache holds three responses under the stations URL
+    if let Some(cached) = read_current(
+        LaunchLibraryFeed::Stations,
+        build_station_urls(config).map(|[stations_url, ..]| stations_url),
+    ) {
+        match parse_station_data(&cached.body, now) {
+            Ok(stations) => {
+                data.stations = stations.stations;
+                data.docking_events = stations.docking_events;
+                state.last_station_request = Some(cached.fetched_at);
+            }
+            Err(err) => eprintln!("[LAUNCH LIBRARY] Ignoring unreadable station cache: {err}"),
+        }
+    }
+}
+
+/// Rebuild the event list from the feed events and upcoming docking moves
+fn merge_events(data: &mut LaunchLibraryData, window_days: i64) {
+    let now = Utc::now();
+    data.events = merge_docking_events(
+        &data.feed_events,
+        &data.docking_events,
+        now,
+        now + Duration::days(window_days),
+    );
+}
+
 pub fn poll_launch_library(
     config: Res<LaunchLibraryConfig>,
+    mut data: ResMut<LaunchLibraryData>,
+    sim_time: Res<SimulationTime>,
     mut state: ResMut<LaunchLibraryState>,
     channels: Option<Res<LaunchLibraryChannels>>,
 ) {
     let Some(channels) = channels else { return };
 
     let now = Utc::now();
+    let anchor = history_anchor(sim_time.current_utc, now, config.window_days);
     let should_force = state.force_refresh;
-    let should_fetch_launches = should_force
-        || state
-            .last_launch_request
-            .is_none_or(|t| now.signed_duration_since(t) >= config.refresh_interval);
-    let should_fetch_events = should_force
-        || state
-            .last_event_request
-            .is_none_or(|t| now.signed_duration_since(t) >= config.refresh_interval);
+    // A pending retry time (backoff or rate limit) overrides both the interval and refresh requests
+    let is_due = |last_request: Option<DateTime<Utc>>,
+                  retry_at: Option<DateTime<Utc>>,
+                  interval: Duration| match retry_at {
+        Some(retry_at) => now >= retry_at,
+        None => {
+            should_force || last_request.is_none_or(|t| now.signed_duration_since(t) >= interval)
+        }
+    };
+    let should_fetch_launches = !state.is_loading_launches
+        && is_due(
+            state.last_launch_request,
+            state.launch_retry_at,
+            config.refresh_interval,
+        );
+    let previous_due = if anchor_moved(state.previous_anchor, anchor, config.window_days) {
+        // Wait between refetches while the clock is being scrubbed
+        state
+            .last_previous_request
+            .is_none_or(|t| (now - t).num_seconds() >= HISTORY_REFETCH_MIN_SECONDS)
+            && state.previous_retry_at.is_none_or(|t| now >= t)
+    } else if anchor.is_some_and(|day| history_range(day, config.window_days).1 > now) {
+        // A window reaching real now keeps gaining launches as they fly
+        is_due(
+            state.last_previous_request,
+            state.previous_retry_at,
+            config.refresh_interval,
+        )
+    } else if anchor.is_some() {
+        // Older launches don't change, so only refetch on request or to retry a failure
+        match state.previous_retry_at {
+            Some(retry_at) => now >= retry_at,
+            None => should_force,
+        }
+    } else {
+        false
+    };
+    let should_fetch_previous = !state.is_loading_previous && previous_due;
+    let should_fetch_events = !state.is_loading_events
+        && is_due(
+            state.last_event_request,
+            state.event_retry_at,
+            config.refresh_interval,
+        );
+    let should_fetch_recent = config.track_deployments
+        && !state.is_loading_recent
+        && is_due(
+            state.last_recent_request,
+            state.recent_retry_at,
+            Duration::hours(RECENT_REFRESH_HOURS),
+        );
+    let should_fetch_stations = !state.is_loading_stations
+        && is_due(
+            state.last_station_request,
+            state.station_retry_at,
+            Duration::hours(STATION_REFRESH_HOURS),
+        );
 
     if should_fetch_launches {
-        let url = build_launches_url(&config, now);
-        if let Err(err) = channels
-            .cmd_tx
-            .send(LaunchLibraryCommand::FetchLaunches { url })
-        {
-            state.launch_error = Some(format!("Failed to queue launches fetch: {err}"));
+        let queued = build_launches_url(&config, now).and_then(|url| {
+            channels
+                .cmd_tx
+                .send(LaunchLibraryCommand::FetchLaunches {
+                    url,
+                    api_token: config.api_token.clone(),
+                    more: false,
+                })
+                .map_err(|err| anyhow::anyhow!("Failed to queue launches fetch: {err}"))
+        });
+        if let Err(err) = queued {
+            state.launch_error = Some(err.to_string());
             state.is_loading_launches = false;
         } else {
             state.last_launch_request = Some(now);
+            state.launch_retry_at = None;
             state.is_loading_launches = true;
             state.launch_error = None;
         }
     }
 
+    if should_fetch_previous {
+        match anchor {
+            Some(day) => {
+                let queued = build_previous_launches_url(&config, day, now).and_then(|url| {
+                    channels
+                        .cmd_tx
+                        .send(LaunchLibraryCommand::FetchPreviousLaunches {
+                            url,
+                            api_token: config.api_token.clone(),
+                        })
+                        .map_err(|err| {
+                            anyhow::anyhow!("Failed to queue past launches fetch: {err}")
+                        })
+                });
+                if let Err(err) = queued {
+                    state.previous_error = Some(err.to_string());
+                } else {
+                    if state.previous_anchor != anchor {
+                        println!(
+                            "[LAUNCH LIBRARY] Listing past launches around {}",
+                            day.format("%Y-%m-%d")
+                        );
+                    }
+                    state.previous_anchor = anchor;
+                    state.last_previous_request = Some(now);
+                    state.previous_retry_at = None;
+                    state.is_loading_previous = true;
+                    state.previous_error = None;
+                }
+            }
+            None => {
+                // The clock is far enough ahead that nothing around it has flown yet
+                state.previous_anchor = None;
+                state.previous_error = None;
+                data.previous_launches.clear();
+            }
+        }
+    }
+
     if should_fetch_events {
-        let url = build_events_url(&config, now);
-        if let Err(err) = channels
-            .cmd_tx
-            .send(LaunchLibraryCommand::FetchEvents { url })
-        {
-            state.event_error = Some(format!("Failed to queue events fetch: {err}"));
+        let queued = build_events_url(&config, now).and_then(|url| {
+            channels
+                .cmd_tx
+                .send(LaunchLibraryCommand::FetchEvents {
+                    url,
+                    api_token: config.api_token.clone(),
+                })
+                .map_err(|err| anyhow::anyhow!("Failed to queue events fetch: {err}"))
+        });
+        if let Err(err) = queued {
+            state.event_error = Some(err.to_string());
             state.is_loading_events = false;
         } else {
             state.last_event_request = Some(now);
+            state.event_retry_at = None;
             state.is_loading_events = true;
             state.event_error = None;
         }
     }
 
+    if should_fetch_recent {
+        let queued = build_recent_launches_url(&config, now).and_then(|url| {
+            channels
+                .cmd_tx
+                .send(LaunchLibraryCommand::FetchRecentLaunches {
+                    url,
+                    api_token: config.api_token.clone(),
+                })
+                .map_err(|err| anyhow::anyhow!("Failed to queue recent launches fetch: {err}"))
+        });
+        if let Err(err) = queued {
+            eprintln!("[LAUNCH LIBRARY] {err}");
+        } else {
+            state.last_recent_request = Some(now);
+            state.recent_retry_at = None;
+            state.is_loading_recent = true;
+        }
+    }
+
+    if should_fetch_stations {
+        let queued =
+            build_station_urls(&config).and_then(|[stations_url, expeditions_url, docking_url]| {
+                channels
+                    .cmd_tx
+                    .send(LaunchLibraryCommand::FetchStations {
+                        stations_url,
+                        expeditions_url,
+                        docking_url,
+                        api_token: config.api_token.clone(),
+                    })
+                    .map_err(|err| anyhow::anyhow!("Failed to queue stations fetch: {err}"))
+            });
+        if let Err(err) = queued {
+            state.station_error = Some(err.to_string());
+        } else {
+            state.last_station_request = Some(now);
+            state.station_retry_at = None;
+            state.is_loading_stations = true;
+            state.station_error = None;
+        }
+    }
+
+    if state.pending_more_launches
+        && !state.is_loading_launches
+        && !state.is_loading_more_launches
+        && state.launch_retry_at.is_none_or(|t| now >= t)
+    {
+        state.pending_more_launches = false;
+        if let Some(url) = data.launches_next.clone() {
+            if let Err(err) = channels.cmd_tx.send(LaunchLibraryCommand::FetchLaunches {
+                url,
+                api_token: config.api_token.clone(),
+                more: true,
+            }) {
+                state.launch_error = Some(format!("Failed to queue next launch page: {err}"));
+            } else {
+                state.is_loading_more_launches = true;
+            }
+        }
+    }
+
+    // Detail requests share the launches endpoint, so they wait out the same backoff
+    if state.loading_detail.is_none()
+        && state.launch_retry_at.is_none_or(|t| now >= t)
+        && let Some(uuid) = state.pending_detail.take()
+    {
+        let url = format!("{}/launches/{uuid}/", config.base_url);
+        if let Err(err) = channels
+            .cmd_tx
+            .send(LaunchLibraryCommand::FetchLaunchDetail {
+                uuid: uuid.clone(),
+                url,
+                api_token: config.api_token.clone(),
+            })
+        {
+            state.detail_error =
+                Some((uuid, format!("Failed to queue launch detail fetch: {err}")));
+        } else {
+            state.loading_detail = Some(uuid);
+            state.detail_error = None;
+        }
+    }
+
     if state.force_refresh {
         state.force_refresh = false;
     }
 }
 
 pub fn apply_launch_library_results(
+    config: Res<LaunchLibraryConfig>,
     mut data: ResMut<LaunchLibraryData>,
     mut state: ResMut<LaunchLibraryState>,
     channels: Option<Res<LaunchLibraryChannels>>,
@@ -79,35 +404,191 @@ pub fn apply_launch_library_results(
 
     while let Ok(msg) = guard.try_recv() {
         match msg {
-            LaunchLibraryResult::Launches(launches) => {
-                data.launches = launches;
+            LaunchLibraryResult::Launches(page) => {
+                data.launches = page.launches;
+                data.launches_next = page.next;
+                data.launches_total = page.total;
                 state.last_launch_update = Some(Utc::now());
                 state.is_loading_launches = false;
                 state.launch_error = None;
+                state.launches_from_cache = false;
+                state.launch_failures = 0;
+            }
+            LaunchLibraryResult::MoreLaunches(page) => {
+                // Launches can shift between pages while paging; skip ones already listed
+                for launch in page.launches {
+                    let listed = data.launches.iter().any(|existing| {
+                        (existing.uuid.is_some() && existing.uuid == launch.uuid)
+                            || (existing.id.is_some() && existing.id == launch.id)
+                    });
+                    if !listed {
+                        data.launches.push(launch);
+                    }
+                }
+                data.launches_next = page.next;
+                data.launches_total = page.total.or(data.launches_total);
+                state.is_loading_more_launches = false;
+                state.launch_failures = 0;
             }
             LaunchLibraryResult::Events(events) => {
-                data.events = events;
+                data.feed_events = events;
+                merge_events(&mut data, config.window_days);
                 state.last_event_update = Some(Utc::now());
                 state.is_loading_events = false;
                 state.event_error = None;
+                state.events_from_cache = false;
+                state.event_failures = 0;
+            }
+            LaunchLibraryResult::RecentLaunches(launches) => {
+                data.recent_launches = launches;
+                state.is_loading_recent = false;
+                state.recent_failures = 0;
+            }
+            LaunchLibraryResult::PreviousLaunches(launches) => {
+                data.previous_launches = launches;
+                state.is_loading_previous = false;
+                state.previous_error = None;
+                state.previous_failures = 0;
+            }
+            LaunchLibraryResult::Stations(stations) => {
+                println!(
+                    "[LAUNCH LIBRARY] {} stations, {} docking events",
+                    stations.stations.len(),
+                    stations.docking_events.len()
+                );
+                data.stations = stations.stations;
+                data.docking_events = stations.docking_events;
+                merge_events(&mut data, config.window_days);
+                state.is_loading_stations = false;
+                state.station_error = None;
+                state.station_failures = 0;
             }
             LaunchLibraryResult::Error { feed, error } => match feed {
                 LaunchLibraryFeed::Launches => {
-                    state.launch_error = Some(error);
+                    state.launch_failures += 1;
+                    let wait = backoff_delay(state.launch_failures, jitter_fraction());
+                    state.launch_retry_at = Some(Utc::now() + wait);
+                    state.launch_error = Some(format!("{error}; retry in {}", format_wait(wait)));
                     state.is_loading_launches = false;
+                    state.is_loading_more_launches = false;
                 }
                 LaunchLibraryFeed::Events => {
-                    state.event_error = Some(error);
+                    state.event_failures += 1;
+                    let wait = backoff_delay(state.event_failures, jitter_fraction());
+                    state.event_retry_at = Some(Utc::now() + wait);
+                    state.event_error = Some(format!("{error}; retry in {}", format_wait(wait)));
                     state.is_loading_events = false;
                 }
+                LaunchLibraryFeed::Recent => {
+                    // Not shown in the panel; the worker has already logged the error
+                    state.recent_failures += 1;
+                    let wait = backoff_delay(state.recent_failures, jitter_fraction());
+                    state.recent_retry_at = Some(Utc::now() + wait);
+                    state.is_loading_recent = false;
+                }
+                LaunchLibraryFeed::Previous => {
+                    state.previous_failures += 1;
+                    let wait = backoff_delay(state.previous_failures, jitter_fraction());
+                    state.previous_retry_at = Some(Utc::now() + wait);
+                    state.previous_error = Some(format!("{error}; retry in {}", format_wait(wait)));
+                    state.is_loading_previous = false;
+                }
+                LaunchLibraryFeed::Stations => {
+                    state.station_failures += 1;
+                    let wait = backoff_delay(state.station_failures, jitter_fraction());
+                    state.station_retry_at = Some(Utc::now() + wait);
+                    state.station_error = Some(format!("{error}; retry in {}", format_wait(wait)));
+                    state.is_loading_stations = false;
+                }
             },
+            LaunchLibraryResult::LaunchDetail(detail) => {
+                if state.loading_detail.as_deref() == Some(detail.uuid.as_str()) {
+                    state.loading_detail = None;
+                }
+                if state
+                    .detail_error
+                    .as_ref()
+                    .is_some_and(|(failed, _)| *failed == detail.uuid)
+                {
+                    state.detail_error = None;
+                }
+                data.details.insert(detail.uuid.clone(), *detail);
+            }
+            LaunchLibraryResult::DetailError { uuid, error } => {
+                if state.loading_detail.as_deref() == Some(uuid.as_str()) {
+                    state.loading_detail = None;
+                }
+                state.detail_error = Some((uuid, error));
+            }
+            LaunchLibraryResult::RateLimited { feed, retry_after } => {
+                apply_rate_limit(&mut state, feed, retry_after);
+            }
+        }
+    }
+}
+
+/// Hold every feed until the server's `Retry-After`, since they share one quota
+fn apply_rate_limit(
+    state: &mut LaunchLibraryState,
+    feed: Option<LaunchLibraryFeed>,
+    retry_after: Option<Duration>,
+) {
+    let wait = retry_after.unwrap_or_else(|| Duration::seconds(RATE_LIMIT_DEFAULT_SECONDS));
+    let retry_at = Utc::now() + wait;
+    println!(
+        "[LAUNCH LIBRARY] Rate limited; pausing requests for {}",
+        format_wait(wait)
+    );
+    let error = format!("Rate limited; retry in {}", format_wait(wait));
+    match feed {
+        Some(LaunchLibraryFeed::Launches) => {
+            state.launch_failures += 1;
+            state.launch_error = Some(error);
+            state.is_loading_launches = false;
+            state.is_loading_more_launches = false;
+        }
+        Some(LaunchLibraryFeed::Events) => {
+            state.event_failures += 1;
+            state.event_error = Some(error);
+            state.is_loading_events = false;
+        }
+        Some(LaunchLibraryFeed::Recent) => {
+            state.recent_failures += 1;
+            state.is_loading_recent = false;
+        }
+        Some(LaunchLibraryFeed::Previous) => {
+            state.previous_failures += 1;
+            state.previous_error = Some(error);
+            state.is_loading_previous = false;
+        }
+        Some(LaunchLibraryFeed::Stations) => {
+            state.station_failures += 1;
+            state.station_error = Some(error);
+            state.is_loading_stations = false;
+        }
+        None => {
+            // Ask again once the pause is over
+            if let Some(uuid) = state.loading_detail.take() {
+                state.detail_error = Some((uuid.clone(), error));
+                state.pending_detail = Some(uuid);
+            }
         }
     }
+    state.launch_retry_at = state.launch_retry_at.max(Some(retry_at));
+    state.event_retry_at = state.event_retry_at.max(Some(retry_at));
+    state.recent_retry_at = state.recent_retry_at.max(Some(retry_at));
+    state.previous_retry_at = state.previous_retry_at.max(Some(retry_at));
+    state.station_retry_at = state.station_retry_at.max(Some(retry_at));
+}
+
+/// `{base_url}/{path}/`; the base is checked at startup, but a bad one must not panic
+fn endpoint_url(config: &LaunchLibraryConfig, path: &str) -> anyhow::Result<reqwest::Url> {
+    reqwest::Url::parse(&format!("{}/{path}/", config.base_url))
+        .map_err(|err| anyhow::anyhow!("Bad LL2 URL {}/{path}/: {err}", config.base_url))
 }
 
-fn build_launches_url(config: &LaunchLibraryConfig, now: DateTime<Utc>) -> String {
-    let mut url =
-        reqwest::Url::parse(&format!("{}/launches/", config.base_url)).expect("launches url");
+fn build_launches_url(config: &LaunchLibraryConfig, now: DateTime<Utc>) -> anyhow::Result<String> {
+    let mut url = endpoint_url(config, "launches")?;
     let end = now + Duration::days(config.window_days);
     url.query_pairs_mut()
         .append_pair("net__gte", &now.to_rfc3339())
@@ -115,11 +596,47 @@ fn build_launches_url(config: &LaunchLibraryConfig, now: DateTime<Utc>) -> Strin
         .append_pair("ordering", "net")
         .append_pair("limit", &config.limit.to_string())
         .append_pair("mode", "detailed");
-    url.to_string()
+    Ok(url.to_string())
+}
+
+/// Past launches in the window around a simulated day; the part after real now
+/// is covered by the upcoming list
+fn build_previous_launches_url(
+    config: &LaunchLibraryConfig,
+    anchor: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> anyhow::Result<String> {
+    let (start, end) = history_range(anchor, config.window_days);
+    let mut url = endpoint_url(config, "launches/previous")?;
+    url.query_pairs_mut()
+        .append_pair("net__gte", &start.to_rfc3339())
+        .append_pair("net__lte", &end.min(now).to_rfc3339())
+        .append_pair("ordering", "net")
+        .append_pair("limit", &config.limit.to_string())
+        .append_pair("mode", "detailed");
+    Ok(url.to_string())
 }
 
-fn build_events_url(config: &LaunchLibraryConfig, now: DateTime<Utc>) -> String {
-    let mut url = reqwest::Url::parse(&format!("{}/events/", config.base_url)).expect("events url");
+/// Launches from the last few days, newest first, for their designators
+fn build_recent_launches_url(
+    config: &LaunchLibraryConfig,
+    now: DateTime<Utc>,
+) -> anyhow::Result<String> {
+    let mut url = endpoint_url(config, "launches/previous")?;
+    url.query_pairs_mut()
+        .append_pair(
+            "net__gte",
+            &(now - Duration::days(RECENT_LAUNCH_DAYS)).to_rfc3339(),
+        )
+        .append_pair("net__lte", &now.to_rfc3339())
+        .append_pair("ordering", "-net")
+        .append_pair("limit", &config.limit.to_string())
+        .append_pair("mode", "detailed");
+    Ok(url.to_string())
+}
+
+fn build_events_url(config: &LaunchLibraryConfig, now: DateTime<Utc>) -> anyhow::Result<String> {
+    let mut url = endpoint_url(config, "events")?;
     let end = now + Duration::days(config.window_days);
     url.query_pairs_mut()
         .append_pair("date__gte", &now.to_rfc3339())
@@ -127,5 +644,157 @@ fn build_events_url(config: &LaunchLibraryConfig, now: DateTime<Utc>) -> String
         .append_pair("ordering", "date")
         .append_pair("limit", &config.limit.to_string())
         .append_pair("mode", "list");
-    url.to_string()
+    Ok(url.to_string())
+}
+
+/// Stations, running expeditions and docking events, fetched together
+fn build_station_urls(config: &LaunchLibraryConfig) -> anyhow::Result<[String; 3]> {
+    let limit = config.limit.to_string();
+    let mut stations = endpoint_url(config, "space_stations")?;
+    stations
+        .query_pairs_mut()
+        .append_pair("limit", &limit)
+        .append_pair("mode", "detailed");
+    let mut expeditions = endpoint_url(config, "expeditions")?;
+    expeditions
+        .query_pairs_mut()
+        .append_pair("ordering", "-start")
+        .append_pair("limit", &limit)
+        .append_pair("mode", "detailed");
+    let mut docking = endpoint_url(config, "docking_events")?;
+    docking
+        .query_pairs_mut()
+        .append_pair("ordering", "-docking")
+        .append_pair("limit", &limit)
+        .append_pair("mode", "detailed");
+    Ok([
+        stations.to_string(),
+        expeditions.to_string(),
+        docking.to_string(),
+    ])
+}
+
+/// Request the CelesTrak group of each recent launch until its objects are cataloged
+///
+/// Each launch gets its own group, named after the mission, so a new deployment
+/// shows up in one color and can be watched as it spreads out.
+pub fn track_deployments(
+    config: Res<LaunchLibraryConfig>,
+    data: Res<LaunchLibraryData>,
+    mut tracker: ResMut<DeploymentTracker>,
+    mut group_registry: ResMut<GroupRegistry>,
+    group_urls: Query<&SatelliteGroupUrl>,
+    fetch: Option<Res<FetchChannels>>,
+) {
+    if !config.track_deployments {
+        return;
+    }
+    let Some(fetch) = fetch else { return };
+
+    let now = Utc::now();
+    if data.is_changed() {
+        tracker.update(&data.recent_launches, now);
+    }
+
+    for deployment in tracker.deployments.iter_mut().filter(|d| !d.loaded) {
+        let url = deployment.group_url();
+        if group_urls.iter().any(|group| group.0 == url) {
+            println!(
+                "[LAUNCH LIBRARY] {} ({}) payloads cataloged",
+                deployment.name, deployment.cospar_id
+            );
+            deployment.loaded = true;
+        }
+    }
+
+    for deployment in tracker.take_due(now) {
+        let url = deployment.group_url();
+        if !group_registry.groups.contains_key(&url) {
+            let color = create_default_group_colors(group_registry.groups.len() + 1)
+                .pop()
+                .unwrap_or(Color::WHITE);
+            group_registry.groups.insert(
+                url.clone(),
+                SatelliteGroup {
+                    name: deployment.name.clone(),
+                    color,
+                },
+            );
+        }
+        println!(
+            "[LAUNCH LIBRARY] Looking up {} ({}) payloads",
+            deployment.name, deployment.cospar_id
+        );
+        if let Err(err) = fetch.cmd_tx.send(FetchCommand::FetchGroup { group: url }) {
+            eprintln!("[LAUNCH LIBRARY] Failed to queue payload lookup: {err}");
+        }
+    }
+}
+
+/// Raise countdown alerts as the clock runs and slip alerts when a refresh moves a NET
+pub fn raise_launch_alerts(
+    config: Res<LaunchLibraryConfig>,
+    data: Res<LaunchLibraryData>,
+    sim_time: Res<SimulationTime>,
+    mut tracker: ResMut<LaunchAlertTracker>,
+) {
+    // NETs are recorded while alerts are off so turning them on doesn't report old slips
+    let mut alerts = if data.is_changed() {
+        tracker.check_nets(&data.launches)
+    } else {
+        Vec::new()
+    };
+    // Only the upcoming list counts down; the past launches around a replayed day have flown
+    alerts.extend(tracker.check_countdowns(&data.launches, sim_time.current_utc));
+    if !config.launch_alerts {
+        return;
+    }
+    for alert in alerts {
+        println!("[LAUNCH ALERT] {}", alert.message());
+        tracker.push(alert);
+    }
+}
+
+/// Write the launch calendar on request, and to `--ics <path>` whenever the lists change
+pub fn export_launch_calendar(
+    config: Res<LaunchLibraryConfig>,
+    data: Res<LaunchLibraryData>,
+    mut state: ResMut<LaunchLibraryState>,
+) {
+    let now = Utc::now();
+    if let Some(path) = &config.calendar_path
+        && data.is_changed()
+        && (!data.launches.is_empty() || !data.events.is_empty())
+    {
+        match std::fs::write(path, build_calendar(&data.launches, &data.events, now)) {
+            Ok(()) => println!("[LAUNCH LIBRARY] Calendar updated: {}", path.display()),
+            Err(err) => eprintln!(
+                "[LAUNCH LIBRARY] Failed to write calendar {}: {err}",
+                path.display()
+            ),
+        }
+    }
+
+    if !state.calendar_export_requested {
+        return;
+    }
+    state.calendar_export_requested = false;
+    let calendar = build_calendar(&data.launches, &data.events, now);
+    let status = match export_dir()
+        .map(|dir| dir.join(CALENDAR_FILE_NAME))
+        .and_then(|path| {
+            std::fs::write(&path, calendar)
+                .map(|()| path)
+                .map_err(Into::into)
+        }) {
+        Ok(path) => {
+            println!("[LAUNCH LIBRARY] Exported {}", path.display());
+            format!("Exported {}", path.display())
+        }
+        Err(err) => {
+            eprintln!("[LAUNCH LIBRARY] Calendar export failed: {err}");
+            format!("Export failed: {err}")
+        }
+    };
+    state.calendar_status = Some(status);
 }
@@ -1,11 +1,13 @@
 use bevy::prelude::*;
 use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::{
     Arc, Mutex,
     mpsc::{Receiver, Sender},
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 #[allow(dead_code)]
 pub struct LaunchSummary {
     pub id: Option<i64>,
@@ -16,9 +18,67 @@ pub struct LaunchSummary {
     pub pad_lat: Option<f64>,
     pub pad_lon: Option<f64>,
     pub pad_location_name: Option<String>,
+    /// Pad country as an ISO alpha-3 code (or name when no code is given)
+    pub pad_country: Option<String>,
     pub provider_name: Option<String>,
     pub mission_name: Option<String>,
     pub orbit_name: Option<String>,
+    pub status_name: Option<String>,
+    /// International designator of the launch, e.g. `2024-123`
+    pub cospar_id: Option<String>,
+    /// LL2 launch id (a UUID), used for `/launches/{id}/`
+    pub uuid: Option<String>,
+}
+
+/// One page of `/launches/` results
+#[derive(Clone, Debug, Default)]
+pub struct LaunchPage {
+    pub launches: Vec<LaunchSummary>,
+    /// LL2 `next` link for the following page
+    pub next: Option<String>,
+    /// Total matching launches reported by LL2
+    pub total: Option<u64>,
+}
+
+/// A booster or spacecraft landing attempt
+#[derive(Clone, Debug)]
+pub struct LandingAttempt {
+    /// Booster serial or spacecraft name, e.g. `B1062`
+    pub vehicle: String,
+    pub attempt: bool,
+    pub success: Option<bool>,
+    pub landing_type: Option<String>,
+    pub location_name: Option<String>,
+    pub location_abbrev: Option<String>,
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Webcast {
+    pub title: Option<String>,
+    pub url: String,
+}
+
+/// Full launch record from `/launches/{id}/`
+#[derive(Clone, Debug)]
+pub struct LaunchDetail {
+    pub uuid: String,
+    pub rocket_name: Option<String>,
+    pub rocket_family: Option<String>,
+    pub rocket_variant: Option<String>,
+    pub mission_type: Option<String>,
+    pub mission_description: Option<String>,
+    pub window_start: Option<DateTime<Utc>>,
+    pub window_end: Option<DateTime<Utc>>,
+    pub status: Option<String>,
+    /// Weather go probability in percent
+    pub probability: Option<i64>,
+    pub webcast_live: bool,
+    pub webcasts: Vec<Webcast>,
+    pub landings: Vec<LandingAttempt>,
+    /// NORAD catalog numbers of the payloads, where LL2 knows them
+    pub payload_norad_ids: Vec<u32>,
 }
 
 #[derive(Clone, Debug)]
@@ -32,33 +92,136 @@ pub struct EventSummary {
     pub description: Option<String>,
 }
 
+/// Astronaut on an active expedition
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrewMember {
+    pub name: String,
+    /// e.g. `Commander` or `Flight Engineer`
+    pub role: Option<String>,
+    /// Agency abbreviation, e.g. `NASA` or `CNSA`
+    pub agency: Option<String>,
+}
+
+/// A space station with its active expeditions and crew
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct SpaceStation {
+    pub id: Option<i64>,
+    pub name: String,
+    /// e.g. `Active` or `De-Orbited`
+    pub status: Option<String>,
+    pub orbit: Option<String>,
+    pub expeditions: Vec<String>,
+    pub crew: Vec<CrewMember>,
+}
+
+/// A visiting vehicle's stay at a station, from `/docking_events/`
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct DockingEvent {
+    pub id: Option<i64>,
+    /// Spacecraft name, e.g. `Crew Dragon Endeavour`
+    pub vehicle: String,
+    pub station: Option<String>,
+    /// Docking port, e.g. `Harmony Zenith`
+    pub port: Option<String>,
+    pub docking_utc: Option<DateTime<Utc>>,
+    /// None while the vehicle has no planned departure
+    pub departure_utc: Option<DateTime<Utc>>,
+}
+
+/// Stations, expeditions and docking events, fetched and cached together
+#[derive(Clone, Debug, Default)]
+pub struct StationData {
+    pub stations: Vec<SpaceStation>,
+    pub docking_events: Vec<DockingEvent>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LaunchLibraryFeed {
     Launches,
     Events,
+    /// Launches from the last few days, for loading their payloads
+    Recent,
+    /// Past launches around the simulation clock (not cached)
+    Previous,
+    /// Space stations, their expeditions and docking events
+    Stations,
 }
 
+/// Production LL2 endpoint (rate limited without a token)
+pub const LL2_PRODUCTION_URL: &str = "https://ll.thespacedevs.com/2.3.0";
+/// LL2 development endpoint: not rate limited, but data may be stale
+pub const LL2_DEV_URL: &str = "https://lldev.thespacedevs.com/2.3.0";
+
 #[derive(Resource, Debug)]
 pub struct LaunchLibraryConfig {
+    /// API root, e.g. [`LL2_PRODUCTION_URL`], [`LL2_DEV_URL`] or a local stand-in
     pub base_url: String,
+    /// Token for paid LL2 tiers, sent as `Authorization: Token <key>`
+    pub api_token: Option<String>,
     pub limit: usize,
     pub window_days: i64,
     pub refresh_interval: Duration,
     pub show_pad_markers: bool,
+    /// Load payload TLEs for recent launches as groups named after the mission
+    pub track_deployments: bool,
+    /// Raise countdown and NET slip alerts for listed launches
+    pub launch_alerts: bool,
+    /// Calendar file kept up to date with upcoming launches and events (`--ics <path>`)
+    pub calendar_path: Option<PathBuf>,
 }
 
 impl Default for LaunchLibraryConfig {
     fn default() -> Self {
         Self {
-            base_url: "https://ll.thespacedevs.com/2.3.0".to_string(),
+            base_url: LL2_PRODUCTION_URL.to_string(),
+            api_token: None,
             limit: 10,
             window_days: 30,
             refresh_interval: Duration::minutes(30),
             show_pad_markers: true,
+            track_deployments: true,
+            launch_alerts: true,
+            calendar_path: None,
         }
     }
 }
 
+impl LaunchLibraryConfig {
+    /// Defaults with `--ll2-dev`, `--ll2-url <url>`, `--ics <path>` and `LL2_API_TOKEN` applied
+    ///
+    /// Fails when `--ll2-url` is not an absolute http(s) URL.
+    pub fn from_args(args: &[String], api_token: Option<String>) -> Result<Self, anyhow::Error> {
+        let mut config = Self::default();
+        if args.iter().any(|arg| arg == "--ll2-dev") {
+            config.base_url = LL2_DEV_URL.to_string();
+        }
+        if args.iter().any(|arg| arg == "--no-deployments") {
+            config.track_deployments = false;
+        }
+        if let Some(pair) = args.windows(2).find(|pair| pair[0] == "--ll2-url") {
+            config.base_url = parse_base_url(&pair[1])?;
+        }
+        if let Some(pair) = args.windows(2).find(|pair| pair[0] == "--ics") {
+            config.calendar_path = Some(PathBuf::from(&pair[1]));
+        }
+        config.api_token = api_token.filter(|token| !token.trim().is_empty());
+        Ok(config)
+    }
+}
+
+/// API root from `--ll2-url`, without a trailing slash
+fn parse_base_url(value: &str) -> Result<String, anyhow::Error> {
+    let url = reqwest::Url::parse(value)
+        .map_err(|err| anyhow::anyhow!("Invalid --ll2-url {value:?}: {err}"))?;
+    // `localhost:8000` parses with `localhost` as the scheme
+    if !matches!(url.scheme(), "http" | "https") || !url.has_host() {
+        anyhow::bail!("Invalid --ll2-url {value:?}: expected an http:// or https:// URL");
+    }
+    Ok(value.trim_end_matches('/').to_string())
+}
+
 #[derive(Resource, Debug, Default)]
 pub struct LaunchLibraryState {
     pub last_launch_request: Option<DateTime<Utc>>,
@@ -70,12 +233,73 @@ pub struct LaunchLibraryState {
     pub launch_error: Option<String>,
     pub event_error: Option<String>,
     pub force_refresh: bool,
+    /// Launches shown are from the disk cache and have not been refreshed yet
+    pub launches_from_cache: bool,
+    /// Events shown are from the disk cache and have not been refreshed yet
+    pub events_from_cache: bool,
+    /// Failed launch fetches in a row, for backoff
+    pub launch_failures: u32,
+    /// Failed event fetches in a row, for backoff
+    pub event_failures: u32,
+    /// No launch fetch before this time (backoff or rate limit)
+    pub launch_retry_at: Option<DateTime<Utc>>,
+    /// No event fetch before this time (backoff or rate limit)
+    pub event_retry_at: Option<DateTime<Utc>>,
+    /// Launch whose detail should be fetched next (set when a launch is opened)
+    pub pending_detail: Option<String>,
+    /// Launch whose detail request is in flight
+    pub loading_detail: Option<String>,
+    /// Last failed detail fetch, as (launch uuid, error)
+    pub detail_error: Option<(String, String)>,
+    /// Fetch the next launch page on the next poll
+    pub pending_more_launches: bool,
+    pub is_loading_more_launches: bool,
+    /// Simulated day the past launch list is centered on
+    pub previous_anchor: Option<DateTime<Utc>>,
+    pub last_previous_request: Option<DateTime<Utc>>,
+    pub is_loading_previous: bool,
+    pub previous_error: Option<String>,
+    /// Failed past launch fetches in a row, for backoff
+    pub previous_failures: u32,
+    /// No past launch fetch before this time (backoff or rate limit)
+    pub previous_retry_at: Option<DateTime<Utc>>,
+    pub last_recent_request: Option<DateTime<Utc>>,
+    pub is_loading_recent: bool,
+    /// Failed recent launch fetches in a row, for backoff
+    pub recent_failures: u32,
+    /// No recent launch fetch before this time (backoff or rate limit)
+    pub recent_retry_at: Option<DateTime<Utc>>,
+    pub last_station_request: Option<DateTime<Utc>>,
+    pub is_loading_stations: bool,
+    pub station_error: Option<String>,
+    /// Failed station fetches in a row, for backoff
+    pub station_failures: u32,
+    /// No station fetch before this time (backoff or rate limit)
+    pub station_retry_at: Option<DateTime<Utc>>,
+    /// Write the calendar to the export directory on the next update
+    pub calendar_export_requested: bool,
+    pub calendar_status: Option<String>,
 }
 
 #[derive(Resource, Debug, Default)]
 pub struct LaunchLibraryData {
     pub launches: Vec<LaunchSummary>,
+    /// Feed events with upcoming dockings and undockings merged in, by date
     pub events: Vec<EventSummary>,
+    /// Events as listed by the `/events/` feed
+    pub feed_events: Vec<EventSummary>,
+    /// Link to the launch page after the ones loaded
+    pub launches_next: Option<String>,
+    /// Total upcoming launches in the window, as reported by LL2
+    pub launches_total: Option<u64>,
+    /// Fetched launch details by launch UUID
+    pub details: HashMap<String, LaunchDetail>,
+    /// Launches from the last few days, newest first
+    pub recent_launches: Vec<LaunchSummary>,
+    /// Past launches around the simulated day, by NET
+    pub previous_launches: Vec<LaunchSummary>,
+    pub stations: Vec<SpaceStation>,
+    pub docking_events: Vec<DockingEvent>,
 }
 
 #[derive(Resource)]
@@ -84,16 +308,99 @@ pub struct LaunchLibraryChannels {
     pub res_rx: Arc<Mutex<Receiver<LaunchLibraryResult>>>,
 }
 
+// Every command is a fetch for one feed
+#[allow(clippy::enum_variant_names)]
 pub enum LaunchLibraryCommand {
-    FetchLaunches { url: String },
-    FetchEvents { url: String },
+    FetchLaunches {
+        url: String,
+        api_token: Option<String>,
+        /// A follow-on page to append rather than a fresh first page
+        more: bool,
+    },
+    FetchEvents {
+        url: String,
+        api_token: Option<String>,
+    },
+    FetchRecentLaunches {
+        url: String,
+        api_token: Option<String>,
+    },
+    FetchPreviousLaunches {
+        url: String,
+        api_token: Option<String>,
+    },
+    FetchStations {
+        stations_url: String,
+        expeditions_url: String,
+        docking_url: String,
+        api_token: Option<String>,
+    },
+    FetchLaunchDetail {
+        uuid: String,
+        url: String,
+        api_token: Option<String>,
+    },
 }
 
 pub enum LaunchLibraryResult {
-    Launches(Vec<LaunchSummary>),
+    Launches(LaunchPage),
+    MoreLaunches(LaunchPage),
     Events(Vec<EventSummary>),
+    RecentLaunches(Vec<LaunchSummary>),
+    PreviousLaunches(Vec<LaunchSummary>),
+    Stations(StationData),
     Error {
         feed: LaunchLibraryFeed,
         error: String,
     },
+    LaunchDetail(Box<LaunchDetail>),
+    DetailError {
+        uuid: String,
+        error: String,
+    },
+    /// HTTP 429; the quota is shared, so both feeds wait (`feed` is None for a detail request)
+    RateLimited {
+        feed: Option<LaunchLibraryFeed>,
+        retry_after: Option<Duration>,
+    },
+}
+
+/// Launch with only a name and NET set, for tests to fill in the fields they check
+#[cfg(test)]
+pub(crate) fn test_launch(name: &str, net: Option<DateTime<Utc>>) -> LaunchSummary {
+    LaunchSummary {
+        name: name.to_string(),
+        net_utc: net,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_ll2_url_from_args() {
+        let config = LaunchLibraryConfig::from_args(
+            &args(&["bevyearth", "--ll2-url", "http://localhost:8000/2.3.0/"]),
+            None,
+        )
+        .unwrap();
+        assert_eq!(config.base_url, "http://localhost:8000/2.3.0");
+
+        for bad in [
+            "127.0.0.1:8000",
+            "ll2.local",
+            "localhost:8000",
+            "ftp://ll2.local",
+        ] {
+            let result =
+                LaunchLibraryConfig::from_args(&args(&["bevyearth", "--ll2-url", bad]), None);
+            assert!(result.is_err(), "{bad}");
+        }
+    }
 }
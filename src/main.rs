@@ -58,6 +58,7 @@ use bevy_feathers::{FeathersPlugins, dark_theme::create_dark_theme, palette, the
 ))]
 use bevy_input_focus::directional_navigation::DirectionalNavigationPlugin;
 
+mod analysis;
 mod core;
 mod launch_library;
 mod orbital;
@@ -75,6 +76,14 @@ mod visualization;
     ),
     all(feature = "debug_basic_scene", feature = "debug_scene_camera")
 ))]
+use analysis::AnalysisPlugin;
+#[cfg(any(
+    all(
+        not(feature = "debug_basic_scene"),
+        not(feature = "debug_scene_camera")
+    ),
+    all(feature = "debug_basic_scene", feature = "debug_scene_camera")
+))]
 use launch_library::LaunchLibraryPlugin;
 #[cfg(any(
     all(
@@ -127,7 +136,8 @@ use ui::{SkyboxPlugin, skybox::Cubemap};
 ))]
 use visualization::{
     CitiesPlugin, EarthPlugin, GroundTrackGizmoPlugin, GroundTrackPlugin, HeatmapPlugin,
-    LaunchesPlugin, MoonPlugin, ShowAxes, SunLight, VisualizationPlugin,
+    LaunchesPlugin, MoonPlugin, PlanetsPlugin, ShowAxes, StarsPlugin, SunLight,
+    VisualizationPlugin,
 };
 
 #[cfg(all(
@@ -415,7 +425,65 @@ fn toggle_dev_camera(
 }
 
 fn main() {
+    // Headless coverage analysis: `--coverage <tle-file> ...` runs without a window
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(parsed) = analysis::coverage::parse_headless_args(&args) {
+        if let Err(e) = parsed.and_then(analysis::coverage::run_headless) {
+            eprintln!("[COVERAGE] {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    // Headless TLE fit: `--fit-tle <ephemeris.csv> ...` writes a fitted TLE file
+    if let Some(parsed) = analysis::tle_fit::parse_headless_args(&args) {
+        if let Err(e) = parsed.and_then(analysis::tle_fit::run_headless) {
+            eprintln!("[TLE FIT] {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    // `--load-tle <file>` (repeatable) loads local TLE files as satellites
+    let tle_files: Vec<std::path::PathBuf> = args
+        .windows(2)
+        .filter(|pair| pair[0] == "--load-tle")
+        .map(|pair| std::path::PathBuf::from(&pair[1]))
+        .collect();
+
+    // `--transmitters <file>` overrides the SatNOGS transmitter database location
+    let transmitters_path = args
+        .windows(2)
+        .find(|pair| pair[0] == "--transmitters")
+        .map(|pair| std::path::PathBuf::from(&pair[1]));
+
+    // `--stars <file>` selects a Bright Star Catalogue or Hipparcos CSV
+    let stars_path = args
+        .windows(2)
+        .find(|pair| pair[0] == "--stars")
+        .map(|pair| std::path::PathBuf::from(&pair[1]));
+
+    // `--ll2-dev` / `--ll2-url <url>` pick the Launch Library endpoint; `LL2_API_TOKEN` holds a paid-tier key
+    let launch_library_config = match launch_library::LaunchLibraryConfig::from_args(
+        &args,
+        std::env::var("LL2_API_TOKEN").ok(),
+    ) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("[LAUNCH LIBRARY] {e}");
+            std::process::exit(1);
+        }
+    };
+
     let mut app = App::new();
+    app.insert_resource(tle::TleFileLoads(tle_files));
+    app.insert_resource(launch_library_config);
+    app.insert_resource(analysis::RadioConfig {
+        database_path: transmitters_path,
+        ..default()
+    });
+    app.insert_resource(visualization::StarCatalogConfig {
+        path: stars_path,
+        ..default()
+    });
 
     app.add_plugins(
         DefaultPlugins
@@ -520,6 +588,8 @@ fn main() {
         // Add our custom plugins
         app.add_plugins(EarthPlugin);
         app.add_plugins(MoonPlugin);
+        app.add_plugins(PlanetsPlugin);
+        app.add_plugins(StarsPlugin);
         app.add_plugins(CitiesPlugin);
         app.add_plugins(OrbitalPlugin);
         app.add_plugins(SatellitePlugin);
@@ -533,6 +603,7 @@ fn main() {
         app.add_plugins(GroundTrackPlugin);
         app.add_plugins(GroundTrackGizmoPlugin);
         app.add_plugins(HeatmapPlugin);
+        app.add_plugins(AnalysisPlugin);
         app.add_systems(Startup, setup);
     }
 
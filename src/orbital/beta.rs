@@ -0,0 +1,234 @@
+//! Solar beta angle and eclipse geometry
+//!
+//! The beta angle is the Sun's elevation above the orbit plane. The plane comes
+//! from the TLE inclination and RAAN, carried forward with J2 nodal precession,
+//! and the Sun from the VSOP87 ephemeris. Eclipse time uses a cylindrical Earth
+//! shadow and a circular orbit at the mean semi-major axis.
+
+use bevy::math::DVec3;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::core::space::EARTH_RADIUS_KM_F64;
+use crate::orbital::decay::semi_major_axis_km;
+use crate::orbital::ephemeris::sun_position;
+use crate::orbital::maneuver::j2_raan_rate_deg_per_day;
+use crate::tle::parser::MeanElements;
+
+/// Unit orbit normal (angular momentum direction) in the equatorial frame
+pub fn orbit_normal(inclination_rad: f64, raan_rad: f64) -> DVec3 {
+    DVec3::new(
+        inclination_rad.sin() * raan_rad.sin(),
+        -inclination_rad.sin() * raan_rad.cos(),
+        inclination_rad.cos(),
+    )
+}
+
+/// Beta angle (rad): positive when the Sun is on the angular momentum side of the orbit plane
+pub fn beta_angle_rad(orbit_normal: DVec3, sun_direction: DVec3) -> f64 {
+    orbit_normal
+        .normalize()
+        .dot(sun_direction.normalize())
+        .clamp(-1.0, 1.0)
+        .asin()
+}
+
+/// Inside the Earth's cylindrical shadow (sampled in the tests to check `eclipse_fraction`)
+#[cfg(test)]
+pub fn in_earth_shadow(position_km: DVec3, sun_direction: DVec3) -> bool {
+    let sun = sun_direction.normalize();
+    let along = position_km.dot(sun);
+    along < 0.0 && (position_km - sun * along).length() < EARTH_RADIUS_KM_F64
+}
+
+/// Beta angle (rad) above which a circular orbit of radius `radius_km` never enters shadow
+pub fn critical_beta_rad(radius_km: f64) -> f64 {
+    (EARTH_RADIUS_KM_F64 / radius_km).clamp(-1.0, 1.0).asin()
+}
+
+/// Fraction of a circular orbit spent in the cylindrical shadow
+pub fn eclipse_fraction(beta_rad: f64, radius_km: f64) -> f64 {
+    if radius_km <= EARTH_RADIUS_KM_F64 || beta_rad.abs() >= critical_beta_rad(radius_km) {
+        return 0.0;
+    }
+    let horizon = (radius_km * radius_km - EARTH_RADIUS_KM_F64 * EARTH_RADIUS_KM_F64).sqrt();
+    (horizon / (radius_km * beta_rad.cos()))
+        .clamp(-1.0, 1.0)
+        .acos()
+        / std::f64::consts::PI
+}
+
+/// Beta angle and eclipse share at one instant
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LightingSample {
+    pub utc: DateTime<Utc>,
+    pub beta_deg: f64,
+    pub eclipse_fraction: f64,
+    /// Time in shadow per orbit
+    pub eclipse_minutes: f64,
+}
+
+/// A run of samples with eclipses on every orbit
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EclipseSeason {
+    pub start_utc: DateTime<Utc>,
+    pub end_utc: DateTime<Utc>,
+    pub max_eclipse_minutes: f64,
+}
+
+/// Sample beta angle and eclipse time for `days` from `start_utc` every `step_hours`
+pub fn lighting_profile(
+    elements: &MeanElements,
+    start_utc: DateTime<Utc>,
+    days: f64,
+    step_hours: f64,
+) -> Vec<LightingSample> {
+    let radius_km = semi_major_axis_km(elements.mean_motion_rev_per_day);
+    let period_min = 1440.0 / elements.mean_motion_rev_per_day;
+    let raan_rate = j2_raan_rate_deg_per_day(elements);
+    let inclination = elements.inclination_deg.to_radians();
+    let step = Duration::seconds((step_hours.max(0.1) * 3600.0) as i64);
+    let end_utc = start_utc + Duration::seconds((days.max(0.0) * 86_400.0) as i64);
+
+    let mut samples = Vec::new();
+    let mut utc = start_utc;
+    while utc <= end_utc {
+        let days_from_epoch = (utc - elements.epoch_utc).num_seconds() as f64 / 86_400.0;
+        let raan = (elements.raan_deg + raan_rate * days_from_epoch).to_radians();
+        let beta = beta_angle_rad(
+            orbit_normal(inclination, raan),
+            sun_position(utc).equatorial,
+        );
+        let fraction = eclipse_fraction(beta, radius_km);
+        samples.push(LightingSample {
+            utc,
+            beta_deg: beta.to_degrees(),
+            eclipse_fraction: fraction,
+            eclipse_minutes: fraction * period_min,
+        });
+        utc += step;
+    }
+    samples
+}
+
+/// Group consecutive eclipsing samples into seasons
+pub fn eclipse_seasons(samples: &[LightingSample]) -> Vec<EclipseSeason> {
+    let mut seasons: Vec<EclipseSeason> = Vec::new();
+    let mut open = false;
+    for sample in samples {
+        if sample.eclipse_fraction <= 0.0 {
+            open = false;
+            continue;
+        }
+        match seasons.last_mut() {
+            Some(season) if open => {
+                season.end_utc = sample.utc;
+                season.max_eclipse_minutes = season.max_eclipse_minutes.max(sample.eclipse_minutes);
+            }
+            _ => seasons.push(EclipseSeason {
+                start_utc: sample.utc,
+                end_utc: sample.utc,
+                max_eclipse_minutes: sample.eclipse_minutes,
+            }),
+        }
+        open = true;
+    }
+    seasons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn elements(inclination_deg: f64, raan_deg: f64, mean_motion: f64) -> MeanElements {
+        MeanElements {
+            epoch_utc: Utc.with_ymd_and_hms(2025, 3, 20, 9, 1, 0).unwrap(),
+            inclination_deg,
+            raan_deg,
+            eccentricity: 0.0001,
+            arg_perigee_deg: 0.0,
+            mean_anomaly_deg: 0.0,
+            mean_motion_rev_per_day: mean_motion,
+            bstar: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_beta_angle_geometry() {
+        // Equatorial orbit: beta is the Sun's declination
+        let normal = orbit_normal(0.0, 0.0);
+        let dec = 23.44_f64.to_radians();
+        let solstice_sun = DVec3::new(0.0, dec.cos(), dec.sin());
+        assert!((beta_angle_rad(normal, solstice_sun) - dec).abs() < 1e-12);
+        // Polar orbit whose plane contains the Sun: beta is zero
+        let polar = orbit_normal(90f64.to_radians(), 0.0);
+        assert!(beta_angle_rad(polar, DVec3::X).abs() < 1e-12);
+        // and with RAAN turned 90 degrees the Sun is face-on
+        let face_on = orbit_normal(90f64.to_radians(), 90f64.to_radians());
+        assert!((beta_angle_rad(face_on, DVec3::X).to_degrees() - 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_eclipse_fraction_matches_sampled_orbit() {
+        let radius = 6778.0;
+        // ISS-like orbit at beta 0: about 36 minutes of a 92.6 minute orbit
+        assert!((eclipse_fraction(0.0, radius) * 92.6 - 36.0).abs() < 0.5);
+        assert_eq!(
+            eclipse_fraction(critical_beta_rad(radius) + 1e-6, radius),
+            0.0
+        );
+
+        for beta_deg in [0.0_f64, 30.0, 60.0] {
+            let beta = beta_deg.to_radians();
+            let sun = DVec3::new(beta.cos(), 0.0, beta.sin());
+            // Circular orbit in the XY plane, Sun tilted out of it by beta
+            let steps = 20_000;
+            let shadowed = (0..steps)
+                .filter(|k| {
+                    let u = std::f64::consts::TAU * *k as f64 / steps as f64;
+                    in_earth_shadow(DVec3::new(radius * u.cos(), radius * u.sin(), 0.0), sun)
+                })
+                .count();
+            let sampled = shadowed as f64 / steps as f64;
+            assert!(
+                (sampled - eclipse_fraction(beta, radius)).abs() < 1e-3,
+                "beta {beta_deg}: sampled {sampled}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_dawn_dusk_sun_synchronous_eclipses_only_near_june_solstice() {
+        // 98.2 deg, 14.6 rev/day, node 90 deg east of the Sun at the March equinox
+        let sso = elements(98.2, 90.0, 14.6);
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let samples = lighting_profile(&sso, start, 364.0, 24.0);
+        assert_eq!(samples.len(), 365);
+        // The plane follows the Sun, so beta stays high all year
+        assert!(samples.iter().all(|s| s.beta_deg > 55.0));
+        // Only when the Sun is furthest north does it dip below the ~64 deg critical beta
+        let seasons = eclipse_seasons(&samples);
+        assert_eq!(seasons.len(), 1, "{seasons:?}");
+        let solstice = Utc.with_ymd_and_hms(2025, 6, 21, 0, 0, 0).unwrap();
+        assert!(seasons[0].start_utc < solstice && solstice < seasons[0].end_utc);
+        assert!(seasons[0].max_eclipse_minutes < 25.0);
+    }
+
+    #[test]
+    fn test_eclipse_seasons_for_geostationary_orbit() {
+        // GEO only sees eclipses for a few weeks around each equinox
+        let geo = elements(0.05, 0.0, 1.002_7);
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let samples = lighting_profile(&geo, start, 365.0, 12.0);
+        let seasons = eclipse_seasons(&samples);
+        assert_eq!(seasons.len(), 2, "{seasons:?}");
+        for season in &seasons {
+            let length_days = (season.end_utc - season.start_utc).num_days();
+            assert!((35..=50).contains(&length_days), "{length_days} days");
+            // Longest GEO eclipse is about 70 minutes
+            assert!((season.max_eclipse_minutes - 69.0).abs() < 3.0);
+        }
+        let spring_mid = seasons[0].start_utc + (seasons[0].end_utc - seasons[0].start_utc) / 2;
+        assert_eq!(spring_mid.format("%m").to_string(), "03");
+    }
+}
@@ -0,0 +1,333 @@
+//! Orbital decay and re-entry prediction
+//!
+//! Uses a circular-orbit lifetime model: the semi-major axis shrinks as
+//! `da/dt = -B ρ(h) √(μ a)`, with ρ from an exponential atmosphere table and
+//! the ballistic coefficient B (Cd·A/m) taken either from the mean-motion
+//! trend across the TLE history or from the B* drag term.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::core::space::EARTH_RADIUS_KM_F64;
+use crate::tle::parser::MeanElements;
+
+/// Earth gravitational parameter (km³/s²)
+pub const MU_EARTH_KM3_S2: f64 = 398_600.441_8;
+/// Altitude treated as re-entry
+pub const REENTRY_ALTITUDE_KM: f64 = 120.0;
+/// Objects above this altitude are not assessed
+pub const MAX_DECAY_ALTITUDE_KM: f64 = 1000.0;
+
+/// Ballistic coefficient (m²/kg) per unit of B* (1/earth radii)
+const BSTAR_TO_BALLISTIC: f64 = 12.741_621;
+/// Lifetimes beyond this are reported as "no re-entry predicted"
+const MAX_HORIZON_DAYS: f64 = 25.0 * 365.25;
+/// History points older than this (relative to the newest) are ignored
+const HISTORY_WINDOW_DAYS: f64 = 30.0;
+const MIN_HISTORY_POINTS: usize = 3;
+const MIN_HISTORY_SPAN_DAYS: f64 = 2.0;
+/// Relative lifetime uncertainty floor; solar activity alone moves density this much
+const MIN_UNCERTAINTY: f64 = 0.2;
+/// Relative lifetime uncertainty for B*-only estimates
+const BSTAR_UNCERTAINTY: f64 = 0.5;
+
+/// Exponential atmosphere: (base altitude km, base density kg/m³, scale height km)
+const DENSITY_TABLE: [(f64, f64, f64); 19] = [
+    (100.0, 5.297e-7, 5.877),
+    (110.0, 9.661e-8, 7.263),
+    (120.0, 2.438e-8, 9.473),
+    (130.0, 8.484e-9, 12.636),
+    (140.0, 3.845e-9, 16.149),
+    (150.0, 2.070e-9, 22.523),
+    (180.0, 5.464e-10, 29.740),
+    (200.0, 2.789e-10, 37.105),
+    (250.0, 7.248e-11, 45.546),
+    (300.0, 2.418e-11, 53.628),
+    (350.0, 9.518e-12, 53.298),
+    (400.0, 3.725e-12, 58.515),
+    (450.0, 1.585e-12, 60.828),
+    (500.0, 6.967e-13, 63.822),
+    (600.0, 1.454e-13, 71.835),
+    (700.0, 3.614e-14, 88.667),
+    (800.0, 1.170e-14, 124.64),
+    (900.0, 5.245e-15, 181.05),
+    (1000.0, 3.019e-15, 268.00),
+];
+
+/// Atmospheric density (kg/m³) at the given altitude
+pub fn atmospheric_density_kg_m3(altitude_km: f64) -> f64 {
+    let (h0, rho0, scale) = DENSITY_TABLE
+        .iter()
+        .rev()
+        .find(|(h0, _, _)| altitude_km >= *h0)
+        .copied()
+        .unwrap_or(DENSITY_TABLE[0]);
+    rho0 * (-(altitude_km - h0) / scale).exp()
+}
+
+/// Semi-major axis (km) for a mean motion in revolutions per day
+pub fn semi_major_axis_km(mean_motion_rev_per_day: f64) -> f64 {
+    let n_rad_s = mean_motion_rev_per_day * std::f64::consts::TAU / 86_400.0;
+    (MU_EARTH_KM3_S2 / (n_rad_s * n_rad_s)).cbrt()
+}
+
+/// Rate of change of the semi-major axis (km/s, negative when decaying)
+fn decay_rate_km_s(semi_major_axis_km: f64, ballistic_m2_kg: f64) -> f64 {
+    let altitude = semi_major_axis_km - EARTH_RADIUS_KM_F64;
+    let mu_m3_s2 = MU_EARTH_KM3_S2 * 1.0e9;
+    let a_m = semi_major_axis_km * 1.0e3;
+    -ballistic_m2_kg * atmospheric_density_kg_m3(altitude) * (mu_m3_s2 * a_m).sqrt() / 1.0e3
+}
+
+/// Semi-major axis loss per day implied by the B* drag term (0 without drag)
+pub fn bstar_decay_km_per_day(elements: &MeanElements) -> f64 {
+    if elements.bstar <= 0.0 {
+        return 0.0;
+    }
+    let a = semi_major_axis_km(elements.mean_motion_rev_per_day);
+    -decay_rate_km_s(a, BSTAR_TO_BALLISTIC * elements.bstar) * 86_400.0
+}
+
+/// Days until the orbit drops to the re-entry altitude, None beyond the horizon
+pub fn lifetime_days(semi_major_axis_km: f64, ballistic_m2_kg: f64) -> Option<f64> {
+    if ballistic_m2_kg <= 0.0 {
+        return None;
+    }
+    let mut a = semi_major_axis_km;
+    let mut elapsed_s = 0.0;
+    while a - EARTH_RADIUS_KM_F64 > REENTRY_ALTITUDE_KM {
+        let rate = -decay_rate_km_s(a, ballistic_m2_kg);
+        // Step so the altitude changes by about a kilometre
+        let dt = (1.0 / rate).clamp(60.0, 5.0 * 86_400.0);
+        a -= rate * dt;
+        elapsed_s += dt;
+        if elapsed_s > MAX_HORIZON_DAYS * 86_400.0 {
+            return None;
+        }
+    }
+    Some(elapsed_s / 86_400.0)
+}
+
+/// Source of the drag estimate behind a prediction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecayMethod {
+    /// Mean-motion growth fitted across the TLE history
+    TleHistory,
+    /// B* drag term of the latest element set
+    Bstar,
+}
+
+impl DecayMethod {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::TleHistory => "TLE history",
+            Self::Bstar => "B*",
+        }
+    }
+}
+
+/// Predicted re-entry with an uncertainty window
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecayPrediction {
+    pub method: DecayMethod,
+    pub reentry_utc: DateTime<Utc>,
+    pub earliest_utc: DateTime<Utc>,
+    pub latest_utc: DateTime<Utc>,
+    /// Mean altitude at the reference epoch
+    pub altitude_km: f64,
+    /// Altitude loss per day at the reference epoch
+    pub decay_rate_km_per_day: f64,
+}
+
+impl DecayPrediction {
+    fn from_lifetime(
+        method: DecayMethod,
+        epoch: DateTime<Utc>,
+        semi_major_axis_km: f64,
+        ballistic_m2_kg: f64,
+        uncertainty: f64,
+    ) -> Option<Self> {
+        let life = lifetime_days(semi_major_axis_km, ballistic_m2_kg)?;
+        let at = |days: f64| epoch + Duration::milliseconds((days * 86_400_000.0) as i64);
+        Some(Self {
+            method,
+            reentry_utc: at(life),
+            earliest_utc: at(life * (1.0 - uncertainty)),
+            latest_utc: at(life * (1.0 + uncertainty)),
+            altitude_km: semi_major_axis_km - EARTH_RADIUS_KM_F64,
+            decay_rate_km_per_day: -decay_rate_km_s(semi_major_axis_km, ballistic_m2_kg) * 86_400.0,
+        })
+    }
+
+    /// Days from `utc` to the nominal re-entry time
+    pub fn days_until(&self, utc: DateTime<Utc>) -> f64 {
+        (self.reentry_utc - utc).num_seconds() as f64 / 86_400.0
+    }
+}
+
+/// Predict re-entry from the B* drag term alone
+pub fn predict_decay_from_bstar(elements: &MeanElements) -> Option<DecayPrediction> {
+    let a = semi_major_axis_km(elements.mean_motion_rev_per_day);
+    if elements.bstar <= 0.0 || a - EARTH_RADIUS_KM_F64 > MAX_DECAY_ALTITUDE_KM {
+        return None;
+    }
+    DecayPrediction::from_lifetime(
+        DecayMethod::Bstar,
+        elements.epoch_utc,
+        a,
+        BSTAR_TO_BALLISTIC * elements.bstar,
+        BSTAR_UNCERTAINTY,
+    )
+}
+
+/// Predict re-entry by fitting mean-motion growth across element sets
+///
+/// The effective ballistic coefficient is recovered from the fitted decay
+/// rate at the current altitude, so density model bias largely cancels.
+pub fn predict_decay_from_history(history: &[MeanElements]) -> Option<DecayPrediction> {
+    let newest = history.iter().max_by_key(|e| e.epoch_utc)?;
+    let points: Vec<(f64, f64)> = history
+        .iter()
+        .map(|e| {
+            let t = (e.epoch_utc - newest.epoch_utc).num_seconds() as f64 / 86_400.0;
+            (t, e.mean_motion_rev_per_day)
+        })
+        .filter(|(t, _)| *t >= -HISTORY_WINDOW_DAYS)
+        .collect();
+    let span = -points.iter().map(|(t, _)| *t).fold(0.0, f64::min);
+    if points.len() < MIN_HISTORY_POINTS || span < MIN_HISTORY_SPAN_DAYS {
+        return None;
+    }
+
+    // Least-squares line n(t) = n0 + ndot t
+    let count = points.len() as f64;
+    let mean_t = points.iter().map(|(t, _)| t).sum::<f64>() / count;
+    let mean_n = points.iter().map(|(_, n)| n).sum::<f64>() / count;
+    let sxx: f64 = points.iter().map(|(t, _)| (t - mean_t).powi(2)).sum();
+    let sxy: f64 = points
+        .iter()
+        .map(|(t, n)| (t - mean_t) * (n - mean_n))
+        .sum();
+    let ndot = sxy / sxx;
+    if ndot <= 0.0 {
+        return None;
+    }
+    let n0 = mean_n - ndot * mean_t;
+    let residual: f64 = points
+        .iter()
+        .map(|(t, n)| (n - (n0 + ndot * t)).powi(2))
+        .sum();
+    let slope_error = (residual / (count - 2.0).max(1.0) / sxx).sqrt();
+
+    let a = semi_major_axis_km(n0);
+    let altitude = a - EARTH_RADIUS_KM_F64;
+    if altitude > MAX_DECAY_ALTITUDE_KM {
+        return None;
+    }
+    // a ∝ n^(-2/3)  =>  da/dt = -(2/3) a ndot / n
+    let da_dt_km_s = -(2.0 / 3.0) * a * ndot / n0 / 86_400.0;
+    let ballistic = -da_dt_km_s * 1.0e3
+        / (atmospheric_density_kg_m3(altitude) * (MU_EARTH_KM3_S2 * 1.0e9 * a * 1.0e3).sqrt());
+    let uncertainty = (2.0 * slope_error / ndot).clamp(MIN_UNCERTAINTY, 0.9);
+
+    DecayPrediction::from_lifetime(
+        DecayMethod::TleHistory,
+        newest.epoch_utc,
+        a,
+        ballistic,
+        uncertainty,
+    )
+}
+
+/// Best available prediction: TLE history when it shows a trend, B* otherwise
+pub fn predict_decay(history: &[MeanElements], latest: &MeanElements) -> Option<DecayPrediction> {
+    predict_decay_from_history(history).or_else(|| predict_decay_from_bstar(latest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn elements_at(epoch: DateTime<Utc>, mean_motion: f64, bstar: f64) -> MeanElements {
+        MeanElements {
+            epoch_utc: epoch,
+            inclination_deg: 51.6,
+            raan_deg: 0.0,
+            eccentricity: 0.0005,
+            arg_perigee_deg: 0.0,
+            mean_anomaly_deg: 0.0,
+            mean_motion_rev_per_day: mean_motion,
+            bstar,
+        }
+    }
+
+    fn mean_motion_for_altitude(altitude_km: f64) -> f64 {
+        let a = EARTH_RADIUS_KM_F64 + altitude_km;
+        (MU_EARTH_KM3_S2 / a.powi(3)).sqrt() * 86_400.0 / std::f64::consts::TAU
+    }
+
+    #[test]
+    fn test_density_decreases_with_altitude() {
+        let mut previous = f64::INFINITY;
+        for altitude in (100..1000).step_by(25) {
+            let rho = atmospheric_density_kg_m3(f64::from(altitude));
+            assert!(rho < previous, "density should fall at {altitude} km");
+            previous = rho;
+        }
+    }
+
+    #[test]
+    fn test_lifetime_shrinks_with_drag() {
+        let a = EARTH_RADIUS_KM_F64 + 350.0;
+        let low_drag = lifetime_days(a, 0.005).expect("decays within horizon");
+        let high_drag = lifetime_days(a, 0.02).expect("decays within horizon");
+        assert!(high_drag < low_drag);
+        assert!(lifetime_days(EARTH_RADIUS_KM_F64 + 100.0, 0.01) == Some(0.0));
+    }
+
+    #[test]
+    fn test_history_fit_recovers_model_lifetime() {
+        let ballistic = 0.01;
+        let a = EARTH_RADIUS_KM_F64 + 300.0;
+        let expected = lifetime_days(a, ballistic).unwrap();
+
+        // Synthesize element sets that follow the model's current decay rate
+        let epoch = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        let n_now = mean_motion_for_altitude(300.0);
+        let da_dt = decay_rate_km_s(a, ballistic) * 86_400.0;
+        let ndot = -1.5 * n_now * da_dt / a;
+        let history: Vec<_> = (0..8)
+            .map(|day| {
+                let t = -f64::from(day);
+                elements_at(
+                    epoch + Duration::days(-i64::from(day)),
+                    n_now + ndot * t,
+                    0.0,
+                )
+            })
+            .collect();
+
+        let prediction = predict_decay_from_history(&history).expect("decay trend");
+        assert_eq!(prediction.method, DecayMethod::TleHistory);
+        let predicted = prediction.days_until(epoch);
+        assert!(
+            (predicted - expected).abs() / expected < 0.05,
+            "predicted {predicted} days, model {expected} days"
+        );
+        assert!(prediction.earliest_utc < prediction.reentry_utc);
+        assert!(prediction.latest_utc > prediction.reentry_utc);
+    }
+
+    #[test]
+    fn test_bstar_fallback_and_high_orbits() {
+        let epoch = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        let low = elements_at(epoch, mean_motion_for_altitude(250.0), 5.0e-4);
+        let prediction = predict_decay(&[low], &low).expect("B* prediction");
+        assert_eq!(prediction.method, DecayMethod::Bstar);
+
+        let geo = elements_at(epoch, 1.0027, 1.0e-4);
+        assert!(predict_decay(&[geo], &geo).is_none());
+        let no_drag = elements_at(epoch, mean_motion_for_altitude(250.0), 0.0);
+        assert!(predict_decay_from_bstar(&no_drag).is_none());
+    }
+}
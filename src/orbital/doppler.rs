@@ -0,0 +1,67 @@
+//! Range rate and Doppler correction for radio links
+//!
+//! The ground station is fixed in ECEF, so the range rate is the satellite's
+//! Earth-relative velocity projected on the line of sight. Positive range rate
+//! means the satellite is receding and the received frequency drops.
+
+use bevy::math::DVec3;
+
+/// Speed of light (km/s)
+pub const SPEED_OF_LIGHT_KM_S: f64 = 299_792.458;
+
+/// Slant range (km) and range rate (km/s) from a ground station to a satellite, all ECEF
+pub fn range_and_rate_km(
+    site_ecef_km: DVec3,
+    sat_ecef_km: DVec3,
+    sat_velocity_km_s: DVec3,
+) -> (f64, f64) {
+    let line_of_sight = sat_ecef_km - site_ecef_km;
+    let range = line_of_sight.length();
+    if range == 0.0 {
+        return (0.0, 0.0);
+    }
+    (range, sat_velocity_km_s.dot(line_of_sight) / range)
+}
+
+/// Frequency heard on the ground for a satellite transmitting at `nominal_hz`
+pub fn downlink_hz(nominal_hz: f64, range_rate_km_s: f64) -> f64 {
+    nominal_hz * (1.0 - range_rate_km_s / SPEED_OF_LIGHT_KM_S)
+}
+
+/// Frequency to transmit so the satellite receives `nominal_hz`
+pub fn uplink_hz(nominal_hz: f64, range_rate_km_s: f64) -> f64 {
+    nominal_hz / (1.0 - range_rate_km_s / SPEED_OF_LIGHT_KM_S)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_rate_sign() {
+        let site = DVec3::new(6371.0, 0.0, 0.0);
+        let sat = DVec3::new(6371.0 + 500.0, 1000.0, 0.0);
+        let (range, receding) = range_and_rate_km(site, sat, DVec3::new(0.0, 7.0, 0.0));
+        assert!((range - 500f64.hypot(1000.0)).abs() < 1e-9);
+        assert!(receding > 6.0 && receding < 7.0);
+        let (_, approaching) = range_and_rate_km(site, sat, DVec3::new(0.0, -7.0, 0.0));
+        assert!((approaching + receding).abs() < 1e-12);
+        // Crossing overhead: velocity perpendicular to the line of sight
+        let overhead = DVec3::new(6871.0, 0.0, 0.0);
+        let (_, rate) = range_and_rate_km(site, overhead, DVec3::new(0.0, 7.5, 0.0));
+        assert!(rate.abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_doppler_shift_at_vhf_and_uhf() {
+        // Approaching at 7 km/s shifts 145.8 MHz up by about 3.4 kHz
+        let shifted = downlink_hz(145.8e6, -7.0);
+        assert!((shifted - 145.8e6 - 3404.4).abs() < 1.0, "{shifted}");
+        // The uplink is pre-compensated the opposite way,
+        let uplink = uplink_hz(435.0e6, -7.0);
+        assert!(uplink < 435.0e6);
+        // and the satellite, seeing the same shift, hears the nominal frequency
+        assert!((downlink_hz(uplink, -7.0) - 435.0e6).abs() < 1e-3);
+        assert_eq!(downlink_hz(437.8e6, 0.0), 437.8e6);
+    }
+}
@@ -0,0 +1,687 @@
+//! Solar and planetary ephemeris
+//!
+//! The Sun comes from a truncated VSOP87 series for the Earth (Meeus,
+//! Astronomical Algorithms, appendix III) with nutation and aberration, good to
+//! about an arcsecond. Planets use the JPL approximate Keplerian elements
+//! (Standish, valid 1800-2050, arcminute level) with light-time correction,
+//! which is plenty for drawing them in the sky.
+
+use bevy::math::DVec3;
+use chrono::{DateTime, Utc};
+use std::f64::consts::{PI, TAU};
+
+use crate::core::coordinates::{eci_to_ecef_km, gmst_rad_with_dut1};
+use crate::orbital::timescale::{TimeScale, julian_date};
+
+/// Astronomical unit (km)
+pub const AU_KM: f64 = 149_597_870.7;
+/// Light time for one astronomical unit (days)
+const LIGHT_TIME_DAYS_PER_AU: f64 = 0.005_775_518_3;
+const ARCSEC_TO_RAD: f64 = PI / (180.0 * 3600.0);
+
+/// One VSOP87 term: A cos(B + C tau)
+type VsopTerm = (f64, f64, f64);
+
+const EARTH_L0: [VsopTerm; 64] = [
+    (175_347_046.0, 0.0, 0.0),
+    (3_341_656.0, 4.669_256_8, 6_283.075_85),
+    (34_894.0, 4.626_10, 12_566.151_70),
+    (3_497.0, 2.744_1, 5_753.384_9),
+    (3_418.0, 2.828_9, 3.523_1),
+    (3_136.0, 3.627_7, 77_713.771_5),
+    (2_676.0, 4.418_1, 7_860.419_4),
+    (2_343.0, 6.135_2, 3_930.209_7),
+    (1_324.0, 0.742_5, 11_506.769_8),
+    (1_273.0, 2.037_1, 529.691_0),
+    (1_199.0, 1.109_6, 1_577.343_5),
+    (990.0, 5.233, 5_884.927),
+    (902.0, 2.045, 26.298),
+    (857.0, 3.508, 398.149),
+    (780.0, 1.179, 5_223.694),
+    (753.0, 2.533, 5_507.553),
+    (505.0, 4.583, 18_849.228),
+    (492.0, 4.205, 775.523),
+    (357.0, 2.920, 0.067),
+    (317.0, 5.849, 11_790.629),
+    (284.0, 1.899, 796.298),
+    (271.0, 0.315, 10_977.079),
+    (243.0, 0.345, 5_486.778),
+    (206.0, 4.806, 2_544.314),
+    (205.0, 1.869, 5_573.143),
+    (202.0, 2.458, 6_069.777),
+    (156.0, 0.833, 213.299),
+    (132.0, 3.411, 2_942.463),
+    (126.0, 1.083, 20.775),
+    (115.0, 0.645, 0.980),
+    (103.0, 0.636, 4_694.003),
+    (102.0, 0.976, 15_720.839),
+    (102.0, 4.267, 7.114),
+    (99.0, 6.21, 2_146.17),
+    (98.0, 0.68, 155.42),
+    (86.0, 5.98, 161_000.69),
+    (85.0, 1.30, 6_275.96),
+    (85.0, 3.67, 71_430.70),
+    (80.0, 1.81, 17_260.15),
+    (79.0, 3.04, 12_036.46),
+    (75.0, 1.76, 5_088.63),
+    (74.0, 3.50, 3_154.69),
+    (74.0, 4.68, 801.82),
+    (70.0, 0.83, 9_437.76),
+    (62.0, 3.98, 8_827.39),
+    (61.0, 1.82, 7_084.90),
+    (57.0, 2.78, 6_286.60),
+    (56.0, 4.39, 14_143.50),
+    (56.0, 3.47, 6_279.55),
+    (52.0, 0.19, 12_139.55),
+    (52.0, 1.33, 1_748.02),
+    (51.0, 0.28, 5_856.48),
+    (49.0, 0.49, 1_194.45),
+    (41.0, 5.37, 8_429.24),
+    (41.0, 2.40, 19_651.05),
+    (39.0, 6.17, 10_447.39),
+    (37.0, 6.04, 10_213.29),
+    (37.0, 2.57, 1_059.38),
+    (36.0, 1.71, 2_352.87),
+    (36.0, 1.78, 6_812.77),
+    (33.0, 0.59, 17_789.85),
+    (30.0, 0.44, 83_996.85),
+    (30.0, 2.74, 1_349.87),
+    (25.0, 3.16, 4_690.48),
+];
+
+const EARTH_L1: [VsopTerm; 34] = [
+    (628_331_966_747.0, 0.0, 0.0),
+    (206_059.0, 2.678_235, 6_283.075_85),
+    (4_303.0, 2.635_1, 12_566.151_7),
+    (425.0, 1.590, 3.523),
+    (119.0, 5.796, 26.298),
+    (109.0, 2.966, 1_577.344),
+    (93.0, 2.59, 18_849.23),
+    (72.0, 1.14, 529.69),
+    (68.0, 1.87, 398.15),
+    (67.0, 4.41, 5_507.55),
+    (59.0, 2.89, 5_223.69),
+    (56.0, 2.17, 155.42),
+    (45.0, 0.40, 796.30),
+    (36.0, 0.47, 775.52),
+    (29.0, 2.65, 7.11),
+    (21.0, 5.34, 0.98),
+    (19.0, 1.85, 5_486.78),
+    (19.0, 4.97, 213.30),
+    (17.0, 2.99, 6_275.96),
+    (16.0, 0.03, 2_544.31),
+    (16.0, 1.43, 2_146.17),
+    (15.0, 1.21, 10_977.08),
+    (12.0, 2.83, 1_748.02),
+    (12.0, 3.26, 5_088.63),
+    (12.0, 5.27, 1_194.45),
+    (12.0, 2.08, 4_694.00),
+    (11.0, 0.77, 553.57),
+    (10.0, 1.30, 6_286.60),
+    (10.0, 4.24, 1_349.87),
+    (9.0, 2.70, 242.73),
+    (9.0, 5.64, 951.72),
+    (8.0, 5.30, 2_352.87),
+    (6.0, 2.65, 9_437.76),
+    (6.0, 4.67, 4_690.48),
+];
+
+const EARTH_L2: [VsopTerm; 20] = [
+    (52_919.0, 0.0, 0.0),
+    (8_720.0, 1.072_1, 6_283.075_8),
+    (309.0, 0.867, 12_566.152),
+    (27.0, 0.05, 3.52),
+    (16.0, 5.19, 26.30),
+    (16.0, 3.68, 155.42),
+    (10.0, 0.76, 18_849.23),
+    (9.0, 2.06, 77_713.77),
+    (7.0, 0.83, 775.52),
+    (5.0, 4.66, 1_577.34),
+    (4.0, 1.03, 7.11),
+    (4.0, 3.44, 5_573.14),
+    (3.0, 5.14, 796.30),
+    (3.0, 6.05, 5_507.55),
+    (3.0, 1.19, 242.73),
+    (3.0, 6.12, 529.69),
+    (3.0, 0.31, 398.15),
+    (3.0, 2.28, 553.57),
+    (2.0, 4.38, 5_223.69),
+    (2.0, 3.75, 0.98),
+];
+
+const EARTH_L3: [VsopTerm; 7] = [
+    (289.0, 5.844, 6_283.076),
+    (35.0, 0.0, 0.0),
+    (17.0, 5.49, 12_566.15),
+    (3.0, 5.20, 155.42),
+    (1.0, 4.72, 3.52),
+    (1.0, 5.30, 18_849.23),
+    (1.0, 5.97, 242.73),
+];
+
+const EARTH_L4: [VsopTerm; 3] = [
+    (114.0, PI, 0.0),
+    (8.0, 4.13, 6_283.08),
+    (1.0, 3.84, 12_566.15),
+];
+
+const EARTH_L5: [VsopTerm; 1] = [(1.0, PI, 0.0)];
+
+const EARTH_B0: [VsopTerm; 5] = [
+    (280.0, 3.199, 84_334.662),
+    (102.0, 5.422, 5_507.553),
+    (80.0, 3.88, 5_223.69),
+    (44.0, 3.70, 2_352.87),
+    (32.0, 4.00, 1_577.34),
+];
+
+const EARTH_B1: [VsopTerm; 2] = [(9.0, 3.90, 5_507.55), (6.0, 1.73, 5_223.69)];
+
+const EARTH_R0: [VsopTerm; 40] = [
+    (100_013_989.0, 0.0, 0.0),
+    (1_670_700.0, 3.098_463_5, 6_283.075_85),
+    (13_956.0, 3.055_25, 12_566.151_70),
+    (3_084.0, 5.198_5, 77_713.771_5),
+    (1_628.0, 1.173_9, 5_753.384_9),
+    (1_576.0, 2.846_9, 7_860.419_4),
+    (925.0, 5.453, 11_506.770),
+    (542.0, 4.564, 3_930.210),
+    (472.0, 3.661, 5_884.927),
+    (346.0, 0.964, 5_507.553),
+    (329.0, 5.900, 5_223.694),
+    (307.0, 0.299, 5_573.143),
+    (243.0, 4.273, 11_790.629),
+    (212.0, 5.847, 1_577.344),
+    (186.0, 5.022, 10_977.079),
+    (175.0, 3.012, 18_849.228),
+    (110.0, 5.055, 5_486.778),
+    (98.0, 0.89, 6_069.78),
+    (86.0, 5.69, 15_720.84),
+    (86.0, 1.27, 161_000.69),
+    (65.0, 0.27, 17_260.15),
+    (63.0, 0.92, 529.69),
+    (57.0, 2.01, 83_996.85),
+    (56.0, 5.24, 71_430.70),
+    (49.0, 3.25, 2_544.31),
+    (47.0, 2.58, 775.52),
+    (45.0, 5.54, 9_437.76),
+    (43.0, 6.01, 6_275.96),
+    (39.0, 5.36, 4_694.00),
+    (38.0, 2.39, 8_827.39),
+    (37.0, 0.83, 19_651.05),
+    (37.0, 4.90, 12_139.55),
+    (36.0, 1.67, 12_036.46),
+    (35.0, 1.84, 2_942.46),
+    (33.0, 0.24, 7_084.90),
+    (32.0, 0.18, 5_088.63),
+    (32.0, 1.78, 398.15),
+    (28.0, 1.21, 6_286.60),
+    (28.0, 1.90, 6_279.55),
+    (26.0, 4.59, 10_447.39),
+];
+
+const EARTH_R1: [VsopTerm; 10] = [
+    (103_019.0, 1.107_490, 6_283.075_85),
+    (1_721.0, 1.064_4, 12_566.151_7),
+    (702.0, PI, 0.0),
+    (32.0, 1.02, 18_849.23),
+    (31.0, 2.84, 5_507.55),
+    (25.0, 1.32, 5_223.69),
+    (18.0, 1.42, 1_577.34),
+    (10.0, 5.91, 10_977.08),
+    (9.0, 1.42, 6_275.96),
+    (9.0, 0.27, 5_486.78),
+];
+
+const EARTH_R2: [VsopTerm; 6] = [
+    (4_359.0, 5.784_6, 6_283.075_8),
+    (124.0, 5.579, 12_566.152),
+    (12.0, PI, 0.0),
+    (9.0, 3.63, 77_713.77),
+    (6.0, 1.87, 5_573.14),
+    (3.0, 5.47, 18_849.23),
+];
+
+const EARTH_R3: [VsopTerm; 2] = [(145.0, 4.273, 6_283.076), (7.0, 3.92, 12_566.15)];
+
+const EARTH_R4: [VsopTerm; 1] = [(4.0, 2.56, 6_283.08)];
+
+/// Evaluate a VSOP87 series: sum over powers of tau, scaled by 1e-8
+fn vsop_series(series: &[&[VsopTerm]], tau: f64) -> f64 {
+    series.iter().rev().fold(0.0, |acc, terms| {
+        let sum: f64 = terms.iter().map(|(a, b, c)| a * (b + c * tau).cos()).sum();
+        acc * tau + sum
+    }) * 1.0e-8
+}
+
+/// Heliocentric ecliptic longitude, latitude (rad, ecliptic of date) and distance (AU) of the Earth
+fn earth_heliocentric(tau: f64) -> (f64, f64, f64) {
+    let l = vsop_series(
+        &[
+            &EARTH_L0, &EARTH_L1, &EARTH_L2, &EARTH_L3, &EARTH_L4, &EARTH_L5,
+        ],
+        tau,
+    );
+    let b = vsop_series(&[&EARTH_B0, &EARTH_B1], tau);
+    let r = vsop_series(
+        &[&EARTH_R0, &EARTH_R1, &EARTH_R2, &EARTH_R3, &EARTH_R4],
+        tau,
+    );
+    (l.rem_euclid(TAU), b, r)
+}
+
+/// Julian Ephemeris Date (TT) for a UTC instant
+pub fn julian_ephemeris_date(utc: DateTime<Utc>) -> f64 {
+    julian_date(utc, TimeScale::Tt)
+}
+
+/// Nutation in longitude and obliquity (rad), Meeus chapter 22 low-accuracy form
+fn nutation(t: f64) -> (f64, f64) {
+    let omega = (125.044_52 - 1_934.136_261 * t).to_radians();
+    let sun_l = (280.466_5 + 36_000.769_8 * t).to_radians();
+    let moon_l = (218.316_5 + 481_267.881_3 * t).to_radians();
+    let dpsi = -17.20 * omega.sin() - 1.32 * (2.0 * sun_l).sin() - 0.23 * (2.0 * moon_l).sin()
+        + 0.21 * (2.0 * omega).sin();
+    let deps = 9.20 * omega.cos() + 0.57 * (2.0 * sun_l).cos() + 0.10 * (2.0 * moon_l).cos()
+        - 0.09 * (2.0 * omega).cos();
+    (dpsi * ARCSEC_TO_RAD, deps * ARCSEC_TO_RAD)
+}
+
+/// Mean obliquity of the ecliptic (rad)
+fn mean_obliquity(t: f64) -> f64 {
+    (84_381.448 - 46.815_0 * t - 0.000_59 * t * t + 0.001_813 * t * t * t) * ARCSEC_TO_RAD
+}
+
+/// Ecliptic (of date) spherical coordinates to equatorial unit vector using obliquity `eps`
+fn ecliptic_to_equatorial(longitude: f64, latitude: f64, eps: f64) -> DVec3 {
+    let x = latitude.cos() * longitude.cos();
+    let y = latitude.cos() * longitude.sin();
+    let z = latitude.sin();
+    DVec3::new(
+        x,
+        y * eps.cos() - z * eps.sin(),
+        y * eps.sin() + z * eps.cos(),
+    )
+}
+
+/// Greenwich apparent sidereal time (rad): GMST plus the equation of the equinoxes
+pub fn apparent_sidereal_time_rad(utc: DateTime<Utc>, dut1_seconds: f64) -> f64 {
+    let t = (julian_ephemeris_date(utc) - 2_451_545.0) / 36_525.0;
+    let (dpsi, deps) = nutation(t);
+    gmst_rad_with_dut1(utc, dut1_seconds) + dpsi * (mean_obliquity(t) + deps).cos()
+}
+
+/// Rotate a true-of-date equatorial vector into ECEF with apparent sidereal time
+pub fn equatorial_of_date_to_ecef(
+    equatorial: DVec3,
+    utc: DateTime<Utc>,
+    dut1_seconds: f64,
+) -> DVec3 {
+    eci_to_ecef_km(equatorial, apparent_sidereal_time_rad(utc, dut1_seconds))
+}
+
+/// Precess a J2000 equatorial unit vector to the mean equator and equinox of date (Meeus 21.2-21.4)
+pub fn precess_from_j2000(equatorial: DVec3, utc: DateTime<Utc>) -> DVec3 {
+    let t = (julian_ephemeris_date(utc) - 2_451_545.0) / 36_525.0;
+    let zeta = (2_306.218_1 * t + 0.301_88 * t * t + 0.017_998 * t * t * t) * ARCSEC_TO_RAD;
+    let z = (2_306.218_1 * t + 1.094_68 * t * t + 0.018_203 * t * t * t) * ARCSEC_TO_RAD;
+    let theta = (2_004.310_9 * t - 0.426_65 * t * t - 0.041_833 * t * t * t) * ARCSEC_TO_RAD;
+
+    let ra0 = equatorial.y.atan2(equatorial.x);
+    let cos_dec0 = equatorial.x.hypot(equatorial.y);
+    let sin_dec0 = equatorial.z;
+    let a = cos_dec0 * (ra0 + zeta).sin();
+    let b = theta.cos() * cos_dec0 * (ra0 + zeta).cos() - theta.sin() * sin_dec0;
+    let c = theta.sin() * cos_dec0 * (ra0 + zeta).cos() + theta.cos() * sin_dec0;
+    let ra = a.atan2(b) + z;
+    let cos_dec = a.hypot(b);
+    DVec3::new(cos_dec * ra.cos(), cos_dec * ra.sin(), c)
+}
+
+/// Apparent geocentric position of the Sun
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SunPosition {
+    /// Apparent right ascension (rad, true equator and equinox of date)
+    pub right_ascension_rad: f64,
+    pub declination_rad: f64,
+    /// Earth-Sun distance (AU)
+    pub distance_au: f64,
+    /// Unit vector, true equator and equinox of date
+    pub equatorial: DVec3,
+}
+
+/// Apparent Sun position at a UTC instant
+pub fn sun_position(utc: DateTime<Utc>) -> SunPosition {
+    let jde = julian_ephemeris_date(utc);
+    let t = (jde - 2_451_545.0) / 36_525.0;
+    let (earth_l, earth_b, r) = earth_heliocentric(t / 10.0);
+
+    // Geocentric = heliocentric Earth turned around, then FK5, nutation and aberration
+    let fk5 = -0.090_33 * ARCSEC_TO_RAD;
+    let (dpsi, deps) = nutation(t);
+    let aberration = -20.489_8 * ARCSEC_TO_RAD / r;
+    let longitude = earth_l + PI + fk5 + dpsi + aberration;
+    let latitude = -earth_b;
+
+    let equatorial = ecliptic_to_equatorial(longitude, latitude, mean_obliquity(t) + deps);
+    SunPosition {
+        right_ascension_rad: equatorial.y.atan2(equatorial.x).rem_euclid(TAU),
+        declination_rad: equatorial.z.clamp(-1.0, 1.0).asin(),
+        distance_au: r,
+        equatorial,
+    }
+}
+
+/// Sun position in ECEF (km)
+pub fn sun_position_ecef_km(utc: DateTime<Utc>, dut1_seconds: f64) -> DVec3 {
+    let sun = sun_position(utc);
+    equatorial_of_date_to_ecef(sun.equatorial, utc, dut1_seconds) * (sun.distance_au * AU_KM)
+}
+
+/// Planets drawn in the sky
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Planet {
+    Mercury,
+    Venus,
+    Mars,
+    Jupiter,
+    Saturn,
+}
+
+impl Planet {
+    pub const ALL: [Planet; 5] = [
+        Planet::Mercury,
+        Planet::Venus,
+        Planet::Mars,
+        Planet::Jupiter,
+        Planet::Saturn,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Planet::Mercury => "Mercury",
+            Planet::Venus => "Venus",
+            Planet::Mars => "Mars",
+            Planet::Jupiter => "Jupiter",
+            Planet::Saturn => "Saturn",
+        }
+    }
+
+    fn elements(self) -> &'static KeplerElements {
+        match self {
+            Planet::Mercury => &MERCURY,
+            Planet::Venus => &VENUS,
+            Planet::Mars => &MARS,
+            Planet::Jupiter => &JUPITER,
+            Planet::Saturn => &SATURN,
+        }
+    }
+
+    /// Visual magnitude from distances (AU) and phase angle (deg), Meeus chapter 41
+    fn magnitude(self, sun_distance: f64, earth_distance: f64, phase_deg: f64) -> f64 {
+        let i = phase_deg;
+        let distance_term = 5.0 * (sun_distance * earth_distance).log10();
+        distance_term
+            + match self {
+                Planet::Mercury => -0.42 + 0.038_0 * i - 0.000_273 * i * i + 0.000_002 * i * i * i,
+                Planet::Venus => -4.40 + 0.000_9 * i + 0.000_239 * i * i - 0.000_000_65 * i * i * i,
+                Planet::Mars => -1.52 + 0.016 * i,
+                Planet::Jupiter => -9.40 + 0.005 * i,
+                Planet::Saturn => -8.88,
+            }
+    }
+}
+
+/// Keplerian elements at J2000 and their rates per Julian century (AU, deg)
+struct KeplerElements {
+    a: [f64; 2],
+    e: [f64; 2],
+    inclination: [f64; 2],
+    mean_longitude: [f64; 2],
+    perihelion_longitude: [f64; 2],
+    node_longitude: [f64; 2],
+}
+
+const MERCURY: KeplerElements = KeplerElements {
+    a: [0.387_099_27, 0.000_000_37],
+    e: [0.205_635_93, 0.000_019_06],
+    inclination: [7.004_979_02, -0.005_947_49],
+    mean_longitude: [252.250_323_50, 149_472.674_111_75],
+    perihelion_longitude: [77.457_796_28, 0.160_476_89],
+    node_longitude: [48.330_765_93, -0.125_340_81],
+};
+
+const VENUS: KeplerElements = KeplerElements {
+    a: [0.723_335_66, 0.000_003_90],
+    e: [0.006_776_72, -0.000_041_07],
+    inclination: [3.394_676_05, -0.000_788_90],
+    mean_longitude: [181.979_099_50, 58_517.815_387_29],
+    perihelion_longitude: [131.602_467_18, 0.002_683_29],
+    node_longitude: [76.679_842_55, -0.277_694_18],
+};
+
+const EARTH_MOON_BARYCENTER: KeplerElements = KeplerElements {
+    a: [1.000_002_61, 0.000_005_62],
+    e: [0.016_711_23, -0.000_043_92],
+    inclination: [-0.000_015_31, -0.012_946_68],
+    mean_longitude: [100.464_571_66, 35_999.372_449_81],
+    perihelion_longitude: [102.937_681_93, 0.323_273_64],
+    node_longitude: [0.0, 0.0],
+};
+
+const MARS: KeplerElements = KeplerElements {
+    a: [1.523_710_34, 0.000_018_47],
+    e: [0.093_394_10, 0.000_078_82],
+    inclination: [1.849_691_42, -0.008_131_31],
+    mean_longitude: [-4.553_432_05, 19_140.302_684_99],
+    perihelion_longitude: [-23.943_629_59, 0.444_410_88],
+    node_longitude: [49.559_538_91, -0.292_573_43],
+};
+
+const JUPITER: KeplerElements = KeplerElements {
+    a: [5.202_887_00, -0.000_116_07],
+    e: [0.048_386_24, -0.000_132_53],
+    inclination: [1.304_396_95, -0.001_837_14],
+    mean_longitude: [34.396_440_51, 3_034.746_127_75],
+    perihelion_longitude: [14.728_479_83, 0.212_526_68],
+    node_longitude: [100.473_909_09, 0.204_691_06],
+};
+
+const SATURN: KeplerElements = KeplerElements {
+    a: [9.536_675_94, -0.001_250_60],
+    e: [0.053_861_79, -0.000_509_91],
+    inclination: [2.485_991_87, 0.001_936_09],
+    mean_longitude: [49.954_244_23, 1_222.493_622_01],
+    perihelion_longitude: [92.598_878_31, -0.418_972_16],
+    node_longitude: [113.662_424_48, -0.288_677_94],
+};
+
+impl KeplerElements {
+    /// Heliocentric position (AU) in the J2000 ecliptic frame, `t` in Julian centuries (TT)
+    fn heliocentric_au(&self, t: f64) -> DVec3 {
+        let at = |pair: [f64; 2]| pair[0] + pair[1] * t;
+        let a = at(self.a);
+        let e = at(self.e);
+        let inclination = at(self.inclination).to_radians();
+        let node = at(self.node_longitude).to_radians();
+        let perihelion = at(self.perihelion_longitude).to_radians();
+        let mean_anomaly = (at(self.mean_longitude).to_radians() - perihelion).rem_euclid(TAU);
+        let arg_perihelion = perihelion - node;
+
+        let mut eccentric_anomaly = mean_anomaly + e * mean_anomaly.sin();
+        for _ in 0..10 {
+            let delta = (eccentric_anomaly - e * eccentric_anomaly.sin() - mean_anomaly)
+                / (1.0 - e * eccentric_anomaly.cos());
+            eccentric_anomaly -= delta;
+            if delta.abs() < 1.0e-12 {
+                break;
+            }
+        }
+        let x_orbit = a * (eccentric_anomaly.cos() - e);
+        let y_orbit = a * (1.0 - e * e).sqrt() * eccentric_anomaly.sin();
+
+        let (sw, cw) = arg_perihelion.sin_cos();
+        let (so, co) = node.sin_cos();
+        let (si, ci) = inclination.sin_cos();
+        DVec3::new(
+            (cw * co - sw * so * ci) * x_orbit + (-sw * co - cw * so * ci) * y_orbit,
+            (cw * so + sw * co * ci) * x_orbit + (-sw * so + cw * co * ci) * y_orbit,
+            (sw * si) * x_orbit + (cw * si) * y_orbit,
+        )
+    }
+}
+
+/// Apparent geocentric position of a planet
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlanetPosition {
+    pub planet: Planet,
+    /// Unit vector, true equator and equinox of date
+    pub equatorial: DVec3,
+    pub distance_au: f64,
+    pub magnitude: f64,
+}
+
+// Checked against published RA/Dec in the tests; the sky draws from `equatorial`
+#[cfg(test)]
+impl PlanetPosition {
+    pub fn right_ascension_rad(&self) -> f64 {
+        self.equatorial.y.atan2(self.equatorial.x).rem_euclid(TAU)
+    }
+
+    pub fn declination_rad(&self) -> f64 {
+        self.equatorial.z.clamp(-1.0, 1.0).asin()
+    }
+}
+
+/// Geocentric position of a planet, corrected for light time and referred to the equinox of date
+pub fn planet_position(planet: Planet, utc: DateTime<Utc>) -> PlanetPosition {
+    let t = (julian_ephemeris_date(utc) - 2_451_545.0) / 36_525.0;
+    let earth = EARTH_MOON_BARYCENTER.heliocentric_au(t);
+
+    // Iterate light time: see the planet where it was when the light left
+    let mut heliocentric = planet.elements().heliocentric_au(t);
+    let mut geocentric = heliocentric - earth;
+    for _ in 0..2 {
+        let light_time_days = geocentric.length() * LIGHT_TIME_DAYS_PER_AU;
+        heliocentric = planet
+            .elements()
+            .heliocentric_au(t - light_time_days / 36_525.0);
+        geocentric = heliocentric - earth;
+    }
+
+    // Precess the J2000 ecliptic longitude to the equinox of date, then apply nutation
+    let precession = (5_029.096_6 * t + 1.111_13 * t * t) * ARCSEC_TO_RAD;
+    let (dpsi, deps) = nutation(t);
+    let longitude = geocentric.y.atan2(geocentric.x) + precession + dpsi;
+    let latitude = (geocentric.z / geocentric.length()).asin();
+    let equatorial = ecliptic_to_equatorial(longitude, latitude, mean_obliquity(t) + deps);
+
+    let sun_distance = heliocentric.length();
+    let earth_distance = geocentric.length();
+    let phase_deg = (heliocentric.dot(geocentric) / (sun_distance * earth_distance))
+        .clamp(-1.0, 1.0)
+        .acos()
+        .to_degrees();
+    PlanetPosition {
+        planet,
+        equatorial,
+        distance_au: earth_distance,
+        magnitude: planet.magnitude(sun_distance, earth_distance, phase_deg),
+    }
+}
+
+/// Unit direction to a planet in ECEF
+pub fn planet_direction_ecef(
+    position: &PlanetPosition,
+    utc: DateTime<Utc>,
+    dut1_seconds: f64,
+) -> DVec3 {
+    equatorial_of_date_to_ecef(position.equatorial, utc, dut1_seconds).normalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orbital::timescale::scale_to_utc;
+    use chrono::TimeZone;
+
+    /// UTC instant for a TT calendar date, as used in the Meeus examples
+    fn tt(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+        scale_to_utc(
+            Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap().naive_utc(),
+            TimeScale::Tt,
+        )
+    }
+
+    #[test]
+    fn test_precession_matches_meeus_example_21b() {
+        // theta Persei, J2000 position with proper motion applied, to 2028 November 13.19 TD
+        let (ra0, dec0) = (41.054_063_f64.to_radians(), 49.227_750_f64.to_radians());
+        let j2000 = DVec3::new(dec0.cos() * ra0.cos(), dec0.cos() * ra0.sin(), dec0.sin());
+        let utc = tt(2028, 11, 13) + chrono::Duration::seconds((0.19 * 86_400.0) as i64);
+        let of_date = precess_from_j2000(j2000, utc);
+        let ra = of_date.y.atan2(of_date.x).to_degrees();
+        let dec = of_date.z.asin().to_degrees();
+        assert!((ra - 41.547_214).abs() < 1e-4, "ra {ra}");
+        assert!((dec - 49.348_483).abs() < 1e-4, "dec {dec}");
+        assert!((of_date.length() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sun_matches_meeus_example_25b() {
+        // 1992 October 13.0 TD: alpha = 13h13m30.749s, delta = -7d47m01.74s, R = 0.99760775 AU
+        let sun = sun_position(tt(1992, 10, 13));
+        let ra_deg = sun.right_ascension_rad.to_degrees();
+        let dec_deg = sun.declination_rad.to_degrees();
+        assert!((ra_deg - 198.378_12).abs() < 0.001, "RA {ra_deg}");
+        assert!((dec_deg + 7.783_82).abs() < 0.001, "Dec {dec_deg}");
+        assert!(
+            (sun.distance_au - 0.997_607_75).abs() < 1.0e-5,
+            "R {}",
+            sun.distance_au
+        );
+    }
+
+    #[test]
+    fn test_sun_distance_range_and_ecef_consistency() {
+        let perihelion = sun_position(tt(2024, 1, 3));
+        let aphelion = sun_position(tt(2024, 7, 5));
+        assert!((perihelion.distance_au - 0.983_3).abs() < 0.000_5);
+        assert!((aphelion.distance_au - 1.016_7).abs() < 0.000_5);
+
+        let utc = Utc.with_ymd_and_hms(2024, 3, 20, 3, 6, 0).unwrap();
+        let ecef = sun_position_ecef_km(utc, 0.0);
+        // Near the March equinox the Sun sits on the equator
+        assert!((ecef.z / ecef.length()).abs() < 0.001);
+        assert!((ecef.length() / AU_KM - 0.996).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_venus_matches_meeus_example_33a() {
+        // 1992 December 20.0 TD: alpha = 21h04m41.454s, delta = -18d53m16.84s, distance 0.910947 AU
+        let venus = planet_position(Planet::Venus, tt(1992, 12, 20));
+        let ra_deg = venus.right_ascension_rad().to_degrees();
+        let dec_deg = venus.declination_rad().to_degrees();
+        assert!((ra_deg - 316.172_73).abs() < 0.05, "RA {ra_deg}");
+        assert!((dec_deg + 18.888_01).abs() < 0.05, "Dec {dec_deg}");
+        assert!((venus.distance_au - 0.910_947).abs() < 0.001);
+        assert!(venus.magnitude < -3.5 && venus.magnitude > -5.0);
+    }
+
+    #[test]
+    fn test_planet_geometry_bounds() {
+        let utc = tt(2025, 6, 1);
+        let sun = sun_position(utc).equatorial;
+        for planet in Planet::ALL {
+            let position = planet_position(planet, utc);
+            let elongation = position.equatorial.angle_between(sun).to_degrees();
+            match planet {
+                Planet::Mercury => assert!(elongation < 28.5, "Mercury {elongation}"),
+                Planet::Venus => assert!(elongation < 47.5, "Venus {elongation}"),
+                Planet::Jupiter => assert!((3.9..6.5).contains(&position.distance_au)),
+                Planet::Saturn => assert!((8.0..11.1).contains(&position.distance_au)),
+                Planet::Mars => assert!((0.37..2.7).contains(&position.distance_au)),
+            }
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! Instantaneous launch opportunities into a target orbit plane
+//!
+//! A site lies in the plane when its sidereal angle is `asin(tan(lat) / tan(i))`
+//! past the node (northbound) or the supplement of that (southbound), so there
+//! are two chances a sidereal day while the site latitude is below the
+//! inclination. The plane node drifts with J2 when it comes from a TLE. The
+//! azimuth is the inertial one, without the Earth rotation correction.
+
+use chrono::{DateTime, Duration, Utc};
+use std::f64::consts::{PI, TAU};
+
+use crate::core::coordinates::gmst_rad_with_dut1;
+use crate::orbital::maneuver::j2_raan_rate_deg_per_day;
+use crate::tle::parser::MeanElements;
+
+/// Coarse step when scanning for crossings; the site turns ~2.5 deg in this time
+const SCAN_STEP_SECONDS: i64 = 600;
+/// Crossings are refined to this precision
+const REFINE_SECONDS: f64 = 0.5;
+
+/// Orbit plane to launch into
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TargetPlane {
+    pub inclination_deg: f64,
+    /// Right ascension of the ascending node at `raan_epoch_utc`
+    pub raan_deg: f64,
+    pub raan_epoch_utc: DateTime<Utc>,
+    /// Nodal drift (J2), zero for a plane entered by hand
+    pub raan_rate_deg_per_day: f64,
+}
+
+impl TargetPlane {
+    /// Plane of a satellite, drifting with J2 from its TLE epoch
+    pub fn from_elements(elements: &MeanElements) -> Self {
+        Self {
+            inclination_deg: elements.inclination_deg,
+            raan_deg: elements.raan_deg,
+            raan_epoch_utc: elements.epoch_utc,
+            raan_rate_deg_per_day: j2_raan_rate_deg_per_day(elements),
+        }
+    }
+
+    pub fn raan_rad_at(&self, utc: DateTime<Utc>) -> f64 {
+        let days = (utc - self.raan_epoch_utc).num_milliseconds() as f64 / 86_400_000.0;
+        (self.raan_deg + self.raan_rate_deg_per_day * days).to_radians()
+    }
+}
+
+/// Which way the vehicle crosses the site latitude
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaunchDirection {
+    Northbound,
+    Southbound,
+}
+
+impl LaunchDirection {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Northbound => "north",
+            Self::Southbound => "south",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LaunchOpportunity {
+    pub utc: DateTime<Utc>,
+    pub direction: LaunchDirection,
+    /// Degrees clockwise from north
+    pub azimuth_deg: f64,
+}
+
+/// Inertial launch azimuth into `inclination_deg` from `lat_deg`, None when out of reach
+///
+/// Solves `sin(az) = cos(i) / cos(lat)` for a direct ascent.
+pub fn launch_azimuth_deg(
+    lat_deg: f64,
+    inclination_deg: f64,
+    direction: LaunchDirection,
+) -> Option<f64> {
+    let ratio = inclination_deg.to_radians().cos() / lat_deg.to_radians().cos();
+    if !(-1.0..=1.0).contains(&ratio) {
+        return None;
+    }
+    let northbound = ratio.asin().to_degrees();
+    Some(match direction {
+        LaunchDirection::Northbound => northbound.rem_euclid(360.0),
+        LaunchDirection::Southbound => 180.0 - northbound,
+    })
+}
+
+/// Angle from the node to the site, along the equator, when the site is in the plane
+fn node_offset_rad(lat_deg: f64, inclination_deg: f64, direction: LaunchDirection) -> Option<f64> {
+    let ratio = lat_deg.to_radians().tan() / inclination_deg.to_radians().tan();
+    if !ratio.is_finite() || !(-1.0..=1.0).contains(&ratio) {
+        return None;
+    }
+    let northbound = ratio.asin();
+    Some(match direction {
+        LaunchDirection::Northbound => northbound,
+        LaunchDirection::Southbound => PI - northbound,
+    })
+}
+
+/// Wrap an angle into [-pi, pi)
+fn wrap_pi(angle: f64) -> f64 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+/// Launch opportunities from a site into `plane` over `days` from `start_utc`, in time order
+pub fn launch_opportunities(
+    lat_deg: f64,
+    lon_deg: f64,
+    plane: &TargetPlane,
+    start_utc: DateTime<Utc>,
+    days: f64,
+    dut1_seconds: f64,
+) -> Vec<LaunchOpportunity> {
+    // An equatorial plane has no node to wait for
+    if plane.inclination_deg.to_radians().sin().abs() < 1e-6 {
+        return Vec::new();
+    }
+    let end_utc = start_utc + Duration::seconds((days.max(0.0) * 86_400.0) as i64);
+    let step = Duration::seconds(SCAN_STEP_SECONDS);
+
+    let mut opportunities = Vec::new();
+    for direction in [LaunchDirection::Northbound, LaunchDirection::Southbound] {
+        let (Some(offset), Some(azimuth_deg)) = (
+            node_offset_rad(lat_deg, plane.inclination_deg, direction),
+            launch_azimuth_deg(lat_deg, plane.inclination_deg, direction),
+        ) else {
+            continue;
+        };
+        // Zero when the site is in the plane; rises through zero once a sidereal day
+        let phase = |utc: DateTime<Utc>| {
+            let sidereal = gmst_rad_with_dut1(utc, dut1_seconds) + lon_deg.to_radians();
+            wrap_pi(sidereal - plane.raan_rad_at(utc) - offset)
+        };
+
+        let mut t0 = start_utc;
+        let mut f0 = phase(t0);
+        while t0 < end_utc {
+            let t1 = (t0 + step).min(end_utc);
+            let f1 = phase(t1);
+            // Skip the wrap from +pi to -pi, which is not a crossing
+            if f0 < 0.0 && f1 >= 0.0 && f1 - f0 < PI {
+                let utc = refine_crossing(&phase, t0, t1);
+                opportunities.push(LaunchOpportunity {
+                    utc,
+                    direction,
+                    azimuth_deg,
+                });
+            }
+            t0 = t1;
+            f0 = f1;
+        }
+    }
+
+    opportunities.sort_by_key(|o| o.utc);
+    // A site at the inclination latitude gets both solutions at the same moment
+    opportunities.dedup_by(|later, earlier| (later.utc - earlier.utc).num_seconds().abs() < 60);
+    opportunities
+}
+
+/// Bisect the zero of `phase` between `lo` (negative) and `hi` (non-negative)
+fn refine_crossing(
+    phase: &impl Fn(DateTime<Utc>) -> f64,
+    mut lo: DateTime<Utc>,
+    mut hi: DateTime<Utc>,
+) -> DateTime<Utc> {
+    while (hi - lo).num_milliseconds() as f64 / 1000.0 > REFINE_SECONDS {
+        let mid = lo + (hi - lo) / 2;
+        if phase(mid) < 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    hi
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::math::DVec3;
+    use chrono::TimeZone;
+
+    fn plane(inclination_deg: f64, raan_deg: f64) -> TargetPlane {
+        TargetPlane {
+            inclination_deg,
+            raan_deg,
+            raan_epoch_utc: Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap(),
+            raan_rate_deg_per_day: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_azimuths() {
+        let north = launch_azimuth_deg(28.5, 51.6, LaunchDirection::Northbound).unwrap();
+        let south = launch_azimuth_deg(28.5, 51.6, LaunchDirection::Southbound).unwrap();
+        assert!((north - 44.98).abs() < 0.05, "{north}");
+        assert!((south - 135.02).abs() < 0.05, "{south}");
+        // Retrograde (sun-synchronous) heads slightly west of north or south
+        let sso = launch_azimuth_deg(34.7, 97.5, LaunchDirection::Southbound).unwrap();
+        assert!((sso - 189.1).abs() < 0.1, "{sso}");
+        assert_eq!(
+            launch_azimuth_deg(45.9, 28.5, LaunchDirection::Northbound),
+            None
+        );
+    }
+
+    #[test]
+    fn test_site_lies_in_plane_at_each_opportunity() {
+        let (lat, lon) = (28.5, -80.6);
+        let target = plane(51.6, 120.0);
+        let start = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let found = launch_opportunities(lat, lon, &target, start, 3.0, 0.0);
+        assert_eq!(found.len(), 6, "{found:?}");
+        assert!(found.windows(2).all(|w| w[0].utc < w[1].utc));
+
+        let normal = crate::orbital::beta::orbit_normal(
+            target.inclination_deg.to_radians(),
+            target.raan_deg.to_radians(),
+        );
+        for opportunity in &found {
+            let sidereal = gmst_rad_with_dut1(opportunity.utc, 0.0) + lon.to_radians();
+            let site = DVec3::new(
+                lat.to_radians().cos() * sidereal.cos(),
+                lat.to_radians().cos() * sidereal.sin(),
+                lat.to_radians().sin(),
+            );
+            // Within ~0.01 deg of the plane
+            assert!(normal.dot(site).abs() < 2e-4, "{opportunity:?}");
+        }
+
+        // Northbound and southbound alternate, each once a sidereal day
+        let northbound: Vec<_> = found
+            .iter()
+            .filter(|o| o.direction == LaunchDirection::Northbound)
+            .collect();
+        let gap = (northbound[1].utc - northbound[0].utc).num_seconds();
+        assert!((gap - 86_164).abs() < 5, "{gap}");
+    }
+
+    #[test]
+    fn test_no_opportunities_above_inclination() {
+        let start = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        // Baikonur can't reach the Cape's due-east inclination directly
+        assert!(launch_opportunities(45.9, 63.3, &plane(28.5, 0.0), start, 2.0, 0.0).is_empty());
+        assert!(launch_opportunities(0.0, 0.0, &plane(0.0, 0.0), start, 2.0, 0.0).is_empty());
+    }
+}
@@ -0,0 +1,239 @@
+//! Maneuver detection between successive element sets
+//!
+//! Compares two TLEs of the same object after removing the natural drift
+//! expected between their epochs (J2 nodal precession, drag decay from B*).
+//! Residual jumps in semi-major axis, inclination or RAAN beyond tolerance are
+//! reported as a likely maneuver with an impulsive delta-v estimate.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::orbital::decay::{MU_EARTH_KM3_S2, bstar_decay_km_per_day, semi_major_axis_km};
+use crate::tle::parser::MeanElements;
+
+/// Second zonal harmonic of the Earth's gravity field
+const J2: f64 = 1.082_626_68e-3;
+/// Equatorial radius used with J2 (km)
+const EQUATORIAL_RADIUS_KM: f64 = 6378.137;
+
+/// Semi-major axis residual always tolerated (TLE fit noise)
+const SMA_TOLERANCE_KM: f64 = 1.0;
+/// Fraction of the predicted drag decay treated as uncertain
+const DRAG_UNCERTAINTY: f64 = 0.5;
+const INCLINATION_TOLERANCE_DEG: f64 = 0.01;
+/// Luni-solar and other slow inclination drift allowed per day
+const INCLINATION_DRIFT_DEG_PER_DAY: f64 = 0.003;
+const RAAN_TOLERANCE_DEG: f64 = 0.05;
+/// RAAN rate model error allowed per day
+const RAAN_DRIFT_DEG_PER_DAY: f64 = 0.005;
+
+/// A likely maneuver between two element sets
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Maneuver {
+    /// Epoch of the element set before the burn
+    pub before_utc: DateTime<Utc>,
+    /// Epoch of the element set after the burn
+    pub after_utc: DateTime<Utc>,
+    /// Semi-major axis change not explained by drag (km)
+    pub delta_sma_km: f64,
+    pub delta_inclination_deg: f64,
+    /// RAAN change not explained by J2 precession (deg)
+    pub delta_raan_deg: f64,
+    /// Impulsive delta-v estimate (m/s)
+    pub delta_v_m_s: f64,
+    /// Semi-major axis jump detected
+    pub in_plane: bool,
+    /// Inclination or RAAN jump detected
+    pub plane_change: bool,
+}
+
+impl Maneuver {
+    /// Best guess for the burn time: halfway between the two epochs
+    pub fn midpoint_utc(&self) -> DateTime<Utc> {
+        self.before_utc + (self.after_utc - self.before_utc) / 2
+    }
+
+    pub fn label(&self) -> &'static str {
+        match (self.in_plane, self.plane_change, self.delta_sma_km > 0.0) {
+            (true, true, true) => "Raise + plane change",
+            (true, true, false) => "Lower + plane change",
+            (true, false, true) => "Orbit raise",
+            (true, false, false) => "Orbit lower",
+            (false, _, _) => "Plane change",
+        }
+    }
+}
+
+/// Add maneuvers to a history, skipping ones between epochs already listed,
+/// and keep it oldest first
+pub fn merge_maneuvers(history: &mut Vec<Maneuver>, more: impl IntoIterator<Item = Maneuver>) {
+    for maneuver in more {
+        let known = history
+            .iter()
+            .any(|m| m.before_utc == maneuver.before_utc && m.after_utc == maneuver.after_utc);
+        if !known {
+            history.push(maneuver);
+        }
+    }
+    history.sort_by_key(|m| m.after_utc);
+}
+
+/// J2 secular RAAN rate (deg/day) for the given mean elements
+pub fn j2_raan_rate_deg_per_day(elements: &MeanElements) -> f64 {
+    let a = semi_major_axis_km(elements.mean_motion_rev_per_day);
+    let p = a * (1.0 - elements.eccentricity * elements.eccentricity);
+    let n_rad_day = elements.mean_motion_rev_per_day * std::f64::consts::TAU;
+    let rate_rad_day = -1.5
+        * n_rad_day
+        * J2
+        * (EQUATORIAL_RADIUS_KM / p).powi(2)
+        * elements.inclination_deg.to_radians().cos();
+    rate_rad_day.to_degrees()
+}
+
+/// Wrap an angle difference into [-180, 180) degrees
+fn wrap_deg(angle: f64) -> f64 {
+    (angle + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Compare two element sets of one object and report a likely maneuver
+///
+/// Returns None when `next` is not newer than `previous` or every change is
+/// within the natural drift tolerance.
+pub fn detect_maneuver(previous: &MeanElements, next: &MeanElements) -> Option<Maneuver> {
+    let dt_days = (next.epoch_utc - previous.epoch_utc).num_seconds() as f64 / 86_400.0;
+    if dt_days <= 0.0 {
+        return None;
+    }
+
+    let a_prev = semi_major_axis_km(previous.mean_motion_rev_per_day);
+    let a_next = semi_major_axis_km(next.mean_motion_rev_per_day);
+    let expected_decay = bstar_decay_km_per_day(previous) * dt_days;
+    let delta_sma = (a_next - a_prev) + expected_decay;
+    let sma_tolerance = SMA_TOLERANCE_KM + DRAG_UNCERTAINTY * expected_decay;
+
+    let delta_inclination = next.inclination_deg - previous.inclination_deg;
+    let inclination_tolerance = INCLINATION_TOLERANCE_DEG + INCLINATION_DRIFT_DEG_PER_DAY * dt_days;
+
+    let predicted_raan = previous.raan_deg + j2_raan_rate_deg_per_day(previous) * dt_days;
+    let delta_raan = wrap_deg(next.raan_deg - predicted_raan);
+    let raan_tolerance = RAAN_TOLERANCE_DEG + RAAN_DRIFT_DEG_PER_DAY * dt_days;
+
+    let in_plane = delta_sma.abs() > sma_tolerance;
+    let plane_change =
+        delta_inclination.abs() > inclination_tolerance || delta_raan.abs() > raan_tolerance;
+    if !in_plane && !plane_change {
+        return None;
+    }
+
+    // Circular-orbit impulsive estimates at the mean radius
+    let a_mean = 0.5 * (a_prev + a_next);
+    let speed_m_s = (MU_EARTH_KM3_S2 / a_mean).sqrt() * 1.0e3;
+    let dv_in_plane = if in_plane {
+        speed_m_s * delta_sma.abs() / (2.0 * a_mean)
+    } else {
+        0.0
+    };
+    let dv_plane = if plane_change {
+        let (i1, i2) = (
+            previous.inclination_deg.to_radians(),
+            next.inclination_deg.to_radians(),
+        );
+        let cos_angle = i1.cos() * i2.cos() + i1.sin() * i2.sin() * delta_raan.to_radians().cos();
+        let angle = cos_angle.clamp(-1.0, 1.0).acos();
+        2.0 * speed_m_s * (0.5 * angle).sin()
+    } else {
+        0.0
+    };
+
+    Some(Maneuver {
+        before_utc: previous.epoch_utc,
+        after_utc: next.epoch_utc,
+        delta_sma_km: delta_sma,
+        delta_inclination_deg: delta_inclination,
+        delta_raan_deg: delta_raan,
+        delta_v_m_s: dv_in_plane.hypot(dv_plane),
+        in_plane,
+        plane_change,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::space::EARTH_RADIUS_KM_F64;
+    use chrono::{Duration, TimeZone};
+
+    fn leo(epoch: DateTime<Utc>, altitude_km: f64, inclination: f64, raan: f64) -> MeanElements {
+        let a = EARTH_RADIUS_KM_F64 + altitude_km;
+        MeanElements {
+            epoch_utc: epoch,
+            inclination_deg: inclination,
+            raan_deg: raan,
+            eccentricity: 0.0005,
+            arg_perigee_deg: 0.0,
+            mean_anomaly_deg: 0.0,
+            mean_motion_rev_per_day: (MU_EARTH_KM3_S2 / a.powi(3)).sqrt() * 86_400.0
+                / std::f64::consts::TAU,
+            bstar: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_natural_precession_is_not_a_maneuver() {
+        let epoch = Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+        let before = leo(epoch, 420.0, 51.6, 100.0);
+        // ISS-like orbits regress about 5 degrees per day
+        let rate = j2_raan_rate_deg_per_day(&before);
+        assert!((-5.5..-4.5).contains(&rate), "rate {rate}");
+        let after = leo(epoch + Duration::days(2), 420.2, 51.6, 100.0 + 2.0 * rate);
+        assert!(detect_maneuver(&before, &after).is_none());
+        // Older element sets are never compared backwards
+        assert!(detect_maneuver(&after, &before).is_none());
+    }
+
+    #[test]
+    fn test_orbit_raise_detected_with_delta_v() {
+        let epoch = Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+        let before = leo(epoch, 400.0, 51.6, 100.0);
+        let rate = j2_raan_rate_deg_per_day(&before);
+        let after = leo(epoch + Duration::days(1), 405.0, 51.6, 100.0 + rate);
+        let maneuver = detect_maneuver(&before, &after).expect("raise detected");
+        assert!(maneuver.in_plane && !maneuver.plane_change);
+        assert_eq!(maneuver.label(), "Orbit raise");
+        // Hohmann-like estimate: v * da / 2a ~= 2.8 m/s for 5 km in LEO
+        assert!((maneuver.delta_v_m_s - 2.8).abs() < 0.1, "{maneuver:?}");
+    }
+
+    #[test]
+    fn test_inclination_change_detected() {
+        let epoch = Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+        let before = leo(epoch, 550.0, 53.0, 10.0);
+        let rate = j2_raan_rate_deg_per_day(&before);
+        let after = leo(epoch + Duration::days(1), 550.0, 53.1, 10.0 + rate);
+        let maneuver = detect_maneuver(&before, &after).expect("plane change detected");
+        assert!(maneuver.plane_change && !maneuver.in_plane);
+        // 0.1 deg at ~7.6 km/s is roughly 13 m/s
+        assert!((maneuver.delta_v_m_s - 13.2).abs() < 0.5, "{maneuver:?}");
+    }
+
+    #[test]
+    fn test_merge_maneuvers_skips_known_epochs() {
+        let epoch = Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+        let raise = |day: i64, altitude_km: f64| {
+            let before = leo(epoch + Duration::days(day), 400.0, 51.6, 100.0);
+            let rate = j2_raan_rate_deg_per_day(&before);
+            let after = leo(
+                epoch + Duration::days(day + 1),
+                altitude_km,
+                51.6,
+                100.0 + rate,
+            );
+            detect_maneuver(&before, &after).expect("raise detected")
+        };
+        let mut history = vec![raise(5, 405.0)];
+        merge_maneuvers(&mut history, [raise(1, 410.0), raise(5, 405.0)]);
+        assert_eq!(history.len(), 2);
+        assert!(history[0].after_utc < history[1].after_utc);
+    }
+}
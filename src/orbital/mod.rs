@@ -5,15 +5,27 @@
 
 use bevy::prelude::*;
 
+pub mod beta;
+pub mod decay;
+pub mod doppler;
+pub mod ephemeris;
+pub mod launch_window;
+pub mod maneuver;
 pub mod moon;
 pub mod propagation;
 pub mod time;
+pub mod timescale;
 
 pub use crate::core::coordinates::{eci_to_ecef_km, gmst_rad_with_dut1};
-use crate::core::space::ecef_to_bevy_km;
+use crate::core::space::{RenderFrame, ecef_to_bevy_km};
 pub use moon::{MoonEcefKm, moon_position_ecef_km};
-pub use propagation::minutes_since_epoch;
-pub use time::{Dut1, SimulationTime, advance_simulation_clock, sun_direction_from_utc};
+pub use propagation::{
+    PropagationErrorKind, constants_from_tle, is_decayed_position, minutes_since_epoch,
+    propagate_ecef_km, propagate_ecef_state_km,
+};
+pub use time::{
+    DisplayTimeScale, Dut1, SimulationTime, advance_simulation_clock, sun_direction_from_utc,
+};
 
 /// Sun direction in Bevy world coordinates
 #[derive(Resource, Deref, DerefMut)]
@@ -41,7 +53,9 @@ impl Plugin for OrbitalPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SimulationTime>()
             .init_resource::<Dut1>()
+            .init_resource::<DisplayTimeScale>()
             .init_resource::<SunDirection>()
+            .init_resource::<RenderFrame>()
             .init_resource::<MoonEcefKm>()
             .add_systems(Update, advance_simulation_clock)
             .add_systems(
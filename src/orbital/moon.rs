@@ -4,7 +4,8 @@ use bevy::math::DVec3;
 use bevy::prelude::*;
 use chrono::{DateTime, Utc};
 
-use crate::core::coordinates::{eci_to_ecef_km, gmst_rad_with_dut1, julian_date_utc};
+use crate::core::coordinates::{eci_to_ecef_km, gmst_rad_with_dut1};
+use crate::orbital::timescale::{TimeScale, julian_date};
 use crate::orbital::{Dut1, SimulationTime};
 
 /// Canonical Moon position in ECEF (km).
@@ -352,7 +353,8 @@ fn normalize_deg(deg: f64) -> f64 {
 
 /// Approximate Moon position in ECEF (km) using low-precision Meeus terms.
 pub fn moon_position_ecef_km(utc: DateTime<Utc>, dut1_seconds: f64) -> DVec3 {
-    let jd = julian_date_utc(utc);
+    // Meeus' lunar series run on dynamical time
+    let jd = julian_date(utc, TimeScale::Tt);
     let t = (jd - 2451545.0) / 36525.0;
 
     let l_prime = normalize_deg(
@@ -1,18 +1,135 @@
 //! Orbital propagation utilities
 
+use bevy::math::DVec3;
 use chrono::{DateTime, Utc};
 
+use crate::core::coordinates::{eci_to_ecef_km, eci_velocity_to_ecef_km_s, gmst_rad_with_dut1};
+use crate::core::space::EARTH_RADIUS_KM_F64;
+
+/// Why a satellite cannot be propagated
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PropagationErrorKind {
+    /// TLE lines could not be parsed
+    InvalidTle,
+    /// No elements were received for the satellite
+    FetchFailed,
+    /// Mean eccentricity left [0, 1)
+    EccentricityOutOfRange,
+    /// Perturbed eccentricity left [0, 1)
+    PerturbedEccentricityOutOfRange,
+    /// Mean motion went negative, so the semi-major axis is not positive
+    NegativeSemiMajorAxis,
+    /// Semi-latus rectum went negative
+    NegativeSemiLatusRectum,
+    /// Propagated position is below the Earth's surface
+    Decayed,
+}
+
+impl PropagationErrorKind {
+    /// Classify an SGP4 propagation error
+    pub fn from_sgp4(error: &sgp4::Error) -> Self {
+        match error {
+            sgp4::Error::OutOfRangeEccentricity { .. } => Self::EccentricityOutOfRange,
+            sgp4::Error::OutOfRangePerturbedEccentricity { .. } => {
+                Self::PerturbedEccentricityOutOfRange
+            }
+            sgp4::Error::NegativeSemiLatusRectum { .. } => Self::NegativeSemiLatusRectum,
+        }
+    }
+
+    /// Classify an SGP4 initialization error (building constants from elements)
+    pub fn from_sgp4_elements(error: &sgp4::ElementsError) -> Self {
+        match error {
+            sgp4::ElementsError::KozaiElementsError(_) => Self::NegativeSemiMajorAxis,
+            sgp4::ElementsError::OutOfRangeEpochEccentricity(_) => Self::EccentricityOutOfRange,
+        }
+    }
+
+    /// Short label for status badges
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::InvalidTle => "Bad TLE",
+            Self::FetchFailed => "No TLE",
+            Self::EccentricityOutOfRange | Self::PerturbedEccentricityOutOfRange => "Ecc err",
+            Self::NegativeSemiMajorAxis => "Neg SMA",
+            Self::NegativeSemiLatusRectum => "Neg SLR",
+            Self::Decayed => "Decayed",
+        }
+    }
+}
+
+/// Whether a propagated ECEF position (km) lies inside the Earth, i.e. the orbit decayed
+pub fn is_decayed_position(ecef_km: DVec3) -> bool {
+    ecef_km.length() < EARTH_RADIUS_KM_F64
+}
+
 /// Calculate minutes since epoch for SGP4 propagation
 pub fn minutes_since_epoch(sim_utc: DateTime<Utc>, epoch: DateTime<Utc>) -> f64 {
     let delta = sim_utc - epoch;
     delta.num_seconds() as f64 / 60.0 + f64::from(delta.subsec_nanos()) / 60.0 / 1.0e9
 }
 
+/// Build SGP4 constants from a TLE line pair
+pub fn constants_from_tle(line1: &str, line2: &str) -> Result<sgp4::Constants, String> {
+    sgp4::Elements::from_tle(None, line1.as_bytes(), line2.as_bytes())
+        .map_err(|e| e.to_string())
+        .and_then(|elements| sgp4::Constants::from_elements(&elements).map_err(|e| e.to_string()))
+}
+
+/// Propagate to a UTC instant and rotate the TEME position into ECEF km
+pub fn propagate_ecef_km(
+    constants: &sgp4::Constants,
+    epoch: DateTime<Utc>,
+    utc: DateTime<Utc>,
+    dut1_seconds: f64,
+) -> Option<DVec3> {
+    let mins = minutes_since_epoch(utc, epoch);
+    let state = constants.propagate(sgp4::MinutesSinceEpoch(mins)).ok()?;
+    let eci = DVec3::from_array(state.position);
+    Some(eci_to_ecef_km(eci, gmst_rad_with_dut1(utc, dut1_seconds)))
+}
+
+/// Propagate to a UTC instant and return the ECEF position (km) and velocity (km/s)
+pub fn propagate_ecef_state_km(
+    constants: &sgp4::Constants,
+    epoch: DateTime<Utc>,
+    utc: DateTime<Utc>,
+    dut1_seconds: f64,
+) -> Option<(DVec3, DVec3)> {
+    let mins = minutes_since_epoch(utc, epoch);
+    let state = constants.propagate(sgp4::MinutesSinceEpoch(mins)).ok()?;
+    let gmst = gmst_rad_with_dut1(utc, dut1_seconds);
+    let position = eci_to_ecef_km(DVec3::from_array(state.position), gmst);
+    let velocity = eci_velocity_to_ecef_km_s(DVec3::from_array(state.velocity), position, gmst);
+    Some((position, velocity))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::TimeZone;
 
+    #[test]
+    fn test_decayed_position_inside_earth() {
+        assert!(is_decayed_position(DVec3::new(6000.0, 0.0, 0.0)));
+        assert!(!is_decayed_position(DVec3::new(0.0, 6778.0, 0.0)));
+    }
+
+    #[test]
+    fn test_sgp4_error_classification() {
+        let kind = PropagationErrorKind::from_sgp4(&sgp4::Error::OutOfRangeEccentricity {
+            eccentricity: 1.2,
+            t: 10.0,
+        });
+        assert_eq!(kind, PropagationErrorKind::EccentricityOutOfRange);
+        assert_eq!(
+            PropagationErrorKind::from_sgp4_elements(&sgp4::ElementsError::KozaiElementsError(
+                sgp4::KozaiElementsError::NegativeKozaiMeanMotion
+            )),
+            PropagationErrorKind::NegativeSemiMajorAxis
+        );
+    }
+
     #[test]
     fn test_minutes_since_epoch() {
         let epoch = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
@@ -2,9 +2,10 @@
 
 use bevy::math::DVec3;
 use bevy::prelude::*;
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
 
-use crate::core::coordinates::{eci_to_ecef_km, gmst_rad_with_dut1, julian_date_utc};
+use crate::orbital::ephemeris::sun_position_ecef_km;
+use crate::orbital::timescale::{TimeScale, scale_to_utc};
 
 #[cfg(test)]
 use chrono::{Datelike, TimeZone, Timelike};
@@ -13,7 +14,12 @@ use chrono::{Datelike, TimeZone, Timelike};
 #[derive(Resource)]
 pub struct SimulationTime {
     pub current_utc: DateTime<Utc>,
+    /// Simulated seconds per real second; negative runs the clock backwards
     pub time_scale: f32,
+    /// Holds the clock without losing the chosen speed
+    pub paused: bool,
+    /// Interval for single steps forward and back
+    pub step_seconds: f32,
 }
 
 impl Default for SimulationTime {
@@ -21,21 +27,63 @@ impl Default for SimulationTime {
         Self {
             current_utc: Utc::now(),
             time_scale: 1.0,
+            paused: false,
+            step_seconds: 60.0,
+        }
+    }
+}
+
+impl SimulationTime {
+    /// Advance by `real_seconds` of wall-clock time at the current scale
+    pub fn advance_by(&mut self, real_seconds: f32) {
+        if self.paused {
+            return;
+        }
+        self.shift_seconds(real_seconds * self.time_scale);
+    }
+
+    /// Move one step interval forward or back, whether or not the clock is paused
+    pub fn step(&mut self, forward: bool) {
+        let sign = if forward { 1.0 } else { -1.0 };
+        self.shift_seconds(sign * self.step_seconds);
+    }
+
+    fn shift_seconds(&mut self, seconds: f32) {
+        let whole = seconds.trunc() as i64;
+        let nanos = ((seconds - seconds.trunc()) * 1_000_000_000.0) as i64;
+        if whole != 0 {
+            self.current_utc += Duration::seconds(whole);
+        }
+        if nanos != 0 {
+            self.current_utc += Duration::nanoseconds(nanos);
         }
     }
 }
 
 /// System to advance simulation UTC by scale
 pub fn advance_simulation_clock(time: Res<Time>, mut sim_time: ResMut<SimulationTime>) {
-    let scaled = (time.delta_secs() * sim_time.time_scale).max(0.0);
-    let whole = scaled.trunc() as i64;
-    let nanos = ((scaled - scaled.trunc()) * 1_000_000_000.0) as i64;
-    if whole != 0 {
-        sim_time.current_utc += Duration::seconds(whole);
-    }
-    if nanos != 0 {
-        sim_time.current_utc += Duration::nanoseconds(nanos);
+    sim_time.advance_by(time.delta_secs());
+}
+
+/// Parse a jump-to date typed as `YYYY-MM-DD`, `YYYY-MM-DD HH:MM[:SS]` or RFC 3339
+///
+/// Readings without an offset are taken in `scale`, so a TT or GPS epoch can be typed directly.
+pub fn parse_jump_time(input: &str, scale: TimeScale) -> Option<DateTime<Utc>> {
+    let input = input.trim();
+    if let Ok(t) = DateTime::parse_from_rfc3339(input) {
+        // An explicit offset already pins the instant, whatever scale the clock shows
+        return Some(t.with_timezone(&Utc));
     }
+    let normalized = input.replace('T', " ");
+    let reading = ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%d %H:%M"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(&normalized, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(&normalized, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })?;
+    Some(scale_to_utc(reading, scale))
 }
 
 /// Resource for UT1-UTC (DUT1) seconds used in GMST computation.
@@ -49,34 +97,14 @@ impl Default for Dut1 {
     }
 }
 
-/// Approximate sun direction in ECEF coordinates for a given UTC time.
+/// Time scale the clock is shown in; the simulation itself always runs in UTC
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct DisplayTimeScale(pub TimeScale);
+
+/// Sun direction in ECEF coordinates for a given UTC time.
 /// Returns a unit vector pointing from Earth to the Sun (ECEF).
 pub fn sun_direction_from_utc(utc: DateTime<Utc>, dut1_seconds: f64) -> DVec3 {
-    let jd = julian_date_utc(utc);
-    let days = jd - 2451545.0; // Days since J2000.0
-
-    let mean_long_deg = 280.460 + 0.9856474 * days;
-    let mean_anom_deg = 357.528 + 0.9856003 * days;
-
-    let mean_long = mean_long_deg.to_radians();
-    let mean_anom = mean_anom_deg.to_radians();
-
-    let ecliptic_long = mean_long
-        + (1.915_f64.to_radians() * mean_anom.sin())
-        + (0.020_f64.to_radians() * (2.0 * mean_anom).sin());
-
-    let obliquity = (23.439 - 0.0000004 * days).to_radians();
-
-    let eci = DVec3::new(
-        ecliptic_long.cos(),
-        obliquity.cos() * ecliptic_long.sin(),
-        obliquity.sin() * ecliptic_long.sin(),
-    );
-
-    let gmst = gmst_rad_with_dut1(utc, dut1_seconds);
-    let ecef = eci_to_ecef_km(eci, gmst);
-
-    ecef.normalize()
+    sun_position_ecef_km(utc, dut1_seconds).normalize()
 }
 
 #[cfg(test)]
@@ -85,15 +113,7 @@ mod tests {
 
     // Helper function for testing time advancement without Bevy resources
     fn test_advance_time(sim_time: &mut SimulationTime, delta_seconds: f32) {
-        let scaled = (delta_seconds * sim_time.time_scale).max(0.0);
-        let whole = scaled.trunc() as i64;
-        let nanos = ((scaled - scaled.trunc()) * 1_000_000_000.0) as i64;
-        if whole != 0 {
-            sim_time.current_utc += Duration::seconds(whole);
-        }
-        if nanos != 0 {
-            sim_time.current_utc += Duration::nanoseconds(nanos);
-        }
+        sim_time.advance_by(delta_seconds);
     }
 
     #[test]
@@ -111,6 +131,7 @@ mod tests {
         let mut sim_time = SimulationTime {
             current_utc: Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap(),
             time_scale: 1.0,
+            ..Default::default()
         };
 
         // Simulate advancing by 2 seconds (crossing into new year)
@@ -131,6 +152,7 @@ mod tests {
         let mut sim_time = SimulationTime {
             current_utc: Utc.with_ymd_and_hms(2000, 2, 28, 23, 59, 58).unwrap(),
             time_scale: 1.0,
+            ..Default::default()
         };
 
         // Advance by 2 seconds to cross into leap day
@@ -159,6 +181,7 @@ mod tests {
         let mut sim_time = SimulationTime {
             current_utc: Utc.with_ymd_and_hms(1900, 2, 28, 12, 0, 0).unwrap(),
             time_scale: 1.0,
+            ..Default::default()
         };
 
         // Advance by 12 hours to cross into March (skipping Feb 29)
@@ -176,6 +199,7 @@ mod tests {
         let mut sim_time = SimulationTime {
             current_utc: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             time_scale: 3600.0, // 1 real second = 1 simulated hour
+            ..Default::default()
         };
 
         let original_time = sim_time.current_utc;
@@ -202,6 +226,7 @@ mod tests {
         let mut sim_time = SimulationTime {
             current_utc: Utc.with_ymd_and_hms(2024, 6, 15, 12, 30, 45).unwrap(),
             time_scale: 1.0,
+            ..Default::default()
         };
 
         let original_time = sim_time.current_utc;
@@ -226,19 +251,72 @@ mod tests {
 
     #[test]
     fn test_advance_simulation_clock_negative_time_scale() {
-        // Test that negative time scales are handled (clamped to 0)
+        // Negative time scales rewind the clock
         let mut sim_time = SimulationTime {
             current_utc: Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap(),
-            time_scale: -1.0, // Negative time scale
+            time_scale: -60.0,
+            ..Default::default()
         };
 
         let original_time = sim_time.current_utc;
 
-        // Try to advance by 1 second with negative scale
-        test_advance_time(&mut sim_time, 1.0);
+        test_advance_time(&mut sim_time, 1.5);
+
+        assert_eq!(sim_time.current_utc - original_time, Duration::seconds(-90));
+    }
+
+    #[test]
+    fn test_pause_and_step() {
+        let mut sim_time = SimulationTime {
+            current_utc: Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap(),
+            time_scale: 100.0,
+            paused: true,
+            step_seconds: 30.0,
+        };
+        let original_time = sim_time.current_utc;
 
-        // Time should not change (negative scale clamped to 0)
+        // Paused keeps the scale but holds the clock
+        test_advance_time(&mut sim_time, 1.0);
         assert_eq!(sim_time.current_utc, original_time);
+        assert_eq!(sim_time.time_scale, 100.0);
+
+        // Steps still move a paused clock
+        sim_time.step(false);
+        sim_time.step(false);
+        sim_time.step(true);
+        assert_eq!(sim_time.current_utc - original_time, Duration::seconds(-30));
+    }
+
+    #[test]
+    fn test_parse_jump_time() {
+        let noon = Utc.with_ymd_and_hms(2030, 3, 1, 12, 0, 0).unwrap();
+        assert_eq!(
+            parse_jump_time("2030-03-01 12:00", TimeScale::Utc),
+            Some(noon)
+        );
+        assert_eq!(
+            parse_jump_time("2030-03-01T12:00:00Z", TimeScale::Utc),
+            Some(noon)
+        );
+        assert_eq!(
+            parse_jump_time("2030-03-01", TimeScale::Utc),
+            Some(noon - Duration::hours(12))
+        );
+        // A GPS reading is 18 s ahead of UTC
+        assert_eq!(
+            parse_jump_time("2030-03-01 12:00:18", TimeScale::Gps),
+            Some(noon)
+        );
+        // ...but a timestamp with an offset is read as written
+        assert_eq!(
+            parse_jump_time("2030-03-01T12:00:00Z", TimeScale::Gps),
+            Some(noon)
+        );
+        assert_eq!(
+            parse_jump_time("2030-03-01T14:00:00+02:00", TimeScale::Tt),
+            Some(noon)
+        );
+        assert_eq!(parse_jump_time("next tuesday", TimeScale::Utc), None);
     }
 
     #[test]
@@ -247,6 +325,7 @@ mod tests {
         let mut sim_time = SimulationTime {
             current_utc: Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap(),
             time_scale: 0.0,
+            ..Default::default()
         };
 
         let original_time = sim_time.current_utc;
@@ -264,6 +343,7 @@ mod tests {
         let mut sim_time = SimulationTime {
             current_utc: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             time_scale: 365.25 * 24.0 * 3600.0, // 1 real second = 1 simulated year
+            ..Default::default()
         };
 
         let original_time = sim_time.current_utc;
@@ -351,6 +431,7 @@ mod tests {
             let mut sim_time = SimulationTime {
                 current_utc: start_time,
                 time_scale: 1.0,
+                ..Default::default()
             };
 
             test_advance_time(&mut sim_time, advance_sec as f32);
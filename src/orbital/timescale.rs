@@ -0,0 +1,264 @@
+//! Astronomical time scales
+//!
+//! The simulation clock runs in UTC. TAI, TT, GPS and TDB are derived from it
+//! with the leap-second table below; UTC Julian dates treat every day as 86400 s,
+//! so elapsed time across a leap second is taken from TAI instead.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+
+use crate::core::coordinates::julian_date_utc;
+
+/// TT - TAI in seconds
+pub const TT_MINUS_TAI_S: f64 = 32.184;
+/// TAI - GPS in seconds (GPS time was aligned with UTC in January 1980)
+pub const TAI_MINUS_GPS_S: f64 = 19.0;
+
+/// Leap-second table: UTC date from which TAI - UTC takes the given value.
+///
+/// Add a row when IERS Bulletin C announces a new leap second.
+pub const LEAP_SECONDS: [(i32, u32, i32); 28] = [
+    (1972, 1, 10),
+    (1972, 7, 11),
+    (1973, 1, 12),
+    (1974, 1, 13),
+    (1975, 1, 14),
+    (1976, 1, 15),
+    (1977, 1, 16),
+    (1978, 1, 17),
+    (1979, 1, 18),
+    (1980, 1, 19),
+    (1981, 7, 20),
+    (1982, 7, 21),
+    (1983, 7, 22),
+    (1985, 7, 23),
+    (1988, 1, 24),
+    (1990, 1, 25),
+    (1991, 1, 26),
+    (1992, 7, 27),
+    (1993, 7, 28),
+    (1994, 7, 29),
+    (1996, 1, 30),
+    (1997, 7, 31),
+    (1999, 1, 32),
+    (2006, 1, 33),
+    (2009, 1, 34),
+    (2012, 7, 35),
+    (2015, 7, 36),
+    (2017, 1, 37),
+];
+
+/// A time scale the clock can be read in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeScale {
+    #[default]
+    Utc,
+    Tai,
+    Tt,
+    Gps,
+    Tdb,
+}
+
+impl TimeScale {
+    pub const ALL: [TimeScale; 5] = [
+        TimeScale::Utc,
+        TimeScale::Tai,
+        TimeScale::Tt,
+        TimeScale::Gps,
+        TimeScale::Tdb,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TimeScale::Utc => "UTC",
+            TimeScale::Tai => "TAI",
+            TimeScale::Tt => "TT",
+            TimeScale::Gps => "GPS",
+            TimeScale::Tdb => "TDB",
+        }
+    }
+
+    /// Next scale in display order, wrapping around
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|s| *s == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Parse a scale name such as `tt` or `GPS`
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|scale| scale.label().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// TAI - UTC in seconds at a UTC instant (10 s before 1972)
+pub fn tai_minus_utc_s(utc: DateTime<Utc>) -> f64 {
+    let date = utc.date_naive();
+    LEAP_SECONDS
+        .iter()
+        .rev()
+        .find(|(year, month, _)| {
+            NaiveDate::from_ymd_opt(*year, *month, 1).is_some_and(|start| date >= start)
+        })
+        .map_or(10.0, |(_, _, seconds)| f64::from(*seconds))
+}
+
+/// TDB - TT in seconds: the periodic relativistic term, under 2 ms
+pub fn tdb_minus_tt_s(jd_tt: f64) -> f64 {
+    let g = (357.53 + 0.985_600_28 * (jd_tt - 2_451_545.0)).to_radians();
+    0.001_657 * g.sin() + 0.000_014 * (2.0 * g).sin()
+}
+
+/// Seconds to add to a UTC reading to get the same instant in `scale`
+pub fn offset_from_utc_s(utc: DateTime<Utc>, scale: TimeScale) -> f64 {
+    let tai = tai_minus_utc_s(utc);
+    match scale {
+        TimeScale::Utc => 0.0,
+        TimeScale::Tai => tai,
+        TimeScale::Tt => tai + TT_MINUS_TAI_S,
+        TimeScale::Gps => tai - TAI_MINUS_GPS_S,
+        TimeScale::Tdb => {
+            let tt = tai + TT_MINUS_TAI_S;
+            tt + tdb_minus_tt_s(julian_date_utc(utc) + tt / 86_400.0)
+        }
+    }
+}
+
+fn seconds(s: f64) -> Duration {
+    Duration::nanoseconds((s * 1.0e9).round() as i64)
+}
+
+/// Calendar reading of a UTC instant in another time scale
+pub fn utc_to_scale(utc: DateTime<Utc>, scale: TimeScale) -> NaiveDateTime {
+    (utc + seconds(offset_from_utc_s(utc, scale))).naive_utc()
+}
+
+/// UTC instant for a calendar reading in `scale`, e.g. an ephemeris epoch in TDB
+pub fn scale_to_utc(reading: NaiveDateTime, scale: TimeScale) -> DateTime<Utc> {
+    let as_utc = reading.and_utc();
+    let mut utc = as_utc;
+    // The offset depends on the UTC date, so settle it across leap-second boundaries
+    for _ in 0..3 {
+        utc = as_utc - seconds(offset_from_utc_s(utc, scale));
+    }
+    utc
+}
+
+/// Julian date of a UTC instant in `scale` (`Tt` gives the Julian Ephemeris Date)
+pub fn julian_date(utc: DateTime<Utc>, scale: TimeScale) -> f64 {
+    julian_date_utc(utc) + offset_from_utc_s(utc, scale) / 86_400.0
+}
+
+/// SI seconds elapsed between two UTC instants, counting leap seconds
+pub fn elapsed_seconds(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    let utc_seconds = (to - from).num_nanoseconds().map_or_else(
+        || (to - from).num_milliseconds() as f64 / 1.0e3,
+        |ns| ns as f64 / 1.0e9,
+    );
+    utc_seconds + tai_minus_utc_s(to) - tai_minus_utc_s(from)
+}
+
+fn gps_epoch() -> DateTime<Utc> {
+    NaiveDate::from_ymd_opt(1980, 1, 6)
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|t| t.and_utc())
+        .unwrap_or_default()
+}
+
+/// GPS week number (not rolled over) and seconds of week, as used by GNSS almanacs
+pub fn gps_week_and_seconds(utc: DateTime<Utc>) -> (i64, f64) {
+    let gps_seconds = elapsed_seconds(gps_epoch(), utc);
+    let week = (gps_seconds / 604_800.0).floor();
+    (week as i64, gps_seconds - week * 604_800.0)
+}
+
+/// UTC instant for a GPS week (not rolled over) and seconds of week
+#[cfg(test)]
+pub fn utc_from_gps_week(week: i64, seconds_of_week: f64) -> DateTime<Utc> {
+    let reading = gps_epoch() + seconds(week as f64 * 604_800.0 + seconds_of_week);
+    scale_to_utc(reading.naive_utc(), TimeScale::Gps)
+}
+
+/// Format a UTC instant as a reading in `scale`
+pub fn format_in_scale(utc: DateTime<Utc>, scale: TimeScale, format: &str) -> String {
+    utc_to_scale(utc, scale).format(format).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_leap_second_table_lookup() {
+        let at = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+        assert_eq!(tai_minus_utc_s(at(1970, 1, 1)), 10.0);
+        assert_eq!(tai_minus_utc_s(at(1980, 1, 6)), 19.0);
+        assert_eq!(tai_minus_utc_s(at(2016, 12, 31)), 36.0);
+        assert_eq!(tai_minus_utc_s(at(2017, 1, 1)), 37.0);
+        assert_eq!(tai_minus_utc_s(at(2026, 6, 1)), 37.0);
+        // Rows are in date order with one second per step
+        for pair in LEAP_SECONDS.windows(2) {
+            assert!((pair[0].0, pair[0].1) < (pair[1].0, pair[1].1));
+            assert_eq!(pair[1].2 - pair[0].2, 1);
+        }
+    }
+
+    #[test]
+    fn test_scale_offsets() {
+        let utc = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(offset_from_utc_s(utc, TimeScale::Tai), 37.0);
+        assert!((offset_from_utc_s(utc, TimeScale::Tt) - 69.184).abs() < 1e-12);
+        assert_eq!(offset_from_utc_s(utc, TimeScale::Gps), 18.0);
+        let tdb = offset_from_utc_s(utc, TimeScale::Tdb) - 69.184;
+        assert!(tdb.abs() < 0.0017 && tdb != 0.0);
+        assert_eq!(
+            format_in_scale(utc, TimeScale::Tt, "%H:%M:%S%.3f"),
+            "00:01:09.184"
+        );
+    }
+
+    #[test]
+    fn test_round_trip_across_leap_second() {
+        let utc = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 50).unwrap();
+        for scale in TimeScale::ALL {
+            for step in 0..30 {
+                let t = utc + Duration::seconds(step);
+                assert_eq!(scale_to_utc(utc_to_scale(t, scale), scale), t, "{scale:?}");
+            }
+        }
+        // Ten UTC seconds across the 2016 leap second are eleven SI seconds
+        let after = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(elapsed_seconds(utc, after), 11.0);
+        assert_eq!(TimeScale::parse("tdb"), Some(TimeScale::Tdb));
+        assert_eq!(TimeScale::Tdb.next(), TimeScale::Utc);
+    }
+
+    #[test]
+    fn test_gps_week_and_seconds() {
+        // 2017-01-01 00:00:00 UTC is GPS week 1930, 18 s into Sunday
+        let utc = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        let (week, sow) = gps_week_and_seconds(utc);
+        assert_eq!(week, 1930);
+        assert!((sow - 18.0).abs() < 1e-9);
+        assert_eq!(utc_from_gps_week(week, sow), utc);
+        assert_eq!(gps_week_and_seconds(gps_epoch()), (0, 0.0));
+    }
+
+    #[test]
+    fn test_julian_ephemeris_date_at_j2000() {
+        // J2000.0 is 2000-01-01 12:00 TT, which is 11:58:55.816 UTC
+        let utc = scale_to_utc(
+            NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+            TimeScale::Tt,
+        );
+        assert_eq!(
+            utc,
+            Utc.with_ymd_and_hms(2000, 1, 1, 11, 58, 55).unwrap() + Duration::milliseconds(816)
+        );
+        assert!((julian_date(utc, TimeScale::Tt) - 2_451_545.0).abs() < 1e-9);
+    }
+}
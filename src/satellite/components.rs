@@ -1,9 +1,11 @@
 //! Satellite components for the Bevy ECS system
 
+use crate::orbital::PropagationErrorKind;
+use crate::orbital::maneuver::Maneuver;
 use crate::tle::TleData;
 use bevy::math::DVec3;
 use bevy::prelude::*;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 
 /// Component marker for satellite entities
 #[derive(Component)]
@@ -29,9 +31,26 @@ pub struct TleComponent(pub TleData);
 #[derive(Component)]
 pub struct Propagator(pub sgp4::Constants);
 
-/// Marker component for satellites that have SGP4 propagation errors
-#[derive(Component)]
-pub struct PropagationError;
+/// Component for satellites that cannot be propagated, with the reason
+#[derive(Component, Clone, Debug)]
+pub struct PropagationError {
+    pub kind: PropagationErrorKind,
+    /// Full message from the fetcher, parser, or propagator
+    pub message: String,
+}
+
+impl PropagationError {
+    pub fn new(kind: PropagationErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+/// Maneuvers detected between successive element sets, oldest first
+#[derive(Component, Default, Clone, Debug)]
+pub struct ManeuverHistory(pub Vec<Maneuver>);
 
 /// Component storing the group URL this satellite belongs to
 #[derive(Component)]
@@ -48,10 +67,49 @@ pub struct SatelliteFlags {
 /// Component that stores orbit trail history for a satellite
 #[derive(Component, Default)]
 pub struct OrbitTrail {
-    /// Historical positions with timestamps
+    /// Historical positions with timestamps, oldest first
     pub history: Vec<TrailPoint>,
 }
 
+/// A clock jump this far beyond the recorded span starts a fresh trail
+const TRAIL_JUMP_MINUTES: i64 = 30;
+
+impl OrbitTrail {
+    /// Record a point, extending the trail at whichever end the clock is moving past
+    ///
+    /// Playing forward appends and playing backward prepends, so the history stays in
+    /// time order; a jump clears it, and the end furthest from `point` is trimmed.
+    pub fn record(&mut self, point: TrailPoint, interval: Duration, max_points: usize) {
+        let now = point.timestamp;
+        let (Some(first), Some(last)) = (self.history.first(), self.history.last()) else {
+            self.history.push(point);
+            return;
+        };
+        let jump = Duration::minutes(TRAIL_JUMP_MINUTES).max(interval * 4);
+        if now > last.timestamp + jump || now < first.timestamp - jump {
+            self.history.clear();
+            self.history.push(point);
+            return;
+        }
+
+        let trim_front = now - first.timestamp >= last.timestamp - now;
+        if now >= last.timestamp + interval {
+            self.history.push(point);
+        } else if now <= first.timestamp - interval {
+            self.history.insert(0, point);
+        }
+
+        if self.history.len() > max_points {
+            let excess = self.history.len() - max_points;
+            if trim_front {
+                self.history.drain(0..excess);
+            } else {
+                self.history.truncate(max_points);
+            }
+        }
+    }
+}
+
 /// A single point in the orbit trail
 #[derive(Clone)]
 pub struct TrailPoint {
@@ -60,3 +118,48 @@ pub struct TrailPoint {
     /// When this point was recorded
     pub timestamp: DateTime<Utc>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn point(t: DateTime<Utc>) -> TrailPoint {
+        TrailPoint {
+            position_ecef_km: DVec3::ZERO,
+            timestamp: t,
+        }
+    }
+
+    #[test]
+    fn test_trail_follows_clock_in_both_directions() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let interval = Duration::seconds(10);
+        let mut trail = OrbitTrail::default();
+        for s in 0..6 {
+            trail.record(point(start + Duration::seconds(s * 10)), interval, 4);
+        }
+        // Forward: keeps the newest four
+        let times: Vec<i64> = trail
+            .history
+            .iter()
+            .map(|p| (p.timestamp - start).num_seconds())
+            .collect();
+        assert_eq!(times, vec![20, 30, 40, 50]);
+
+        // Rewinding inside the span adds nothing, then prepends past the oldest point
+        trail.record(point(start + Duration::seconds(35)), interval, 4);
+        assert_eq!(trail.history.len(), 4);
+        trail.record(point(start + Duration::seconds(10)), interval, 4);
+        let times: Vec<i64> = trail
+            .history
+            .iter()
+            .map(|p| (p.timestamp - start).num_seconds())
+            .collect();
+        assert_eq!(times, vec![10, 20, 30, 40]);
+
+        // A jump starts over
+        trail.record(point(start + Duration::days(3)), interval, 4);
+        assert_eq!(trail.history.len(), 1);
+    }
+}
@@ -1,13 +1,14 @@
 //! Satellite systems for propagation and position updates
 
-use crate::core::coordinates::EARTH_RADIUS_KM;
-use crate::core::space::{WorldEcefKm, ecef_to_bevy_km};
+use crate::core::coordinates::{EARTH_RADIUS_KM, ecef_held_inertial_km, eci_velocity_to_ecef_km_s};
+use crate::core::space::{RenderFrame, WorldEcefKm, WorldVelocityEcefKmS, ecef_to_bevy_km};
 use crate::orbital::{
-    Dut1, SimulationTime, eci_to_ecef_km, gmst_rad_with_dut1, minutes_since_epoch,
+    Dut1, PropagationErrorKind, SimulationTime, eci_to_ecef_km, gmst_rad_with_dut1,
+    is_decayed_position, minutes_since_epoch,
 };
 use crate::satellite::components::{
-    NoradId, OrbitTrail, Propagator, Satellite, SatelliteColor, SatelliteFlags, SatelliteGroupUrl,
-    SatelliteName, TleComponent, TrailPoint,
+    NoradId, OrbitTrail, PropagationError, Propagator, Satellite, SatelliteColor, SatelliteFlags,
+    SatelliteGroupUrl, SatelliteName, TleComponent, TrailPoint,
 };
 use crate::satellite::resources::{
     GroupMaterialCache, GroupRegistry, NoradIndex, SatelliteRenderAssets, SelectedSatellite,
@@ -31,11 +32,14 @@ type PropagateQuery<'w, 's> = Query<
     's,
     (
         Entity,
+        &'static NoradId,
         &'static TleComponent,
         &'static Propagator,
         &'static mut Transform,
         &'static mut SatelliteColor,
         Option<&'static mut WorldEcefKm>,
+        Option<&'static mut WorldVelocityEcefKmS>,
+        Option<&'static PropagationError>,
     ),
     With<Satellite>,
 >;
@@ -68,7 +72,11 @@ pub fn init_satellite_render_assets(mut commands: Commands, mut meshes: ResMut<A
     });
 }
 
-/// System to propagate satellites using SGP4 and update their transforms
+/// System to propagate satellites using SGP4 and update their transforms.
+///
+/// Failures at the current simulation time (including positions below the
+/// surface) are recorded as a `PropagationError` and cleared once the
+/// satellite propagates cleanly again, e.g. after scrubbing time back.
 pub fn propagate_satellites_system(
     sim_time: Res<SimulationTime>,
     dut1: Res<Dut1>,
@@ -76,20 +84,70 @@ pub fn propagate_satellites_system(
     mut commands: Commands,
 ) {
     let gmst = gmst_rad_with_dut1(sim_time.current_utc, **dut1);
-    for (entity, tle_comp, propagator, mut transform, _color, world_opt) in &mut q {
+    for (
+        entity,
+        norad,
+        tle_comp,
+        propagator,
+        mut transform,
+        _color,
+        world_opt,
+        velocity_opt,
+        error_opt,
+    ) in &mut q
+    {
         let mins = minutes_since_epoch(sim_time.current_utc, tle_comp.0.epoch_utc);
         // sgp4 2.3.0 expects MinutesSinceEpoch newtype and returns arrays
-        if let Ok(state) = propagator.0.propagate(sgp4::MinutesSinceEpoch(mins)) {
-            let pos = state.position; // [f64; 3] in km (TEME)
-            let eci = DVec3::new(pos[0], pos[1], pos[2]);
-            let ecef = eci_to_ecef_km(eci, gmst);
-
-            transform.translation = ecef_to_bevy_km(ecef);
-            if let Some(mut world) = world_opt {
-                world.0 = ecef;
-            } else {
-                commands.entity(entity).insert(WorldEcefKm(ecef));
+        let error = match propagator.0.propagate(sgp4::MinutesSinceEpoch(mins)) {
+            Ok(state) => {
+                let pos = state.position; // [f64; 3] in km (TEME)
+                let eci = DVec3::new(pos[0], pos[1], pos[2]);
+                let ecef = eci_to_ecef_km(eci, gmst);
+
+                transform.translation = ecef_to_bevy_km(ecef);
+                if let Some(mut world) = world_opt {
+                    world.0 = ecef;
+                } else {
+                    commands.entity(entity).insert(WorldEcefKm(ecef));
+                }
+                // Earth-relative velocity, used for range rate and Doppler
+                let velocity =
+                    eci_velocity_to_ecef_km_s(DVec3::from_array(state.velocity), ecef, gmst);
+                if let Some(mut world_velocity) = velocity_opt {
+                    world_velocity.0 = velocity;
+                } else {
+                    commands
+                        .entity(entity)
+                        .insert(WorldVelocityEcefKmS(velocity));
+                }
+
+                is_decayed_position(ecef).then(|| {
+                    PropagationError::new(
+                        PropagationErrorKind::Decayed,
+                        format!("radius {:.0} km is below the surface", ecef.length()),
+                    )
+                })
+            }
+            Err(e) => Some(PropagationError::new(
+                PropagationErrorKind::from_sgp4(&e),
+                e.to_string(),
+            )),
+        };
+
+        // Only touch the component on transitions to keep change detection quiet
+        match (error, error_opt) {
+            (Some(err), Some(existing)) if existing.kind == err.kind => {}
+            (Some(err), _) => {
+                eprintln!(
+                    "[SGP4] norad={} propagation failed at {} ({:?}): {}",
+                    norad.0, sim_time.current_utc, err.kind, err.message
+                );
+                commands.entity(entity).insert(err);
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<PropagationError>();
             }
+            (None, None) => {}
         }
     }
 }
@@ -153,6 +211,9 @@ pub fn update_orbit_trails_system(
     mut commands: Commands,
 ) {
     let current_time = sim_time.current_utc;
+    let interval = chrono::Duration::milliseconds(
+        (config_bundle.trail_cfg.update_interval_seconds * 1000.0) as i64,
+    );
 
     for (mut trail, world_ecef, flags) in &mut trail_query {
         // Only update trail if it's enabled for this satellite
@@ -162,29 +223,15 @@ pub fn update_orbit_trails_system(
             continue;
         }
 
-        // Check if enough time has passed to add a new trail point
-        let should_add_point = trail.history.is_empty()
-            || trail.history.last().is_none_or(|last| {
-                current_time
-                    .signed_duration_since(last.timestamp)
-                    .num_milliseconds() as f32
-                    / 1000.0
-                    >= config_bundle.trail_cfg.update_interval_seconds
-            });
-
-        if should_add_point {
-            // Add new trail point
-            trail.history.push(TrailPoint {
+        // Extends forward or backward with the clock; jumps start a new trail
+        trail.record(
+            TrailPoint {
                 position_ecef_km: world_ecef.0,
                 timestamp: current_time,
-            });
-        }
-
-        // Limit number of points to max 1000
-        if trail.history.len() > config_bundle.trail_cfg.max_points {
-            let excess = trail.history.len() - config_bundle.trail_cfg.max_points;
-            trail.history.drain(0..excess);
-        }
+            },
+            interval,
+            config_bundle.trail_cfg.max_points,
+        );
     }
 
     // Add OrbitTrail component to satellites that don't have it but need it
@@ -196,32 +243,52 @@ pub fn update_orbit_trails_system(
 }
 
 /// System to draw orbit trails using gizmos
+///
+/// In the inertial frame each point is held where it was against the stars, so
+/// a full revolution closes into an ellipse instead of drifting with the Earth.
 pub fn draw_orbit_trails_system(
     trail_query: Query<(&OrbitTrail, &SatelliteColor, &SatelliteFlags), With<Satellite>>,
+    render_frame: Res<RenderFrame>,
+    sim_time: Res<SimulationTime>,
+    dut1: Res<Dut1>,
     mut gizmos: Gizmos,
 ) {
+    let gmst_now = gmst_rad_with_dut1(sim_time.current_utc, **dut1);
+    let to_render = |point: &TrailPoint| match *render_frame {
+        RenderFrame::Ecef => ecef_to_bevy_km(point.position_ecef_km),
+        RenderFrame::Inertial => ecef_to_bevy_km(ecef_held_inertial_km(
+            point.position_ecef_km,
+            gmst_rad_with_dut1(point.timestamp, **dut1),
+            gmst_now,
+        )),
+    };
+
     for (trail, color, flags) in trail_query.iter() {
         if !flags.show_trail || trail.history.len() < 2 {
             continue;
         }
 
         let srgba = color.0.to_srgba();
-        let trail_length = trail.history.len() as f32;
+        // Fade by time from the clock, so the trail fades the right way when playing backwards
+        let age_s = |point: &TrailPoint| {
+            (sim_time.current_utc - point.timestamp)
+                .num_milliseconds()
+                .unsigned_abs() as f32
+                / 1000.0
+        };
+        let (first, last) = (&trail.history[0], &trail.history[trail.history.len() - 1]);
+        let span_s = age_s(first).max(age_s(last)).max(1.0);
 
         // Draw lines between consecutive trail points
-        for (i, window) in trail.history.windows(2).enumerate() {
-            let point1 = &window[0];
-            let point2 = &window[1];
-
-            // Calculate alpha based on position in trail (newer = more opaque)
-            let alpha = (0.1 + 0.9 * (i as f32 / trail_length.max(1.0))).min(1.0);
+        for window in trail.history.windows(2) {
+            // Calculate alpha based on time from now (nearer = more opaque)
+            let alpha =
+                (0.1 + 0.9 * (1.0 - age_s(&window[1]).min(age_s(&window[0])) / span_s)).min(1.0);
 
             let trail_color = Color::srgba(srgba.red, srgba.green, srgba.blue, alpha);
 
             // Draw line segment (convert canonical ECEF to Bevy render space)
-            let point1_bevy = ecef_to_bevy_km(point1.position_ecef_km);
-            let point2_bevy = ecef_to_bevy_km(point2.position_ecef_km);
-            gizmos.line(point1_bevy, point2_bevy, trail_color);
+            gizmos.line(to_render(&window[0]), to_render(&window[1]), trail_color);
         }
     }
 }
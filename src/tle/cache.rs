@@ -1,8 +1,12 @@
 //! TLE disk caching module
 //!
 //! Provides persistent caching of TLE data to disk, reducing network requests
-//! and enabling offline operation for recently-viewed satellites.
+//! and enabling offline operation for recently-viewed satellites. Every distinct
+//! element set written is also kept in a per-satellite history file so trends
+//! (decay, maneuvers) can be analysed across epochs, and the maneuvers found
+//! between them are kept next to it.
 
+use crate::orbital::maneuver::{Maneuver, merge_maneuvers};
 use chrono::{DateTime, Duration, Utc};
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
@@ -20,6 +24,9 @@ pub struct CachedTle {
     pub cached_at: DateTime<Utc>,
 }
 
+/// Maximum number of element sets kept in a satellite's history file
+pub const MAX_HISTORY_ENTRIES: usize = 120;
+
 /// TLE disk cache manager
 pub struct TleCache {
     cache_dir: PathBuf,
@@ -76,11 +83,73 @@ impl TleCache {
 
     /// Write a TLE entry to disk cache
     ///
-    /// Creates or overwrites the cache file for the given NORAD ID.
+    /// Creates or overwrites the cache file for the given NORAD ID and records
+    /// the element set in the satellite's history.
     pub fn write(&self, entry: &CachedTle) -> Result<(), anyhow::Error> {
         let path = self.cache_path(entry.norad);
         let contents = serde_json::to_string_pretty(entry)?;
         fs::write(&path, contents)?;
+        self.append_history(entry)
+    }
+
+    /// Read all element sets recorded for a NORAD ID, oldest epoch first
+    ///
+    /// Returns an empty list if no history has been recorded yet.
+    pub fn read_history(&self, norad: u32) -> Result<Vec<CachedTle>, anyhow::Error> {
+        let path = self.history_path(norad);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let contents = fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Add an element set to the history, ignoring repeats of a known epoch
+    pub fn append_history(&self, entry: &CachedTle) -> Result<(), anyhow::Error> {
+        let mut history = self.read_history(entry.norad)?;
+        if history.iter().any(|h| h.epoch_utc == entry.epoch_utc) {
+            return Ok(());
+        }
+        history.push(entry.clone());
+        history.sort_by_key(|h| h.epoch_utc);
+        if history.len() > MAX_HISTORY_ENTRIES {
+            history.drain(..history.len() - MAX_HISTORY_ENTRIES);
+        }
+
+        let path = self.history_path(entry.norad);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, serde_json::to_string(&history)?)?;
+        Ok(())
+    }
+
+    /// Read the maneuvers detected for a NORAD ID, oldest first
+    ///
+    /// Returns an empty list if none have been recorded.
+    pub fn read_maneuvers(&self, norad: u32) -> Result<Vec<Maneuver>, anyhow::Error> {
+        let path = self.maneuvers_path(norad);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let contents = fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Add detected maneuvers to the saved ones, ignoring any already recorded
+    pub fn append_maneuvers(
+        &self,
+        norad: u32,
+        maneuvers: &[Maneuver],
+    ) -> Result<(), anyhow::Error> {
+        let mut saved = self.read_maneuvers(norad)?;
+        merge_maneuvers(&mut saved, maneuvers.iter().cloned());
+
+        let path = self.maneuvers_path(norad);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, serde_json::to_string(&saved)?)?;
         Ok(())
     }
 
@@ -98,6 +167,18 @@ impl TleCache {
     fn cache_path(&self, norad: u32) -> PathBuf {
         self.cache_dir.join(format!("{norad}.json"))
     }
+
+    /// Get the file path for a satellite's element set history
+    fn history_path(&self, norad: u32) -> PathBuf {
+        self.cache_dir.join("history").join(format!("{norad}.json"))
+    }
+
+    /// Get the file path for a satellite's detected maneuvers
+    fn maneuvers_path(&self, norad: u32) -> PathBuf {
+        self.cache_dir
+            .join("maneuvers")
+            .join(format!("{norad}.json"))
+    }
 }
 
 #[cfg(test)]
@@ -286,6 +367,67 @@ mod tests {
         assert!(cache30.is_valid(&old_entry));
     }
 
+    #[test]
+    fn test_history_keeps_distinct_epochs_in_order() {
+        let cache_dir = unique_temp_dir("history");
+        let cache = TleCache::new_in_dir(cache_dir, 7).expect("Failed to create cache");
+
+        let entry = |days_ago: i64| CachedTle {
+            norad: 22222,
+            name: Some("HISTORY TEST".to_string()),
+            line1: "1 22222U 24001A   26044.51782528  .00000000  00000-0  00000-0 0  9999"
+                .to_string(),
+            line2: "2 22222  51.6416 247.4627 0006703 290.1234  69.8765 15.48919393123456"
+                .to_string(),
+            epoch_utc: Utc::now() - Duration::days(days_ago),
+            cached_at: Utc::now(),
+        };
+
+        let newest = entry(1);
+        cache.write(&newest).expect("Write should succeed");
+        cache.write(&entry(5)).expect("Write should succeed");
+        // Re-writing a known epoch must not duplicate it
+        cache.write(&newest).expect("Write should succeed");
+
+        let history = cache.read_history(22222).expect("History should load");
+        assert_eq!(history.len(), 2);
+        assert!(history[0].epoch_utc < history[1].epoch_utc);
+        assert!(cache.read_history(11111).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_maneuvers_persist_across_instances() {
+        let cache_dir = unique_temp_dir("maneuvers");
+        let cache = TleCache::new_in_dir(cache_dir.clone(), 7).expect("Failed to create cache");
+        let maneuver = |days_ago: i64| Maneuver {
+            before_utc: Utc::now() - Duration::days(days_ago + 1),
+            after_utc: Utc::now() - Duration::days(days_ago),
+            delta_sma_km: 5.0,
+            delta_inclination_deg: 0.0,
+            delta_raan_deg: 0.0,
+            delta_v_m_s: 2.8,
+            in_plane: true,
+            plane_change: false,
+        };
+        assert!(cache.read_maneuvers(25544).unwrap().is_empty());
+
+        let recent = maneuver(1);
+        cache
+            .append_maneuvers(25544, std::slice::from_ref(&recent))
+            .expect("Append should succeed");
+        // Appending a known maneuver again must not duplicate it
+        cache
+            .append_maneuvers(25544, &[maneuver(10), recent.clone()])
+            .expect("Append should succeed");
+
+        // A new instance (an app restart) reads them back, oldest first
+        let cache2 = TleCache::new_in_dir(cache_dir, 7).expect("Failed to create second cache");
+        let loaded = cache2.read_maneuvers(25544).expect("Maneuvers should load");
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[1], recent);
+        assert!(loaded[0].after_utc < loaded[1].after_utc);
+    }
+
     #[test]
     fn test_integration_cache_then_network_simulation() {
         let cache_dir = unique_temp_dir("integration");
@@ -9,11 +9,11 @@ use std::sync::{Arc, Mutex, mpsc};
 use std::thread;
 
 /// Parsed TLE entry with optional name, line pair, NORAD ID, and epoch.
-struct TleEntry {
-    name: Option<String>,
-    line1: String,
-    line2: String,
-    norad: u32,
+pub(crate) struct TleEntry {
+    pub(crate) name: Option<String>,
+    pub(crate) line1: String,
+    pub(crate) line2: String,
+    pub(crate) norad: u32,
 }
 
 /// Send a cached TLE entry as a success result
@@ -30,7 +30,7 @@ fn send_cached_result(tx: &Sender<FetchResultMsg>, cached: CachedTle) {
 
 /// Clean a TLE response body: strip BOM, CRLF, leading/trailing whitespace,
 /// and drop empty lines.
-fn clean_tle_lines(body: &str) -> Vec<String> {
+pub(crate) fn clean_tle_lines(body: &str) -> Vec<String> {
     body.lines()
         .map(|raw| {
             raw.trim_matches(|c| c == '\u{feff}' || c == '\r' || c == '\n' || c == ' ')
@@ -42,7 +42,7 @@ fn clean_tle_lines(body: &str) -> Vec<String> {
 
 /// Iterate over cleaned TLE lines and yield all valid (line1, line2) pairs
 /// with optional preceding name line and extracted NORAD ID.
-fn parse_tle_pairs(lines: &[String]) -> Vec<TleEntry> {
+pub(crate) fn parse_tle_pairs(lines: &[String]) -> Vec<TleEntry> {
     let mut entries = Vec::new();
     let mut i = 0;
     while i + 1 < lines.len() {
@@ -252,6 +252,54 @@ pub fn start_tle_worker(cache_config: crate::tle::types::TleCacheConfig) -> Fetc
                             }
                         }
                     }
+                    FetchCommand::LoadManeuvers(norad) => {
+                        let Some(ref cache) = cache else { continue };
+                        match cache.read_maneuvers(norad) {
+                            Ok(maneuvers) if maneuvers.is_empty() => {}
+                            Ok(maneuvers) => {
+                                let _ = res_tx.send(FetchResultMsg::Maneuvers { norad, maneuvers });
+                            }
+                            Err(e) => {
+                                eprintln!(
+                                    "[TLE CACHE ERROR] maneuver read failed for norad={norad}: {e}"
+                                );
+                            }
+                        }
+                    }
+                    FetchCommand::SaveManeuvers { norad, maneuvers } => {
+                        if let Some(ref cache) = cache
+                            && let Err(e) = cache.append_maneuvers(norad, &maneuvers)
+                        {
+                            eprintln!(
+                                "[TLE CACHE ERROR] maneuver write failed for norad={norad}: {e}"
+                            );
+                        }
+                    }
+                    FetchCommand::LoadFile(path) => match std::fs::read_to_string(&path) {
+                        Ok(body) => {
+                            let entries = parse_tle_pairs(&clean_tle_lines(&body));
+                            println!(
+                                "[TLE FILE] {} entries from {}",
+                                entries.len(),
+                                path.display()
+                            );
+                            for entry in entries {
+                                let epoch_utc =
+                                    parse_tle_epoch_to_utc(&entry.line1).unwrap_or_else(Utc::now);
+                                let _ = res_tx.send(FetchResultMsg::Success {
+                                    norad: entry.norad,
+                                    name: entry.name,
+                                    line1: entry.line1,
+                                    line2: entry.line2,
+                                    epoch_utc,
+                                    group: None,
+                                });
+                            }
+                        }
+                        Err(e) => {
+                            eprintln!("[TLE FILE] failed to read {}: {e}", path.display());
+                        }
+                    },
                     FetchCommand::FetchGroup { group } => {
                         let send = |m| {
                             let _ = res_tx.send(m);
@@ -13,7 +13,13 @@ pub mod types;
 
 pub use fetcher::start_tle_worker;
 pub use systems::process_fetch_results_system;
-pub use types::{FetchChannels, FetchCommand, TleCacheConfig, TleData};
+pub use types::{FetchChannels, FetchCommand, TleCacheConfig, TleData, TleFreshness};
+
+use std::path::PathBuf;
+
+/// Local TLE files to load once the worker is running (`--load-tle <file>`)
+#[derive(Resource, Default, Clone, Debug)]
+pub struct TleFileLoads(pub Vec<PathBuf>);
 
 /// Plugin for TLE data management and processing
 pub struct TlePlugin;
@@ -21,7 +27,8 @@ pub struct TlePlugin;
 impl Plugin for TlePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<TleCacheConfig>()
-            .add_systems(Startup, setup_tle_worker)
+            .init_resource::<TleFileLoads>()
+            .add_systems(Startup, (setup_tle_worker, load_tle_files).chain())
             .add_systems(Update, process_fetch_results_system);
     }
 }
@@ -32,3 +39,11 @@ fn setup_tle_worker(mut commands: Commands, config: Res<TleCacheConfig>) {
     println!("[INIT] TLE worker started");
     commands.insert_resource(channels);
 }
+
+/// Send the requested local TLE files to the worker
+fn load_tle_files(loads: Res<TleFileLoads>, fetch: Option<Res<FetchChannels>>) {
+    let Some(fetch) = fetch else { return };
+    for path in &loads.0 {
+        let _ = fetch.cmd_tx.send(FetchCommand::LoadFile(path.clone()));
+    }
+}
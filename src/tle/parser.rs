@@ -34,10 +34,76 @@ pub fn parse_tle_epoch_to_utc(line1: &str) -> Option<DateTime<Utc>> {
     Some(DateTime::<Utc>::from_naive_utc_and_offset(ndt, Utc))
 }
 
+/// Mean orbital elements read directly from the fixed TLE columns
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeanElements {
+    pub epoch_utc: DateTime<Utc>,
+    pub inclination_deg: f64,
+    pub raan_deg: f64,
+    pub eccentricity: f64,
+    pub arg_perigee_deg: f64,
+    pub mean_anomaly_deg: f64,
+    /// Mean motion in revolutions per day
+    pub mean_motion_rev_per_day: f64,
+    /// B* drag term in inverse Earth radii
+    pub bstar: f64,
+}
+
+/// Parse the mean elements and B* from a TLE line pair
+pub fn parse_tle_mean_elements(line1: &str, line2: &str) -> Option<MeanElements> {
+    if line1.len() < 61 || line2.len() < 63 {
+        return None;
+    }
+    let field = |line: &str, range: std::ops::Range<usize>| -> Option<f64> {
+        line.get(range)?.trim().parse().ok()
+    };
+    Some(MeanElements {
+        epoch_utc: parse_tle_epoch_to_utc(line1)?,
+        inclination_deg: field(line2, 8..16)?,
+        raan_deg: field(line2, 17..25)?,
+        eccentricity: format!("0.{}", line2.get(26..33)?.trim()).parse().ok()?,
+        arg_perigee_deg: field(line2, 34..42)?,
+        mean_anomaly_deg: field(line2, 43..51)?,
+        mean_motion_rev_per_day: field(line2, 52..63)?,
+        bstar: parse_implied_exponent(line1.get(53..61)?)?,
+    })
+}
+
+/// Parse a TLE "implied decimal point" field such as ` 34123-4` (0.34123e-4)
+fn parse_implied_exponent(raw: &str) -> Option<f64> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Some(0.0);
+    }
+    let (sign, rest) = match raw.as_bytes()[0] {
+        b'-' => (-1.0, &raw[1..]),
+        b'+' => (1.0, &raw[1..]),
+        _ => (1.0, raw),
+    };
+    let split = rest.rfind(['-', '+'])?;
+    let (mantissa, exponent) = rest.split_at(split);
+    let mantissa: f64 = format!("0.{mantissa}").parse().ok()?;
+    let exponent: i32 = exponent.parse().ok()?;
+    Some(sign * mantissa * 10f64.powi(exponent))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_mean_elements() {
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let elements = parse_tle_mean_elements(line1, line2).expect("valid TLE");
+        assert!((elements.inclination_deg - 51.6416).abs() < 1e-9);
+        assert!((elements.raan_deg - 247.4627).abs() < 1e-9);
+        assert!((elements.eccentricity - 0.0006703).abs() < 1e-12);
+        assert!((elements.mean_motion_rev_per_day - 15.72125391).abs() < 1e-8);
+        assert!((elements.bstar + 0.11606e-4).abs() < 1e-12);
+        assert!(parse_tle_mean_elements("1 short", line2).is_none());
+    }
+
     #[test]
     fn test_parse_tle_epoch() {
         // Test with a typical TLE line 1
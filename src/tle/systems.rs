@@ -1,20 +1,27 @@
 //! TLE processing systems
 
+use crate::orbital::PropagationErrorKind;
+use crate::orbital::maneuver::{Maneuver, detect_maneuver, merge_maneuvers};
 use crate::satellite::components::{
-    NoradId, PropagationError, Propagator, Satellite, SatelliteColor, SatelliteFlags,
-    SatelliteGroupUrl, SatelliteName, TleComponent,
+    ManeuverHistory, NoradId, PropagationError, Propagator, Satellite, SatelliteColor,
+    SatelliteFlags, SatelliteGroupUrl, SatelliteName, TleComponent,
 };
 use crate::satellite::resources::{ColorHueCounter, GroupRegistry, NoradIndex};
-use crate::tle::parser::parse_tle_epoch_to_utc;
-use crate::tle::types::{FetchChannels, FetchResultMsg, TleData};
+use crate::tle::parser::{parse_tle_epoch_to_utc, parse_tle_mean_elements};
+use crate::tle::types::{FetchChannels, FetchCommand, FetchResultMsg, TleData};
 use crate::ui::state::RightPanelUI;
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 /// System to drain fetch results and build SGP4 propagators.
 ///
 /// When a new satellite arrives, this system spawns a data-only entity
 /// (no mesh/material). The `materialize_satellite_entities_system` adds
-/// rendering components on the next frame.
+/// rendering components on the next frame. When a newer TLE arrives for a
+/// known satellite it is compared with the previous one to detect maneuvers.
+/// Maneuvers are saved next to the TLE cache and read back when a satellite
+/// gets its first TLE.
+#[allow(clippy::type_complexity)]
 pub fn process_fetch_results_system(
     mut norad_index: ResMut<NoradIndex>,
     mut color_hue: ResMut<ColorHueCounter>,
@@ -23,12 +30,24 @@ pub fn process_fetch_results_system(
     fetch: Option<Res<FetchChannels>>,
     mut commands: Commands,
     // Queries for updating existing satellite entities
-    mut sat_query: Query<(&mut SatelliteColor, Option<&mut SatelliteName>), With<Satellite>>,
+    mut sat_query: Query<
+        (
+            &mut SatelliteColor,
+            Option<&TleComponent>,
+            Option<&ManeuverHistory>,
+        ),
+        With<Satellite>,
+    >,
 ) {
     let Some(fetch) = fetch else { return };
     let Ok(guard) = fetch.res_rx.lock() else {
         return;
     };
+    // Commands only apply after the drain, so a second result for the same satellite
+    // must see the element set and history left by the first one here
+    let mut latest_tle: HashMap<Entity, TleData> = HashMap::new();
+    let mut histories: HashMap<Entity, Vec<Maneuver>> = HashMap::new();
+    let mut detected: HashMap<u32, Vec<Maneuver>> = HashMap::new();
     while let Ok(msg) = guard.try_recv() {
         match msg {
             FetchResultMsg::Success {
@@ -41,18 +60,61 @@ pub fn process_fetch_results_system(
             } => {
                 let name_val = name.or_else(|| Some(format!("NORAD {norad}")));
                 let epoch = parse_tle_epoch_to_utc(&line1).unwrap_or(epoch_utc);
-                let tle_data = TleData { epoch_utc: epoch };
+                let tle_data = TleData {
+                    epoch_utc: epoch,
+                    line1: line1.clone(),
+                    line2: line2.clone(),
+                };
 
-                // Build SGP4 model
+                // Build SGP4 model, keeping the failure reason for the UI
                 let sgp4_result =
                     sgp4::Elements::from_tle(name_val.clone(), line1.as_bytes(), line2.as_bytes())
-                        .map_err(|e| e.to_string())
+                        .map_err(|e| {
+                            PropagationError::new(PropagationErrorKind::InvalidTle, e.to_string())
+                        })
                         .and_then(|elements| {
-                            sgp4::Constants::from_elements(&elements).map_err(|e| e.to_string())
+                            sgp4::Constants::from_elements(&elements).map_err(|e| {
+                                PropagationError::new(
+                                    PropagationErrorKind::from_sgp4_elements(&e),
+                                    e.to_string(),
+                                )
+                            })
                         });
 
                 if let Some(&entity) = norad_index.map.get(&norad) {
                     // ── Update existing entity ──
+                    let previous = latest_tle.get(&entity).cloned().or_else(|| {
+                        sat_query
+                            .get(entity)
+                            .ok()
+                            .and_then(|(_, tle, _)| tle.map(|tle| tle.0.clone()))
+                    });
+                    if previous.is_none() {
+                        // First element set for a satellite added by NORAD ID
+                        let _ = fetch.cmd_tx.send(FetchCommand::LoadManeuvers(norad));
+                    }
+                    if let Some(previous) = previous
+                        && let Some(before) =
+                            parse_tle_mean_elements(&previous.line1, &previous.line2)
+                        && let Some(after) = parse_tle_mean_elements(&line1, &line2)
+                        && let Some(maneuver) = detect_maneuver(&before, &after)
+                    {
+                        println!(
+                            "[MANEUVER] norad={norad} {} between {} and {}: da={:+.2} km di={:+.3} deg dRAAN={:+.3} deg dv~{:.1} m/s",
+                            maneuver.label(),
+                            maneuver.before_utc.to_rfc3339(),
+                            maneuver.after_utc.to_rfc3339(),
+                            maneuver.delta_sma_km,
+                            maneuver.delta_inclination_deg,
+                            maneuver.delta_raan_deg,
+                            maneuver.delta_v_m_s
+                        );
+                        let saved = sat_query.get(entity).ok().and_then(|(_, _, h)| h);
+                        pending_history(&mut histories, entity, saved).push(maneuver.clone());
+                        detected.entry(norad).or_default().push(maneuver);
+                    }
+                    latest_tle.insert(entity, tle_data.clone());
+
                     let mut ec = commands.entity(entity);
                     ec.insert(TleComponent(tle_data));
                     ec.remove::<PropagationError>();
@@ -65,10 +127,13 @@ pub fn process_fetch_results_system(
                         Ok(constants) => {
                             ec.insert(Propagator(constants));
                         }
-                        Err(e) => {
+                        Err(err) => {
                             ec.remove::<Propagator>();
-                            eprintln!("[SGP4] norad={norad} error: {e}");
-                            ec.insert(PropagationError);
+                            eprintln!(
+                                "[SGP4] norad={norad} error ({:?}): {}",
+                                err.kind, err.message
+                            );
+                            ec.insert(err);
                         }
                     }
 
@@ -76,7 +141,7 @@ pub fn process_fetch_results_system(
                     if let Some(group_url) = &group {
                         if let Some(registry) = &group_registry
                             && let Some(grp) = registry.groups.get(group_url)
-                            && let Ok((mut color, _)) = sat_query.get_mut(entity)
+                            && let Ok((mut color, _, _)) = sat_query.get_mut(entity)
                         {
                             color.0 = grp.color;
                         }
@@ -91,7 +156,7 @@ pub fn process_fetch_results_system(
                         NoradId(norad),
                         SatelliteColor(color),
                         SatelliteFlags::default(),
-                        TleComponent(tle_data),
+                        TleComponent(tle_data.clone()),
                     ));
 
                     if let Some(name) = &name_val {
@@ -102,9 +167,12 @@ pub fn process_fetch_results_system(
                         Ok(constants) => {
                             ec.insert(Propagator(constants));
                         }
-                        Err(e) => {
-                            eprintln!("[SGP4] norad={norad} error: {e}");
-                            ec.insert(PropagationError);
+                        Err(err) => {
+                            eprintln!(
+                                "[SGP4] norad={norad} error ({:?}): {}",
+                                err.kind, err.message
+                            );
+                            ec.insert(err);
                         }
                     }
 
@@ -114,6 +182,14 @@ pub fn process_fetch_results_system(
 
                     let entity = ec.id();
                     norad_index.map.insert(norad, entity);
+                    latest_tle.insert(entity, tle_data);
+                    let _ = fetch.cmd_tx.send(FetchCommand::LoadManeuvers(norad));
+                }
+            }
+            FetchResultMsg::Maneuvers { norad, maneuvers } => {
+                if let Some(&entity) = norad_index.map.get(&norad) {
+                    let saved = sat_query.get(entity).ok().and_then(|(_, _, h)| h);
+                    merge_maneuvers(pending_history(&mut histories, entity, saved), maneuvers);
                 }
             }
             FetchResultMsg::Failure { norad, error } => {
@@ -123,7 +199,10 @@ pub fn process_fetch_results_system(
                         .entity(entity)
                         .remove::<TleComponent>()
                         .remove::<Propagator>()
-                        .insert(PropagationError);
+                        .insert(PropagationError::new(
+                            PropagationErrorKind::FetchFailed,
+                            error,
+                        ));
                 } else {
                     eprintln!("[TLE DISPATCH] failure for unknown norad={norad} (not in index)");
                 }
@@ -139,6 +218,27 @@ pub fn process_fetch_results_system(
             }
         }
     }
+
+    for (entity, history) in histories {
+        commands.entity(entity).insert(ManeuverHistory(history));
+    }
+    for (norad, maneuvers) in detected {
+        let _ = fetch
+            .cmd_tx
+            .send(FetchCommand::SaveManeuvers { norad, maneuvers });
+    }
+}
+
+/// History a result in this drain adds to: the one started earlier in the
+/// drain, else a copy of the satellite's current one
+fn pending_history<'a>(
+    histories: &'a mut HashMap<Entity, Vec<Maneuver>>,
+    entity: Entity,
+    saved: Option<&ManeuverHistory>,
+) -> &'a mut Vec<Maneuver> {
+    histories
+        .entry(entity)
+        .or_insert_with(|| saved.map(|history| history.0.clone()).unwrap_or_default())
 }
 
 /// Determine color for a new satellite based on group registry or golden-angle hue.
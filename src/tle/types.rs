@@ -1,7 +1,9 @@
 //! TLE data types and communication structures
 
+use crate::orbital::maneuver::Maneuver;
 use bevy::prelude::*;
 use chrono::{DateTime, Utc};
+use std::path::PathBuf;
 use std::sync::{
     Arc, Mutex,
     mpsc::{Receiver, Sender},
@@ -11,6 +13,43 @@ use std::sync::{
 #[derive(Clone)]
 pub struct TleData {
     pub epoch_utc: DateTime<Utc>,
+    /// Raw TLE lines, kept so analyses can rebuild the SGP4 model off-thread
+    pub line1: String,
+    pub line2: String,
+}
+
+impl TleData {
+    /// Days between the TLE epoch and `utc` (negative when `utc` is before the epoch)
+    pub fn age_days(&self, utc: DateTime<Utc>) -> f64 {
+        (utc - self.epoch_utc).num_seconds() as f64 / 86_400.0
+    }
+}
+
+/// Element sets younger than this many days are considered fresh
+pub const TLE_AGING_DAYS: f64 = 3.0;
+/// Element sets older than this many days are considered stale
+pub const TLE_STALE_DAYS: f64 = 14.0;
+
+/// How trustworthy a TLE is at a given time, based on distance from its epoch
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TleFreshness {
+    Fresh,
+    Aging,
+    Stale,
+}
+
+impl TleFreshness {
+    /// Classify by age in days; SGP4 error grows in both directions from the epoch
+    pub fn from_age_days(age_days: f64) -> Self {
+        let age = age_days.abs();
+        if age < TLE_AGING_DAYS {
+            Self::Fresh
+        } else if age < TLE_STALE_DAYS {
+            Self::Aging
+        } else {
+            Self::Stale
+        }
+    }
 }
 
 /// Commands for the TLE fetcher worker thread
@@ -20,6 +59,15 @@ pub enum FetchCommand {
     Fetch(u32),
     /// Fetch all satellites in a Celestrak group (e.g., "weather")
     FetchGroup { group: String },
+    /// Load every TLE in a local file (e.g. a fitted TLE), bypassing the cache
+    LoadFile(PathBuf),
+    /// Send back the maneuvers saved for a satellite, if there are any
+    LoadManeuvers(u32),
+    /// Add newly detected maneuvers to the ones saved for a satellite
+    SaveManeuvers {
+        norad: u32,
+        maneuvers: Vec<Maneuver>,
+    },
 }
 
 /// Results from the TLE fetcher worker thread
@@ -45,6 +93,11 @@ pub enum FetchResultMsg {
         group: String,
         error: String,
     },
+    /// Maneuvers saved in an earlier session
+    Maneuvers {
+        norad: u32,
+        maneuvers: Vec<Maneuver>,
+    },
 }
 
 /// Resource containing channels for communicating with the TLE worker thread
@@ -74,3 +127,26 @@ impl Default for TleCacheConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_tle_age_and_freshness() {
+        let tle = TleData {
+            epoch_utc: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            line1: String::new(),
+            line2: String::new(),
+        };
+        let later = Utc.with_ymd_and_hms(2024, 1, 11, 12, 0, 0).unwrap();
+        assert!((tle.age_days(later) - 10.5).abs() < 1e-9);
+        assert_eq!(
+            TleFreshness::from_age_days(tle.age_days(later)),
+            TleFreshness::Aging
+        );
+        assert_eq!(TleFreshness::from_age_days(1.0), TleFreshness::Fresh);
+        assert_eq!(TleFreshness::from_age_days(-20.0), TleFreshness::Stale);
+    }
+}
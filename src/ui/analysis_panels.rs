@@ -0,0 +1,1538 @@
+//! Analysis panels: heatmap legend, coverage, revisit, satellite details, radio
+//! pass, beta angle and launch window readouts
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::ecs::spawn::Spawn;
+use bevy::prelude::*;
+use bevy::text::TextColor;
+use bevy::ui::auto_directional_navigation::AutoDirectionalNavigation;
+use bevy_feathers::controls::{ButtonProps, checkbox, radio};
+use bevy_feathers::theme::ThemedText;
+use bevy_ui_widgets::ValueChange;
+use chrono::{DateTime, Utc};
+
+use crate::analysis::radio::detail_target_norad;
+use crate::analysis::revisit::site_ecef_km;
+use crate::analysis::{
+    AnalysisSite, CoverageConfig, CoverageMetric, CoverageState, DecayConfig, LaunchWindowConfig,
+    LaunchWindowState, LightingConfig, LightingState, RadioConfig, RadioState, ReentryPrediction,
+    RevisitConfig, RevisitState, RevisitTargets, TransmitterDb,
+};
+use crate::core::coordinates::elevation_deg_ecef_dvec;
+use crate::core::space::{EARTH_RADIUS_KM_F64, WorldEcefKm, WorldVelocityEcefKmS};
+use crate::orbital::doppler::{downlink_hz, range_and_rate_km, uplink_hz};
+use crate::orbital::time::SimulationTime;
+use crate::satellite::SelectedSatellite;
+use crate::satellite::components::{
+    ManeuverHistory, NoradId, PropagationError, Satellite, SatelliteFlags, SatelliteName,
+    TleComponent,
+};
+use crate::satellite::resources::NoradIndex;
+use crate::ui::systems::{
+    ButtonAction, CheckboxBinding, PANEL_INNER_BG, PANEL_TEXT_ACCENT, SliderBinding,
+    format_day_span, spawn_fixed_button, spawn_labeled_slider,
+};
+use crate::visualization::colormaps::turbo_colormap;
+use crate::visualization::{HeatmapConfig, HeatmapMetric, HeatmapState};
+
+#[derive(Component)]
+pub(crate) struct CoverageStatusText;
+
+#[derive(Component)]
+pub(crate) struct SatelliteDetailsText;
+
+#[derive(Component)]
+pub(crate) struct ManeuverTimeline;
+
+#[derive(Component)]
+pub(crate) struct ManeuverListText;
+
+#[derive(Component)]
+pub(crate) struct RadioReadoutText;
+
+#[derive(Component)]
+pub(crate) struct RadioPassStatusText;
+
+#[derive(Component)]
+pub(crate) struct DopplerCurve;
+
+#[derive(Component)]
+pub(crate) struct DopplerCurveText;
+
+#[derive(Component)]
+pub(crate) struct LightingStatusText;
+
+#[derive(Component)]
+pub(crate) struct BetaAngleCurve;
+
+#[derive(Component)]
+pub(crate) struct EclipseCurve;
+
+#[derive(Component)]
+pub(crate) struct EclipseSeasonsText;
+
+#[derive(Component)]
+pub(crate) struct RevisitSiteText;
+
+/// Launch window site or target readout
+#[derive(Component, Clone, Copy)]
+pub(crate) enum LaunchWindowChoiceText {
+    Site,
+    Target,
+}
+
+#[derive(Component)]
+pub(crate) struct LaunchWindowStatusText;
+
+#[derive(Component)]
+pub(crate) struct LaunchWindowResultsText;
+
+#[derive(Component)]
+pub(crate) struct RevisitStatusText;
+
+#[derive(Component)]
+pub(crate) struct RevisitStatsText;
+
+#[derive(Component)]
+pub(crate) struct RevisitTimeline;
+
+#[derive(Component)]
+pub(crate) struct RevisitTimelineStartText;
+
+#[derive(Component)]
+pub(crate) struct RevisitTimelineEndText;
+
+#[derive(Component)]
+pub(crate) struct HeatmapLegendLabelText;
+
+#[derive(Component)]
+pub(crate) struct HeatmapLegendMinText;
+
+#[derive(Component)]
+pub(crate) struct HeatmapLegendMaxText;
+
+#[derive(Component, Clone, Copy)]
+pub(crate) struct HeatmapMetricBinding(pub(crate) HeatmapMetric);
+
+/// DOP constellation choice; None uses every loaded satellite
+#[derive(Component, Clone, Copy)]
+pub(crate) struct DopGroupBinding(pub(crate) Option<&'static str>);
+
+#[derive(Component, Clone, Copy)]
+pub(crate) struct CoverageMetricBinding(pub(crate) CoverageMetric);
+
+#[derive(Component, Clone, Copy)]
+pub(crate) struct RevisitTargetBinding(pub(crate) RevisitTargets);
+
+/// Color bar with min/max labels describing the heatmap colors
+pub(crate) fn spawn_heatmap_legend(section: &mut ChildSpawnerCommands) {
+    const LEGEND_STEPS: usize = 16;
+
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                width: Val::Percent(100.0),
+                height: Val::Px(8.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|bar| {
+            for step in 0..LEGEND_STEPS {
+                let t = step as f32 / (LEGEND_STEPS - 1) as f32;
+                let [r, g, b, _] = turbo_colormap(t);
+                bar.spawn((
+                    Node {
+                        flex_grow: 1.0,
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(r, g, b)),
+                ));
+            }
+        });
+
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|row| {
+            let font = TextFont {
+                font_size: 10.0,
+                ..default()
+            };
+            let color = TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85));
+            row.spawn((
+                HeatmapLegendMinText,
+                bevy::ui::widget::Text::new("--"),
+                ThemedText,
+                font.clone(),
+                color,
+            ));
+            row.spawn((
+                HeatmapLegendLabelText,
+                bevy::ui::widget::Text::new("No data"),
+                ThemedText,
+                font.clone(),
+                color,
+            ));
+            row.spawn((
+                HeatmapLegendMaxText,
+                bevy::ui::widget::Text::new("--"),
+                ThemedText,
+                font,
+                color,
+            ));
+        });
+}
+
+/// Revisit site, targets, stats and access timeline
+pub(crate) fn spawn_revisit_section(
+    section: &mut ChildSpawnerCommands,
+    revisit_cfg: &RevisitConfig,
+) {
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|row| {
+            row.spawn((
+                RevisitSiteText,
+                bevy::ui::widget::Text::new("Site: not set"),
+                ThemedText,
+                TextFont {
+                    font_size: 11.0,
+                    ..default()
+                },
+            ));
+            spawn_fixed_button(
+                row,
+                72.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::PickAnalysisSite,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Pick",
+            );
+        });
+
+    section.spawn((bevy::ui::widget::Text::new("Satellites"), ThemedText));
+    for (targets, label) in [
+        (RevisitTargets::AllLoaded, "All loaded"),
+        (RevisitTargets::Selected, "Selected satellite"),
+        (RevisitTargets::Group, "Chosen group"),
+    ] {
+        section.spawn((radio(
+            (
+                RevisitTargetBinding(targets),
+                AutoDirectionalNavigation::default(),
+            ),
+            Spawn((bevy::ui::widget::Text::new(label), ThemedText)),
+        ),));
+    }
+
+    spawn_labeled_slider(
+        section,
+        "Duration (h)",
+        SliderBinding::RevisitDurationHours,
+        1.0,
+        72.0,
+        revisit_cfg.duration_hours,
+        1.0,
+    );
+    spawn_labeled_slider(
+        section,
+        "Step (s)",
+        SliderBinding::RevisitStepSeconds,
+        10.0,
+        300.0,
+        revisit_cfg.step_seconds,
+        10.0,
+    );
+    spawn_labeled_slider(
+        section,
+        "Min elevation (deg)",
+        SliderBinding::RevisitMinElevation,
+        0.0,
+        45.0,
+        revisit_cfg.min_elevation_deg,
+        1.0,
+    );
+
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|row| {
+            spawn_fixed_button(
+                row,
+                72.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::RunRevisit,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Run",
+            );
+            spawn_fixed_button(
+                row,
+                96.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::ExportRevisit,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Export CSV",
+            );
+        });
+
+    section.spawn((
+        RevisitStatusText,
+        bevy::ui::widget::Text::new(""),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+    ));
+
+    section.spawn((
+        RevisitTimeline,
+        Node {
+            position_type: PositionType::Relative,
+            width: Val::Percent(100.0),
+            height: Val::Px(18.0),
+            ..default()
+        },
+        BackgroundColor(PANEL_INNER_BG),
+    ));
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|row| {
+            for marker in [true, false] {
+                let mut text = row.spawn((
+                    bevy::ui::widget::Text::new(""),
+                    ThemedText,
+                    TextFont {
+                        font_size: 10.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+                ));
+                if marker {
+                    text.insert(RevisitTimelineStartText);
+                } else {
+                    text.insert(RevisitTimelineEndText);
+                }
+            }
+        });
+
+    section.spawn((
+        RevisitStatsText,
+        bevy::ui::widget::Text::new(""),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+    ));
+}
+
+/// Time-integrated coverage grid settings, metric and export
+pub(crate) fn spawn_coverage_section(
+    section: &mut ChildSpawnerCommands,
+    coverage_cfg: &CoverageConfig,
+) {
+    spawn_labeled_slider(
+        section,
+        "Duration (h)",
+        SliderBinding::CoverageDurationHours,
+        1.0,
+        72.0,
+        coverage_cfg.duration_hours,
+        1.0,
+    );
+    spawn_labeled_slider(
+        section,
+        "Step (s)",
+        SliderBinding::CoverageStepSeconds,
+        10.0,
+        600.0,
+        coverage_cfg.step_seconds,
+        10.0,
+    );
+    spawn_labeled_slider(
+        section,
+        "Cell size (deg)",
+        SliderBinding::CoverageCellSize,
+        1.0,
+        15.0,
+        coverage_cfg.region.cell_size_deg as f32,
+        1.0,
+    );
+    spawn_labeled_slider(
+        section,
+        "Min elevation (deg)",
+        SliderBinding::CoverageMinElevation,
+        0.0,
+        45.0,
+        coverage_cfg.min_elevation_deg,
+        1.0,
+    );
+    spawn_labeled_slider(
+        section,
+        "Lat min",
+        SliderBinding::CoverageLatMin,
+        -90.0,
+        90.0,
+        coverage_cfg.region.lat_min_deg as f32,
+        5.0,
+    );
+    spawn_labeled_slider(
+        section,
+        "Lat max",
+        SliderBinding::CoverageLatMax,
+        -90.0,
+        90.0,
+        coverage_cfg.region.lat_max_deg as f32,
+        5.0,
+    );
+    spawn_labeled_slider(
+        section,
+        "Lon min",
+        SliderBinding::CoverageLonMin,
+        -180.0,
+        180.0,
+        coverage_cfg.region.lon_min_deg as f32,
+        5.0,
+    );
+    spawn_labeled_slider(
+        section,
+        "Lon max",
+        SliderBinding::CoverageLonMax,
+        -180.0,
+        180.0,
+        coverage_cfg.region.lon_max_deg as f32,
+        5.0,
+    );
+
+    section.spawn((bevy::ui::widget::Text::new("Metric"), ThemedText));
+    for metric in CoverageMetric::ALL {
+        section.spawn((radio(
+            (
+                CoverageMetricBinding(metric),
+                AutoDirectionalNavigation::default(),
+            ),
+            Spawn((bevy::ui::widget::Text::new(metric.label()), ThemedText)),
+        ),));
+    }
+
+    section.spawn((checkbox(
+        (
+            CheckboxBinding::CoverageOverlay,
+            AutoDirectionalNavigation::default(),
+        ),
+        Spawn((bevy::ui::widget::Text::new("Show on globe"), ThemedText)),
+    ),));
+
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|row| {
+            spawn_fixed_button(
+                row,
+                72.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::RunCoverage,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Run",
+            );
+            spawn_fixed_button(
+                row,
+                96.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::ExportCoverage,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Export CSV",
+            );
+        });
+
+    section.spawn((
+        CoverageStatusText,
+        bevy::ui::widget::Text::new(""),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+    ));
+}
+
+/// Orbit, decay and maneuver readout for the selected satellite
+pub(crate) fn spawn_satellite_details_section(
+    section: &mut ChildSpawnerCommands,
+    decay_cfg: &DecayConfig,
+) {
+    section.spawn((
+        SatelliteDetailsText,
+        bevy::ui::widget::Text::new("Select or track a satellite"),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+    ));
+    section.spawn((
+        bevy::ui::widget::Text::new("Maneuvers"),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+    ));
+    section.spawn((
+        ManeuverTimeline,
+        Node {
+            position_type: PositionType::Relative,
+            width: Val::Percent(100.0),
+            height: Val::Px(14.0),
+            ..default()
+        },
+        BackgroundColor(PANEL_INNER_BG),
+    ));
+    section.spawn((
+        ManeuverListText,
+        bevy::ui::widget::Text::new(""),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+    ));
+    spawn_labeled_slider(
+        section,
+        "Flag re-entry within (days)",
+        SliderBinding::ReentryFlagDays,
+        1.0,
+        365.0,
+        decay_cfg.flag_within_days,
+        1.0,
+    );
+}
+
+/// Doppler readout and curve for the selected satellite
+pub(crate) fn spawn_radio_pass_section(section: &mut ChildSpawnerCommands) {
+    section.spawn((
+        RadioReadoutText,
+        bevy::ui::widget::Text::new(""),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+    ));
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|row| {
+            spawn_fixed_button(
+                row,
+                120.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::NextTransmitter,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Transmitter",
+            );
+            spawn_fixed_button(
+                row,
+                96.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::PlanRadioPass,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Plan Pass",
+            );
+        });
+    section.spawn((
+        RadioPassStatusText,
+        bevy::ui::widget::Text::new("Uses the Revisit Analysis site"),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+    ));
+    section.spawn((
+        DopplerCurve,
+        Node {
+            position_type: PositionType::Relative,
+            width: Val::Percent(100.0),
+            height: Val::Px(64.0),
+            ..default()
+        },
+        BackgroundColor(PANEL_INNER_BG),
+    ));
+    section.spawn((
+        DopplerCurveText,
+        bevy::ui::widget::Text::new(""),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+    ));
+}
+
+/// Beta angle and eclipse plots with eclipse seasons
+pub(crate) fn spawn_lighting_section(
+    section: &mut ChildSpawnerCommands,
+    lighting_cfg: &LightingConfig,
+) {
+    spawn_labeled_slider(
+        section,
+        "Date range (days)",
+        SliderBinding::LightingDays,
+        7.0,
+        730.0,
+        lighting_cfg.days,
+        1.0,
+    );
+    spawn_fixed_button(
+        section,
+        96.0,
+        ButtonProps::default(),
+        (
+            ButtonAction::RunLighting,
+            AutoDirectionalNavigation::default(),
+        ),
+        "Compute",
+    );
+    section.spawn((
+        LightingStatusText,
+        bevy::ui::widget::Text::new("Uses the detail satellite"),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+    ));
+    section.spawn((
+        BetaAngleCurve,
+        Node {
+            position_type: PositionType::Relative,
+            width: Val::Percent(100.0),
+            height: Val::Px(64.0),
+            ..default()
+        },
+        BackgroundColor(PANEL_INNER_BG),
+    ));
+    section.spawn((
+        EclipseCurve,
+        Node {
+            position_type: PositionType::Relative,
+            width: Val::Percent(100.0),
+            height: Val::Px(48.0),
+            margin: UiRect::top(Val::Px(4.0)),
+            ..default()
+        },
+        BackgroundColor(PANEL_INNER_BG),
+    ));
+    section.spawn((
+        EclipseSeasonsText,
+        bevy::ui::widget::Text::new(""),
+        ThemedText,
+        TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+    ));
+}
+
+/// Launch window site, target and results
+pub(crate) fn spawn_launch_window_section(
+    section: &mut ChildSpawnerCommands,
+    launch_window_cfg: &LaunchWindowConfig,
+) {
+    for (label, action) in [
+        (
+            LaunchWindowChoiceText::Site,
+            ButtonAction::CycleLaunchWindowSite,
+        ),
+        (
+            LaunchWindowChoiceText::Target,
+            ButtonAction::CycleLaunchWindowTarget,
+        ),
+    ] {
+        section
+            .spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+                ThemedText,
+            ))
+            .with_children(|row| {
+                row.spawn((
+                    label,
+                    bevy::ui::widget::Text::new(""),
+                    ThemedText,
+                    TextFont {
+                        font_size: 11.0,
+                        ..default()
+                    },
+                ));
+                spawn_fixed_button(
+                    row,
+                    72.0,
+                    ButtonProps::default(),
+                    (action, AutoDirectionalNavigation::default()),
+                    "Change",
+                );
+            });
+    }
+    spawn_labeled_slider(
+        section,
+        "Inclination (deg)",
+        SliderBinding::LaunchWindowInclination,
+        0.0,
+        180.0,
+        launch_window_cfg.inclination_deg,
+        0.1,
+    );
+    spawn_labeled_slider(
+        section,
+        "RAAN (deg)",
+        SliderBinding::LaunchWindowRaan,
+        0.0,
+        360.0,
+        launch_window_cfg.raan_deg,
+        0.5,
+    );
+    spawn_labeled_slider(
+        section,
+        "Date range (days)",
+        SliderBinding::LaunchWindowDays,
+        1.0,
+        30.0,
+        launch_window_cfg.days,
+        1.0,
+    );
+    spawn_fixed_button(
+        section,
+        96.0,
+        ButtonProps::default(),
+        (
+            ButtonAction::RunLaunchWindow,
+            AutoDirectionalNavigation::default(),
+        ),
+        "Compute",
+    );
+    section.spawn((
+        LaunchWindowStatusText,
+        bevy::ui::widget::Text::new("Times the site passes under the target plane"),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+    ));
+    section.spawn((
+        LaunchWindowResultsText,
+        bevy::ui::widget::Text::new(""),
+        ThemedText,
+        TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+    ));
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_heatmap_legend(
+    state: Res<HeatmapState>,
+    mut texts: ParamSet<(
+        Query<&mut bevy::ui::widget::Text, With<HeatmapLegendLabelText>>,
+        Query<&mut bevy::ui::widget::Text, With<HeatmapLegendMinText>>,
+        Query<&mut bevy::ui::widget::Text, With<HeatmapLegendMaxText>>,
+    )>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    let (label, min, max) = match &state.legend {
+        Some(legend) => (
+            legend.label.clone(),
+            format_legend_value(legend.min),
+            format_legend_value(legend.max),
+        ),
+        None => ("No data".to_string(), "--".to_string(), "--".to_string()),
+    };
+    for mut text in &mut texts.p0() {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+    for mut text in &mut texts.p1() {
+        if text.0 != min {
+            text.0 = min.clone();
+        }
+    }
+    for mut text in &mut texts.p2() {
+        if text.0 != max {
+            text.0 = max.clone();
+        }
+    }
+}
+
+fn format_legend_value(value: f32) -> String {
+    if value.abs() >= 100.0 || value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_revisit_panel(
+    mut commands: Commands,
+    state: Res<RevisitState>,
+    site: Res<AnalysisSite>,
+    timelines: Query<Entity, With<RevisitTimeline>>,
+    mut texts: ParamSet<(
+        Query<&mut bevy::ui::widget::Text, With<RevisitSiteText>>,
+        Query<&mut bevy::ui::widget::Text, With<RevisitStatusText>>,
+        Query<&mut bevy::ui::widget::Text, With<RevisitStatsText>>,
+        Query<&mut bevy::ui::widget::Text, With<RevisitTimelineStartText>>,
+        Query<&mut bevy::ui::widget::Text, With<RevisitTimelineEndText>>,
+    )>,
+) {
+    if site.is_changed() {
+        let label = match (site.picking, site.lat_lon_deg) {
+            (true, _) => "Site: click the globe...".to_string(),
+            (false, Some((lat, lon))) => format!("Site: {lat:.2}, {lon:.2}"),
+            (false, None) => "Site: not set".to_string(),
+        };
+        for mut text in &mut texts.p0() {
+            text.0 = label.clone();
+        }
+    }
+
+    if !state.is_changed() {
+        return;
+    }
+    let status = state.status.as_deref().unwrap_or("No analysis run yet");
+    for mut text in &mut texts.p1() {
+        text.0 = status.to_string();
+    }
+
+    let Some(report) = state.report.as_ref() else {
+        return;
+    };
+
+    let mut stats = format!(
+        "Passes: {} ({} merged)\nCovered: {:.1}%",
+        report.accesses.len(),
+        report.merged.len(),
+        report.percent_covered()
+    );
+    if let Some(gap) = report.longest_gap() {
+        stats.push_str(&format!(
+            "\nLongest gap: {} from {}",
+            format_duration_s(gap.duration_s()),
+            gap.start_utc.format("%m-%d %H:%M")
+        ));
+    }
+    if let Some(mean_gap) = report.mean_gap_s() {
+        stats.push_str(&format!("\nMean gap: {}", format_duration_s(mean_gap)));
+    }
+    for mut text in &mut texts.p2() {
+        text.0 = stats.clone();
+    }
+    for mut text in &mut texts.p3() {
+        text.0 = report.start_utc.format("%m-%d %H:%M").to_string();
+    }
+    for mut text in &mut texts.p4() {
+        text.0 = report.end_utc.format("%m-%d %H:%M").to_string();
+    }
+
+    // Rebuild the timeline: one bar per merged access, longest gap underlined
+    let window_s = report.window_s().max(1.0);
+    let percent_of = |t: DateTime<Utc>| {
+        (((t - report.start_utc).num_milliseconds() as f64 / 1000.0) / window_s * 100.0) as f32
+    };
+    for timeline in &timelines {
+        commands.entity(timeline).despawn_children();
+        commands.entity(timeline).with_children(|bar| {
+            for &(start, end) in &report.merged {
+                let left = percent_of(start);
+                bar.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(left),
+                        width: Val::Percent((percent_of(end) - left).max(0.3)),
+                        top: Val::Px(2.0),
+                        bottom: Val::Px(4.0),
+                        ..default()
+                    },
+                    BackgroundColor(PANEL_TEXT_ACCENT.with_alpha(0.8)),
+                ));
+            }
+            if let Some(gap) = report.longest_gap() {
+                let left = percent_of(gap.start_utc);
+                bar.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(left),
+                        width: Val::Percent(percent_of(gap.end_utc) - left),
+                        bottom: Val::Px(0.0),
+                        height: Val::Px(2.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(1.0, 0.35, 0.35)),
+                ));
+            }
+        });
+    }
+}
+
+fn format_duration_s(seconds: f64) -> String {
+    let total = seconds.round().max(0.0) as i64;
+    let (hours, minutes, secs) = (total / 3600, (total % 3600) / 60, total % 60);
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m")
+    } else if minutes > 0 {
+        format!("{minutes}m {secs:02}s")
+    } else {
+        format!("{secs}s")
+    }
+}
+
+pub(crate) fn update_coverage_status_text(
+    state: Res<CoverageState>,
+    mut texts: Query<&mut bevy::ui::widget::Text, With<CoverageStatusText>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    let status = state.status.as_deref().unwrap_or("No analysis run yet");
+    for mut text in &mut texts {
+        text.0 = status.to_string();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_satellite_details(
+    satellites: Query<
+        (
+            &NoradId,
+            Option<&SatelliteName>,
+            &SatelliteFlags,
+            Option<&TleComponent>,
+            Option<&PropagationError>,
+            Option<&WorldEcefKm>,
+            Option<&ReentryPrediction>,
+        ),
+        With<Satellite>,
+    >,
+    selected: Res<SelectedSatellite>,
+    norad_index: Res<NoradIndex>,
+    decay_cfg: Res<DecayConfig>,
+    sim_time: Res<SimulationTime>,
+    mut texts: Query<&mut bevy::ui::widget::Text, With<SatelliteDetailsText>>,
+) {
+    // Tracked satellite first, otherwise the one last clicked on the globe
+    let target = selected
+        .tracking
+        .and_then(|norad| norad_index.map.get(&norad))
+        .and_then(|&entity| satellites.get(entity).ok())
+        .or_else(|| satellites.iter().find(|(_, _, flags, ..)| flags.is_clicked));
+
+    let details = match target {
+        None => "Select or track a satellite".to_string(),
+        Some((norad, name_opt, _, tle_opt, error_opt, world_opt, reentry_opt)) => {
+            let now = sim_time.current_utc;
+            let mut lines = vec![format!(
+                "{} ({})",
+                name_opt.map_or("Unnamed", |n| n.0.as_str()),
+                norad.0
+            )];
+            if let Some(tle) = tle_opt {
+                lines.push(format!(
+                    "TLE epoch: {} ({} old)",
+                    tle.0.epoch_utc.format("%Y-%m-%d %H:%M"),
+                    format_day_span(tle.0.age_days(now))
+                ));
+            }
+            lines.push(match error_opt {
+                Some(error) => format!("Status: {} - {}", error.kind.label(), error.message),
+                None => "Status: OK".to_string(),
+            });
+            if let Some(world) = world_opt {
+                lines.push(format!(
+                    "Altitude: {:.0} km",
+                    world.0.length() - EARTH_RADIUS_KM_F64
+                ));
+            }
+            if let Some(reentry) = reentry_opt {
+                let prediction = &reentry.0;
+                lines.push(format!(
+                    "Re-entry: {} (in {}, {})",
+                    prediction.reentry_utc.format("%Y-%m-%d"),
+                    format_day_span(prediction.days_until(now)),
+                    prediction.method.label()
+                ));
+                lines.push(format!(
+                    "Window: {} to {}",
+                    prediction.earliest_utc.format("%Y-%m-%d"),
+                    prediction.latest_utc.format("%Y-%m-%d")
+                ));
+                lines.push(format!(
+                    "Decay: {:.2} km/day at {:.0} km",
+                    prediction.decay_rate_km_per_day, prediction.altitude_km
+                ));
+                if decay_cfg.is_flagged(prediction, now) {
+                    lines.push(format!(
+                        "Flagged: may re-enter within {:.0} days",
+                        decay_cfg.flag_within_days
+                    ));
+                }
+            }
+            lines.join("\n")
+        }
+    };
+
+    for mut text in &mut texts {
+        if text.0 != details {
+            text.0 = details.clone();
+        }
+    }
+}
+
+/// Maneuver timeline and recent-maneuver list for the detail target
+pub(crate) fn update_maneuver_timeline(
+    satellites: Query<(&NoradId, &SatelliteFlags, Option<&ManeuverHistory>), With<Satellite>>,
+    selected: Res<SelectedSatellite>,
+    norad_index: Res<NoradIndex>,
+    timelines: Query<Entity, With<ManeuverTimeline>>,
+    mut texts: Query<&mut bevy::ui::widget::Text, With<ManeuverListText>>,
+    mut shown: Local<Option<(u32, usize)>>,
+    mut commands: Commands,
+) {
+    const LISTED_MANEUVERS: usize = 5;
+
+    let target = selected
+        .tracking
+        .and_then(|norad| norad_index.map.get(&norad))
+        .and_then(|&entity| satellites.get(entity).ok())
+        .or_else(|| satellites.iter().find(|(_, flags, _)| flags.is_clicked));
+    let maneuvers = target
+        .and_then(|(_, _, history)| history)
+        .map_or(&[][..], |history| history.0.as_slice());
+
+    // Rebuild only when the target or its history changes
+    let key = target.map(|(norad, ..)| (norad.0, maneuvers.len()));
+    if *shown == key {
+        return;
+    }
+    *shown = key;
+
+    let listing = if target.is_none() {
+        String::new()
+    } else if maneuvers.is_empty() {
+        "None detected".to_string()
+    } else {
+        maneuvers
+            .iter()
+            .rev()
+            .take(LISTED_MANEUVERS)
+            .map(|m| {
+                format!(
+                    "{}  {}  {:+.1} km  {:.1} m/s",
+                    m.midpoint_utc().format("%m-%d %H:%M"),
+                    m.label(),
+                    m.delta_sma_km,
+                    m.delta_v_m_s
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    for mut text in &mut texts {
+        text.0 = listing.clone();
+    }
+
+    // Bars span each before/after epoch pair, padded by a day on both ends
+    let (Some(first), Some(last)) = (maneuvers.first(), maneuvers.last()) else {
+        for timeline in &timelines {
+            commands.entity(timeline).despawn_children();
+        }
+        return;
+    };
+    let start = first.before_utc - chrono::Duration::days(1);
+    let end = last.after_utc + chrono::Duration::days(1);
+    let span_s = ((end - start).num_seconds() as f32).max(1.0);
+    let percent_of = |t: DateTime<Utc>| (t - start).num_seconds() as f32 / span_s * 100.0;
+    for timeline in &timelines {
+        commands.entity(timeline).despawn_children();
+        commands.entity(timeline).with_children(|bar| {
+            for maneuver in maneuvers {
+                let left = percent_of(maneuver.before_utc);
+                let color = if maneuver.plane_change {
+                    Color::srgb(1.0, 0.6, 0.2)
+                } else {
+                    PANEL_TEXT_ACCENT
+                };
+                bar.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(left),
+                        width: Val::Percent((percent_of(maneuver.after_utc) - left).max(1.0)),
+                        top: Val::Px(2.0),
+                        bottom: Val::Px(2.0),
+                        ..default()
+                    },
+                    BackgroundColor(color.with_alpha(0.85)),
+                ));
+            }
+        });
+    }
+}
+
+/// Live range, range rate and Doppler-corrected frequencies for the detail target
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_radio_readout(
+    satellites: Query<
+        (
+            &NoradId,
+            &SatelliteFlags,
+            Option<&WorldEcefKm>,
+            Option<&WorldVelocityEcefKmS>,
+        ),
+        With<Satellite>,
+    >,
+    selected: Res<SelectedSatellite>,
+    norad_index: Res<NoradIndex>,
+    site: Res<AnalysisSite>,
+    db: Res<TransmitterDb>,
+    radio_cfg: Res<RadioConfig>,
+    mut texts: Query<&mut bevy::ui::widget::Text, With<RadioReadoutText>>,
+) {
+    let target = detail_target_norad(
+        &selected,
+        &norad_index,
+        satellites
+            .iter()
+            .map(|(norad, flags, ..)| (norad.0, flags.is_clicked)),
+    );
+
+    let readout = match target {
+        None => "Select or track a satellite".to_string(),
+        Some(norad) => {
+            let transmitter = db.pick(norad, radio_cfg.transmitter_index);
+            let mut lines = vec![match transmitter {
+                Some((index, transmitter)) => format!(
+                    "Transmitter {}/{}: {}",
+                    index + 1,
+                    db.for_norad(norad).len(),
+                    transmitter.label()
+                ),
+                None => db
+                    .error
+                    .clone()
+                    .unwrap_or_else(|| format!("No transmitters for {norad}")),
+            }];
+            let state = norad_index
+                .map
+                .get(&norad)
+                .and_then(|&entity| satellites.get(entity).ok())
+                .and_then(|(_, _, position, velocity)| Some((position?.0, velocity?.0)));
+            match (site.lat_lon_deg, state) {
+                (None, _) => lines.push("Pick a site in Revisit Analysis".to_string()),
+                (_, None) => lines.push("No position yet".to_string()),
+                (Some((lat_deg, lon_deg)), Some((position, velocity))) => {
+                    let ground = site_ecef_km(lat_deg, lon_deg);
+                    let (range_km, rate_km_s) = range_and_rate_km(ground, position, velocity);
+                    lines.push(format!(
+                        "El {:.1} deg  Range {range_km:.0} km  Rate {rate_km_s:+.3} km/s",
+                        elevation_deg_ecef_dvec(ground, position)
+                    ));
+                    if let Some((_, transmitter)) = transmitter {
+                        if let Some(nominal) = transmitter.downlink_center_hz() {
+                            let hz = downlink_hz(nominal, rate_km_s);
+                            lines.push(format_corrected_frequency("Down", nominal, hz));
+                        }
+                        if let Some(nominal) = transmitter.uplink_center_hz() {
+                            let hz = uplink_hz(nominal, rate_km_s);
+                            lines.push(format_corrected_frequency("Up", nominal, hz));
+                        }
+                    }
+                }
+            }
+            lines.join("\n")
+        }
+    };
+
+    for mut text in &mut texts {
+        if text.0 != readout {
+            text.0 = readout.clone();
+        }
+    }
+}
+
+/// Doppler curve of the planned pass: downlink shift, or range rate without a downlink
+pub(crate) fn update_doppler_curve(
+    radio_state: Res<RadioState>,
+    curves: Query<Entity, With<DopplerCurve>>,
+    mut status_texts: Query<
+        &mut bevy::ui::widget::Text,
+        (With<RadioPassStatusText>, Without<DopplerCurveText>),
+    >,
+    mut curve_texts: Query<
+        &mut bevy::ui::widget::Text,
+        (With<DopplerCurveText>, Without<RadioPassStatusText>),
+    >,
+    mut commands: Commands,
+) {
+    if !radio_state.is_changed() {
+        return;
+    }
+    if let Some(status) = &radio_state.status {
+        for mut text in &mut status_texts {
+            text.0 = status.clone();
+        }
+    }
+    for curve in &curves {
+        commands.entity(curve).despawn_children();
+    }
+    let Some(pass) = &radio_state.pass else {
+        for mut text in &mut curve_texts {
+            text.0 = String::new();
+        }
+        return;
+    };
+
+    let values: Vec<(DateTime<Utc>, f64)> = pass
+        .samples
+        .iter()
+        .map(|sample| {
+            let value = match (sample.downlink_hz, pass.downlink_nominal_hz) {
+                (Some(hz), Some(nominal)) => hz - nominal,
+                _ => sample.range_rate_km_s,
+            };
+            (sample.utc, value)
+        })
+        .collect();
+    let scale = values
+        .iter()
+        .map(|(_, value)| value.abs())
+        .fold(1.0e-9, f64::max);
+    let span_s = ((pass.los_utc - pass.aos_utc).num_milliseconds() as f32 / 1000.0).max(1.0);
+    for curve in &curves {
+        commands.entity(curve).with_children(|plot| {
+            // Zero line: no shift at closest approach
+            plot.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    right: Val::Px(0.0),
+                    top: Val::Percent(50.0),
+                    height: Val::Px(1.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.5, 0.6, 0.7, 0.4)),
+            ));
+            for (utc, value) in &values {
+                let x = (*utc - pass.aos_utc).num_milliseconds() as f32 / 1000.0 / span_s;
+                plot.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(x * 98.0),
+                        bottom: Val::Percent(48.0 + (value / scale) as f32 * 45.0),
+                        width: Val::Px(3.0),
+                        height: Val::Px(3.0),
+                        ..default()
+                    },
+                    BackgroundColor(PANEL_TEXT_ACCENT),
+                ));
+            }
+        });
+    }
+
+    let label = pass.transmitter.as_deref().unwrap_or("No transmitter");
+    let range = match pass.downlink_shift_range_hz() {
+        Some((low, high)) => format!(
+            "Downlink shift {:+.2} to {:+.2} kHz",
+            high / 1.0e3,
+            low / 1.0e3
+        ),
+        None => {
+            let (low, high) = values
+                .iter()
+                .fold((f64::MAX, f64::MIN), |(lo, hi), (_, v)| {
+                    (lo.min(*v), hi.max(*v))
+                });
+            format!("Range rate {low:+.2} to {high:+.2} km/s")
+        }
+    };
+    for mut text in &mut curve_texts {
+        text.0 = format!("{label}\n{range}");
+    }
+}
+
+pub(crate) fn update_lighting_plots(
+    lighting_state: Res<LightingState>,
+    beta_curves: Query<Entity, (With<BetaAngleCurve>, Without<EclipseCurve>)>,
+    eclipse_curves: Query<Entity, (With<EclipseCurve>, Without<BetaAngleCurve>)>,
+    mut status_texts: Query<
+        &mut bevy::ui::widget::Text,
+        (With<LightingStatusText>, Without<EclipseSeasonsText>),
+    >,
+    mut season_texts: Query<
+        &mut bevy::ui::widget::Text,
+        (With<EclipseSeasonsText>, Without<LightingStatusText>),
+    >,
+    mut commands: Commands,
+) {
+    if !lighting_state.is_changed() {
+        return;
+    }
+    if let Some(status) = &lighting_state.status {
+        for mut text in &mut status_texts {
+            text.0 = status.clone();
+        }
+    }
+    for curve in beta_curves.iter().chain(eclipse_curves.iter()) {
+        commands.entity(curve).despawn_children();
+    }
+    let Some(profile) = &lighting_state.profile else {
+        for mut text in &mut season_texts {
+            text.0 = String::new();
+        }
+        return;
+    };
+
+    let span_s = ((profile.end_utc - profile.start_utc).num_seconds() as f32).max(1.0);
+    let x_of = |utc: DateTime<Utc>| (utc - profile.start_utc).num_seconds() as f32 / span_s;
+    let dot = |left: f32, bottom: f32, color: Color| {
+        (
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(left * 98.0),
+                bottom: Val::Percent(bottom.clamp(0.0, 96.0)),
+                width: Val::Px(3.0),
+                height: Val::Px(3.0),
+                ..default()
+            },
+            BackgroundColor(color),
+        )
+    };
+    for curve in &beta_curves {
+        commands.entity(curve).with_children(|plot| {
+            // Zero line: Sun in the orbit plane
+            plot.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    right: Val::Px(0.0),
+                    top: Val::Percent(50.0),
+                    height: Val::Px(1.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.5, 0.6, 0.7, 0.4)),
+            ));
+            for sample in &profile.samples {
+                plot.spawn(dot(
+                    x_of(sample.utc),
+                    48.0 + sample.beta_deg as f32 / 90.0 * 45.0,
+                    PANEL_TEXT_ACCENT,
+                ));
+            }
+        });
+    }
+    let max_minutes = profile.max_eclipse_minutes().max(1.0);
+    for curve in &eclipse_curves {
+        commands.entity(curve).with_children(|plot| {
+            for sample in &profile.samples {
+                plot.spawn(dot(
+                    x_of(sample.utc),
+                    2.0 + (sample.eclipse_minutes / max_minutes) as f32 * 90.0,
+                    Color::srgb(0.95, 0.6, 0.3),
+                ));
+            }
+        });
+    }
+
+    for mut text in &mut season_texts {
+        text.0 = format!(
+            "NORAD {}  period {:.1} min\nBeta -90..+90 deg, eclipse 0..{:.0} min\n{}",
+            profile.norad,
+            profile.period_min,
+            max_minutes,
+            profile.seasons_text()
+        );
+    }
+}
+
+/// Most opportunities listed in the panel; all of them go on the timeline
+const LAUNCH_WINDOW_LIST_LIMIT: usize = 12;
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_launch_window_texts(
+    config: Res<LaunchWindowConfig>,
+    state: Res<LaunchWindowState>,
+    mut texts: ParamSet<(
+        Query<(&mut bevy::ui::widget::Text, &LaunchWindowChoiceText)>,
+        Query<&mut bevy::ui::widget::Text, With<LaunchWindowStatusText>>,
+        Query<&mut bevy::ui::widget::Text, With<LaunchWindowResultsText>>,
+    )>,
+) {
+    if config.is_changed() {
+        for (mut text, choice) in &mut texts.p0() {
+            text.0 = match choice {
+                LaunchWindowChoiceText::Site => format!("Site: {}", config.site.label()),
+                LaunchWindowChoiceText::Target => format!("Target: {}", config.target.label()),
+            };
+        }
+    }
+    if !state.is_changed() {
+        return;
+    }
+    if let Some(status) = &state.status {
+        for mut text in &mut texts.p1() {
+            text.0 = status.clone();
+        }
+    }
+
+    let listing = state.report.as_ref().map_or_else(String::new, |report| {
+        let mut lines: Vec<String> = report
+            .opportunities
+            .iter()
+            .take(LAUNCH_WINDOW_LIST_LIMIT)
+            .map(|opportunity| {
+                format!(
+                    "{}  {}bound  az {:.1} deg",
+                    opportunity.utc.format("%Y-%m-%d %H:%M:%S UTC"),
+                    opportunity.direction.label(),
+                    opportunity.azimuth_deg
+                )
+            })
+            .collect();
+        if let Some(more) = report
+            .opportunities
+            .len()
+            .checked_sub(LAUNCH_WINDOW_LIST_LIMIT)
+            .filter(|more| *more > 0)
+        {
+            lines.push(format!("+{more} more on the timeline"));
+        }
+        lines.join("\n")
+    });
+    for mut text in &mut texts.p2() {
+        text.0 = listing.clone();
+    }
+}
+
+/// Corrected frequency with its offset from nominal, e.g. "Down 145.803404 MHz (+3.40 kHz)"
+fn format_corrected_frequency(label: &str, nominal_hz: f64, corrected_hz: f64) -> String {
+    format!(
+        "{label} {:.6} MHz ({:+.2} kHz)",
+        corrected_hz / 1.0e6,
+        (corrected_hz - nominal_hz) / 1.0e3
+    )
+}
+
+pub(crate) fn handle_heatmap_metric_change(
+    ev: On<ValueChange<bool>>,
+    q_metric: Query<&HeatmapMetricBinding>,
+    q_dop_group: Query<&DopGroupBinding>,
+    mut heatmap_cfg: ResMut<HeatmapConfig>,
+) {
+    if !ev.value {
+        return;
+    }
+    if let Ok(binding) = q_metric.get(ev.source) {
+        heatmap_cfg.metric = binding.0;
+    } else if let Ok(binding) = q_dop_group.get(ev.source) {
+        heatmap_cfg.dop_group = binding.0.map(str::to_string);
+    }
+}
+
+pub(crate) fn handle_revisit_target_change(
+    ev: On<ValueChange<bool>>,
+    q_binding: Query<&RevisitTargetBinding>,
+    mut revisit_cfg: ResMut<RevisitConfig>,
+) {
+    let Ok(binding) = q_binding.get(ev.source) else {
+        return;
+    };
+    if ev.value {
+        revisit_cfg.targets = binding.0;
+    }
+}
+
+pub(crate) fn handle_coverage_metric_change(
+    ev: On<ValueChange<bool>>,
+    q_binding: Query<&CoverageMetricBinding>,
+    mut coverage_cfg: ResMut<CoverageConfig>,
+) {
+    let Ok(binding) = q_binding.get(ev.source) else {
+        return;
+    };
+    if ev.value {
+        coverage_cfg.metric = binding.0;
+    }
+}
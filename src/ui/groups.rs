@@ -207,6 +207,26 @@ pub const SATELLITE_GROUPS: &[(&str, &str)] = &[
     ),
 ];
 
+/// Navigation constellations offered as DOP sources in the heatmap (subset of `SATELLITE_GROUPS`)
+pub const GNSS_GROUPS: &[(&str, &str)] = &[
+    (
+        "https://celestrak.org/NORAD/elements/gp.php?GROUP=gps-ops&FORMAT=TLE",
+        "GPS",
+    ),
+    (
+        "https://celestrak.org/NORAD/elements/gp.php?GROUP=galileo&FORMAT=TLE",
+        "Galileo",
+    ),
+    (
+        "https://celestrak.org/NORAD/elements/gp.php?GROUP=glo-ops&FORMAT=TLE",
+        "GLONASS",
+    ),
+    (
+        "https://celestrak.org/NORAD/elements/gp.php?GROUP=beidou&FORMAT=TLE",
+        "Beidou",
+    ),
+];
+
 /// Creates default colors for satellite groups using golden angle distribution
 ///
 /// Uses the golden angle (137.5°) to distribute hues evenly around the color wheel,
@@ -0,0 +1,1536 @@
+//! Launch Library panels: launch and event lists, the detail popup, alert banner
+//! and space station readout
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::ecs::spawn::Spawn;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::Key;
+use bevy::picking::Pickable;
+use bevy::prelude::*;
+use bevy::text::{TextColor, TextLayout};
+use bevy::ui::RelativeCursorPosition;
+use bevy::ui::auto_directional_navigation::AutoDirectionalNavigation;
+use bevy::window::SystemCursorIcon;
+use bevy_feathers::controls::{ButtonProps, checkbox};
+use bevy_feathers::cursor::EntityCursor;
+use bevy_feathers::theme::ThemedText;
+use bevy_input_focus::{FocusedInput, tab_navigation::TabIndex};
+use bevy_ui_widgets::Button as UiWidgetButton;
+
+use crate::core::coordinates::Coordinates;
+use crate::core::space::ecef_to_bevy_km;
+use crate::launch_library::stations::{docked_vehicles, station_norad_id, upcoming_moves};
+use crate::launch_library::{
+    LaunchAlertTracker, LaunchDetail, LaunchFilter, LaunchFilterField, LaunchLibraryData,
+    LaunchLibraryState, filtered_indices,
+};
+use crate::orbital::time::SimulationTime;
+use crate::satellite::SelectedSatellite;
+use crate::satellite::resources::NoradIndex;
+use crate::tle::FetchChannels;
+use crate::ui::state::{
+    CameraFocusState, CameraFocusTarget, CameraPose, LaunchLibraryItemKind, LaunchLibraryUiState,
+};
+use crate::ui::systems::{
+    ButtonAction, CheckboxBinding, MainCameraQuery, PANEL_BG, PANEL_EDGE, PANEL_TEXT_ACCENT,
+    UiEntities, clear_list_children, format_time, latest_time, spawn_fixed_button,
+    spawn_satellite_for_norad, spawn_scrollbar, truncate_text,
+};
+
+#[derive(Component)]
+struct LaunchList;
+
+#[derive(Component)]
+struct EventList;
+
+#[derive(Component)]
+pub(crate) struct LaunchLibraryUpdatedText;
+
+#[derive(Component)]
+pub(crate) struct LaunchLibraryErrorText;
+
+#[derive(Component)]
+pub(crate) struct LaunchCalendarStatusText;
+
+#[derive(Component)]
+pub(crate) struct SpaceStationStatusText;
+
+#[derive(Component)]
+pub(crate) struct SpaceStationBodyText;
+
+#[derive(Component)]
+pub(crate) struct LaunchAlertBanner;
+
+#[derive(Component)]
+pub(crate) struct LaunchAlertBannerText;
+
+#[derive(Component)]
+pub(crate) struct LaunchLibraryPopupOverlay;
+
+#[derive(Component)]
+pub(crate) struct LaunchLibraryPopupTitle;
+
+#[derive(Component)]
+pub(crate) struct LaunchLibraryPopupBody;
+
+#[derive(Component)]
+pub(crate) struct LaunchLibraryItemButton {
+    pub(crate) kind: LaunchLibraryItemKind,
+    pub(crate) index: usize,
+}
+
+#[derive(Component)]
+pub(crate) struct LaunchSearchField;
+
+#[derive(Component)]
+pub(crate) struct LaunchSearchValueText;
+
+#[derive(Component)]
+pub(crate) struct LaunchFilterSummaryText;
+
+#[derive(Component)]
+pub(crate) struct LaunchPagingText;
+
+/// Launch and event lists with search, filters and paging; returns (launch list, event list)
+pub(crate) fn spawn_launches_section(section: &mut ChildSpawnerCommands) -> (Entity, Entity) {
+    let mut launch_list = Entity::PLACEHOLDER;
+    let mut event_list = Entity::PLACEHOLDER;
+
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|row| {
+            row.spawn((checkbox(
+                (
+                    CheckboxBinding::LaunchPadMarkers,
+                    AutoDirectionalNavigation::default(),
+                ),
+                Spawn((bevy::ui::widget::Text::new("Show pad markers"), ThemedText)),
+            ),));
+            spawn_fixed_button(
+                row,
+                72.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::RefreshLaunchLibrary,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Refresh",
+            );
+        });
+
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|row| {
+            row.spawn((checkbox(
+                (
+                    CheckboxBinding::LaunchAlerts,
+                    AutoDirectionalNavigation::default(),
+                ),
+                Spawn((bevy::ui::widget::Text::new("Countdown alerts"), ThemedText)),
+            ),));
+            spawn_fixed_button(
+                row,
+                80.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::ExportLaunchCalendar,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Export .ics",
+            );
+        });
+    section.spawn((
+        LaunchCalendarStatusText,
+        bevy::ui::widget::Text::new(""),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+    ));
+
+    section.spawn((
+        LaunchLibraryUpdatedText,
+        bevy::ui::widget::Text::new("Updated: --"),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+    ));
+    section.spawn((
+        LaunchLibraryErrorText,
+        bevy::ui::widget::Text::new(""),
+        ThemedText,
+        TextColor(Color::srgb(1.0, 0.35, 0.35)),
+    ));
+
+    section.spawn((
+        bevy::ui::widget::Text::new("Upcoming Launches"),
+        ThemedText,
+        TextFont {
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(PANEL_TEXT_ACCENT),
+    ));
+
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(8.0),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+            ThemedText,
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    width: Val::Px(180.0),
+                    height: Val::Px(28.0),
+                    padding: UiRect::horizontal(Val::Px(6.0)),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.08, 0.1, 0.14, 1.0)),
+                ThemedText,
+                AutoDirectionalNavigation::default(),
+                TabIndex(0),
+                LaunchSearchField,
+            ))
+            .with_children(|field| {
+                field.spawn((
+                    LaunchSearchValueText,
+                    bevy::ui::widget::Text::new("Search launches"),
+                    ThemedText,
+                ));
+            });
+            spawn_fixed_button(
+                row,
+                64.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::ClearLaunchFilters,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Clear",
+            );
+        });
+
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(6.0),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+            ThemedText,
+        ))
+        .with_children(|row| {
+            for field in [
+                LaunchFilterField::Provider,
+                LaunchFilterField::Country,
+                LaunchFilterField::Orbit,
+                LaunchFilterField::Status,
+            ] {
+                spawn_fixed_button(
+                    row,
+                    64.0,
+                    ButtonProps::default(),
+                    (
+                        ButtonAction::CycleLaunchFilter(field),
+                        AutoDirectionalNavigation::default(),
+                    ),
+                    field.label(),
+                );
+            }
+        });
+
+    section.spawn((
+        LaunchFilterSummaryText,
+        bevy::ui::widget::Text::new("Filters: none"),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+    ));
+
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(6.0),
+                height: Val::Px(140.0),
+                width: Val::Percent(100.0),
+                min_width: Val::Px(0.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|container| {
+            let list_entity = container
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.0),
+                        width: Val::Percent(100.0),
+                        min_width: Val::Px(0.0),
+                        height: Val::Px(140.0),
+                        overflow: Overflow::scroll_y(),
+                        padding: UiRect::all(Val::Px(4.0)),
+                        ..default()
+                    },
+                    ScrollPosition::default(),
+                    ThemedText,
+                    LaunchList,
+                ))
+                .id();
+
+            spawn_scrollbar(container, list_entity, 140.0);
+            launch_list = list_entity;
+        });
+
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(8.0),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+            ThemedText,
+        ))
+        .with_children(|row| {
+            spawn_fixed_button(
+                row,
+                64.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::MoreLaunches,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "More",
+            );
+            row.spawn((
+                LaunchPagingText,
+                bevy::ui::widget::Text::new(""),
+                ThemedText,
+                TextFont {
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+            ));
+        });
+
+    section.spawn((
+        bevy::ui::widget::Text::new("Upcoming Events"),
+        ThemedText,
+        TextFont {
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(PANEL_TEXT_ACCENT),
+    ));
+
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(6.0),
+                height: Val::Px(120.0),
+                width: Val::Percent(100.0),
+                min_width: Val::Px(0.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|container| {
+            let list_entity = container
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.0),
+                        width: Val::Percent(100.0),
+                        min_width: Val::Px(0.0),
+                        height: Val::Px(120.0),
+                        overflow: Overflow::scroll_y(),
+                        padding: UiRect::all(Val::Px(4.0)),
+                        ..default()
+                    },
+                    ScrollPosition::default(),
+                    ThemedText,
+                    EventList,
+                ))
+                .id();
+
+            spawn_scrollbar(container, list_entity, 120.0);
+            event_list = list_entity;
+        });
+
+    (launch_list, event_list)
+}
+
+/// Station crew and docked vehicle readout
+pub(crate) fn spawn_space_stations_section(section: &mut ChildSpawnerCommands) {
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|row| {
+            row.spawn((
+                SpaceStationStatusText,
+                bevy::ui::widget::Text::new("Loading stations..."),
+                ThemedText,
+                TextFont {
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
+            ));
+            spawn_fixed_button(
+                row,
+                96.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::AddSpaceStations,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Add stations",
+            );
+        });
+    section.spawn((
+        SpaceStationBodyText,
+        bevy::ui::widget::Text::new(""),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+    ));
+}
+
+/// Banner across the top of the view for new launch alerts
+pub(crate) fn spawn_launch_alert_banner(parent: &mut ChildSpawnerCommands) {
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                right: Val::Px(0.0),
+                top: Val::Px(64.0),
+                justify_content: JustifyContent::Center,
+                display: Display::None,
+                ..default()
+            },
+            Pickable::IGNORE,
+            ThemedText,
+            LaunchAlertBanner,
+        ))
+        .with_children(|banner| {
+            banner.spawn((
+                LaunchAlertBannerText,
+                bevy::ui::widget::Text::new(""),
+                ThemedText,
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(PANEL_TEXT_ACCENT),
+                Node {
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(PANEL_BG),
+                Pickable::IGNORE,
+            ));
+        });
+}
+
+/// Launch and event detail popup, hidden until an item is opened
+pub(crate) fn spawn_launch_popup(parent: &mut ChildSpawnerCommands) {
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                right: Val::Px(0.0),
+                top: Val::Px(0.0),
+                bottom: Val::Px(0.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.03, 0.05, 0.55)),
+            Pickable::IGNORE,
+            ThemedText,
+            LaunchLibraryPopupOverlay,
+        ))
+        .with_children(|overlay| {
+            overlay
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(8.0),
+                        width: Val::Px(420.0),
+                        padding: UiRect::all(Val::Px(12.0)),
+                        ..default()
+                    },
+                    BackgroundColor(PANEL_BG),
+                    ThemedText,
+                ))
+                .with_children(|popup| {
+                    popup
+                        .spawn((
+                            Node {
+                                flex_direction: FlexDirection::Row,
+                                align_items: AlignItems::Center,
+                                column_gap: Val::Px(8.0),
+                                width: Val::Percent(100.0),
+                                ..default()
+                            },
+                            ThemedText,
+                        ))
+                        .with_children(|header| {
+                            header.spawn((
+                                LaunchLibraryPopupTitle,
+                                bevy::ui::widget::Text::new(""),
+                                ThemedText,
+                                TextFont {
+                                    font_size: 13.0,
+                                    ..default()
+                                },
+                                TextColor(PANEL_TEXT_ACCENT),
+                            ));
+                            header.spawn(Node {
+                                flex_grow: 1.0,
+                                min_width: Val::Px(0.0),
+                                ..default()
+                            });
+                            spawn_fixed_button(
+                                header,
+                                40.0,
+                                ButtonProps::default(),
+                                (
+                                    ButtonAction::CloseLaunchPopup,
+                                    AutoDirectionalNavigation::default(),
+                                ),
+                                "Close",
+                            );
+                        });
+
+                    popup.spawn((
+                        LaunchLibraryPopupBody,
+                        bevy::ui::widget::Text::new(""),
+                        ThemedText,
+                        TextFont {
+                            font_size: 11.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgba(0.7, 0.8, 0.9, 0.85)),
+                    ));
+
+                    popup
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::FlexEnd,
+                            ..default()
+                        })
+                        .with_children(|footer| {
+                            spawn_fixed_button(
+                                footer,
+                                100.0,
+                                ButtonProps::default(),
+                                (
+                                    ButtonAction::AddLaunchPayloads,
+                                    AutoDirectionalNavigation::default(),
+                                ),
+                                "Add payloads",
+                            );
+                        });
+                });
+        });
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_launch_filter_texts(
+    data: Res<LaunchLibraryData>,
+    state: Res<LaunchLibraryState>,
+    filter: Res<LaunchFilter>,
+    mut texts: ParamSet<(
+        Query<&mut bevy::ui::widget::Text, With<LaunchSearchValueText>>,
+        Query<&mut bevy::ui::widget::Text, With<LaunchFilterSummaryText>>,
+        Query<&mut bevy::ui::widget::Text, With<LaunchPagingText>>,
+    )>,
+) {
+    if !data.is_changed() && !state.is_changed() && !filter.is_changed() {
+        return;
+    }
+
+    for mut text in &mut texts.p0() {
+        text.0 = if filter.search.is_empty() {
+            "Search launches".to_string()
+        } else {
+            filter.search.clone()
+        };
+    }
+
+    let active: Vec<String> = [
+        LaunchFilterField::Provider,
+        LaunchFilterField::Country,
+        LaunchFilterField::Orbit,
+        LaunchFilterField::Status,
+    ]
+    .into_iter()
+    .filter_map(|field| {
+        filter
+            .field(field)
+            .map(|value| format!("{} {value}", field.label().to_lowercase()))
+    })
+    .collect();
+    for mut text in &mut texts.p1() {
+        text.0 = if active.is_empty() {
+            "Filters: none".to_string()
+        } else {
+            truncate_text(&format!("Filters: {}", active.join(", ")), 70)
+        };
+    }
+
+    let shown = filtered_indices(&data.launches, &filter).len();
+    let total = data
+        .launches_total
+        .map_or_else(String::new, |total| format!(" of {total}"));
+    for mut text in &mut texts.p2() {
+        text.0 = if state.is_loading_more_launches {
+            "Loading next page...".to_string()
+        } else if data.launches_next.is_none() {
+            format!("Showing {shown} / {} loaded (all)", data.launches.len())
+        } else {
+            format!("Showing {shown} / {} loaded{total}", data.launches.len())
+        };
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_launch_library_texts(
+    data: Res<LaunchLibraryData>,
+    state: Res<LaunchLibraryState>,
+    mut texts: ParamSet<(
+        Query<&mut bevy::ui::widget::Text, With<LaunchLibraryUpdatedText>>,
+        Query<&mut bevy::ui::widget::Text, With<LaunchLibraryErrorText>>,
+        Query<&mut bevy::ui::widget::Text, With<LaunchCalendarStatusText>>,
+    )>,
+) {
+    if !data.is_changed() && !state.is_changed() {
+        return;
+    }
+
+    let latest = latest_time([state.last_launch_update, state.last_event_update, None]);
+    let counts = format!(
+        "Launches: {} | Events: {}",
+        data.launches.len(),
+        data.events.len()
+    );
+
+    let from_cache = state.launches_from_cache || state.events_from_cache;
+    for mut text in &mut texts.p0() {
+        let updated = format_time(latest);
+        text.0 = match (
+            state.is_loading_launches || state.is_loading_events,
+            from_cache,
+        ) {
+            (true, true) => format!("Cached {updated}, refreshing... ({counts})"),
+            (true, false) => "Loading...".to_string(),
+            (false, true) => format!("Cached {updated} (stale, {counts})"),
+            (false, false) => format!("Updated: {updated} ({counts})"),
+        };
+    }
+
+    for mut text in &mut texts.p1() {
+        let err = state
+            .launch_error
+            .as_deref()
+            .or(state.event_error.as_deref());
+        text.0 = err.map(|e| format!("Data error: {e}")).unwrap_or_default();
+    }
+
+    for mut text in &mut texts.p2() {
+        text.0 = state.calendar_status.clone().unwrap_or_default();
+    }
+}
+
+/// How long new launch alerts stay on screen (seconds)
+const LAUNCH_ALERT_BANNER_SECONDS: f32 = 12.0;
+
+/// Most alerts shown in the banner at once
+const LAUNCH_ALERT_BANNER_LINES: usize = 3;
+
+/// Show newly raised launch alerts in a banner at the top of the screen
+pub(crate) fn update_launch_alert_banner(
+    tracker: Res<LaunchAlertTracker>,
+    time: Res<Time>,
+    mut shown: Local<(usize, f32)>,
+    mut banners: Query<&mut Node, With<LaunchAlertBanner>>,
+    mut texts: Query<&mut bevy::ui::widget::Text, With<LaunchAlertBannerText>>,
+) {
+    let now = time.elapsed_secs();
+    let (seen, shown_at) = &mut *shown;
+    if tracker.raised != *seen {
+        let fresh = (tracker.raised - *seen)
+            .min(tracker.alerts.len())
+            .min(LAUNCH_ALERT_BANNER_LINES);
+        let message = tracker.alerts[tracker.alerts.len() - fresh..]
+            .iter()
+            .map(|alert| alert.message())
+            .collect::<Vec<_>>()
+            .join("\n");
+        for mut text in &mut texts {
+            text.0 = message.clone();
+        }
+        *seen = tracker.raised;
+        *shown_at = now;
+    }
+
+    let display = if *seen > 0 && now - *shown_at < LAUNCH_ALERT_BANNER_SECONDS {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    for mut node in &mut banners {
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
+
+/// Queue a `/launches/{id}/` fetch when a launch without detail is opened
+pub(crate) fn request_selected_launch_detail(
+    launch_ui: Res<LaunchLibraryUiState>,
+    data: Res<LaunchLibraryData>,
+    mut state: ResMut<LaunchLibraryState>,
+) {
+    if !launch_ui.is_changed() {
+        return;
+    }
+    let Some(uuid) = launch_ui
+        .selection
+        .and_then(|selection| selection.launch(&data))
+        .and_then(|launch| launch.uuid.as_ref())
+    else {
+        return;
+    };
+    if !data.details.contains_key(uuid) && state.loading_detail.as_ref() != Some(uuid) {
+        state.pending_detail = Some(uuid.clone());
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_launch_library_popup(
+    data: Res<LaunchLibraryData>,
+    state: Res<LaunchLibraryState>,
+    launch_ui: Res<LaunchLibraryUiState>,
+    mut overlay_nodes: Query<&mut Node, With<LaunchLibraryPopupOverlay>>,
+    mut texts: ParamSet<(
+        Query<&mut bevy::ui::widget::Text, With<LaunchLibraryPopupTitle>>,
+        Query<&mut bevy::ui::widget::Text, With<LaunchLibraryPopupBody>>,
+    )>,
+) {
+    if !data.is_changed() && !launch_ui.is_changed() && !state.is_changed() {
+        return;
+    }
+
+    let Ok(mut overlay) = overlay_nodes.single_mut() else {
+        return;
+    };
+
+    let Some(selection) = launch_ui.selection else {
+        overlay.display = Display::None;
+        return;
+    };
+
+    let (title, body) = match selection.kind {
+        LaunchLibraryItemKind::Launch | LaunchLibraryItemKind::PastLaunch => {
+            let Some(launch) = selection.launch(&data) else {
+                overlay.display = Display::None;
+                return;
+            };
+
+            let mut lines = Vec::new();
+            lines.push(format!("NET: {}", format_time(launch.net_utc)));
+            if let Some(provider) = launch.provider_name.as_deref() {
+                lines.push(format!("Provider: {provider}"));
+            }
+            if let Some(mission) = launch.mission_name.as_deref() {
+                lines.push(format!("Mission: {mission}"));
+            }
+            if let Some(orbit) = launch.orbit_name.as_deref() {
+                lines.push(format!("Orbit: {orbit}"));
+            }
+            if let Some(pad) = launch.pad_name.as_deref() {
+                lines.push(format!("Pad: {pad}"));
+            }
+            if let Some(location) = launch.pad_location_name.as_deref() {
+                lines.push(format!("Location: {location}"));
+            }
+            if let Some(cospar) = launch.cospar_id.as_deref() {
+                lines.push(format!("COSPAR: {cospar}"));
+            }
+
+            if let Some(uuid) = launch.uuid.as_ref() {
+                if let Some(detail) = data.details.get(uuid) {
+                    push_launch_detail_lines(&mut lines, detail);
+                } else {
+                    lines.push(String::new());
+                    // Another launch's failure shouldn't show here
+                    match state.detail_error.as_ref() {
+                        Some((failed, error)) if failed == uuid => {
+                            lines.push(format!("Details unavailable: {error}"));
+                        }
+                        _ => lines.push("Loading details...".to_string()),
+                    }
+                }
+            }
+
+            (launch.name.clone(), lines.join("\n"))
+        }
+        LaunchLibraryItemKind::Event => {
+            let Some(event) = data.events.get(selection.index) else {
+                overlay.display = Display::None;
+                return;
+            };
+
+            let mut lines = Vec::new();
+            lines.push(format!("Date: {}", format_time(event.date_utc)));
+            if let Some(type_name) = event.type_name.as_deref() {
+                lines.push(format!("Type: {type_name}"));
+            }
+            if let Some(location) = event.location.as_deref() {
+                lines.push(format!("Location: {location}"));
+            }
+            if let Some(description) = event.description.as_deref() {
+                lines.push(String::new());
+                lines.push(description.to_string());
+            }
+
+            (event.name.clone(), lines.join("\n"))
+        }
+    };
+
+    overlay.display = Display::Flex;
+
+    for mut text in &mut texts.p0() {
+        text.0 = title.clone();
+    }
+
+    for mut text in &mut texts.p1() {
+        text.0 = body.clone();
+    }
+}
+
+fn push_launch_detail_lines(lines: &mut Vec<String>, detail: &LaunchDetail) {
+    if let Some(status) = detail.status.as_deref() {
+        match detail.probability {
+            Some(probability) => lines.push(format!("Status: {status} ({probability}% go)")),
+            None => lines.push(format!("Status: {status}")),
+        }
+    }
+    if let Some(rocket) = detail.rocket_name.as_deref() {
+        let mut parts = Vec::new();
+        if let Some(family) = detail.rocket_family.as_deref() {
+            parts.push(format!("family {family}"));
+        }
+        if let Some(variant) = detail.rocket_variant.as_deref() {
+            parts.push(format!("variant {variant}"));
+        }
+        if parts.is_empty() {
+            lines.push(format!("Rocket: {rocket}"));
+        } else {
+            lines.push(format!("Rocket: {rocket} ({})", parts.join(", ")));
+        }
+    }
+    if detail.window_start.is_some() || detail.window_end.is_some() {
+        lines.push(format!(
+            "Window: {} to {}",
+            format_time(detail.window_start),
+            format_time(detail.window_end)
+        ));
+    }
+    if let Some(mission_type) = detail.mission_type.as_deref() {
+        lines.push(format!("Mission type: {mission_type}"));
+    }
+    if !detail.payload_norad_ids.is_empty() {
+        let ids: Vec<String> = detail
+            .payload_norad_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+        lines.push(format!("Payload NORAD IDs: {}", ids.join(", ")));
+    }
+    for landing in &detail.landings {
+        let outcome = match (landing.attempt, landing.success) {
+            (false, _) => "no attempt",
+            (true, None) => "planned",
+            (true, Some(true)) => "landed",
+            (true, Some(false)) => "failed",
+        };
+        let site = landing
+            .location_abbrev
+            .as_deref()
+            .or(landing.location_name.as_deref())
+            .unwrap_or("--");
+        let kind = landing.landing_type.as_deref().unwrap_or("Landing");
+        lines.push(format!(
+            "Landing: {} {kind} at {site} ({outcome})",
+            landing.vehicle
+        ));
+    }
+    if detail.webcast_live {
+        lines.push("Webcast: LIVE".to_string());
+    }
+    for webcast in detail.webcasts.iter().take(3) {
+        match webcast.title.as_deref() {
+            Some(title) => lines.push(format!("Watch: {title} - {}", webcast.url)),
+            None => lines.push(format!("Watch: {}", webcast.url)),
+        }
+    }
+    if let Some(description) = detail.mission_description.as_deref() {
+        lines.push(String::new());
+        lines.push(description.to_string());
+    }
+}
+
+pub(crate) fn focus_camera_on_launch_selection(
+    mut launch_ui: ResMut<LaunchLibraryUiState>,
+    data: Res<LaunchLibraryData>,
+    mut camera_focus: ResMut<CameraFocusState>,
+    mut selected: ResMut<SelectedSatellite>,
+    mut q_camera: MainCameraQuery<'_, '_>,
+) {
+    if !launch_ui.is_changed() {
+        return;
+    }
+
+    let Some(launch) = launch_ui
+        .selection
+        .and_then(|selection| selection.launch(&data))
+    else {
+        return;
+    };
+
+    let (Some(lat), Some(lon)) = (launch.pad_lat, launch.pad_lon) else {
+        return;
+    };
+
+    let Ok(coords) = Coordinates::from_degrees(lat as f32, lon as f32) else {
+        return;
+    };
+    let ecef = coords.get_point_on_sphere_ecef_km_dvec();
+    let bevy_pos = ecef_to_bevy_km(ecef);
+    let mut dir = bevy_pos.normalize_or_zero();
+    if dir.length_squared() < 1e-6 {
+        dir = Vec3::Z;
+    }
+
+    if let Ok((mut poc, mut cam_transform)) = q_camera.single_mut() {
+        selected.tracking = None;
+        selected.selected = None;
+        camera_focus.target = CameraFocusTarget::Earth;
+
+        let current_radius = poc.radius.unwrap_or(poc.target_radius).max(1.0);
+        let target_radius = current_radius.min(9_000.0);
+        let pitch = dir.y.asin();
+        let yaw = dir.x.atan2(dir.z);
+        let pose = CameraPose {
+            radius: target_radius,
+            yaw,
+            pitch,
+        };
+        launch_ui.camera_target = Some(pose);
+        poc.target_radius = target_radius;
+        poc.target_pitch = pitch;
+        poc.target_yaw = yaw;
+        poc.focus = Vec3::ZERO;
+        poc.force_update = true;
+        cam_transform.look_at(Vec3::ZERO, Vec3::Y);
+    }
+}
+
+pub(crate) fn animate_launch_camera_focus(
+    time: Res<Time>,
+    mut launch_ui: ResMut<LaunchLibraryUiState>,
+    mut q_camera: MainCameraQuery<'_, '_>,
+) {
+    let Some(target) = launch_ui.camera_target else {
+        return;
+    };
+
+    let Ok((mut poc, mut cam_transform)) = q_camera.single_mut() else {
+        return;
+    };
+
+    let dt = time.delta_secs();
+    let smooth_factor = 0.12_f32;
+    let lerp = 1.0_f32 - (1.0_f32 - smooth_factor).powf(dt * 60.0);
+
+    let mut radius = poc.radius.unwrap_or(poc.target_radius).max(1.0);
+    let mut pitch = poc.pitch.unwrap_or(poc.target_pitch);
+    let mut yaw = poc.yaw.unwrap_or(poc.target_yaw);
+
+    let mut yaw_diff = target.yaw - yaw;
+    if yaw_diff > std::f32::consts::PI {
+        yaw_diff -= 2.0 * std::f32::consts::PI;
+    } else if yaw_diff < -std::f32::consts::PI {
+        yaw_diff += 2.0 * std::f32::consts::PI;
+    }
+
+    radius += (target.radius - radius) * lerp;
+    pitch += (target.pitch - pitch) * lerp;
+    yaw += yaw_diff * lerp;
+
+    poc.radius = Some(radius);
+    poc.pitch = Some(pitch);
+    poc.yaw = Some(yaw);
+    poc.focus = Vec3::ZERO;
+    poc.target_radius = target.radius;
+    poc.target_pitch = target.pitch;
+    poc.target_yaw = target.yaw;
+    poc.force_update = true;
+
+    let camera_pos = Vec3::new(
+        radius * pitch.cos() * yaw.sin(),
+        radius * pitch.sin(),
+        radius * pitch.cos() * yaw.cos(),
+    );
+    cam_transform.translation = camera_pos;
+    cam_transform.look_at(Vec3::ZERO, Vec3::Y);
+
+    let done = (radius - target.radius).abs() < 5.0
+        && (pitch - target.pitch).abs() < 0.002
+        && (yaw - target.yaw).abs() < 0.002;
+    if done {
+        launch_ui.camera_target = None;
+    }
+}
+
+pub(crate) fn update_launch_library_lists(
+    data: Res<LaunchLibraryData>,
+    state: Res<LaunchLibraryState>,
+    filter: Res<LaunchFilter>,
+    launch_ui: Res<LaunchLibraryUiState>,
+    ui_entities: Res<UiEntities>,
+    children: Query<&Children>,
+    mut commands: Commands,
+) {
+    if !data.is_changed() && !state.is_changed() && !launch_ui.is_changed() && !filter.is_changed()
+    {
+        return;
+    }
+
+    let shown = filtered_indices(&data.launches, &filter);
+    let shown_past = filtered_indices(&data.previous_launches, &filter);
+    let muted = Color::srgba(0.6, 0.7, 0.8, 0.75);
+    clear_list_children(ui_entities.launch_list, &children, &mut commands);
+    commands
+        .entity(ui_entities.launch_list)
+        .with_children(|parent| {
+            if shown.is_empty() {
+                let message = if data.launches.is_empty() {
+                    "No upcoming launches."
+                } else {
+                    "No launches match the filters."
+                };
+                spawn_list_note(parent, message, muted);
+            } else {
+                for index in shown {
+                    let selected = launch_ui.selection.is_some_and(|sel| {
+                        sel.kind == LaunchLibraryItemKind::Launch && sel.index == index
+                    });
+                    spawn_launch_row(
+                        parent,
+                        &data.launches[index],
+                        LaunchLibraryItemKind::Launch,
+                        index,
+                        selected,
+                    );
+                }
+            }
+
+            // Past launches around the simulated day follow the upcoming ones
+            let Some(day) = state.previous_anchor else {
+                return;
+            };
+            spawn_list_note(
+                parent,
+                &format!("Past launches around {}", day.format("%Y-%m-%d")),
+                PANEL_TEXT_ACCENT,
+            );
+            if let Some(error) = state.previous_error.as_deref() {
+                spawn_list_note(parent, &format!("Unavailable: {error}"), muted);
+            } else if data.previous_launches.is_empty() {
+                let message = if state.is_loading_previous {
+                    "Loading past launches..."
+                } else {
+                    "No past launches around this date."
+                };
+                spawn_list_note(parent, message, muted);
+            } else if shown_past.is_empty() {
+                spawn_list_note(parent, "No launches match the filters.", muted);
+            }
+            for index in shown_past {
+                let selected = launch_ui.selection.is_some_and(|sel| {
+                    sel.kind == LaunchLibraryItemKind::PastLaunch && sel.index == index
+                });
+                spawn_launch_row(
+                    parent,
+                    &data.previous_launches[index],
+                    LaunchLibraryItemKind::PastLaunch,
+                    index,
+                    selected,
+                );
+            }
+        });
+
+    clear_list_children(ui_entities.event_list, &children, &mut commands);
+    commands
+        .entity(ui_entities.event_list)
+        .with_children(|parent| {
+            if data.events.is_empty() {
+                spawn_list_note(parent, "No upcoming events.", muted);
+            } else {
+                for (index, event) in data.events.iter().enumerate() {
+                    let selected = launch_ui.selection.is_some_and(|sel| {
+                        sel.kind == LaunchLibraryItemKind::Event && sel.index == index
+                    });
+                    spawn_event_row(parent, event, index, selected);
+                }
+            }
+        });
+}
+
+/// A line of text in a launch library list, between or instead of rows
+fn spawn_list_note(parent: &mut ChildSpawnerCommands, message: &str, color: Color) {
+    parent.spawn((
+        bevy::ui::widget::Text::new(message),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+fn spawn_launch_row(
+    parent: &mut ChildSpawnerCommands,
+    launch: &crate::launch_library::LaunchSummary,
+    kind: LaunchLibraryItemKind,
+    index: usize,
+    selected: bool,
+) {
+    let net = format_time(launch.net_utc);
+    let header = truncate_text(&format!("{net} • {}", launch.name), 72);
+    let pad = launch.pad_name.as_deref().unwrap_or("Pad TBD");
+    let location = launch
+        .pad_location_name
+        .as_deref()
+        .unwrap_or("Location TBD");
+    let details = truncate_text(&format!("{pad} ({location})"), 70);
+
+    let mut info_parts = Vec::new();
+    if let Some(provider) = launch.provider_name.as_deref() {
+        info_parts.push(provider);
+    }
+    if let Some(mission) = launch.mission_name.as_deref() {
+        info_parts.push(mission);
+    }
+    if let Some(orbit) = launch.orbit_name.as_deref() {
+        info_parts.push(orbit);
+    }
+    let info_line = if info_parts.is_empty() {
+        None
+    } else {
+        Some(truncate_text(&info_parts.join(" • "), 70))
+    };
+
+    let bg = launch_row_color(index, selected);
+
+    parent
+        .spawn((
+            UiWidgetButton,
+            LaunchLibraryItemButton { kind, index },
+            AutoDirectionalNavigation::default(),
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                width: Val::Percent(100.0),
+                height: Val::Auto,
+                min_height: Val::Px(46.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                align_items: AlignItems::FlexStart,
+                justify_content: JustifyContent::FlexStart,
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(bg),
+            EntityCursor::System(SystemCursorIcon::Pointer),
+            RelativeCursorPosition::default(),
+            Pickable::default(),
+            ThemedText,
+        ))
+        .with_children(|row| {
+            row.spawn((
+                bevy::ui::widget::Text::new(header),
+                ThemedText,
+                TextFont {
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextLayout::new_with_no_wrap(),
+            ));
+            row.spawn((
+                bevy::ui::widget::Text::new(details),
+                ThemedText,
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.6, 0.7, 0.8, 0.75)),
+                TextLayout::new_with_no_wrap(),
+            ));
+            if let Some(info) = info_line {
+                row.spawn((
+                    bevy::ui::widget::Text::new(info),
+                    ThemedText,
+                    TextFont {
+                        font_size: 10.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(0.55, 0.65, 0.78, 0.7)),
+                    TextLayout::new_with_no_wrap(),
+                ));
+            }
+        })
+        .insert(Outline::new(Val::Px(1.0), Val::Px(0.0), PANEL_EDGE));
+}
+
+fn spawn_event_row(
+    parent: &mut ChildSpawnerCommands,
+    event: &crate::launch_library::EventSummary,
+    index: usize,
+    selected: bool,
+) {
+    let date = format_time(event.date_utc);
+    let header = truncate_text(&format!("{date} • {}", event.name), 72);
+    let type_name = event.type_name.as_deref().unwrap_or("Event");
+    let location = event.location.as_deref().unwrap_or("Location TBD");
+    let details = truncate_text(&format!("{type_name} • {location}"), 70);
+    let desc = event
+        .description
+        .as_deref()
+        .map(|text| truncate_text(text, 96));
+
+    let bg = event_row_color(index, selected);
+
+    parent
+        .spawn((
+            UiWidgetButton,
+            LaunchLibraryItemButton {
+                kind: LaunchLibraryItemKind::Event,
+                index,
+            },
+            AutoDirectionalNavigation::default(),
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                width: Val::Percent(100.0),
+                height: Val::Auto,
+                min_height: Val::Px(46.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                align_items: AlignItems::FlexStart,
+                justify_content: JustifyContent::FlexStart,
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(bg),
+            EntityCursor::System(SystemCursorIcon::Pointer),
+            RelativeCursorPosition::default(),
+            Pickable::default(),
+            ThemedText,
+        ))
+        .with_children(|row| {
+            row.spawn((
+                bevy::ui::widget::Text::new(header),
+                ThemedText,
+                TextFont {
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextLayout::new_with_no_wrap(),
+            ));
+            row.spawn((
+                bevy::ui::widget::Text::new(details),
+                ThemedText,
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.6, 0.7, 0.8, 0.75)),
+                TextLayout::new_with_no_wrap(),
+            ));
+            if let Some(desc) = desc
+                && !desc.trim().is_empty()
+            {
+                row.spawn((
+                    bevy::ui::widget::Text::new(desc),
+                    ThemedText,
+                    TextFont {
+                        font_size: 10.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(0.55, 0.65, 0.78, 0.7)),
+                    TextLayout::new_with_no_wrap(),
+                ));
+            }
+        })
+        .insert(Outline::new(Val::Px(1.0), Val::Px(0.0), PANEL_EDGE));
+}
+
+fn launch_row_color(index: usize, selected: bool) -> Color {
+    if selected {
+        Color::srgba(0.12, 0.32, 0.36, 0.95)
+    } else if index.is_multiple_of(2) {
+        Color::srgba(0.06, 0.08, 0.11, 0.92)
+    } else {
+        Color::srgba(0.09, 0.12, 0.16, 0.92)
+    }
+}
+
+fn event_row_color(index: usize, selected: bool) -> Color {
+    if selected {
+        Color::srgba(0.12, 0.3, 0.34, 0.95)
+    } else if index.is_multiple_of(2) {
+        Color::srgba(0.06, 0.08, 0.11, 0.92)
+    } else {
+        Color::srgba(0.09, 0.12, 0.16, 0.92)
+    }
+}
+
+/// Add the selected launch's payloads (from its LL2 detail) to the satellite list
+pub(crate) fn process_pending_launch_payloads(
+    mut launch_ui: ResMut<LaunchLibraryUiState>,
+    data: Res<LaunchLibraryData>,
+    mut norad_index: ResMut<NoradIndex>,
+    fetch_channels: Option<Res<FetchChannels>>,
+    mut commands: Commands,
+) {
+    if !launch_ui.pending_add_payloads {
+        return;
+    }
+    launch_ui.pending_add_payloads = false;
+
+    let Some(detail) = launch_ui
+        .selection
+        .and_then(|selection| selection.launch(&data))
+        .and_then(|launch| launch.uuid.as_ref())
+        .and_then(|uuid| data.details.get(uuid))
+    else {
+        println!("[LAUNCH LIBRARY] No payload details loaded for this launch yet");
+        return;
+    };
+
+    let mut added = 0;
+    for &norad in &detail.payload_norad_ids {
+        if norad_index.map.contains_key(&norad) {
+            continue;
+        }
+        match spawn_satellite_for_norad(
+            norad,
+            &mut norad_index,
+            fetch_channels.as_deref(),
+            &mut commands,
+        ) {
+            Ok(()) => added += 1,
+            Err(err) => eprintln!("[LAUNCH LIBRARY] {err}"),
+        }
+    }
+    println!(
+        "[LAUNCH LIBRARY] Added {added} of {} payloads",
+        detail.payload_norad_ids.len()
+    );
+}
+
+/// Add the satellites of the crewed stations, so they can be seen and highlighted
+pub(crate) fn process_pending_station_adds(
+    mut launch_ui: ResMut<LaunchLibraryUiState>,
+    data: Res<LaunchLibraryData>,
+    mut norad_index: ResMut<NoradIndex>,
+    fetch_channels: Option<Res<FetchChannels>>,
+    mut commands: Commands,
+) {
+    if !launch_ui.pending_add_stations {
+        return;
+    }
+    launch_ui.pending_add_stations = false;
+
+    for (station, norad) in data
+        .stations
+        .iter()
+        .filter_map(|station| Some((station, station_norad_id(&station.name)?)))
+    {
+        if norad_index.map.contains_key(&norad) {
+            continue;
+        }
+        match spawn_satellite_for_norad(
+            norad,
+            &mut norad_index,
+            fetch_channels.as_deref(),
+            &mut commands,
+        ) {
+            Ok(()) => println!("[LAUNCH LIBRARY] Added {} ({norad})", station.name),
+            Err(err) => eprintln!("[LAUNCH LIBRARY] {err}"),
+        }
+    }
+}
+
+/// Upcoming dockings and undockings listed under the stations
+const STATION_MOVES_LIMIT: usize = 6;
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_space_station_texts(
+    data: Res<LaunchLibraryData>,
+    state: Res<LaunchLibraryState>,
+    sim_time: Res<SimulationTime>,
+    mut texts: ParamSet<(
+        Query<&mut bevy::ui::widget::Text, With<SpaceStationStatusText>>,
+        Query<&mut bevy::ui::widget::Text, With<SpaceStationBodyText>>,
+    )>,
+) {
+    if !data.is_changed() && !state.is_changed() && !sim_time.is_changed() {
+        return;
+    }
+    let status = if let Some(error) = &state.station_error {
+        error.clone()
+    } else if state.is_loading_stations {
+        "Loading stations...".to_string()
+    } else {
+        let crew: usize = data.stations.iter().map(|s| s.crew.len()).sum();
+        format!("{} stations, {crew} crew", data.stations.len())
+    };
+    for mut text in &mut texts.p0() {
+        text.0 = status.clone();
+    }
+
+    // Docked vehicles follow the simulation clock; crews are as of the last refresh
+    let now = sim_time.current_utc;
+    let mut lines = Vec::new();
+    for station in &data.stations {
+        lines.push(match &station.orbit {
+            Some(orbit) => format!("{} ({orbit})", station.name),
+            None => station.name.clone(),
+        });
+        if !station.expeditions.is_empty() {
+            lines.push(format!("  {}", station.expeditions.join(", ")));
+        }
+        for member in &station.crew {
+            let extra = [member.role.as_deref(), member.agency.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(", ");
+            lines.push(if extra.is_empty() {
+                format!("  {}", member.name)
+            } else {
+                format!("  {} ({extra})", member.name)
+            });
+        }
+        for event in docked_vehicles(station, &data.docking_events, now) {
+            lines.push(match &event.port {
+                Some(port) => format!("  Docked: {} at {port}", event.vehicle),
+                None => format!("  Docked: {}", event.vehicle),
+            });
+        }
+    }
+    let moves = upcoming_moves(&data.docking_events, now, now + chrono::Duration::days(60));
+    if !moves.is_empty() {
+        lines.push("Upcoming".to_string());
+    }
+    for scheduled in moves.iter().take(STATION_MOVES_LIMIT) {
+        lines.push(format!(
+            "  {}  {} {}",
+            scheduled.utc.format("%Y-%m-%d %H:%M UTC"),
+            scheduled.event.vehicle,
+            scheduled.kind.label().to_lowercase()
+        ));
+    }
+    let body = lines.join("\n");
+    for mut text in &mut texts.p1() {
+        if text.0 != body {
+            text.0 = body.clone();
+        }
+    }
+}
+
+pub(crate) fn launch_search_on_key_input(
+    ev: On<FocusedInput<bevy::input::keyboard::KeyboardInput>>,
+    q_input: Query<(), With<LaunchSearchField>>,
+    mut filter: ResMut<LaunchFilter>,
+) {
+    if !q_input.contains(ev.focused_entity) {
+        return;
+    }
+
+    let event = &ev.event().input;
+    if event.state != ButtonState::Pressed {
+        return;
+    }
+
+    match &event.logical_key {
+        Key::Backspace => {
+            filter.search.pop();
+        }
+        Key::Escape => filter.search.clear(),
+        Key::Space => filter.search.push(' '),
+        Key::Character(text) => {
+            filter
+                .search
+                .extend(text.chars().filter(|c| !c.is_control()));
+        }
+        _ => {}
+    }
+}
@@ -5,14 +5,18 @@
 
 use bevy::prelude::*;
 
+pub mod analysis_panels;
 pub mod groups;
+pub mod launch_library;
 pub mod skybox;
 pub mod state;
 pub mod systems;
+pub mod timeline;
 
 pub use skybox::SkyboxPlugin;
 pub use state::{
-    CameraFocusState, LaunchLibraryUiState, MoonCameraState, RightPanelUI, UIState, UiLayoutState,
+    CameraFocusState, LaunchLibraryUiState, MoonCameraState, RightPanelUI, TimeControlUi,
+    TimelineUi, UIState, UiLayoutState,
 };
 #[allow(unused_imports)]
 pub use systems::MainCamera;
@@ -26,6 +30,8 @@ impl Plugin for UiPlugin {
         app.init_resource::<UIState>()
             .init_resource::<UiLayoutState>()
             .init_resource::<RightPanelUI>()
+            .init_resource::<TimeControlUi>()
+            .init_resource::<TimelineUi>()
             .init_resource::<CameraFocusState>()
             .init_resource::<MoonCameraState>()
             .init_resource::<LaunchLibraryUiState>()
@@ -1,5 +1,6 @@
 //! UI state management
 
+use crate::launch_library::{LaunchLibraryData, LaunchSummary};
 use bevy::prelude::*;
 
 /// Main UI state resource
@@ -62,6 +63,8 @@ impl Default for UiLayoutState {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LaunchLibraryItemKind {
     Launch,
+    /// A launch from the past list around the simulated day
+    PastLaunch,
     Event,
 }
 
@@ -71,10 +74,25 @@ pub struct LaunchLibrarySelection {
     pub index: usize,
 }
 
+impl LaunchLibrarySelection {
+    /// The selected launch, from the upcoming or the past list
+    pub fn launch<'a>(&self, data: &'a LaunchLibraryData) -> Option<&'a LaunchSummary> {
+        match self.kind {
+            LaunchLibraryItemKind::Launch => data.launches.get(self.index),
+            LaunchLibraryItemKind::PastLaunch => data.previous_launches.get(self.index),
+            LaunchLibraryItemKind::Event => None,
+        }
+    }
+}
+
 #[derive(Resource, Default)]
 pub struct LaunchLibraryUiState {
     pub selection: Option<LaunchLibrarySelection>,
     pub camera_target: Option<CameraPose>,
+    /// Add the selected launch's payloads to the satellite list
+    pub pending_add_payloads: bool,
+    /// Add the crewed stations' satellites to the satellite list
+    pub pending_add_stations: bool,
 }
 
 /// Right panel UI state
@@ -87,6 +105,48 @@ pub struct RightPanelUI {
     pub pending_add: bool,
     /// URL of the group whose color is currently being edited (for color picker UI)
     pub editing_group_color: Option<String>,
+    /// Which satellites the Satellites List shows
+    pub list_filter: SatelliteListFilter,
+}
+
+/// Time Controls section state
+#[derive(Resource, Default)]
+pub struct TimeControlUi {
+    /// Date being typed into the jump field
+    pub date_input: String,
+    pub pending_jump: bool,
+    pub status: Option<String>,
+}
+
+/// Timeline strip in the bottom panel
+#[derive(Resource)]
+pub struct TimelineUi {
+    /// Span shown around the simulation time
+    pub window_hours: f32,
+    /// Label of the marker under the pointer
+    pub hovered: Option<String>,
+}
+
+impl Default for TimelineUi {
+    fn default() -> Self {
+        Self {
+            window_hours: 48.0,
+            hovered: None,
+        }
+    }
+}
+
+/// Row filter for the Satellites List
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SatelliteListFilter {
+    #[default]
+    All,
+    /// Satellites with a fetch, parse, or propagation error
+    Errors,
+    /// Satellites whose TLE epoch is far from the simulation time
+    Stale,
+    /// Satellites flagged for re-entry within the configured window
+    Reentry,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -11,7 +11,7 @@ use bevy::input::{ButtonInput, ButtonState};
 use bevy::picking::Pickable;
 use bevy::picking::events::{Click, Drag, DragEnd, DragStart, Pointer};
 use bevy::prelude::*;
-use bevy::text::{TextColor, TextLayout};
+use bevy::text::TextColor;
 use bevy::ui::UiSystems;
 use bevy::ui::auto_directional_navigation::{AutoDirectionalNavigation, AutoDirectionalNavigator};
 use bevy::ui::{BackgroundGradient, Checked, Gradient, IsDefaultUiCamera, RelativeCursorPosition};
@@ -29,17 +29,27 @@ use bevy_feathers::tokens;
 use bevy_input_focus::{FocusedInput, InputFocus, InputFocusVisible, tab_navigation::TabIndex};
 use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraSystemSet};
 use bevy_ui_widgets::{
-    Activate, Button as UiWidgetButton, Slider, SliderPrecision, SliderRange, SliderStep,
-    SliderValue, ValueChange, checkbox_self_update, slider_self_update,
+    Activate, Slider, SliderPrecision, SliderRange, SliderStep, SliderValue, ValueChange,
+    checkbox_self_update, slider_self_update,
 };
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
-use crate::core::coordinates::Coordinates;
-use crate::core::space::ecef_to_bevy_km;
-use crate::launch_library::{LaunchLibraryConfig, LaunchLibraryData, LaunchLibraryState};
+use crate::analysis::{
+    AnalysisSite, CoverageConfig, CoverageState, DecayConfig, LaunchWindowConfig,
+    LaunchWindowState, LightingConfig, LightingState, RadioConfig, RadioState, ReentryPrediction,
+    RevisitConfig, RevisitState,
+};
+use crate::core::coordinates::inertial_yaw_step;
+use crate::core::space::{RenderFrame, ecef_to_bevy_km};
+use crate::launch_library::{
+    LaunchFilter, LaunchFilterField, LaunchLibraryConfig, LaunchLibraryData, LaunchLibraryState,
+};
 use crate::orbital::time::SimulationTime;
-use crate::orbital::{Dut1, MoonEcefKm, moon_position_ecef_km};
+use crate::orbital::timescale::{TimeScale, format_in_scale, gps_week_and_seconds};
+use crate::orbital::{
+    DisplayTimeScale, Dut1, MoonEcefKm, gmst_rad_with_dut1, moon_position_ecef_km,
+};
 use crate::satellite::components::{
     NoradId, PropagationError, Propagator, Satellite, SatelliteColor, SatelliteFlags,
     SatelliteGroupUrl, SatelliteName, TleComponent,
@@ -47,15 +57,40 @@ use crate::satellite::components::{
 use crate::satellite::resources::NoradIndex;
 use crate::satellite::{OrbitTrailConfig, SatelliteRenderConfig, SelectedSatellite};
 use crate::space_weather::{AuroraGrid, KpIndex, SolarWind, SpaceWeatherConfig, SpaceWeatherState};
-use crate::tle::{FetchChannels, FetchCommand};
-use crate::ui::groups::SATELLITE_GROUPS;
+use crate::tle::{FetchChannels, FetchCommand, TleFreshness};
+use crate::ui::analysis_panels::{
+    CoverageMetricBinding, DopGroupBinding, HeatmapMetricBinding, RevisitTargetBinding,
+    handle_coverage_metric_change, handle_heatmap_metric_change, handle_revisit_target_change,
+    spawn_coverage_section, spawn_heatmap_legend, spawn_launch_window_section,
+    spawn_lighting_section, spawn_radio_pass_section, spawn_revisit_section,
+    spawn_satellite_details_section, update_coverage_status_text, update_doppler_curve,
+    update_heatmap_legend, update_launch_window_texts, update_lighting_plots,
+    update_maneuver_timeline, update_radio_readout, update_revisit_panel, update_satellite_details,
+};
+use crate::ui::groups::{GNSS_GROUPS, SATELLITE_GROUPS};
+use crate::ui::launch_library::{
+    LaunchLibraryItemButton, LaunchSearchField, animate_launch_camera_focus,
+    focus_camera_on_launch_selection, launch_search_on_key_input, process_pending_launch_payloads,
+    process_pending_station_adds, request_selected_launch_detail, spawn_launch_alert_banner,
+    spawn_launch_popup, spawn_launches_section, spawn_space_stations_section,
+    update_launch_alert_banner, update_launch_filter_texts, update_launch_library_lists,
+    update_launch_library_popup, update_launch_library_texts, update_space_station_texts,
+};
 use crate::ui::state::{
-    CameraFocusState, CameraFocusTarget, CameraPose, LaunchLibraryItemKind, LaunchLibrarySelection,
-    LaunchLibraryUiState, MoonCameraState, RightPanelUI, UIState, UiLayoutState,
+    CameraFocusState, CameraFocusTarget, CameraPose, LaunchLibrarySelection, LaunchLibraryUiState,
+    MoonCameraState, RightPanelUI, SatelliteListFilter, TimeControlUi, TimelineUi, UIState,
+    UiLayoutState,
+};
+use crate::ui::timeline::{
+    DateInputField, date_input_on_key_input, position_timeline_markers, process_pending_jump,
+    rebuild_timeline_markers, spawn_time_controls_section, spawn_timeline_row,
+    timeline_marker_on_click, timeline_marker_on_out, timeline_marker_on_over, timeline_on_drag,
+    update_time_control_texts, update_timeline_texts,
 };
 use crate::visualization::moon::Moon;
 use crate::visualization::{
-    ArrowConfig, GroundTrackConfig, GroundTrackGizmoConfig, HeatmapConfig, RangeMode,
+    ArrowConfig, GroundTrackConfig, GroundTrackGizmoConfig, HeatmapConfig, HeatmapMetric,
+    HeatmapSource, RangeMode,
 };
 
 const MOON_FOCUS_DISTANCE_KM: f32 = 10_000.0;
@@ -70,14 +105,14 @@ pub struct UiConfigBundle {
 }
 
 #[derive(Resource)]
-struct UiEntities {
+pub(crate) struct UiEntities {
     left_panel: Entity,
     right_panel: Entity,
     top_panel: Entity,
     bottom_panel: Entity,
     satellite_list: Entity,
-    launch_list: Entity,
-    event_list: Entity,
+    pub(crate) launch_list: Entity,
+    pub(crate) event_list: Entity,
 }
 
 #[derive(Component)]
@@ -95,12 +130,6 @@ struct BottomPanel;
 #[derive(Component)]
 struct SatelliteList;
 
-#[derive(Component)]
-struct LaunchList;
-
-#[derive(Component)]
-struct EventList;
-
 #[derive(Component)]
 struct GroupList;
 
@@ -128,6 +157,9 @@ struct TimeScaleValueText;
 #[derive(Component)]
 struct FocusToggleText;
 
+#[derive(Component)]
+struct RenderFrameToggleText;
+
 fn queue_set_checked(commands: &mut Commands, entity: Entity, checked: bool) {
     commands
         .entity(entity)
@@ -176,27 +208,6 @@ struct SpaceWeatherErrorText;
 #[derive(Component)]
 struct AuroraStatusText;
 
-#[derive(Component)]
-struct LaunchLibraryUpdatedText;
-
-#[derive(Component)]
-struct LaunchLibraryErrorText;
-
-#[derive(Component)]
-struct LaunchLibraryPopupOverlay;
-
-#[derive(Component)]
-struct LaunchLibraryPopupTitle;
-
-#[derive(Component)]
-struct LaunchLibraryPopupBody;
-
-#[derive(Component)]
-struct LaunchLibraryItemButton {
-    kind: LaunchLibraryItemKind,
-    index: usize,
-}
-
 #[derive(Component)]
 struct TextInputField;
 
@@ -206,6 +217,9 @@ struct TextInputValueText;
 #[derive(Component)]
 struct TextInputPlaceholderText;
 
+#[derive(Component)]
+struct PauseToggleText;
+
 #[derive(Component)]
 struct TooltipBubble;
 
@@ -216,7 +230,7 @@ struct TooltipToggle {
 
 #[derive(Component, Clone, Copy)]
 #[allow(dead_code)]
-enum CheckboxBinding {
+pub(crate) enum CheckboxBinding {
     ShowAxes,
     ShowArrows,
     GroundTracksEnabled,
@@ -225,12 +239,14 @@ enum CheckboxBinding {
     TrailsAll,
     TracksAll,
     HeatmapEnabled,
+    CoverageOverlay,
     AuroraOverlay,
     LaunchPadMarkers,
+    LaunchAlerts,
 }
 
 #[derive(Component, Clone, Copy)]
-enum SliderBinding {
+pub(crate) enum SliderBinding {
     GroundTrackRadius,
     GizmoSegments,
     GizmoCenterDotSize,
@@ -241,6 +257,26 @@ enum SliderBinding {
     HeatmapFixedMax,
     HeatmapChunkSize,
     HeatmapChunksPerFrame,
+    HeatmapMinElevation,
+    HeatmapDopFixedMax,
+    CoverageDurationHours,
+    CoverageStepSeconds,
+    CoverageCellSize,
+    CoverageMinElevation,
+    CoverageLatMin,
+    CoverageLatMax,
+    CoverageLonMin,
+    CoverageLonMax,
+    RevisitDurationHours,
+    RevisitStepSeconds,
+    RevisitMinElevation,
+    ReentryFlagDays,
+    LightingDays,
+    LaunchWindowInclination,
+    LaunchWindowRaan,
+    LaunchWindowDays,
+    TimeStepMinutes,
+    TimelineWindowHours,
     AuroraIntensity,
     AuroraAlpha,
     AuroraLongitudeOffset,
@@ -257,16 +293,43 @@ enum RangeModeBinding {
     Fixed,
 }
 
+#[derive(Component, Clone, Copy)]
+struct SatelliteFilterBinding(SatelliteListFilter);
+
 #[derive(Component, Clone)]
-enum ButtonAction {
+pub(crate) enum ButtonAction {
     LoadGroup,
     ClearAll,
     AddSatellite,
     StopTracking,
     TimeNow,
+    TogglePause,
+    StepBack,
+    StepForward,
+    ReverseTime,
+    JumpToDate,
     ToggleFocusTarget,
+    ToggleRenderFrame,
     RefreshLaunchLibrary,
+    CycleLaunchFilter(LaunchFilterField),
+    ClearLaunchFilters,
+    MoreLaunches,
     CloseLaunchPopup,
+    AddLaunchPayloads,
+    ExportLaunchCalendar,
+    AddSpaceStations,
+    RunCoverage,
+    ExportCoverage,
+    PickAnalysisSite,
+    RunRevisit,
+    ExportRevisit,
+    NextTransmitter,
+    PlanRadioPass,
+    RunLighting,
+    CycleLaunchWindowSite,
+    CycleLaunchWindowTarget,
+    RunLaunchWindow,
+    CycleTimeScale,
 }
 
 /// Component marker for color preview UI element
@@ -357,23 +420,40 @@ struct UiFontHandles {
     bold: Handle<Font>,
 }
 
-const PANEL_BG: Color = Color::srgba(0.03, 0.05, 0.08, 0.78);
-const PANEL_EDGE: Color = Color::srgba(0.1, 0.9, 0.95, 0.35);
+pub(crate) const PANEL_BG: Color = Color::srgba(0.03, 0.05, 0.08, 0.78);
+
+pub(crate) const PANEL_EDGE: Color = Color::srgba(0.1, 0.9, 0.95, 0.35);
+
 const PANEL_DIVIDER: Color = Color::srgba(0.12, 0.3, 0.35, 0.9);
-const PANEL_INNER_BG: Color = Color::srgba(0.02, 0.04, 0.06, 0.85);
-const PANEL_TEXT_ACCENT: Color = Color::srgba(0.4, 1.0, 1.0, 1.0);
+
+pub(crate) const PANEL_INNER_BG: Color = Color::srgba(0.02, 0.04, 0.06, 0.85);
+
+pub(crate) const PANEL_TEXT_ACCENT: Color = Color::srgba(0.4, 1.0, 1.0, 1.0);
+
 const TOOLTIP_BG: Color = Color::srgba(0.02, 0.08, 0.12, 0.95);
+
 const TOOLTIP_TEXT: Color = Color::srgba(0.8, 0.9, 1.0, 0.95);
+
 const TOOLTIP_MAX_WIDTH_PX: f32 = 220.0;
+
 const TOOLTIP_ICON_BG: Color = Color::srgba(0.08, 0.12, 0.18, 0.9);
+
 const TOOLTIP_ICON_SIZE_PX: f32 = 14.0;
+
 const UI_FONT_PATH: &str = "Orbitron-Medium.ttf";
+
 const UI_FONT_BOLD_PATH: &str = "Orbitron-Bold.ttf";
+
 const TOP_PANEL_HEIGHT_PX: f32 = 52.0;
-const BOTTOM_PANEL_HEIGHT_PX: f32 = 32.0;
+
+const BOTTOM_PANEL_HEIGHT_PX: f32 = 68.0;
+
 const GRID_LINE: Color = Color::srgba(0.1, 0.6, 0.7, 0.10);
+
 const GRID_STEPS: [f32; 9] = [10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0];
+
 const SATELLITE_LIST_NAME_CHAR_PX: f32 = 7.0;
+
 const SATELLITE_LIST_FIXED_WIDTH_PX: f32 = 316.0;
 
 #[derive(Clone, Copy)]
@@ -384,14 +464,14 @@ enum Edge {
 }
 
 #[derive(Clone, Copy)]
-struct LabelStyle {
-    font_size: f32,
-    color: Option<Color>,
-    bold: bool,
+pub(crate) struct LabelStyle {
+    pub(crate) font_size: f32,
+    pub(crate) color: Option<Color>,
+    pub(crate) bold: bool,
 }
 
 impl LabelStyle {
-    fn normal(font_size: f32) -> Self {
+    pub(crate) fn normal(font_size: f32) -> Self {
         Self {
             font_size,
             color: None,
@@ -432,7 +512,7 @@ type SliderVisualQuery<'w, 's> = Query<
     With<Slider>,
 >;
 
-type MainCameraQuery<'w, 's> =
+pub(crate) type MainCameraQuery<'w, 's> =
     Query<'w, 's, (&'static mut PanOrbitCamera, &'static mut Transform), With<MainCamera>>;
 
 type SatListQuery<'w, 's> = Query<
@@ -445,6 +525,7 @@ type SatListQuery<'w, 's> = Query<
         Option<&'static Propagator>,
         Option<&'static PropagationError>,
         Option<&'static TleComponent>,
+        Option<&'static ReentryPrediction>,
     ),
     With<Satellite>,
 >;
@@ -461,16 +542,59 @@ struct SyncWidgetStateParams<'w, 's> {
     arrows: Res<'w, ArrowConfig>,
     config_bundle: Res<'w, UiConfigBundle>,
     heatmap_cfg: Res<'w, HeatmapConfig>,
+    coverage_cfg: Res<'w, CoverageConfig>,
+    revisit_cfg: Res<'w, RevisitConfig>,
+    decay_cfg: Res<'w, DecayConfig>,
+    lighting_cfg: Res<'w, LightingConfig>,
+    launch_window_cfg: Res<'w, LaunchWindowConfig>,
     space_weather_cfg: Res<'w, SpaceWeatherConfig>,
     launch_library_cfg: Res<'w, LaunchLibraryConfig>,
     camera_focus: Res<'w, CameraFocusState>,
     selected: Res<'w, SelectedSatellite>,
     sim_time: Res<'w, crate::orbital::SimulationTime>,
+    timeline_ui: Res<'w, TimelineUi>,
     right_ui: Res<'w, RightPanelUI>,
     _launch_ui: Res<'w, LaunchLibraryUiState>,
     checkboxes: Query<'w, 's, (Entity, &'static CheckboxBinding, Option<&'static Checked>)>,
     range_modes: Query<'w, 's, (Entity, &'static RangeModeBinding, Option<&'static Checked>)>,
+    coverage_metrics: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static CoverageMetricBinding,
+            Option<&'static Checked>,
+        ),
+    >,
+    heatmap_metrics: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static HeatmapMetricBinding,
+            Option<&'static Checked>,
+        ),
+    >,
+    dop_groups: Query<'w, 's, (Entity, &'static DopGroupBinding, Option<&'static Checked>)>,
+    revisit_targets: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static RevisitTargetBinding,
+            Option<&'static Checked>,
+        ),
+    >,
     group_choices: Query<'w, 's, (Entity, &'static GroupChoice, Option<&'static Checked>)>,
+    satellite_filters: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static SatelliteFilterBinding,
+            Option<&'static Checked>,
+        ),
+    >,
     sliders: Query<'w, 's, (Entity, &'static SliderBinding), With<SliderValue>>,
     slider_values: Query<'w, 's, &'static SliderValue>,
     satellite_toggles: Query<'w, 's, (Entity, &'static SatelliteToggle, Option<&'static Checked>)>,
@@ -506,6 +630,20 @@ struct ButtonActivateParams<'w, 's> {
     fetch_channels: Option<Res<'w, FetchChannels>>,
     launch_library_state: ResMut<'w, LaunchLibraryState>,
     launch_ui: ResMut<'w, LaunchLibraryUiState>,
+    launch_data: Res<'w, LaunchLibraryData>,
+    launch_filter: ResMut<'w, LaunchFilter>,
+    coverage_state: ResMut<'w, CoverageState>,
+    revisit_cfg: ResMut<'w, RevisitConfig>,
+    revisit_state: ResMut<'w, RevisitState>,
+    analysis_site: ResMut<'w, AnalysisSite>,
+    radio_cfg: ResMut<'w, RadioConfig>,
+    radio_state: ResMut<'w, RadioState>,
+    render_frame: ResMut<'w, RenderFrame>,
+    lighting_state: ResMut<'w, LightingState>,
+    launch_window_cfg: ResMut<'w, LaunchWindowConfig>,
+    launch_window_state: ResMut<'w, LaunchWindowState>,
+    display_time_scale: ResMut<'w, DisplayTimeScale>,
+    time_ui: ResMut<'w, TimeControlUi>,
 }
 
 #[derive(SystemParam)]
@@ -546,6 +684,9 @@ impl Plugin for UiSystemsPlugin {
                 update_status_texts,
                 update_space_weather_texts,
                 update_launch_library_texts,
+                update_launch_filter_texts,
+                update_launch_alert_banner,
+                request_selected_launch_detail,
                 update_launch_library_popup,
                 focus_camera_on_launch_selection,
                 animate_launch_camera_focus,
@@ -553,10 +694,33 @@ impl Plugin for UiSystemsPlugin {
                 update_satellite_list_panel_width,
             ),
         )
+        .add_systems(
+            Update,
+            (
+                update_coverage_status_text,
+                update_heatmap_legend,
+                update_revisit_panel,
+                update_satellite_details,
+                update_maneuver_timeline,
+                update_radio_readout,
+                update_doppler_curve,
+                update_lighting_plots,
+                update_launch_window_texts,
+                update_space_station_texts,
+                update_render_frame_toggle_text,
+                rebuild_timeline_markers,
+                position_timeline_markers.after(rebuild_timeline_markers),
+                update_timeline_texts,
+            ),
+        )
         .add_systems(
             Update,
             (
                 process_pending_add,
+                process_pending_launch_payloads,
+                process_pending_station_adds,
+                process_pending_jump,
+                update_time_control_texts,
                 sync_widget_states,
                 sync_slider_visuals,
                 handle_group_loading_text,
@@ -583,6 +747,10 @@ impl Plugin for UiSystemsPlugin {
             PostUpdate,
             update_camera_input_from_ui_hover.before(PanOrbitCameraSystemSet),
         )
+        .add_systems(
+            PostUpdate,
+            hold_camera_inertial.before(PanOrbitCameraSystemSet),
+        )
         .add_systems(
             PostUpdate,
             lock_camera_focus_to_target.after(PanOrbitCameraSystemSet),
@@ -594,10 +762,20 @@ impl Plugin for UiSystemsPlugin {
         .add_observer(handle_checkbox_change)
         .add_observer(handle_slider_change)
         .add_observer(handle_range_mode_change)
+        .add_observer(handle_coverage_metric_change)
+        .add_observer(handle_heatmap_metric_change)
+        .add_observer(handle_revisit_target_change)
+        .add_observer(handle_satellite_filter_change)
         .add_observer(handle_group_color_plane_change)
         .add_observer(handle_group_color_green_change)
         .add_observer(text_input_on_click)
         .add_observer(text_input_on_key_input)
+        .add_observer(date_input_on_key_input)
+        .add_observer(launch_search_on_key_input)
+        .add_observer(timeline_on_drag)
+        .add_observer(timeline_marker_on_click)
+        .add_observer(timeline_marker_on_over)
+        .add_observer(timeline_marker_on_out)
         .add_observer(handle_tooltip_toggle_click)
         .add_observer(handle_group_choice)
         .add_observer(handle_group_swatch_click)
@@ -631,6 +809,42 @@ fn enforce_ui_camera_settings(mut cameras: UiCameraQuery<'_, '_>) {
     }
 }
 
+/// In the inertial frame, turn the main camera about the Earth's axis as sidereal
+/// time advances so it stays fixed against the stars
+///
+/// Yawing the camera by -dGMST draws the same picture as turning the Earth and
+/// everything on it by +dGMST, but world positions stay ECEF, so picking, trails
+/// and the Moon keep reading them unchanged. Satellite tracking and Moon focus
+/// already pin the camera to their target, so the hold stands aside for them.
+fn hold_camera_inertial(
+    render_frame: Res<RenderFrame>,
+    sim_time: Res<SimulationTime>,
+    dut1: Res<Dut1>,
+    camera_focus: Res<CameraFocusState>,
+    selected: Res<SelectedSatellite>,
+    mut last_gmst: Local<Option<f64>>,
+    mut q_camera: MainCameraQuery<'_, '_>,
+) {
+    let gmst = gmst_rad_with_dut1(sim_time.current_utc, **dut1);
+    let previous = last_gmst.replace(gmst);
+    if *render_frame != RenderFrame::Inertial
+        || camera_focus.target == CameraFocusTarget::Moon
+        || selected.tracking.is_some()
+    {
+        return;
+    }
+    let (Some(previous), Ok((mut poc, _))) = (previous, q_camera.single_mut()) else {
+        return;
+    };
+
+    let step = inertial_yaw_step(previous, gmst) as f32;
+    if step == 0.0 {
+        return;
+    }
+    poc.target_yaw += step;
+    poc.yaw = poc.yaw.map(|yaw| yaw + step);
+}
+
 fn lock_camera_focus_to_target(
     camera_focus: Res<CameraFocusState>,
     moon_pos: Res<MoonEcefKm>,
@@ -747,10 +961,16 @@ fn setup_ui(
     layout: Res<UiLayoutState>,
     config_bundle: Res<UiConfigBundle>,
     heatmap_cfg: Res<HeatmapConfig>,
+    coverage_cfg: Res<CoverageConfig>,
+    revisit_cfg: Res<RevisitConfig>,
+    decay_cfg: Res<DecayConfig>,
+    lighting_cfg: Res<LightingConfig>,
+    launch_window_cfg: Res<LaunchWindowConfig>,
     space_weather_cfg: Res<SpaceWeatherConfig>,
     _launch_library_cfg: Res<LaunchLibraryConfig>,
     selected: Res<SelectedSatellite>,
     sim_time: Res<crate::orbital::SimulationTime>,
+    timeline_ui: Res<TimelineUi>,
     group_registry: Option<Res<crate::satellite::resources::GroupRegistry>>,
 ) {
     let root = commands
@@ -859,9 +1079,10 @@ fn setup_ui(
                 right: Val::Px(0.0),
                 bottom: Val::Px(0.0),
                 height: Val::Px(BOTTOM_PANEL_HEIGHT_PX),
-                padding: UiRect::horizontal(Val::Px(12.0)),
-                align_items: AlignItems::Center,
-                column_gap: Val::Px(12.0),
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(4.0),
                 ..default()
             },
             BackgroundColor(PANEL_BG),
@@ -882,89 +1103,8 @@ fn setup_ui(
     commands.entity(root).add_child(top_panel);
     commands.entity(root).add_child(bottom_panel);
     commands.entity(root).with_children(|parent| {
-        parent
-            .spawn((
-                Node {
-                    position_type: PositionType::Absolute,
-                    left: Val::Px(0.0),
-                    right: Val::Px(0.0),
-                    top: Val::Px(0.0),
-                    bottom: Val::Px(0.0),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    display: Display::None,
-                    ..default()
-                },
-                BackgroundColor(Color::srgba(0.02, 0.03, 0.05, 0.55)),
-                Pickable::IGNORE,
-                ThemedText,
-                LaunchLibraryPopupOverlay,
-            ))
-            .with_children(|overlay| {
-                overlay
-                    .spawn((
-                        Node {
-                            flex_direction: FlexDirection::Column,
-                            row_gap: Val::Px(8.0),
-                            width: Val::Px(420.0),
-                            padding: UiRect::all(Val::Px(12.0)),
-                            ..default()
-                        },
-                        BackgroundColor(PANEL_BG),
-                        ThemedText,
-                    ))
-                    .with_children(|popup| {
-                        popup
-                            .spawn((
-                                Node {
-                                    flex_direction: FlexDirection::Row,
-                                    align_items: AlignItems::Center,
-                                    column_gap: Val::Px(8.0),
-                                    width: Val::Percent(100.0),
-                                    ..default()
-                                },
-                                ThemedText,
-                            ))
-                            .with_children(|header| {
-                                header.spawn((
-                                    LaunchLibraryPopupTitle,
-                                    bevy::ui::widget::Text::new(""),
-                                    ThemedText,
-                                    TextFont {
-                                        font_size: 13.0,
-                                        ..default()
-                                    },
-                                    TextColor(PANEL_TEXT_ACCENT),
-                                ));
-                                header.spawn(Node {
-                                    flex_grow: 1.0,
-                                    min_width: Val::Px(0.0),
-                                    ..default()
-                                });
-                                spawn_fixed_button(
-                                    header,
-                                    40.0,
-                                    ButtonProps::default(),
-                                    (
-                                        ButtonAction::CloseLaunchPopup,
-                                        AutoDirectionalNavigation::default(),
-                                    ),
-                                    "Close",
-                                );
-                            });
-
-                        popup.spawn((
-                            LaunchLibraryPopupBody,
-                            bevy::ui::widget::Text::new(""),
-                            ThemedText,
-                            TextFont {
-                                font_size: 11.0,
-                                ..default()
-                            },
-                            TextColor(Color::srgba(0.7, 0.8, 0.9, 0.85)),
-                        ));
-                    });
-            });
+        spawn_launch_alert_banner(parent);
+        spawn_launch_popup(parent);
     });
 
     commands.entity(left_panel).with_children(|panel| {
@@ -1145,145 +1285,11 @@ fn setup_ui(
         });
 
         let _ = spawn_section(parent, "Launches & Events", false, |section| {
-            section
-                .spawn((
-                    Node {
-                        flex_direction: FlexDirection::Row,
-                        align_items: AlignItems::Center,
-                        column_gap: Val::Px(8.0),
-                        width: Val::Percent(100.0),
-                        ..default()
-                    },
-                    ThemedText,
-                ))
-                .with_children(|row| {
-                    row.spawn((checkbox(
-                        (
-                            CheckboxBinding::LaunchPadMarkers,
-                            AutoDirectionalNavigation::default(),
-                        ),
-                        Spawn((bevy::ui::widget::Text::new("Show pad markers"), ThemedText)),
-                    ),));
-                    spawn_fixed_button(
-                        row,
-                        72.0,
-                        ButtonProps::default(),
-                        (
-                            ButtonAction::RefreshLaunchLibrary,
-                            AutoDirectionalNavigation::default(),
-                        ),
-                        "Refresh",
-                    );
-                });
-
-            section.spawn((
-                LaunchLibraryUpdatedText,
-                bevy::ui::widget::Text::new("Updated: --"),
-                ThemedText,
-                TextFont {
-                    font_size: 11.0,
-                    ..default()
-                },
-                TextColor(Color::srgba(0.6, 0.7, 0.8, 0.85)),
-            ));
-            section.spawn((
-                LaunchLibraryErrorText,
-                bevy::ui::widget::Text::new(""),
-                ThemedText,
-                TextColor(Color::srgb(1.0, 0.35, 0.35)),
-            ));
-
-            section.spawn((
-                bevy::ui::widget::Text::new("Upcoming Launches"),
-                ThemedText,
-                TextFont {
-                    font_size: 12.0,
-                    ..default()
-                },
-                TextColor(PANEL_TEXT_ACCENT),
-            ));
-
-            section
-                .spawn((
-                    Node {
-                        flex_direction: FlexDirection::Row,
-                        column_gap: Val::Px(6.0),
-                        height: Val::Px(140.0),
-                        width: Val::Percent(100.0),
-                        min_width: Val::Px(0.0),
-                        ..default()
-                    },
-                    ThemedText,
-                ))
-                .with_children(|container| {
-                    let list_entity = container
-                        .spawn((
-                            Node {
-                                flex_direction: FlexDirection::Column,
-                                row_gap: Val::Px(4.0),
-                                width: Val::Percent(100.0),
-                                min_width: Val::Px(0.0),
-                                height: Val::Px(140.0),
-                                overflow: Overflow::scroll_y(),
-                                padding: UiRect::all(Val::Px(4.0)),
-                                ..default()
-                            },
-                            ScrollPosition::default(),
-                            ThemedText,
-                            LaunchList,
-                        ))
-                        .id();
-
-                    spawn_scrollbar(container, list_entity, 140.0);
-                    launch_list = list_entity;
-                });
-
-            section.spawn((
-                bevy::ui::widget::Text::new("Upcoming Events"),
-                ThemedText,
-                TextFont {
-                    font_size: 12.0,
-                    ..default()
-                },
-                TextColor(PANEL_TEXT_ACCENT),
-            ));
-
-            section
-                .spawn((
-                    Node {
-                        flex_direction: FlexDirection::Row,
-                        column_gap: Val::Px(6.0),
-                        height: Val::Px(120.0),
-                        width: Val::Percent(100.0),
-                        min_width: Val::Px(0.0),
-                        ..default()
-                    },
-                    ThemedText,
-                ))
-                .with_children(|container| {
-                    let list_entity = container
-                        .spawn((
-                            Node {
-                                flex_direction: FlexDirection::Column,
-                                row_gap: Val::Px(4.0),
-                                width: Val::Percent(100.0),
-                                min_width: Val::Px(0.0),
-                                height: Val::Px(120.0),
-                                overflow: Overflow::scroll_y(),
-                                padding: UiRect::all(Val::Px(4.0)),
-                                ..default()
-                            },
-                            ScrollPosition::default(),
-                            ThemedText,
-                            EventList,
-                        ))
-                        .id();
-
-                    spawn_scrollbar(container, list_entity, 120.0);
-                    event_list = list_entity;
-                });
+            (launch_list, event_list) = spawn_launches_section(section);
         });
 
+        let _ = spawn_section(parent, "Space Stations", false, spawn_space_stations_section);
+
         let _ = spawn_section(parent, "City → Sat Vis", false, |section| {
             section.spawn((checkbox(
                 (
@@ -1294,6 +1300,10 @@ fn setup_ui(
             ),));
         });
 
+        let _ = spawn_section(parent, "Revisit Analysis", false, |section| {
+            spawn_revisit_section(section, &revisit_cfg);
+        });
+
     });
 
     // Right panel contents
@@ -1591,6 +1601,10 @@ fn setup_ui(
 
                         });
 
+                        let _ = spawn_section(parent, "Time Controls", false, |section| {
+                            spawn_time_controls_section(section, &sim_time, &timeline_ui);
+                        });
+
                         let _ = spawn_section(parent, "Add Satellite", false, |section| {
                             section
                                 .spawn((
@@ -1795,6 +1809,49 @@ fn setup_ui(
                                 0.05,
                             );
 
+                            section.spawn((bevy::ui::widget::Text::new("Metric"), ThemedText));
+                            for metric in HeatmapMetric::ALL {
+                                section.spawn((radio(
+                                    (
+                                        HeatmapMetricBinding(metric),
+                                        AutoDirectionalNavigation::default(),
+                                    ),
+                                    Spawn((
+                                        bevy::ui::widget::Text::new(metric.label()),
+                                        ThemedText,
+                                    )),
+                                ),));
+                            }
+                            spawn_labeled_slider(
+                                section,
+                                "Elevation mask (deg)",
+                                SliderBinding::HeatmapMinElevation,
+                                0.0,
+                                45.0,
+                                heatmap_cfg.min_elevation_deg,
+                                1.0,
+                            );
+
+                            section.spawn((
+                                bevy::ui::widget::Text::new("DOP constellation"),
+                                ThemedText,
+                            ));
+                            section.spawn((radio(
+                                (DopGroupBinding(None), AutoDirectionalNavigation::default()),
+                                Spawn((bevy::ui::widget::Text::new("All loaded"), ThemedText)),
+                            ),));
+                            for (url, name) in GNSS_GROUPS {
+                                section.spawn((radio(
+                                    (
+                                        DopGroupBinding(Some(*url)),
+                                        AutoDirectionalNavigation::default(),
+                                    ),
+                                    Spawn((bevy::ui::widget::Text::new(*name), ThemedText)),
+                                ),));
+                            }
+
+                            spawn_heatmap_legend(section);
+
                             section.spawn((bevy::ui::widget::Text::new("Range mode"), ThemedText));
                             section.spawn((radio(
                                 (RangeModeBinding::Auto, AutoDirectionalNavigation::default()),
@@ -1817,6 +1874,15 @@ fn setup_ui(
                                 heatmap_cfg.fixed_max.unwrap_or(20) as f32,
                                 1.0,
                             );
+                            spawn_labeled_slider(
+                                section,
+                                "DOP fixed max",
+                                SliderBinding::HeatmapDopFixedMax,
+                                2.0,
+                                20.0,
+                                heatmap_cfg.dop_fixed_max,
+                                0.5,
+                            );
 
                             spawn_labeled_slider(
                                 section,
@@ -1838,6 +1904,10 @@ fn setup_ui(
                             );
                         });
 
+                        let _ = spawn_section(parent, "Coverage Analysis", false, |section| {
+                            spawn_coverage_section(section, &coverage_cfg);
+                        });
+
                         let _ = spawn_section(parent, "Satellite Rendering", false, |section| {
                             spawn_labeled_slider(
                                 section,
@@ -1895,28 +1965,74 @@ fn setup_ui(
                             );
                         });
 
+                        let _ = spawn_section(parent, "Satellite Details", false, |section| {
+                            spawn_satellite_details_section(section, &decay_cfg);
+                        });
+
+                        let _ = spawn_section(parent, "Radio Pass", false, spawn_radio_pass_section);
+
+                        let _ = spawn_section(parent, "Beta Angle & Eclipse", false, |section| {
+                            spawn_lighting_section(section, &lighting_cfg);
+                        });
+
+                        let _ = spawn_section(parent, "Launch Window", false, |section| {
+                            spawn_launch_window_section(section, &launch_window_cfg);
+                        });
+
                         let satellite_list_section = spawn_section(
                             parent,
                             "Satellites List",
                             false,
                             |section| {
-                            // Header Row
+                            // Filter Row
                             section
                                 .spawn((
                                     Node {
                                         flex_direction: FlexDirection::Row,
                                         align_items: AlignItems::Center,
-                                        column_gap: Val::Px(6.0),
-                                        padding: UiRect::horizontal(Val::Px(4.0)),
+                                        column_gap: Val::Px(8.0),
                                         width: Val::Percent(100.0),
-                                        min_width: Val::Px(0.0),
                                         ..default()
                                     },
                                     ThemedText,
                                 ))
-                                .with_children(|header| {
-                                    header.spawn((
-                                        bevy::ui::widget::Text::new("NORAD"),
+                                .with_children(|row| {
+                                    for (filter, label) in [
+                                        (SatelliteListFilter::All, "All"),
+                                        (SatelliteListFilter::Errors, "Errors"),
+                                        (SatelliteListFilter::Stale, "Stale TLE"),
+                                        (SatelliteListFilter::Reentry, "Re-entry"),
+                                    ] {
+                                        row.spawn((radio(
+                                            (
+                                                SatelliteFilterBinding(filter),
+                                                AutoDirectionalNavigation::default(),
+                                            ),
+                                            Spawn((
+                                                bevy::ui::widget::Text::new(label),
+                                                ThemedText,
+                                            )),
+                                        ),));
+                                    }
+                                });
+
+                            // Header Row
+                            section
+                                .spawn((
+                                    Node {
+                                        flex_direction: FlexDirection::Row,
+                                        align_items: AlignItems::Center,
+                                        column_gap: Val::Px(6.0),
+                                        padding: UiRect::horizontal(Val::Px(4.0)),
+                                        width: Val::Percent(100.0),
+                                        min_width: Val::Px(0.0),
+                                        ..default()
+                                    },
+                                    ThemedText,
+                                ))
+                                .with_children(|header| {
+                                    header.spawn((
+                                        bevy::ui::widget::Text::new("NORAD"),
                                         ThemedText,
                                         Node {
                                             width: Val::Px(90.0),
@@ -1944,7 +2060,7 @@ fn setup_ui(
                                         bevy::ui::widget::Text::new("Status"),
                                         ThemedText,
                                         Node {
-                                            width: Val::Px(60.0),
+                                            width: Val::Px(76.0),
                                             ..default()
                                         },
                                         TextFont {
@@ -2043,43 +2159,60 @@ fn setup_ui(
         spawn_top_panel_toggles_row(parent);
     });
 
-    // Bottom panel contents
-    commands.entity(bottom_panel).with_children(|parent| {
-        spawn_pill_chip(
-            parent,
-            "Satellites: 0",
-            LabelStyle::normal(11.0),
-            SatelliteCountText,
-        );
-        spawn_pill_chip(
-            parent,
-            "TLE Fetcher: --",
-            LabelStyle::normal(11.0),
-            FetchStatusText,
-        );
-        spawn_pill_chip(
-            parent,
-            "Selected: None",
-            LabelStyle::normal(11.0),
-            SelectedSatelliteText,
-        );
-        parent
+    // Bottom panel contents: timeline strip above the status row
+    commands.entity(bottom_panel).with_children(|panel| {
+        spawn_timeline_row(panel, timeline_ui.window_hours);
+        panel
             .spawn((
                 Node {
-                    position_type: PositionType::Absolute,
-                    left: Val::Px(0.0),
-                    right: Val::Px(0.0),
-                    top: Val::Px(0.0),
-                    bottom: Val::Px(0.0),
-                    justify_content: JustifyContent::Center,
+                    position_type: PositionType::Relative,
+                    width: Val::Percent(100.0),
+                    height: Val::Px(26.0),
                     align_items: AlignItems::Center,
+                    column_gap: Val::Px(12.0),
                     ..default()
                 },
                 Pickable::IGNORE,
                 ThemedText,
             ))
-            .with_children(|center| {
-                spawn_focus_toggle_row(center);
+            .with_children(|parent| {
+                spawn_pill_chip(
+                    parent,
+                    "Satellites: 0",
+                    LabelStyle::normal(11.0),
+                    SatelliteCountText,
+                );
+                spawn_pill_chip(
+                    parent,
+                    "TLE Fetcher: --",
+                    LabelStyle::normal(11.0),
+                    FetchStatusText,
+                );
+                spawn_pill_chip(
+                    parent,
+                    "Selected: None",
+                    LabelStyle::normal(11.0),
+                    SelectedSatelliteText,
+                );
+                parent
+                    .spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(0.0),
+                            right: Val::Px(0.0),
+                            top: Val::Px(0.0),
+                            bottom: Val::Px(0.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        Pickable::IGNORE,
+                        ThemedText,
+                    ))
+                    .with_children(|center| {
+                        spawn_focus_toggle_row(center);
+                        spawn_render_frame_toggle_row(center);
+                    });
             });
     });
 
@@ -2104,7 +2237,7 @@ struct LabeledSliderRowProps<'a> {
     step: f32,
 }
 
-fn spawn_labeled_slider(
+pub(crate) fn spawn_labeled_slider(
     parent: &mut ChildSpawnerCommands,
     label: &str,
     binding: SliderBinding,
@@ -2127,7 +2260,7 @@ fn spawn_labeled_slider(
     );
 }
 
-fn spawn_styled_text<B: Bundle>(
+pub(crate) fn spawn_styled_text<B: Bundle>(
     parent: &mut ChildSpawnerCommands,
     label: &str,
     style: LabelStyle,
@@ -2468,7 +2601,7 @@ fn spawn_space_weather_row<B: Bundle>(
     });
 }
 
-fn spawn_fixed_button<B: Bundle>(
+pub(crate) fn spawn_fixed_button<B: Bundle>(
     parent: &mut ChildSpawnerCommands,
     width_px: f32,
     props: ButtonProps,
@@ -2508,7 +2641,7 @@ fn spawn_top_time_row(parent: &mut ChildSpawnerCommands) {
                 flex_direction: FlexDirection::Row,
                 align_items: AlignItems::Center,
                 column_gap: Val::Px(8.0),
-                width: Val::Px(220.0),
+                width: Val::Px(300.0),
                 flex_grow: 0.0,
                 ..default()
             },
@@ -2517,6 +2650,16 @@ fn spawn_top_time_row(parent: &mut ChildSpawnerCommands) {
         ))
         .with_children(|left| {
             spawn_pill_chip(left, "UTC: --", LabelStyle::normal(15.0), TimeText);
+            spawn_fixed_button(
+                left,
+                64.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::CycleTimeScale,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Scale",
+            );
         });
 }
 
@@ -2527,7 +2670,7 @@ fn spawn_top_speed_row(parent: &mut ChildSpawnerCommands, time_scale: f32) {
                 flex_direction: FlexDirection::Row,
                 align_items: AlignItems::Center,
                 column_gap: Val::Px(8.0),
-                width: Val::Px(280.0),
+                width: Val::Px(380.0),
                 flex_grow: 0.0,
                 ..default()
             },
@@ -2557,7 +2700,7 @@ fn spawn_top_speed_row(parent: &mut ChildSpawnerCommands, time_scale: f32) {
                         .spawn(slider(
                             SliderProps {
                                 value: time_scale,
-                                min: 1.0,
+                                min: -1_000.0,
                                 max: 1_000.0,
                             },
                             (
@@ -2594,9 +2737,26 @@ fn spawn_top_speed_row(parent: &mut ChildSpawnerCommands, time_scale: f32) {
                         (ButtonAction::TimeNow, AutoDirectionalNavigation::default()),
                         "Now",
                     );
+                    row.spawn(button(
+                        ButtonProps::default(),
+                        (
+                            ButtonAction::TogglePause,
+                            AutoDirectionalNavigation::default(),
+                        ),
+                        Spawn((
+                            PauseToggleText,
+                            bevy::ui::widget::Text::new("Pause"),
+                            ThemedText,
+                            TextFont {
+                                font_size: 12.0,
+                                ..default()
+                            },
+                        )),
+                    ))
+                    .insert(Outline::new(Val::Px(1.0), Val::Px(0.0), PANEL_EDGE));
                     spawn_info_icon_with_tooltip(
                         row,
-                        "Jumps the simulation clock to the current UTC time.",
+                        "Now jumps the simulation clock to the current UTC time. Negative speeds run the clock backwards.",
                     );
                 });
         });
@@ -2651,6 +2811,67 @@ fn spawn_focus_toggle_row(parent: &mut ChildSpawnerCommands) {
         });
 }
 
+fn spawn_render_frame_toggle_row(parent: &mut ChildSpawnerCommands) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                width: Val::Px(160.0),
+                flex_grow: 0.0,
+                ..default()
+            },
+            Pickable::IGNORE,
+            ThemedText,
+        ))
+        .with_children(|row| {
+            row.spawn(Node {
+                width: Val::Px(88.0),
+                flex_grow: 0.0,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            })
+            .with_children(|container| {
+                container
+                    .spawn(button(
+                        ButtonProps::default(),
+                        (
+                            ButtonAction::ToggleRenderFrame,
+                            AutoDirectionalNavigation::default(),
+                        ),
+                        Spawn((
+                            RenderFrameToggleText,
+                            bevy::ui::widget::Text::new(RenderFrame::Inertial.label()),
+                            ThemedText,
+                            TextFont {
+                                font_size: 12.0,
+                                ..default()
+                            },
+                        )),
+                    ))
+                    .insert(Outline::new(Val::Px(1.0), Val::Px(0.0), PANEL_EDGE));
+            });
+            spawn_info_icon_with_tooltip(
+                row,
+                "Switch between the Earth-fixed view and an inertial view where the stars stay put and the Earth turns.",
+            );
+        });
+}
+
+fn update_render_frame_toggle_text(
+    render_frame: Res<RenderFrame>,
+    mut texts: Query<&mut bevy::ui::widget::Text, With<RenderFrameToggleText>>,
+) {
+    if !render_frame.is_changed() {
+        return;
+    }
+    for mut text in &mut texts {
+        text.0 = render_frame.toggled().label().to_string();
+    }
+}
+
 fn spawn_top_panel_toggles_row(parent: &mut ChildSpawnerCommands) {
     parent
         .spawn((
@@ -2907,7 +3128,11 @@ fn slider_precision_from_step(step: f32) -> i32 {
     precision
 }
 
-fn spawn_scrollbar(parent: &mut ChildSpawnerCommands, target: Entity, height: f32) -> Entity {
+pub(crate) fn spawn_scrollbar(
+    parent: &mut ChildSpawnerCommands,
+    target: Entity,
+    height: f32,
+) -> Entity {
     use bevy_ui_widgets::{ControlOrientation, CoreScrollbarThumb, Scrollbar};
 
     parent
@@ -2965,7 +3190,16 @@ fn spawn_scrollbar_fill(parent: &mut ChildSpawnerCommands, target: Entity) -> En
         .id()
 }
 
-fn toggle_panels_keyboard(input: Res<ButtonInput<KeyCode>>, mut state: ResMut<UIState>) {
+fn toggle_panels_keyboard(
+    input: Res<ButtonInput<KeyCode>>,
+    focus: Option<Res<InputFocus>>,
+    q_search: Query<(), With<LaunchSearchField>>,
+    mut state: ResMut<UIState>,
+) {
+    // Letters typed into the launch search are not shortcuts
+    if focus.is_some_and(|focus| focus.0.is_some_and(|entity| q_search.contains(entity))) {
+        return;
+    }
     if input.just_pressed(KeyCode::KeyH) {
         state.show_left_panel = !state.show_left_panel;
     }
@@ -3256,14 +3490,39 @@ fn update_time_display(
     mut texts: ParamSet<(
         Query<&mut bevy::ui::widget::Text, With<TimeText>>,
         Query<&mut bevy::ui::widget::Text, With<TimeScaleValueText>>,
+        Query<&mut bevy::ui::widget::Text, With<PauseToggleText>>,
     )>,
     sim_time: Res<crate::orbital::SimulationTime>,
+    display_time_scale: Res<DisplayTimeScale>,
 ) {
+    // GNSS readers also want the GPS week and seconds of week
+    let gps_week = match **display_time_scale {
+        TimeScale::Gps => {
+            let (week, seconds) = gps_week_and_seconds(sim_time.current_utc);
+            format!(" (wk {week}, {seconds:.0} s)")
+        }
+        _ => String::new(),
+    };
     for mut text in &mut texts.p0() {
-        text.0 = format!("UTC: {}", sim_time.current_utc.format("%Y-%m-%d %H:%M:%S"));
+        text.0 = format!(
+            "{}: {}{gps_week}",
+            display_time_scale.label(),
+            format_in_scale(
+                sim_time.current_utc,
+                **display_time_scale,
+                "%Y-%m-%d %H:%M:%S"
+            )
+        );
     }
     for mut text in &mut texts.p1() {
-        text.0 = format!("{:.0}x", sim_time.time_scale.max(1.0));
+        text.0 = if sim_time.paused {
+            "Paused".to_string()
+        } else {
+            format!("{:.0}x", sim_time.time_scale)
+        };
+    }
+    for mut text in &mut texts.p2() {
+        text.0 = if sim_time.paused { "Play" } else { "Pause" }.to_string();
     }
 }
 
@@ -3298,7 +3557,15 @@ fn update_satellite_list_panel_width(
 #[allow(clippy::type_complexity)]
 fn update_status_texts(
     satellites: Query<(), With<Satellite>>,
-    all_satellites: Query<(&NoradId, Option<&SatelliteName>, &SatelliteFlags), With<Satellite>>,
+    all_satellites: Query<
+        (
+            &NoradId,
+            Option<&SatelliteName>,
+            &SatelliteFlags,
+            Option<&PropagationError>,
+        ),
+        With<Satellite>,
+    >,
     norad_index: Res<NoradIndex>,
     mut texts: ParamSet<(
         Query<&mut bevy::ui::widget::Text, With<SatelliteCountText>>,
@@ -3321,10 +3588,21 @@ fn update_status_texts(
     }
     for mut text in &mut texts.p2() {
         // Find clicked satellite by checking flags
-        let clicked = all_satellites.iter().find(|(_, _, flags)| flags.is_clicked);
-        if let Some((norad, name_opt, _)) = clicked {
+        let clicked = all_satellites
+            .iter()
+            .find(|(_, _, flags, _)| flags.is_clicked);
+        if let Some((norad, name_opt, _, error_opt)) = clicked {
             let name = name_opt.map_or("Unnamed", |n| n.0.as_str());
-            text.0 = format!("Selected: {} ({})", name, norad.0);
+            text.0 = match error_opt {
+                Some(error) => format!(
+                    "Selected: {} ({}) - {}: {}",
+                    name,
+                    norad.0,
+                    error.kind.label(),
+                    error.message
+                ),
+                None => format!("Selected: {} ({})", name, norad.0),
+            };
         } else {
             text.0 = "Selected: None".to_string();
         }
@@ -3333,7 +3611,7 @@ fn update_status_texts(
     for mut text in &mut texts.p3() {
         if let Some(norad) = selected.tracking {
             if let Some(&entity) = norad_index.map.get(&norad) {
-                if let Ok((_, name_opt, _)) = all_satellites.get(entity) {
+                if let Ok((_, name_opt, _, _)) = all_satellites.get(entity) {
                     let name = name_opt.map_or("Unnamed", |n| n.0.as_str());
                     text.0 = format!("Tracking: {name} ({norad})");
                 } else {
@@ -3443,318 +3721,22 @@ fn update_space_weather_texts(
     }
 }
 
-#[allow(clippy::type_complexity)]
-fn update_launch_library_texts(
-    data: Res<LaunchLibraryData>,
-    state: Res<LaunchLibraryState>,
-    mut texts: ParamSet<(
-        Query<&mut bevy::ui::widget::Text, With<LaunchLibraryUpdatedText>>,
-        Query<&mut bevy::ui::widget::Text, With<LaunchLibraryErrorText>>,
-    )>,
-) {
-    if !data.is_changed() && !state.is_changed() {
-        return;
-    }
-
-    let latest = latest_time([state.last_launch_update, state.last_event_update, None]);
-    let counts = format!(
-        "Launches: {} | Events: {}",
-        data.launches.len(),
-        data.events.len()
-    );
-
-    for mut text in &mut texts.p0() {
-        if state.is_loading_launches || state.is_loading_events {
-            text.0 = "Loading...".to_string();
-        } else {
-            let updated = format_time(latest);
-            text.0 = format!("Updated: {updated} ({counts})");
-        }
-    }
-
-    for mut text in &mut texts.p1() {
-        let err = state
-            .launch_error
-            .as_deref()
-            .or(state.event_error.as_deref());
-        text.0 = err.map(|e| format!("Data error: {e}")).unwrap_or_default();
-    }
-}
-
-#[allow(clippy::type_complexity)]
-fn update_launch_library_popup(
-    data: Res<LaunchLibraryData>,
-    launch_ui: Res<LaunchLibraryUiState>,
-    mut overlay_nodes: Query<&mut Node, With<LaunchLibraryPopupOverlay>>,
-    mut texts: ParamSet<(
-        Query<&mut bevy::ui::widget::Text, With<LaunchLibraryPopupTitle>>,
-        Query<&mut bevy::ui::widget::Text, With<LaunchLibraryPopupBody>>,
-    )>,
-) {
-    if !data.is_changed() && !launch_ui.is_changed() {
-        return;
-    }
-
-    let Ok(mut overlay) = overlay_nodes.single_mut() else {
-        return;
-    };
-
-    let Some(selection) = launch_ui.selection else {
-        overlay.display = Display::None;
-        return;
-    };
-
-    let (title, body) = match selection.kind {
-        LaunchLibraryItemKind::Launch => {
-            let Some(launch) = data.launches.get(selection.index) else {
-                overlay.display = Display::None;
-                return;
-            };
-
-            let mut lines = Vec::new();
-            lines.push(format!("NET: {}", format_time(launch.net_utc)));
-            if let Some(provider) = launch.provider_name.as_deref() {
-                lines.push(format!("Provider: {provider}"));
-            }
-            if let Some(mission) = launch.mission_name.as_deref() {
-                lines.push(format!("Mission: {mission}"));
-            }
-            if let Some(orbit) = launch.orbit_name.as_deref() {
-                lines.push(format!("Orbit: {orbit}"));
-            }
-            if let Some(pad) = launch.pad_name.as_deref() {
-                lines.push(format!("Pad: {pad}"));
-            }
-            if let Some(location) = launch.pad_location_name.as_deref() {
-                lines.push(format!("Location: {location}"));
-            }
-
-            (launch.name.clone(), lines.join("\n"))
-        }
-        LaunchLibraryItemKind::Event => {
-            let Some(event) = data.events.get(selection.index) else {
-                overlay.display = Display::None;
-                return;
-            };
-
-            let mut lines = Vec::new();
-            lines.push(format!("Date: {}", format_time(event.date_utc)));
-            if let Some(type_name) = event.type_name.as_deref() {
-                lines.push(format!("Type: {type_name}"));
-            }
-            if let Some(location) = event.location.as_deref() {
-                lines.push(format!("Location: {location}"));
-            }
-            if let Some(description) = event.description.as_deref() {
-                lines.push(String::new());
-                lines.push(description.to_string());
-            }
-
-            (event.name.clone(), lines.join("\n"))
-        }
-    };
-
-    overlay.display = Display::Flex;
-
-    for mut text in &mut texts.p0() {
-        text.0 = title.clone();
-    }
-
-    for mut text in &mut texts.p1() {
-        text.0 = body.clone();
-    }
-}
-
-fn focus_camera_on_launch_selection(
-    mut launch_ui: ResMut<LaunchLibraryUiState>,
-    data: Res<LaunchLibraryData>,
-    mut camera_focus: ResMut<CameraFocusState>,
-    mut selected: ResMut<SelectedSatellite>,
-    mut q_camera: MainCameraQuery<'_, '_>,
-) {
-    if !launch_ui.is_changed() {
-        return;
-    }
-
-    let Some(selection) = launch_ui.selection else {
-        return;
-    };
-
-    if selection.kind != LaunchLibraryItemKind::Launch {
-        return;
-    }
-
-    let Some(launch) = data.launches.get(selection.index) else {
-        return;
-    };
-
-    let (Some(lat), Some(lon)) = (launch.pad_lat, launch.pad_lon) else {
-        return;
-    };
-
-    let Ok(coords) = Coordinates::from_degrees(lat as f32, lon as f32) else {
-        return;
-    };
-    let ecef = coords.get_point_on_sphere_ecef_km_dvec();
-    let bevy_pos = ecef_to_bevy_km(ecef);
-    let mut dir = bevy_pos.normalize_or_zero();
-    if dir.length_squared() < 1e-6 {
-        dir = Vec3::Z;
-    }
-
-    if let Ok((mut poc, mut cam_transform)) = q_camera.single_mut() {
-        selected.tracking = None;
-        selected.selected = None;
-        camera_focus.target = CameraFocusTarget::Earth;
-
-        let current_radius = poc.radius.unwrap_or(poc.target_radius).max(1.0);
-        let target_radius = current_radius.min(9_000.0);
-        let pitch = dir.y.asin();
-        let yaw = dir.x.atan2(dir.z);
-        let pose = CameraPose {
-            radius: target_radius,
-            yaw,
-            pitch,
-        };
-        launch_ui.camera_target = Some(pose);
-        poc.target_radius = target_radius;
-        poc.target_pitch = pitch;
-        poc.target_yaw = yaw;
-        poc.focus = Vec3::ZERO;
-        poc.force_update = true;
-        cam_transform.look_at(Vec3::ZERO, Vec3::Y);
-    }
-}
-
-fn animate_launch_camera_focus(
-    time: Res<Time>,
-    mut launch_ui: ResMut<LaunchLibraryUiState>,
-    mut q_camera: MainCameraQuery<'_, '_>,
-) {
-    let Some(target) = launch_ui.camera_target else {
-        return;
-    };
-
-    let Ok((mut poc, mut cam_transform)) = q_camera.single_mut() else {
-        return;
-    };
-
-    let dt = time.delta_secs();
-    let smooth_factor = 0.12_f32;
-    let lerp = 1.0_f32 - (1.0_f32 - smooth_factor).powf(dt * 60.0);
-
-    let mut radius = poc.radius.unwrap_or(poc.target_radius).max(1.0);
-    let mut pitch = poc.pitch.unwrap_or(poc.target_pitch);
-    let mut yaw = poc.yaw.unwrap_or(poc.target_yaw);
-
-    let mut yaw_diff = target.yaw - yaw;
-    if yaw_diff > std::f32::consts::PI {
-        yaw_diff -= 2.0 * std::f32::consts::PI;
-    } else if yaw_diff < -std::f32::consts::PI {
-        yaw_diff += 2.0 * std::f32::consts::PI;
-    }
-
-    radius += (target.radius - radius) * lerp;
-    pitch += (target.pitch - pitch) * lerp;
-    yaw += yaw_diff * lerp;
-
-    poc.radius = Some(radius);
-    poc.pitch = Some(pitch);
-    poc.yaw = Some(yaw);
-    poc.focus = Vec3::ZERO;
-    poc.target_radius = target.radius;
-    poc.target_pitch = target.pitch;
-    poc.target_yaw = target.yaw;
-    poc.force_update = true;
-
-    let camera_pos = Vec3::new(
-        radius * pitch.cos() * yaw.sin(),
-        radius * pitch.sin(),
-        radius * pitch.cos() * yaw.cos(),
-    );
-    cam_transform.translation = camera_pos;
-    cam_transform.look_at(Vec3::ZERO, Vec3::Y);
-
-    let done = (radius - target.radius).abs() < 5.0
-        && (pitch - target.pitch).abs() < 0.002
-        && (yaw - target.yaw).abs() < 0.002;
-    if done {
-        launch_ui.camera_target = None;
-    }
-}
-
-fn format_time(timestamp: Option<DateTime<Utc>>) -> String {
+pub(crate) fn format_time(timestamp: Option<DateTime<Utc>>) -> String {
     timestamp.map_or_else(
         || "--".to_string(),
         |t| t.format("%Y-%m-%d %H:%M UTC").to_string(),
     )
 }
 
-fn latest_time(times: [Option<DateTime<Utc>>; 3]) -> Option<DateTime<Utc>> {
+pub(crate) fn latest_time(times: [Option<DateTime<Utc>>; 3]) -> Option<DateTime<Utc>> {
     times.into_iter().flatten().max()
 }
 
-fn update_launch_library_lists(
-    data: Res<LaunchLibraryData>,
-    launch_ui: Res<LaunchLibraryUiState>,
-    ui_entities: Res<UiEntities>,
-    children: Query<&Children>,
-    mut commands: Commands,
+pub(crate) fn clear_list_children(
+    list_entity: Entity,
+    children: &Query<&Children>,
+    commands: &mut Commands,
 ) {
-    if !data.is_changed() && !launch_ui.is_changed() {
-        return;
-    }
-
-    clear_list_children(ui_entities.launch_list, &children, &mut commands);
-    commands
-        .entity(ui_entities.launch_list)
-        .with_children(|parent| {
-            if data.launches.is_empty() {
-                parent.spawn((
-                    bevy::ui::widget::Text::new("No upcoming launches."),
-                    ThemedText,
-                    TextFont {
-                        font_size: 11.0,
-                        ..default()
-                    },
-                    TextColor(Color::srgba(0.6, 0.7, 0.8, 0.75)),
-                ));
-            } else {
-                for (index, launch) in data.launches.iter().enumerate() {
-                    let selected = launch_ui.selection.is_some_and(|sel| {
-                        sel.kind == LaunchLibraryItemKind::Launch && sel.index == index
-                    });
-                    spawn_launch_row(parent, launch, index, selected);
-                }
-            }
-        });
-
-    clear_list_children(ui_entities.event_list, &children, &mut commands);
-    commands
-        .entity(ui_entities.event_list)
-        .with_children(|parent| {
-            if data.events.is_empty() {
-                parent.spawn((
-                    bevy::ui::widget::Text::new("No upcoming events."),
-                    ThemedText,
-                    TextFont {
-                        font_size: 11.0,
-                        ..default()
-                    },
-                    TextColor(Color::srgba(0.6, 0.7, 0.8, 0.75)),
-                ));
-            } else {
-                for (index, event) in data.events.iter().enumerate() {
-                    let selected = launch_ui.selection.is_some_and(|sel| {
-                        sel.kind == LaunchLibraryItemKind::Event && sel.index == index
-                    });
-                    spawn_event_row(parent, event, index, selected);
-                }
-            }
-        });
-}
-
-fn clear_list_children(list_entity: Entity, children: &Query<&Children>, commands: &mut Commands) {
     if let Ok(list_children) = children.get(list_entity) {
         for child in list_children.iter() {
             commands.entity(child).despawn_children();
@@ -3763,184 +3745,7 @@ fn clear_list_children(list_entity: Entity, children: &Query<&Children>, command
     }
 }
 
-fn spawn_launch_row(
-    parent: &mut ChildSpawnerCommands,
-    launch: &crate::launch_library::LaunchSummary,
-    index: usize,
-    selected: bool,
-) {
-    let net = format_time(launch.net_utc);
-    let header = truncate_text(&format!("{net} • {}", launch.name), 72);
-    let pad = launch.pad_name.as_deref().unwrap_or("Pad TBD");
-    let location = launch
-        .pad_location_name
-        .as_deref()
-        .unwrap_or("Location TBD");
-    let details = truncate_text(&format!("{pad} ({location})"), 70);
-
-    let mut info_parts = Vec::new();
-    if let Some(provider) = launch.provider_name.as_deref() {
-        info_parts.push(provider);
-    }
-    if let Some(mission) = launch.mission_name.as_deref() {
-        info_parts.push(mission);
-    }
-    if let Some(orbit) = launch.orbit_name.as_deref() {
-        info_parts.push(orbit);
-    }
-    let info_line = if info_parts.is_empty() {
-        None
-    } else {
-        Some(truncate_text(&info_parts.join(" • "), 70))
-    };
-
-    let bg = launch_row_color(index, selected);
-
-    parent
-        .spawn((
-            UiWidgetButton,
-            LaunchLibraryItemButton {
-                kind: LaunchLibraryItemKind::Launch,
-                index,
-            },
-            AutoDirectionalNavigation::default(),
-            Node {
-                flex_direction: FlexDirection::Column,
-                row_gap: Val::Px(4.0),
-                width: Val::Percent(100.0),
-                height: Val::Auto,
-                min_height: Val::Px(46.0),
-                padding: UiRect::all(Val::Px(6.0)),
-                align_items: AlignItems::FlexStart,
-                justify_content: JustifyContent::FlexStart,
-                overflow: Overflow::clip(),
-                ..default()
-            },
-            BackgroundColor(bg),
-            EntityCursor::System(SystemCursorIcon::Pointer),
-            RelativeCursorPosition::default(),
-            Pickable::default(),
-            ThemedText,
-        ))
-        .with_children(|row| {
-            row.spawn((
-                bevy::ui::widget::Text::new(header),
-                ThemedText,
-                TextFont {
-                    font_size: 11.0,
-                    ..default()
-                },
-                TextLayout::new_with_no_wrap(),
-            ));
-            row.spawn((
-                bevy::ui::widget::Text::new(details),
-                ThemedText,
-                TextFont {
-                    font_size: 10.0,
-                    ..default()
-                },
-                TextColor(Color::srgba(0.6, 0.7, 0.8, 0.75)),
-                TextLayout::new_with_no_wrap(),
-            ));
-            if let Some(info) = info_line {
-                row.spawn((
-                    bevy::ui::widget::Text::new(info),
-                    ThemedText,
-                    TextFont {
-                        font_size: 10.0,
-                        ..default()
-                    },
-                    TextColor(Color::srgba(0.55, 0.65, 0.78, 0.7)),
-                    TextLayout::new_with_no_wrap(),
-                ));
-            }
-        })
-        .insert(Outline::new(Val::Px(1.0), Val::Px(0.0), PANEL_EDGE));
-}
-
-fn spawn_event_row(
-    parent: &mut ChildSpawnerCommands,
-    event: &crate::launch_library::EventSummary,
-    index: usize,
-    selected: bool,
-) {
-    let date = format_time(event.date_utc);
-    let header = truncate_text(&format!("{date} • {}", event.name), 72);
-    let type_name = event.type_name.as_deref().unwrap_or("Event");
-    let location = event.location.as_deref().unwrap_or("Location TBD");
-    let details = truncate_text(&format!("{type_name} • {location}"), 70);
-    let desc = event
-        .description
-        .as_deref()
-        .map(|text| truncate_text(text, 96));
-
-    let bg = event_row_color(index, selected);
-
-    parent
-        .spawn((
-            UiWidgetButton,
-            LaunchLibraryItemButton {
-                kind: LaunchLibraryItemKind::Event,
-                index,
-            },
-            AutoDirectionalNavigation::default(),
-            Node {
-                flex_direction: FlexDirection::Column,
-                row_gap: Val::Px(4.0),
-                width: Val::Percent(100.0),
-                height: Val::Auto,
-                min_height: Val::Px(46.0),
-                padding: UiRect::all(Val::Px(6.0)),
-                align_items: AlignItems::FlexStart,
-                justify_content: JustifyContent::FlexStart,
-                overflow: Overflow::clip(),
-                ..default()
-            },
-            BackgroundColor(bg),
-            EntityCursor::System(SystemCursorIcon::Pointer),
-            RelativeCursorPosition::default(),
-            Pickable::default(),
-            ThemedText,
-        ))
-        .with_children(|row| {
-            row.spawn((
-                bevy::ui::widget::Text::new(header),
-                ThemedText,
-                TextFont {
-                    font_size: 11.0,
-                    ..default()
-                },
-                TextLayout::new_with_no_wrap(),
-            ));
-            row.spawn((
-                bevy::ui::widget::Text::new(details),
-                ThemedText,
-                TextFont {
-                    font_size: 10.0,
-                    ..default()
-                },
-                TextColor(Color::srgba(0.6, 0.7, 0.8, 0.75)),
-                TextLayout::new_with_no_wrap(),
-            ));
-            if let Some(desc) = desc
-                && !desc.trim().is_empty()
-            {
-                row.spawn((
-                    bevy::ui::widget::Text::new(desc),
-                    ThemedText,
-                    TextFont {
-                        font_size: 10.0,
-                        ..default()
-                    },
-                    TextColor(Color::srgba(0.55, 0.65, 0.78, 0.7)),
-                    TextLayout::new_with_no_wrap(),
-                ));
-            }
-        })
-        .insert(Outline::new(Val::Px(1.0), Val::Px(0.0), PANEL_EDGE));
-}
-
-fn truncate_text(text: &str, max_len: usize) -> String {
+pub(crate) fn truncate_text(text: &str, max_len: usize) -> String {
     let count = text.chars().count();
     if count <= max_len {
         return text.to_string();
@@ -3951,26 +3756,6 @@ fn truncate_text(text: &str, max_len: usize) -> String {
     trimmed
 }
 
-fn launch_row_color(index: usize, selected: bool) -> Color {
-    if selected {
-        Color::srgba(0.12, 0.32, 0.36, 0.95)
-    } else if index.is_multiple_of(2) {
-        Color::srgba(0.06, 0.08, 0.11, 0.92)
-    } else {
-        Color::srgba(0.09, 0.12, 0.16, 0.92)
-    }
-}
-
-fn event_row_color(index: usize, selected: bool) -> Color {
-    if selected {
-        Color::srgba(0.12, 0.3, 0.34, 0.95)
-    } else if index.is_multiple_of(2) {
-        Color::srgba(0.06, 0.08, 0.11, 0.92)
-    } else {
-        Color::srgba(0.09, 0.12, 0.16, 0.92)
-    }
-}
-
 fn handle_group_loading_text(
     right_ui: Res<RightPanelUI>,
     mut group_text: Query<&mut bevy::ui::widget::Text, With<GroupLoadingText>>,
@@ -4060,6 +3845,23 @@ fn process_pending_add(
         return;
     }
 
+    right_ui.error = spawn_satellite_for_norad(
+        norad,
+        &mut norad_index,
+        fetch_channels.as_deref(),
+        &mut commands,
+    )
+    .err();
+    right_ui.input.clear();
+}
+
+/// Spawn a satellite entity for `norad` and request its TLE
+pub(crate) fn spawn_satellite_for_norad(
+    norad: u32,
+    norad_index: &mut NoradIndex,
+    fetch_channels: Option<&FetchChannels>,
+    commands: &mut Commands,
+) -> Result<(), String> {
     let seed = norad.wrapping_mul(1664525).wrapping_add(1013904223);
     let hue = (seed as f32 / u32::MAX as f32).fract();
     let sat = (0.65 + ((norad % 7) as f32) * 0.035).clamp(0.6, 0.9);
@@ -4077,16 +3879,13 @@ fn process_pending_add(
         .id();
     norad_index.map.insert(norad, entity);
 
-    right_ui.error = None;
-    if let Some(fetch) = fetch_channels {
-        if let Err(e) = fetch.cmd_tx.send(FetchCommand::Fetch(norad)) {
-            right_ui.error = Some(format!("Failed to fetch NORAD {norad}: {e}"));
-        }
-    } else {
-        right_ui.error = Some("Fetch service not available".to_string());
-    }
-
-    right_ui.input.clear();
+    let Some(fetch) = fetch_channels else {
+        return Err("Fetch service not available".to_string());
+    };
+    fetch
+        .cmd_tx
+        .send(FetchCommand::Fetch(norad))
+        .map_err(|e| format!("Failed to fetch NORAD {norad}: {e}"))
 }
 
 #[derive(Component)]
@@ -4103,9 +3902,13 @@ struct SatelliteRowRefs {
     trail_chk: Entity,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn update_satellite_list(
     sat_query: SatListQuery<'_, '_>,
     selected: Res<SelectedSatellite>,
+    right_ui: Res<RightPanelUI>,
+    decay_cfg: Res<DecayConfig>,
+    sim_time: Res<SimulationTime>,
     ui_entities: Res<UiEntities>,
     row_query: Query<(Entity, &SatelliteRow, &SatelliteRowRefs)>,
     mut texts: Query<(&mut bevy::ui::widget::Text, Option<&mut TextColor>)>,
@@ -4123,18 +3926,47 @@ fn update_satellite_list(
 
     let parent = ui_entities.satellite_list;
 
-    for (norad_id, name_opt, flags, propagator_opt, error_opt, tle_opt) in &sat_data {
+    for (norad_id, name_opt, flags, propagator_opt, error_opt, tle_opt, reentry_opt) in &sat_data {
         let norad = norad_id.0;
+        let age_days = tle_opt.map(|tle| tle.0.age_days(sim_time.current_utc));
+        let freshness = age_days.map(TleFreshness::from_age_days);
+        let reentry_days = reentry_opt
+            .filter(|reentry| decay_cfg.is_flagged(&reentry.0, sim_time.current_utc))
+            .map(|reentry| reentry.0.days_until(sim_time.current_utc));
+        let visible = match right_ui.list_filter {
+            SatelliteListFilter::All => true,
+            SatelliteListFilter::Errors => error_opt.is_some(),
+            SatelliteListFilter::Stale => freshness == Some(TleFreshness::Stale),
+            SatelliteListFilter::Reentry => reentry_days.is_some(),
+        };
+        if !visible {
+            // Leaving the row in existing_rows despawns it below
+            continue;
+        }
+
         let is_tracking = selected.tracking == Some(norad);
-        let (status_text, status_color) = if error_opt.is_some() {
-            ("Error", Color::srgb(1.0, 0.2, 0.2))
-        } else if propagator_opt.is_some() {
-            ("Ready", Color::srgb(0.2, 0.9, 0.2))
-        } else if tle_opt.is_some() {
-            ("TLE", Color::srgb(0.9, 0.9, 0.2))
-        } else {
-            ("Fetching", Color::srgb(0.7, 0.7, 0.7))
+        let (status_text, status_color) = match (error_opt, propagator_opt, age_days, freshness) {
+            (Some(error), ..) => (error.kind.label().to_string(), Color::srgb(1.0, 0.2, 0.2)),
+            (None, Some(_), ..) if reentry_days.is_some() => (
+                format!(
+                    "Re-entry {}",
+                    format_day_span(reentry_days.unwrap_or_default())
+                ),
+                Color::srgb(1.0, 0.4, 0.6),
+            ),
+            (None, Some(_), Some(age), Some(freshness)) => {
+                let (label, color) = match freshness {
+                    TleFreshness::Fresh => ("Ready", Color::srgb(0.2, 0.9, 0.2)),
+                    TleFreshness::Aging => ("Aging", Color::srgb(0.9, 0.9, 0.2)),
+                    TleFreshness::Stale => ("Stale", Color::srgb(1.0, 0.55, 0.1)),
+                };
+                (format!("{label} {}", format_day_span(age)), color)
+            }
+            (None, Some(_), ..) => ("Ready".to_string(), Color::srgb(0.2, 0.9, 0.2)),
+            (None, None, Some(_), _) => ("TLE".to_string(), Color::srgb(0.9, 0.9, 0.2)),
+            (None, None, None, _) => ("Fetching".to_string(), Color::srgb(0.7, 0.7, 0.7)),
         };
+        let status_text = status_text.as_str();
 
         let name_str = name_opt.map(|n| n.0.as_str()).unwrap_or("Unnamed");
 
@@ -4203,6 +4035,17 @@ fn update_satellite_list(
     }
 }
 
+/// Compact signed day count for badges, e.g. "5h" or "12d"
+pub(crate) fn format_day_span(days: f64) -> String {
+    let span = days.abs();
+    let sign = if days < 0.0 { "-" } else { "" };
+    if span < 1.0 {
+        format!("{sign}{:.0}h", span * 24.0)
+    } else {
+        format!("{sign}{span:.0}d")
+    }
+}
+
 fn spawn_satellite_row(
     parent: &mut ChildSpawnerCommands,
     norad: u32,
@@ -4289,7 +4132,7 @@ fn spawn_satellite_row(
                     ThemedText,
                     TextColor(status_color),
                     Node {
-                        min_width: Val::Px(60.0),
+                        min_width: Val::Px(76.0),
                         ..default()
                     },
                 ))
@@ -4381,11 +4224,17 @@ fn sync_widget_states(mut params: SyncWidgetStateParams<'_, '_>) {
         || params.arrows.is_changed()
         || params.config_bundle.is_changed()
         || params.heatmap_cfg.is_changed()
+        || params.coverage_cfg.is_changed()
+        || params.revisit_cfg.is_changed()
+        || params.decay_cfg.is_changed()
+        || params.lighting_cfg.is_changed()
+        || params.launch_window_cfg.is_changed()
         || params.space_weather_cfg.is_changed()
         || params.launch_library_cfg.is_changed()
         || params.camera_focus.is_changed()
         || params.selected.is_changed()
         || params.sim_time.is_changed()
+        || params.timeline_ui.is_changed()
         || params.right_ui.is_changed()
         || params.norad_index.is_changed()
         || !params.flags_changed.is_empty()
@@ -4427,9 +4276,16 @@ fn sync_widget_states(mut params: SyncWidgetStateParams<'_, '_>) {
                             .iter()
                             .all(|(flags, _)| flags.show_ground_track)
                 }
-                CheckboxBinding::HeatmapEnabled => params.heatmap_cfg.enabled,
+                CheckboxBinding::HeatmapEnabled => {
+                    params.heatmap_cfg.enabled && params.heatmap_cfg.source == HeatmapSource::Live
+                }
+                CheckboxBinding::CoverageOverlay => {
+                    params.heatmap_cfg.enabled
+                        && params.heatmap_cfg.source == HeatmapSource::Coverage
+                }
                 CheckboxBinding::AuroraOverlay => params.space_weather_cfg.aurora_enabled,
                 CheckboxBinding::LaunchPadMarkers => params.launch_library_cfg.show_pad_markers,
+                CheckboxBinding::LaunchAlerts => params.launch_library_cfg.launch_alerts,
             };
 
             match (should_check, checked.is_some()) {
@@ -4459,6 +4315,71 @@ fn sync_widget_states(mut params: SyncWidgetStateParams<'_, '_>) {
             }
         }
 
+        for (entity, binding, checked) in params.heatmap_metrics {
+            let should_check = binding.0 == params.heatmap_cfg.metric;
+            match (should_check, checked.is_some()) {
+                (true, false) => {
+                    queue_set_checked(&mut params.commands, entity, true);
+                }
+                (false, true) => {
+                    queue_set_checked(&mut params.commands, entity, false);
+                }
+                _ => {}
+            }
+        }
+
+        for (entity, binding, checked) in params.dop_groups {
+            let should_check = binding.0 == params.heatmap_cfg.dop_group.as_deref();
+            match (should_check, checked.is_some()) {
+                (true, false) => {
+                    queue_set_checked(&mut params.commands, entity, true);
+                }
+                (false, true) => {
+                    queue_set_checked(&mut params.commands, entity, false);
+                }
+                _ => {}
+            }
+        }
+
+        for (entity, binding, checked) in params.revisit_targets {
+            let should_check = binding.0 == params.revisit_cfg.targets;
+            match (should_check, checked.is_some()) {
+                (true, false) => {
+                    queue_set_checked(&mut params.commands, entity, true);
+                }
+                (false, true) => {
+                    queue_set_checked(&mut params.commands, entity, false);
+                }
+                _ => {}
+            }
+        }
+
+        for (entity, binding, checked) in params.coverage_metrics {
+            let should_check = binding.0 == params.coverage_cfg.metric;
+            match (should_check, checked.is_some()) {
+                (true, false) => {
+                    queue_set_checked(&mut params.commands, entity, true);
+                }
+                (false, true) => {
+                    queue_set_checked(&mut params.commands, entity, false);
+                }
+                _ => {}
+            }
+        }
+
+        for (entity, binding, checked) in params.satellite_filters {
+            let should_check = binding.0 == params.right_ui.list_filter;
+            match (should_check, checked.is_some()) {
+                (true, false) => {
+                    queue_set_checked(&mut params.commands, entity, true);
+                }
+                (false, true) => {
+                    queue_set_checked(&mut params.commands, entity, false);
+                }
+                _ => {}
+            }
+        }
+
         if let Some(selected_group) = params.right_ui.selected_group.as_deref() {
             for (entity, choice, checked) in params.group_choices {
                 let should_check = choice.0 == selected_group;
@@ -4490,6 +4411,24 @@ fn sync_widget_states(mut params: SyncWidgetStateParams<'_, '_>) {
                 SliderBinding::HeatmapFixedMax => params.heatmap_cfg.fixed_max.unwrap_or(20) as f32,
                 SliderBinding::HeatmapChunkSize => params.heatmap_cfg.chunk_size as f32,
                 SliderBinding::HeatmapChunksPerFrame => params.heatmap_cfg.chunks_per_frame as f32,
+                SliderBinding::HeatmapMinElevation => params.heatmap_cfg.min_elevation_deg,
+                SliderBinding::HeatmapDopFixedMax => params.heatmap_cfg.dop_fixed_max,
+                SliderBinding::CoverageDurationHours => params.coverage_cfg.duration_hours,
+                SliderBinding::CoverageStepSeconds => params.coverage_cfg.step_seconds,
+                SliderBinding::CoverageCellSize => params.coverage_cfg.region.cell_size_deg as f32,
+                SliderBinding::CoverageMinElevation => params.coverage_cfg.min_elevation_deg,
+                SliderBinding::CoverageLatMin => params.coverage_cfg.region.lat_min_deg as f32,
+                SliderBinding::CoverageLatMax => params.coverage_cfg.region.lat_max_deg as f32,
+                SliderBinding::CoverageLonMin => params.coverage_cfg.region.lon_min_deg as f32,
+                SliderBinding::CoverageLonMax => params.coverage_cfg.region.lon_max_deg as f32,
+                SliderBinding::RevisitDurationHours => params.revisit_cfg.duration_hours,
+                SliderBinding::RevisitStepSeconds => params.revisit_cfg.step_seconds,
+                SliderBinding::RevisitMinElevation => params.revisit_cfg.min_elevation_deg,
+                SliderBinding::ReentryFlagDays => params.decay_cfg.flag_within_days,
+                SliderBinding::LightingDays => params.lighting_cfg.days,
+                SliderBinding::LaunchWindowInclination => params.launch_window_cfg.inclination_deg,
+                SliderBinding::LaunchWindowRaan => params.launch_window_cfg.raan_deg,
+                SliderBinding::LaunchWindowDays => params.launch_window_cfg.days,
                 SliderBinding::AuroraIntensity => params.space_weather_cfg.aurora_intensity_scale,
                 SliderBinding::AuroraAlpha => params.space_weather_cfg.aurora_alpha,
                 SliderBinding::AuroraLongitudeOffset => {
@@ -4504,6 +4443,8 @@ fn sync_widget_states(mut params: SyncWidgetStateParams<'_, '_>) {
                 SliderBinding::TrackingDistance => params.selected.tracking_offset,
                 SliderBinding::TrackingSmoothness => params.selected.smooth_factor,
                 SliderBinding::TimeScale => params.sim_time.time_scale,
+                SliderBinding::TimeStepMinutes => params.sim_time.step_seconds / 60.0,
+                SliderBinding::TimelineWindowHours => params.timeline_ui.window_hours,
             };
             if let Ok(current) = params.slider_values.get(entity) {
                 if (current.0 - value).abs() > f32::EPSILON {
@@ -4664,6 +4605,18 @@ fn handle_button_activate(ev: On<Activate>, mut params: ButtonActivateParams<'_,
             ButtonAction::TimeNow => {
                 params.sim_time.current_utc = chrono::Utc::now();
                 params.sim_time.time_scale = 1.0;
+                params.sim_time.paused = false;
+            }
+            ButtonAction::TogglePause => {
+                params.sim_time.paused = !params.sim_time.paused;
+            }
+            ButtonAction::StepBack => params.sim_time.step(false),
+            ButtonAction::StepForward => params.sim_time.step(true),
+            ButtonAction::ReverseTime => {
+                params.sim_time.time_scale = -params.sim_time.time_scale;
+            }
+            ButtonAction::JumpToDate => {
+                params.time_ui.pending_jump = true;
             }
             ButtonAction::ToggleFocusTarget => {
                 if let Ok((mut poc, mut cam_transform)) = params.q_camera.single_mut() {
@@ -4702,12 +4655,81 @@ fn handle_button_activate(ev: On<Activate>, mut params: ButtonActivateParams<'_,
                     }
                 }
             }
+            ButtonAction::ToggleRenderFrame => {
+                *params.render_frame = params.render_frame.toggled();
+                println!("[FRAME] Viewing in {} frame", params.render_frame.label());
+            }
             ButtonAction::RefreshLaunchLibrary => {
                 params.launch_library_state.force_refresh = true;
             }
+            ButtonAction::CycleLaunchFilter(field) => {
+                // Values from both lists, so past launches can be filtered too
+                let data = &params.launch_data;
+                params
+                    .launch_filter
+                    .cycle(*field, data.launches.iter().chain(&data.previous_launches));
+            }
+            ButtonAction::ClearLaunchFilters => {
+                // Skip a no-op clear so the launch lists aren't rebuilt
+                if params.launch_filter.is_active() {
+                    params.launch_filter.clear();
+                }
+            }
+            ButtonAction::MoreLaunches => {
+                params.launch_library_state.pending_more_launches = true;
+            }
             ButtonAction::CloseLaunchPopup => {
                 params.launch_ui.selection = None;
             }
+            ButtonAction::AddLaunchPayloads => {
+                params.launch_ui.pending_add_payloads = true;
+            }
+            ButtonAction::ExportLaunchCalendar => {
+                params.launch_library_state.calendar_export_requested = true;
+            }
+            ButtonAction::AddSpaceStations => {
+                params.launch_ui.pending_add_stations = true;
+            }
+            ButtonAction::RunCoverage => {
+                params.coverage_state.run_requested = true;
+            }
+            ButtonAction::ExportCoverage => {
+                params.coverage_state.export_requested = true;
+            }
+            ButtonAction::PickAnalysisSite => {
+                params.analysis_site.picking = !params.analysis_site.picking;
+            }
+            ButtonAction::RunRevisit => {
+                // The group comes from the Satellite Groups section
+                params.revisit_cfg.group = params.right_ui.selected_group.clone();
+                params.revisit_state.run_requested = true;
+            }
+            ButtonAction::ExportRevisit => {
+                params.revisit_state.export_requested = true;
+            }
+            ButtonAction::NextTransmitter => {
+                params.radio_cfg.transmitter_index =
+                    params.radio_cfg.transmitter_index.wrapping_add(1);
+            }
+            ButtonAction::PlanRadioPass => {
+                params.radio_state.run_requested = true;
+            }
+            ButtonAction::RunLighting => {
+                params.lighting_state.run_requested = true;
+            }
+            ButtonAction::CycleLaunchWindowSite => {
+                params.launch_window_cfg.site = params.launch_window_cfg.site.next();
+            }
+            ButtonAction::CycleLaunchWindowTarget => {
+                params.launch_window_cfg.target = params.launch_window_cfg.target.next();
+            }
+            ButtonAction::RunLaunchWindow => {
+                params.launch_window_state.run_requested = true;
+            }
+            ButtonAction::CycleTimeScale => {
+                params.display_time_scale.0 = params.display_time_scale.next();
+                println!("[TIME] showing {}", params.display_time_scale.label());
+            }
         }
     }
 
@@ -4806,11 +4828,25 @@ fn handle_checkbox_change(ev: On<ValueChange<bool>>, mut params: CheckboxChangeP
                     }
                 }
             }
-            CheckboxBinding::HeatmapEnabled => params.heatmap_cfg.enabled = ev.value,
+            CheckboxBinding::HeatmapEnabled => {
+                params.heatmap_cfg.enabled = ev.value;
+                if ev.value {
+                    params.heatmap_cfg.source = HeatmapSource::Live;
+                }
+            }
+            CheckboxBinding::CoverageOverlay => {
+                params.heatmap_cfg.enabled = ev.value;
+                params.heatmap_cfg.source = if ev.value {
+                    HeatmapSource::Coverage
+                } else {
+                    HeatmapSource::Live
+                };
+            }
             CheckboxBinding::AuroraOverlay => params.space_weather_cfg.aurora_enabled = ev.value,
             CheckboxBinding::LaunchPadMarkers => {
                 params.launch_library_cfg.show_pad_markers = ev.value;
             }
+            CheckboxBinding::LaunchAlerts => params.launch_library_cfg.launch_alerts = ev.value,
         }
         return;
     }
@@ -4826,14 +4862,21 @@ fn handle_checkbox_change(ev: On<ValueChange<bool>>, mut params: CheckboxChangeP
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_slider_change(
     ev: On<ValueChange<f32>>,
     q_binding: Query<&SliderBinding>,
     mut config_bundle: ResMut<UiConfigBundle>,
     mut heatmap_cfg: ResMut<HeatmapConfig>,
+    mut coverage_cfg: ResMut<CoverageConfig>,
+    mut revisit_cfg: ResMut<RevisitConfig>,
+    mut decay_cfg: ResMut<DecayConfig>,
+    mut lighting_cfg: ResMut<LightingConfig>,
+    mut launch_window_cfg: ResMut<LaunchWindowConfig>,
     mut space_weather_cfg: ResMut<SpaceWeatherConfig>,
     mut selected: ResMut<SelectedSatellite>,
     mut sim_time: ResMut<crate::orbital::SimulationTime>,
+    mut timeline_ui: ResMut<TimelineUi>,
 ) {
     let Ok(binding) = q_binding.get(ev.source) else {
         return;
@@ -4862,6 +4905,26 @@ fn handle_slider_change(
         SliderBinding::HeatmapChunksPerFrame => {
             heatmap_cfg.chunks_per_frame = ev.value.round().clamp(1.0, 5.0) as usize;
         }
+        SliderBinding::HeatmapMinElevation => heatmap_cfg.min_elevation_deg = ev.value,
+        SliderBinding::HeatmapDopFixedMax => heatmap_cfg.dop_fixed_max = ev.value,
+        SliderBinding::CoverageDurationHours => coverage_cfg.duration_hours = ev.value,
+        SliderBinding::CoverageStepSeconds => coverage_cfg.step_seconds = ev.value,
+        SliderBinding::CoverageCellSize => {
+            coverage_cfg.region.cell_size_deg = f64::from(ev.value.clamp(1.0, 15.0));
+        }
+        SliderBinding::CoverageMinElevation => coverage_cfg.min_elevation_deg = ev.value,
+        SliderBinding::CoverageLatMin => coverage_cfg.region.lat_min_deg = f64::from(ev.value),
+        SliderBinding::CoverageLatMax => coverage_cfg.region.lat_max_deg = f64::from(ev.value),
+        SliderBinding::CoverageLonMin => coverage_cfg.region.lon_min_deg = f64::from(ev.value),
+        SliderBinding::CoverageLonMax => coverage_cfg.region.lon_max_deg = f64::from(ev.value),
+        SliderBinding::RevisitDurationHours => revisit_cfg.duration_hours = ev.value,
+        SliderBinding::RevisitStepSeconds => revisit_cfg.step_seconds = ev.value,
+        SliderBinding::RevisitMinElevation => revisit_cfg.min_elevation_deg = ev.value,
+        SliderBinding::ReentryFlagDays => decay_cfg.flag_within_days = ev.value,
+        SliderBinding::LightingDays => lighting_cfg.days = ev.value.round(),
+        SliderBinding::LaunchWindowInclination => launch_window_cfg.inclination_deg = ev.value,
+        SliderBinding::LaunchWindowRaan => launch_window_cfg.raan_deg = ev.value,
+        SliderBinding::LaunchWindowDays => launch_window_cfg.days = ev.value.round(),
         SliderBinding::AuroraIntensity => {
             space_weather_cfg.aurora_intensity_scale = ev.value;
         }
@@ -4878,6 +4941,8 @@ fn handle_slider_change(
         SliderBinding::TrackingDistance => selected.tracking_offset = ev.value,
         SliderBinding::TrackingSmoothness => selected.smooth_factor = ev.value,
         SliderBinding::TimeScale => sim_time.time_scale = ev.value,
+        SliderBinding::TimeStepMinutes => sim_time.step_seconds = ev.value * 60.0,
+        SliderBinding::TimelineWindowHours => timeline_ui.window_hours = ev.value,
     }
 }
 
@@ -4898,6 +4963,19 @@ fn handle_range_mode_change(
     };
 }
 
+fn handle_satellite_filter_change(
+    ev: On<ValueChange<bool>>,
+    q_binding: Query<&SatelliteFilterBinding>,
+    mut right_ui: ResMut<RightPanelUI>,
+) {
+    let Ok(binding) = q_binding.get(ev.source) else {
+        return;
+    };
+    if ev.value {
+        right_ui.list_filter = binding.0;
+    }
+}
+
 fn handle_group_choice(
     ev: On<ValueChange<bool>>,
     q_choice: Query<&GroupChoice>,
@@ -5027,9 +5105,17 @@ fn handle_group_color_green_change(
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn text_input_on_click(
     ev: On<Pointer<Click>>,
-    q_input: Query<(), With<TextInputField>>,
+    q_input: Query<
+        (),
+        Or<(
+            With<TextInputField>,
+            With<DateInputField>,
+            With<LaunchSearchField>,
+        )>,
+    >,
     focus: Option<ResMut<InputFocus>>,
     focus_visible: Option<ResMut<InputFocusVisible>>,
 ) {
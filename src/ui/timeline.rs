@@ -0,0 +1,596 @@
+//! Bottom timeline strip and the time control panel
+
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::Key;
+use bevy::picking::Pickable;
+use bevy::picking::events::{Click, Drag, Out, Over, Pointer};
+use bevy::prelude::*;
+use bevy::ui::auto_directional_navigation::AutoDirectionalNavigation;
+use bevy_feathers::controls::ButtonProps;
+use bevy_feathers::theme::ThemedText;
+use bevy_input_focus::{FocusedInput, tab_navigation::TabIndex};
+use chrono::{DateTime, Utc};
+
+use crate::analysis::{LaunchWindowState, LightingState, RadioState, RevisitState};
+use crate::launch_library::LaunchLibraryData;
+use crate::orbital::DisplayTimeScale;
+use crate::orbital::time::{SimulationTime, parse_jump_time};
+use crate::ui::state::{TimeControlUi, TimelineUi};
+use crate::ui::systems::{
+    ButtonAction, LabelStyle, PANEL_EDGE, PANEL_INNER_BG, PANEL_TEXT_ACCENT, SliderBinding,
+    spawn_fixed_button, spawn_labeled_slider, spawn_styled_text,
+};
+
+#[derive(Component)]
+pub(crate) struct DateInputField;
+
+#[derive(Component)]
+pub(crate) struct DateInputValueText;
+
+#[derive(Component)]
+pub(crate) struct TimeControlStatusText;
+
+#[derive(Component)]
+pub(crate) struct TimelineStrip;
+
+/// Event on the timeline; spans (passes, eclipse seasons) carry an end time
+#[derive(Component)]
+pub(crate) struct TimelineMarker {
+    start_utc: DateTime<Utc>,
+    end_utc: Option<DateTime<Utc>>,
+    label: String,
+}
+
+#[derive(Component)]
+pub(crate) struct TimelineEdgeText {
+    future: bool,
+}
+
+#[derive(Component)]
+pub(crate) struct TimelineHoverText;
+
+/// Cap on pass markers so a long revisit report doesn't flood the timeline
+const TIMELINE_MAX_PASS_MARKERS: usize = 200;
+
+pub(crate) fn spawn_timeline_row(parent: &mut ChildSpawnerCommands, window_hours: f32) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                width: Val::Percent(100.0),
+                height: Val::Px(26.0),
+                ..default()
+            },
+            Pickable::IGNORE,
+            ThemedText,
+        ))
+        .with_children(|row| {
+            let edge = format_window_edge(window_hours);
+            spawn_styled_text(
+                row,
+                &format!("-{edge}"),
+                LabelStyle::normal(11.0),
+                TimelineEdgeText { future: false },
+            );
+            row.spawn((
+                TimelineStrip,
+                Node {
+                    position_type: PositionType::Relative,
+                    flex_grow: 1.0,
+                    height: Val::Px(20.0),
+                    border_radius: BorderRadius::all(Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(PANEL_INNER_BG),
+                Outline::new(Val::Px(1.0), Val::Px(0.0), PANEL_EDGE),
+            ))
+            .with_children(|strip| {
+                // The clock sits at the centre; the strip moves under it
+                strip.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(50.0),
+                        top: Val::Px(0.0),
+                        bottom: Val::Px(0.0),
+                        width: Val::Px(2.0),
+                        ..default()
+                    },
+                    BackgroundColor(PANEL_TEXT_ACCENT),
+                    Pickable::IGNORE,
+                ));
+            });
+            spawn_styled_text(
+                row,
+                &format!("+{edge}"),
+                LabelStyle::normal(11.0),
+                TimelineEdgeText { future: true },
+            );
+            row.spawn((
+                Node {
+                    width: Val::Px(300.0),
+                    flex_shrink: 0.0,
+                    ..default()
+                },
+                Pickable::IGNORE,
+                ThemedText,
+            ))
+            .with_children(|label| {
+                spawn_styled_text(
+                    label,
+                    "Drag to scrub, click a marker to jump",
+                    LabelStyle::normal(11.0),
+                    TimelineHoverText,
+                );
+            });
+        });
+}
+
+/// Half-window label such as `12h` or `5d`
+fn format_window_edge(window_hours: f32) -> String {
+    let half = window_hours / 2.0;
+    if half >= 48.0 {
+        format!("{:.0}d", half / 24.0)
+    } else {
+        format!("{half:.0}h")
+    }
+}
+
+/// Pause, step and jump-to-date controls with the timeline window
+pub(crate) fn spawn_time_controls_section(
+    section: &mut ChildSpawnerCommands,
+    sim_time: &SimulationTime,
+    timeline_ui: &TimelineUi,
+) {
+    spawn_labeled_slider(
+        section,
+        "Step (min)",
+        SliderBinding::TimeStepMinutes,
+        1.0,
+        1_440.0,
+        sim_time.step_seconds / 60.0,
+        1.0,
+    );
+    spawn_labeled_slider(
+        section,
+        "Timeline (h)",
+        SliderBinding::TimelineWindowHours,
+        1.0,
+        720.0,
+        timeline_ui.window_hours,
+        1.0,
+    );
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ThemedText,
+        ))
+        .with_children(|row| {
+            spawn_fixed_button(
+                row,
+                72.0,
+                ButtonProps::default(),
+                (ButtonAction::StepBack, AutoDirectionalNavigation::default()),
+                "Back",
+            );
+            spawn_fixed_button(
+                row,
+                72.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::StepForward,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Forward",
+            );
+            spawn_fixed_button(
+                row,
+                72.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::ReverseTime,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Reverse",
+            );
+        });
+    section
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(8.0),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+            ThemedText,
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    width: Val::Px(180.0),
+                    height: Val::Px(28.0),
+                    padding: UiRect::horizontal(Val::Px(6.0)),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.08, 0.1, 0.14, 1.0)),
+                ThemedText,
+                AutoDirectionalNavigation::default(),
+                TabIndex(0),
+                DateInputField,
+            ))
+            .with_children(|field| {
+                field.spawn((
+                    DateInputValueText,
+                    bevy::ui::widget::Text::new("YYYY-MM-DD HH:MM"),
+                    ThemedText,
+                ));
+            });
+
+            spawn_fixed_button(
+                row,
+                64.0,
+                ButtonProps::default(),
+                (
+                    ButtonAction::JumpToDate,
+                    AutoDirectionalNavigation::default(),
+                ),
+                "Jump",
+            );
+        });
+    section.spawn((
+        TimeControlStatusText,
+        bevy::ui::widget::Text::new("Dates are read in the time scale shown in the top bar"),
+        ThemedText,
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+    ));
+}
+
+pub(crate) fn date_input_on_key_input(
+    ev: On<FocusedInput<bevy::input::keyboard::KeyboardInput>>,
+    q_input: Query<(), With<DateInputField>>,
+    mut time_ui: ResMut<TimeControlUi>,
+) {
+    if !q_input.contains(ev.focused_entity) {
+        return;
+    }
+
+    let event = &ev.event().input;
+    if event.state != ButtonState::Pressed {
+        return;
+    }
+
+    match &event.logical_key {
+        Key::Backspace => {
+            time_ui.date_input.pop();
+        }
+        Key::Enter => {
+            time_ui.pending_jump = true;
+        }
+        Key::Space => time_ui.date_input.push(' '),
+        Key::Character(text) => {
+            for c in text.chars() {
+                if c.is_ascii_digit() || matches!(c, '-' | ':' | '.' | '+' | 'T' | 'Z') {
+                    time_ui.date_input.push(c);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+pub(crate) fn process_pending_jump(
+    mut time_ui: ResMut<TimeControlUi>,
+    mut sim_time: ResMut<SimulationTime>,
+    display_time_scale: Res<DisplayTimeScale>,
+) {
+    if !time_ui.pending_jump {
+        return;
+    }
+    time_ui.pending_jump = false;
+
+    match parse_jump_time(&time_ui.date_input, **display_time_scale) {
+        Some(utc) => {
+            sim_time.current_utc = utc;
+            println!("[TIME] jumped to {}", utc.to_rfc3339());
+            time_ui.status = Some(format!("Jumped to {} UTC", utc.format("%Y-%m-%d %H:%M:%S")));
+        }
+        None => {
+            time_ui.status = Some(format!(
+                "Could not read \"{}\"; use YYYY-MM-DD HH:MM",
+                time_ui.date_input
+            ));
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_time_control_texts(
+    time_ui: Res<TimeControlUi>,
+    mut texts: ParamSet<(
+        Query<&mut bevy::ui::widget::Text, With<DateInputValueText>>,
+        Query<&mut bevy::ui::widget::Text, With<TimeControlStatusText>>,
+    )>,
+) {
+    if !time_ui.is_changed() {
+        return;
+    }
+    for mut text in &mut texts.p0() {
+        text.0 = if time_ui.date_input.is_empty() {
+            "YYYY-MM-DD HH:MM".to_string()
+        } else {
+            time_ui.date_input.clone()
+        };
+    }
+    if let Some(status) = &time_ui.status {
+        for mut text in &mut texts.p1() {
+            text.0 = status.clone();
+        }
+    }
+}
+
+/// Rebuild timeline markers when launches, events or computed passes change
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rebuild_timeline_markers(
+    launch_data: Res<LaunchLibraryData>,
+    radio_state: Res<RadioState>,
+    revisit_state: Res<RevisitState>,
+    lighting_state: Res<LightingState>,
+    launch_window_state: Res<LaunchWindowState>,
+    strips: Query<Entity, With<TimelineStrip>>,
+    markers: Query<Entity, With<TimelineMarker>>,
+    mut built: Local<bool>,
+    mut commands: Commands,
+) {
+    if *built
+        && !launch_data.is_changed()
+        && !radio_state.is_changed()
+        && !revisit_state.is_changed()
+        && !lighting_state.is_changed()
+        && !launch_window_state.is_changed()
+    {
+        return;
+    }
+    let Some(strip) = strips.iter().next() else {
+        return;
+    };
+    *built = true;
+    for marker in &markers {
+        commands.entity(marker).despawn();
+    }
+
+    let mut entries: Vec<(TimelineMarker, Color)> = Vec::new();
+    for launch in launch_data
+        .launches
+        .iter()
+        .chain(&launch_data.previous_launches)
+    {
+        if let Some(net) = launch.net_utc {
+            entries.push((
+                TimelineMarker {
+                    start_utc: net,
+                    end_utc: None,
+                    label: format!("Launch: {}", launch.name),
+                },
+                Color::srgb(1.0, 0.55, 0.2),
+            ));
+        }
+    }
+    for event in &launch_data.events {
+        if let Some(date) = event.date_utc {
+            entries.push((
+                TimelineMarker {
+                    start_utc: date,
+                    end_utc: None,
+                    label: format!("Event: {}", event.name),
+                },
+                Color::srgb(0.75, 0.5, 1.0),
+            ));
+        }
+    }
+    if let Some(pass) = &radio_state.pass {
+        entries.push((
+            TimelineMarker {
+                start_utc: pass.aos_utc,
+                end_utc: Some(pass.los_utc),
+                label: format!(
+                    "Radio pass {} (max {:.0} deg)",
+                    pass.norad, pass.max_elevation_deg
+                ),
+            },
+            Color::srgb(0.3, 0.95, 0.5),
+        ));
+    }
+    if let Some(report) = &revisit_state.report {
+        for access in report.accesses.iter().take(TIMELINE_MAX_PASS_MARKERS) {
+            entries.push((
+                TimelineMarker {
+                    start_utc: access.start_utc,
+                    end_utc: Some(access.end_utc),
+                    label: format!(
+                        "Pass {} over site (max {:.0} deg)",
+                        access.norad, access.max_elevation_deg
+                    ),
+                },
+                Color::srgba(0.3, 0.8, 1.0, 0.7),
+            ));
+        }
+    }
+    if let Some(profile) = &lighting_state.profile {
+        for season in &profile.seasons {
+            entries.push((
+                TimelineMarker {
+                    start_utc: season.start_utc,
+                    end_utc: Some(season.end_utc),
+                    label: format!(
+                        "Eclipse season {} (up to {:.1} min)",
+                        profile.norad, season.max_eclipse_minutes
+                    ),
+                },
+                Color::srgba(0.55, 0.55, 0.65, 0.6),
+            ));
+        }
+    }
+    if let Some(report) = &launch_window_state.report {
+        for opportunity in &report.opportunities {
+            entries.push((
+                TimelineMarker {
+                    start_utc: opportunity.utc,
+                    end_utc: None,
+                    label: format!(
+                        "Launch window {} ({}bound, az {:.1} deg)",
+                        report.target_label,
+                        opportunity.direction.label(),
+                        opportunity.azimuth_deg
+                    ),
+                },
+                Color::srgb(1.0, 0.9, 0.35),
+            ));
+        }
+    }
+
+    commands.entity(strip).with_children(|strip| {
+        for (marker, color) in entries {
+            // Spans sit in the lower half so instant markers stay visible above them
+            let (top, height) = if marker.end_utc.is_some() {
+                (Val::Percent(55.0), Val::Percent(35.0))
+            } else {
+                (Val::Percent(10.0), Val::Percent(80.0))
+            };
+            strip.spawn((
+                marker,
+                Node {
+                    position_type: PositionType::Absolute,
+                    top,
+                    height,
+                    width: Val::Px(3.0),
+                    display: Display::None,
+                    ..default()
+                },
+                BackgroundColor(color),
+            ));
+        }
+    });
+}
+
+/// Place markers relative to the clock, which sits at the centre of the strip
+pub(crate) fn position_timeline_markers(
+    sim_time: Res<SimulationTime>,
+    timeline_ui: Res<TimelineUi>,
+    mut markers: Query<(&TimelineMarker, &mut Node)>,
+) {
+    let window_s = f64::from(timeline_ui.window_hours.max(0.1)) * 3600.0;
+    let fraction = |utc: DateTime<Utc>| {
+        0.5 + (utc - sim_time.current_utc).num_milliseconds() as f64 / 1000.0 / window_s
+    };
+    for (marker, mut node) in &mut markers {
+        let start = fraction(marker.start_utc);
+        let end = marker.end_utc.map_or(start, fraction);
+        if end < 0.0 || start > 1.0 {
+            node.display = Display::None;
+            continue;
+        }
+        node.display = Display::Flex;
+        let left = start.max(0.0);
+        node.left = Val::Percent((left * 100.0) as f32);
+        node.width = if marker.end_utc.is_some() {
+            Val::Percent(((end.min(1.0) - left) * 100.0).max(0.3) as f32)
+        } else {
+            Val::Px(3.0)
+        };
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_timeline_texts(
+    timeline_ui: Res<TimelineUi>,
+    mut texts: ParamSet<(
+        Query<(&mut bevy::ui::widget::Text, &TimelineEdgeText)>,
+        Query<&mut bevy::ui::widget::Text, With<TimelineHoverText>>,
+    )>,
+) {
+    if !timeline_ui.is_changed() {
+        return;
+    }
+    let edge = format_window_edge(timeline_ui.window_hours);
+    for (mut text, side) in &mut texts.p0() {
+        text.0 = format!("{}{edge}", if side.future { "+" } else { "-" });
+    }
+    for mut text in &mut texts.p1() {
+        text.0 = timeline_ui
+            .hovered
+            .clone()
+            .unwrap_or_else(|| "Drag to scrub, click a marker to jump".to_string());
+    }
+}
+
+/// Dragging the strip scrubs the clock; dragging right moves back in time
+pub(crate) fn timeline_on_drag(
+    ev: On<Pointer<Drag>>,
+    strips: Query<&ComputedNode, With<TimelineStrip>>,
+    timeline_ui: Res<TimelineUi>,
+    mut sim_time: ResMut<SimulationTime>,
+) {
+    let Ok(node) = strips.get(ev.entity) else {
+        return;
+    };
+    let width_px = node.size().x * node.inverse_scale_factor();
+    if width_px <= 0.0 {
+        return;
+    }
+    let window_s = f64::from(timeline_ui.window_hours) * 3600.0;
+    let shift_s = -f64::from(ev.event().delta.x / width_px) * window_s;
+    sim_time.current_utc += chrono::Duration::milliseconds((shift_s * 1000.0) as i64);
+}
+
+pub(crate) fn timeline_marker_on_click(
+    ev: On<Pointer<Click>>,
+    markers: Query<&TimelineMarker>,
+    mut sim_time: ResMut<SimulationTime>,
+) {
+    let Ok(marker) = markers.get(ev.entity) else {
+        return;
+    };
+    sim_time.current_utc = marker.start_utc;
+    println!(
+        "[TIME] jumped to {} ({})",
+        marker.start_utc.to_rfc3339(),
+        marker.label
+    );
+}
+
+pub(crate) fn timeline_marker_on_over(
+    ev: On<Pointer<Over>>,
+    markers: Query<&TimelineMarker>,
+    mut timeline_ui: ResMut<TimelineUi>,
+) {
+    if let Ok(marker) = markers.get(ev.entity) {
+        timeline_ui.hovered = Some(format!(
+            "{}  {}",
+            marker.start_utc.format("%Y-%m-%d %H:%M"),
+            marker.label
+        ));
+    }
+}
+
+pub(crate) fn timeline_marker_on_out(
+    ev: On<Pointer<Out>>,
+    markers: Query<(), With<TimelineMarker>>,
+    mut timeline_ui: ResMut<TimelineUi>,
+) {
+    if markers.contains(ev.entity) {
+        timeline_ui.hovered = None;
+    }
+}
@@ -0,0 +1,324 @@
+//! Approximate ascent trajectories from launch pads
+//!
+//! LL2 gives no trajectory, so the path is estimated: the launch azimuth comes
+//! from the pad latitude and a nominal inclination for the target orbit, and
+//! altitude and downrange follow a generic two-stage profile to a low parking
+//! orbit. The arc is flown along a great circle fixed at T-0 while the Earth
+//! turns beneath it, and plays out as the simulation clock passes the NET.
+
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::core::coordinates::{Coordinates, EARTH_ROTATION_RAD_S, enu_basis_ecef_dvec};
+use crate::core::space::{EARTH_RADIUS_KM_F64, ecef_to_bevy_km};
+use crate::launch_library::{LaunchFilter, LaunchLibraryConfig, LaunchLibraryData, LaunchSummary};
+use crate::orbital::SimulationTime;
+use crate::orbital::launch_window::{LaunchDirection, launch_azimuth_deg};
+use crate::ui::state::{LaunchLibraryItemKind, LaunchLibraryUiState};
+use crate::visualization::launches::{LaunchPadMarker, launch_at_pad};
+
+/// Generic ascent profile: (seconds after T-0, altitude km, downrange km)
+const ASCENT_PROFILE: &[(f64, f64, f64)] = &[
+    (0.0, 0.0, 0.0),
+    (60.0, 11.0, 3.0),
+    (150.0, 68.0, 75.0),
+    (220.0, 110.0, 210.0),
+    (300.0, 150.0, 450.0),
+    (420.0, 185.0, 1000.0),
+    (510.0, 200.0, 1600.0),
+];
+/// First stage cutoff and separation
+pub const MECO_SECONDS: f64 = 150.0;
+/// Second stage cutoff: orbit insertion, where the arc ends
+pub const SECO_SECONDS: f64 = 510.0;
+/// How long before T-0 the arc is shown for launches that aren't selected
+const PREVIEW_MINUTES: i64 = 30;
+/// How long after orbit insertion the flown arc stays up
+const LINGER_MINUTES: i64 = 10;
+const ARC_SAMPLES: usize = 64;
+
+const PREDICTED_COLOR: Color = Color::srgba(1.0, 0.75, 0.3, 0.35);
+const FLOWN_COLOR: Color = Color::srgba(1.0, 0.55, 0.15, 0.95);
+const GROUND_COLOR: Color = Color::srgba(1.0, 0.75, 0.3, 0.25);
+const STAGING_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.8);
+
+/// Nominal inclination for a launch, or None to fly due east
+///
+/// Most LL2 launches to low Earth orbit only say "Low Earth Orbit", so the
+/// launch and mission names decide: station crew and cargo flights go to the ISS or Tiangong
+/// plane and Starlink to its main 53 degree shell.
+pub fn target_inclination_deg(launch: &LaunchSummary) -> Option<f64> {
+    let orbit = launch.orbit_name.as_deref()?.to_lowercase();
+    if orbit.contains("sun-synchronous") || orbit == "sso" {
+        Some(97.5)
+    } else if orbit.contains("polar") {
+        Some(90.0)
+    } else if orbit.contains("medium earth") || orbit == "meo" {
+        Some(55.0)
+    } else if orbit.contains("low earth") || orbit == "leo" {
+        let mission = launch.mission_name.as_deref().unwrap_or_default();
+        leo_mission_inclination_deg(&format!("{} {mission}", launch.name))
+    } else {
+        None
+    }
+}
+
+/// Inclination of a well-known low Earth orbit destination named in `names`
+fn leo_mission_inclination_deg(names: &str) -> Option<f64> {
+    let names = names.to_lowercase();
+    let words: Vec<&str> = names
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    let has = |word: &str| words.contains(&word);
+    if has("iss")
+        || has("crs")
+        || has("soyuz")
+        || has("progress")
+        || has("cygnus")
+        || has("starliner")
+        || has("axiom")
+        || names.contains("crew-")
+        || names.contains("ax-")
+    {
+        Some(51.6)
+    } else if has("shenzhou") || has("tianzhou") || has("tiangong") {
+        Some(41.5)
+    } else if has("starlink") {
+        Some(53.0)
+    } else {
+        None
+    }
+}
+
+/// Launch azimuth in degrees clockwise from north
+///
+/// Orbits the pad can't reach directly, and unknown targets, fly due east. Prograde
+/// targets take the northbound solution and polar or retrograde ones the southbound.
+pub fn ascent_azimuth_deg(pad_lat_deg: f64, inclination_deg: Option<f64>) -> f64 {
+    inclination_deg
+        .and_then(|inclination| {
+            let direction = if inclination >= 80.0 {
+                LaunchDirection::Southbound
+            } else {
+                LaunchDirection::Northbound
+            };
+            launch_azimuth_deg(pad_lat_deg, inclination, direction)
+        })
+        .unwrap_or(90.0)
+}
+
+/// Altitude and downrange (km) at `t_s` seconds after T-0, held at insertion afterwards
+pub fn profile_at(t_s: f64) -> (f64, f64) {
+    let t = t_s.clamp(0.0, SECO_SECONDS);
+    for pair in ASCENT_PROFILE.windows(2) {
+        let (t0, alt0, dr0) = pair[0];
+        let (t1, alt1, dr1) = pair[1];
+        if t <= t1 {
+            let f = (t - t0) / (t1 - t0);
+            return (alt0 + (alt1 - alt0) * f, dr0 + (dr1 - dr0) * f);
+        }
+    }
+    let (_, alt, dr) = ASCENT_PROFILE[ASCENT_PROFILE.len() - 1];
+    (alt, dr)
+}
+
+/// ECEF position (km) of the vehicle `t_s` seconds after T-0
+pub fn ascent_position_ecef_km(pad_ecef_km: DVec3, azimuth_deg: f64, t_s: f64) -> DVec3 {
+    let (east, north, up) = enu_basis_ecef_dvec(pad_ecef_km);
+    let azimuth = azimuth_deg.to_radians();
+    let heading = north * azimuth.cos() + east * azimuth.sin();
+    let (altitude_km, downrange_km) = profile_at(t_s);
+    let angle = downrange_km / EARTH_RADIUS_KM_F64;
+    let inertial = (up * angle.cos() + heading * angle.sin()) * (EARTH_RADIUS_KM_F64 + altitude_km);
+    // The path is fixed in space from T-0, so the Earth turns east beneath it
+    let spin = -EARTH_ROTATION_RAD_S * t_s.max(0.0);
+    let (sin, cos) = spin.sin_cos();
+    DVec3::new(
+        inertial.x * cos - inertial.y * sin,
+        inertial.x * sin + inertial.y * cos,
+        inertial.z,
+    )
+}
+
+/// Draw the estimated ascent for the selected launch and any launch near T-0
+pub fn draw_ascent_trajectories(
+    mut gizmos: Gizmos,
+    data: Res<LaunchLibraryData>,
+    config: Res<LaunchLibraryConfig>,
+    filter: Res<LaunchFilter>,
+    launch_ui: Res<LaunchLibraryUiState>,
+    sim_time: Res<SimulationTime>,
+    markers: Query<&LaunchPadMarker>,
+) {
+    if !config.show_pad_markers {
+        return;
+    }
+
+    let now = sim_time.current_utc;
+    let lists = [
+        (LaunchLibraryItemKind::Launch, &data.launches),
+        (LaunchLibraryItemKind::PastLaunch, &data.previous_launches),
+    ];
+
+    for marker in &markers {
+        let Ok(pad) = Coordinates::from_degrees(marker.pad_lat as f32, marker.pad_lon as f32)
+        else {
+            continue;
+        };
+        let pad_ecef = pad.get_point_on_sphere_ecef_km_dvec();
+
+        for (kind, launches) in lists {
+            for (index, launch) in launches.iter().enumerate() {
+                let Some(net) = launch.net_utc else { continue };
+                if !filter.matches(launch) || !launch_at_pad(marker, launch) {
+                    continue;
+                }
+                let selected = launch_ui
+                    .selection
+                    .is_some_and(|selection| selection.kind == kind && selection.index == index);
+                if !selected && !near_liftoff(net, now) {
+                    continue;
+                }
+                let azimuth = ascent_azimuth_deg(marker.pad_lat, target_inclination_deg(launch));
+                let elapsed = (now - net).num_milliseconds() as f64 / 1000.0;
+                draw_ascent(&mut gizmos, pad_ecef, azimuth, elapsed);
+            }
+        }
+    }
+}
+
+fn near_liftoff(net: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    let seco = Duration::seconds(SECO_SECONDS as i64);
+    now >= net - Duration::minutes(PREVIEW_MINUTES)
+        && now <= net + seco + Duration::minutes(LINGER_MINUTES)
+}
+
+fn draw_ascent(gizmos: &mut Gizmos, pad_ecef: DVec3, azimuth: f64, elapsed_s: f64) {
+    let point_at = |t_s: f64| ascent_position_ecef_km(pad_ecef, azimuth, t_s);
+    let samples: Vec<(f64, DVec3)> = (0..=ARC_SAMPLES)
+        .map(|i| {
+            let t_s = SECO_SECONDS * i as f64 / ARC_SAMPLES as f64;
+            (t_s, point_at(t_s))
+        })
+        .collect();
+
+    for pair in samples.windows(2) {
+        let (t0, p0) = pair[0];
+        let (_, p1) = pair[1];
+        let color = if t0 < elapsed_s {
+            FLOWN_COLOR
+        } else {
+            PREDICTED_COLOR
+        };
+        gizmos.line(ecef_to_bevy_km(p0), ecef_to_bevy_km(p1), color);
+        let ground = |p: DVec3| ecef_to_bevy_km(p.normalize() * EARTH_RADIUS_KM_F64);
+        gizmos.line(ground(p0), ground(p1), GROUND_COLOR);
+    }
+
+    for staging in [MECO_SECONDS, SECO_SECONDS] {
+        let position = ecef_to_bevy_km(point_at(staging));
+        let normal = Dir3::new(position).unwrap_or(Dir3::Y);
+        gizmos.circle(
+            Isometry3d::new(position, Quat::from_rotation_arc(Vec3::Z, *normal)),
+            12.0,
+            STAGING_COLOR,
+        );
+    }
+
+    if (0.0..=SECO_SECONDS).contains(&elapsed_s) {
+        gizmos.sphere(
+            Isometry3d::from_translation(ecef_to_bevy_km(point_at(elapsed_s))),
+            10.0,
+            FLOWN_COLOR,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launch_library::types::test_launch;
+
+    fn launch(name: &str, mission: Option<&str>, orbit: &str) -> LaunchSummary {
+        LaunchSummary {
+            mission_name: mission.map(str::to_string),
+            orbit_name: Some(orbit.to_string()),
+            ..test_launch(name, None)
+        }
+    }
+
+    /// Azimuth from a pad latitude for a launch as LL2 lists it
+    fn azimuth(pad_lat_deg: f64, launch: &LaunchSummary) -> f64 {
+        ascent_azimuth_deg(pad_lat_deg, target_inclination_deg(launch))
+    }
+
+    #[test]
+    fn test_launch_azimuth() {
+        // ISS cargo from Cape Canaveral heads northeast
+        let crs = launch(
+            "Falcon 9 Block 5 | Dragon CRS-2 SpX-32",
+            Some("SpX-32"),
+            "Low Earth Orbit",
+        );
+        let iss = azimuth(28.5, &crs);
+        assert!((iss - 45.0).abs() < 0.5, "{iss}");
+        let starlink = azimuth(
+            28.5,
+            &launch(
+                "Falcon 9 Block 5 | Starlink Group 6-40",
+                None,
+                "Low Earth Orbit",
+            ),
+        );
+        assert!((starlink - 43.2).abs() < 0.5, "{starlink}");
+        let shenzhou = azimuth(
+            40.96,
+            &launch(
+                "Long March 2F/G | Shenzhou 19",
+                Some("Shenzhou 19"),
+                "Low Earth Orbit",
+            ),
+        );
+        assert!((shenzhou - 82.7).abs() < 0.5, "{shenzhou}");
+        // Sun-synchronous from Vandenberg heads south, slightly west
+        let sso = azimuth(
+            34.7,
+            &launch(
+                "Falcon 9 Block 5 | Transporter-12",
+                None,
+                "Sun-Synchronous Orbit",
+            ),
+        );
+        assert!((sso - 189.1).abs() < 0.5, "{sso}");
+        // GTO, other LEO missions and unreachable inclinations fly due east
+        let gto = launch(
+            "Falcon 9 Block 5 | GPS III SV08",
+            None,
+            "Geostationary Transfer Orbit",
+        );
+        assert_eq!(azimuth(28.5, &gto), 90.0);
+        let rideshare = launch("Electron | Kinéis Mission", None, "Low Earth Orbit");
+        assert_eq!(azimuth(-39.3, &rideshare), 90.0);
+        assert_eq!(ascent_azimuth_deg(45.6, Some(28.5)), 90.0);
+    }
+
+    #[test]
+    fn test_ascent_climbs_downrange() {
+        assert_eq!(profile_at(-30.0), (0.0, 0.0));
+        assert_eq!(profile_at(SECO_SECONDS + 600.0), profile_at(SECO_SECONDS));
+        let (alt, dr) = profile_at(105.0);
+        assert!(alt > 11.0 && alt < 68.0 && dr > 3.0 && dr < 75.0);
+
+        // Due east from the equator stays on the equator and ends ~1600 km downrange
+        let pad = DVec3::new(EARTH_RADIUS_KM_F64, 0.0, 0.0);
+        let end = ascent_position_ecef_km(pad, 90.0, SECO_SECONDS);
+        assert!(end.z.abs() < 1e-6);
+        assert!((end.length() - EARTH_RADIUS_KM_F64 - 200.0).abs() < 1e-6);
+        let lon_deg = end.y.atan2(end.x).to_degrees();
+        let expected = 1600.0 / EARTH_RADIUS_KM_F64 * 180.0 / std::f64::consts::PI
+            - EARTH_ROTATION_RAD_S * SECO_SECONDS * 180.0 / std::f64::consts::PI;
+        assert!((lon_deg - expected).abs() < 1e-6, "{lon_deg} vs {expected}");
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::mesh::VertexAttributeValues;
 use bevy::prelude::*;
 
+use crate::analysis::AnalysisSite;
 use crate::core::coordinates::{Coordinates, EARTH_RADIUS_KM};
 
 /// Plugin for Earth rendering and mesh generation
@@ -67,13 +68,19 @@ pub fn generate_unified_earth(
             Visibility::Visible,
             Name::new("Earth"),
         ))
-        .observe(|mut event: On<Pointer<Click>>| {
-            let hit = &event.hit;
-            if let Some(pos) = hit.position {
-                let coords: Coordinates = pos.into();
-                let (lat, lon) = coords.as_degrees();
-                info!("Latlon of selected point: Lat: {}, Lon: {}", lat, lon);
-            }
-            event.propagate(false);
-        });
+        .observe(
+            |mut event: On<Pointer<Click>>, mut site: ResMut<AnalysisSite>| {
+                let hit = &event.hit;
+                if let Some(pos) = hit.position {
+                    let coords: Coordinates = pos.into();
+                    let (lat, lon) = coords.as_degrees();
+                    info!("Latlon of selected point: Lat: {}, Lon: {}", lat, lon);
+                    if site.picking {
+                        site.lat_lon_deg = Some((f64::from(lat), f64::from(lon)));
+                        site.picking = false;
+                    }
+                }
+                event.propagate(false);
+            },
+        );
 }
@@ -1,8 +1,10 @@
 //! Satellite visibility heatmap implementation
 //!
 //! This module provides real-time satellite visibility heatmapping on the Earth surface.
-//! It colors Earth mesh vertices based on the number of visible satellites from each point,
-//! using efficient chunked updates for smooth performance.
+//! It colors Earth mesh vertices based on a selectable per-point metric (visible satellite
+//! count, count above an elevation mask, best elevation, or GNSS dilution of precision),
+//! using efficient chunked updates for smooth performance. It can also display the
+//! per-cell statistics of the latest coverage analysis instead of live values.
 
 use bevy::ecs::system::SystemParam;
 use bevy::math::DVec3;
@@ -10,11 +12,14 @@ use bevy::prelude::*;
 use bevy::tasks::{ComputeTaskPool, Task, block_on};
 use std::time::Instant;
 
+use crate::analysis::{CoverageConfig, CoverageState};
 use crate::core::coordinates::{
-    EARTH_RADIUS_KM, hemisphere_prefilter_ecef_dvec, los_visible_ecef_dvec,
+    EARTH_RADIUS_KM, dop_ecef_dvec, elevation_deg_ecef_dvec, hemisphere_prefilter_ecef_dvec,
+    los_visible_ecef_dvec, visible_above_mask_ecef_dvec,
 };
 use crate::core::space::{WorldEcefKm, bevy_to_ecef_km};
 use crate::satellite::Satellite;
+use crate::satellite::components::SatelliteGroupUrl;
 use crate::visualization::colormaps::turbo_colormap;
 use crate::visualization::earth::EarthMeshHandle;
 
@@ -27,6 +32,14 @@ struct HeatmapOverlay;
 pub struct HeatmapConfig {
     /// Enable/disable heatmap rendering
     pub enabled: bool,
+    /// What the overlay displays
+    pub source: HeatmapSource,
+    /// Per-vertex quantity computed from live satellite positions
+    pub metric: HeatmapMetric,
+    /// Elevation mask (degrees) for the mask-aware metrics
+    pub min_elevation_deg: f32,
+    /// Group URL whose satellites feed the DOP metrics (None = all loaded satellites)
+    pub dop_group: Option<String>,
     /// Update period in seconds (0.5 recommended for smooth updates)
     pub update_period_s: f32,
     /// Alpha transparency for heatmap colors (0.0-1.0)
@@ -35,12 +48,137 @@ pub struct HeatmapConfig {
     pub range_mode: RangeMode,
     /// Fixed maximum count for normalization (used when `range_mode` is Fixed)
     pub fixed_max: Option<u32>,
+    /// Fixed maximum DOP for normalization (used when `range_mode` is Fixed)
+    pub dop_fixed_max: f32,
     /// Performance tuning: vertices to process per frame
     pub chunk_size: usize,
     /// Performance tuning: chunks to process per frame
     pub chunks_per_frame: usize,
 }
 
+/// Data shown by the heatmap overlay
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum HeatmapSource {
+    /// Live metric computed from current satellite positions
+    #[default]
+    Live,
+    /// Selected metric from the latest coverage analysis
+    Coverage,
+}
+
+/// Per-vertex quantity for the live heatmap
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum HeatmapMetric {
+    /// Satellites with line of sight above the horizon
+    #[default]
+    VisibleCount,
+    /// Satellites at or above the elevation mask
+    CountAboveMask,
+    /// Highest elevation of any satellite (degrees)
+    MaxElevation,
+    /// Geometric dilution of precision
+    Gdop,
+    /// Position dilution of precision
+    Pdop,
+    /// Horizontal dilution of precision
+    Hdop,
+}
+
+impl HeatmapMetric {
+    pub const ALL: [HeatmapMetric; 6] = [
+        HeatmapMetric::VisibleCount,
+        HeatmapMetric::CountAboveMask,
+        HeatmapMetric::MaxElevation,
+        HeatmapMetric::Gdop,
+        HeatmapMetric::Pdop,
+        HeatmapMetric::Hdop,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            HeatmapMetric::VisibleCount => "Visible count",
+            HeatmapMetric::CountAboveMask => "Count above mask",
+            HeatmapMetric::MaxElevation => "Max elevation (deg)",
+            HeatmapMetric::Gdop => "GDOP",
+            HeatmapMetric::Pdop => "PDOP",
+            HeatmapMetric::Hdop => "HDOP",
+        }
+    }
+
+    pub fn is_count(self) -> bool {
+        matches!(
+            self,
+            HeatmapMetric::VisibleCount | HeatmapMetric::CountAboveMask
+        )
+    }
+
+    pub fn is_dop(self) -> bool {
+        matches!(
+            self,
+            HeatmapMetric::Gdop | HeatmapMetric::Pdop | HeatmapMetric::Hdop
+        )
+    }
+
+    /// Normalization range used in `RangeMode::Fixed`
+    fn fixed_range(self, config: &HeatmapConfig) -> (f32, f32) {
+        match self {
+            HeatmapMetric::VisibleCount | HeatmapMetric::CountAboveMask => {
+                (0.0, config.fixed_max.unwrap_or(20) as f32)
+            }
+            HeatmapMetric::MaxElevation => (config.min_elevation_deg, 90.0),
+            HeatmapMetric::Gdop | HeatmapMetric::Pdop | HeatmapMetric::Hdop => {
+                (1.0, config.dop_fixed_max.max(1.5))
+            }
+        }
+    }
+
+    /// Metric value at a surface point; NaN where it is undefined
+    fn evaluate(self, surface_point: DVec3, satellites: &[DVec3], min_elevation_deg: f64) -> f32 {
+        match self {
+            HeatmapMetric::VisibleCount => {
+                count_visible_satellites(&surface_point, satellites, f64::from(EARTH_RADIUS_KM))
+                    as f32
+            }
+            HeatmapMetric::CountAboveMask => satellites
+                .iter()
+                .filter(|&&sat| visible_above_mask_ecef_dvec(surface_point, sat, min_elevation_deg))
+                .count() as f32,
+            HeatmapMetric::MaxElevation => {
+                let best = satellites
+                    .iter()
+                    .map(|&sat| elevation_deg_ecef_dvec(surface_point, sat))
+                    .fold(f64::NEG_INFINITY, f64::max);
+                if best >= min_elevation_deg {
+                    best as f32
+                } else {
+                    f32::NAN
+                }
+            }
+            HeatmapMetric::Gdop | HeatmapMetric::Pdop | HeatmapMetric::Hdop => {
+                dop_ecef_dvec(surface_point, satellites, min_elevation_deg).map_or(
+                    f32::NAN,
+                    |dop| {
+                        let value = match self {
+                            HeatmapMetric::Gdop => dop.gdop,
+                            HeatmapMetric::Pdop => dop.pdop,
+                            _ => dop.hdop,
+                        };
+                        value as f32
+                    },
+                )
+            }
+        }
+    }
+}
+
+/// Label and value range of the colors currently on the globe
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeatmapLegend {
+    pub label: String,
+    pub min: f32,
+    pub max: f32,
+}
+
 /// Range normalization modes for color mapping
 #[derive(Clone, Debug, PartialEq)]
 pub enum RangeMode {
@@ -54,10 +192,15 @@ impl Default for HeatmapConfig {
     fn default() -> Self {
         Self {
             enabled: false,
+            source: HeatmapSource::Live,
+            metric: HeatmapMetric::VisibleCount,
+            min_elevation_deg: 10.0,
+            dop_group: None,
             update_period_s: 0.5,
             color_alpha: 0.7,
             range_mode: RangeMode::Auto,
             fixed_max: Some(20),
+            dop_fixed_max: 6.0,
             chunk_size: 2000,
             chunks_per_frame: 1,
         }
@@ -71,29 +214,32 @@ pub struct HeatmapState {
     pub last_update_instant: Instant,
     /// Earth mesh handle for vertex color updates
     pub earth_mesh_handle: Option<Handle<Mesh>>,
-    /// Visibility counts per vertex
-    pub vertex_counts: Vec<u32>,
     /// Computed color buffer for vertices
     pub color_buffer: Vec<[f32; 4]>,
     /// Vertex positions (cached for performance)
     pub vertex_positions: Vec<Vec3>,
     /// Whether vertex positions have been cached
     pub positions_cached: bool,
-    /// In-flight async task for computing visibility counts
-    pub pending_task: Option<Task<Vec<u32>>>,
+    /// In-flight async task computing per-vertex metric values
+    pub pending_task: Option<Task<(HeatmapMetric, Vec<f32>)>>,
+    /// Legend for the colors currently applied
+    pub legend: Option<HeatmapLegend>,
 }
 
 #[derive(SystemParam)]
 struct HeatmapParams<'w, 's> {
     meshes: ResMut<'w, Assets<Mesh>>,
     materials: ResMut<'w, Assets<StandardMaterial>>,
-    satellite_query: Query<'w, 's, &'static WorldEcefKm, With<Satellite>>,
+    satellite_query:
+        Query<'w, 's, (&'static WorldEcefKm, Option<&'static SatelliteGroupUrl>), With<Satellite>>,
     heatmap_query: Query<
         'w,
         's,
         (&'static Mesh3d, &'static MeshMaterial3d<StandardMaterial>),
         With<HeatmapOverlay>,
     >,
+    coverage_config: Res<'w, CoverageConfig>,
+    coverage_state: Res<'w, CoverageState>,
 }
 
 impl Default for HeatmapState {
@@ -101,11 +247,11 @@ impl Default for HeatmapState {
         Self {
             last_update_instant: Instant::now(),
             earth_mesh_handle: None,
-            vertex_counts: Vec::new(),
             color_buffer: Vec::new(),
             vertex_positions: Vec::new(),
             positions_cached: false,
             pending_task: None,
+            legend: None,
         }
     }
 }
@@ -147,7 +293,6 @@ fn initialize_heatmap_system(
             && let Some(positions) = mesh.attribute(Mesh::ATTRIBUTE_POSITION)
         {
             let vertex_count = positions.len();
-            state.vertex_counts.resize(vertex_count, 0);
             state
                 .color_buffer
                 .resize(vertex_count, [0.0, 0.0, 0.0, 0.0]);
@@ -221,6 +366,8 @@ fn update_heatmap_system(
         mut materials,
         satellite_query,
         heatmap_query,
+        coverage_config,
+        coverage_state,
     } = params;
 
     if !config.enabled {
@@ -255,8 +402,57 @@ fn update_heatmap_system(
         return;
     }
 
-    // Collect current satellite positions in ECEF
-    let satellite_positions_ecef: Vec<DVec3> = collect_satellite_positions_ecef(&satellite_query);
+    if config.source == HeatmapSource::Coverage {
+        // Drop any in-flight live task so stale values are not applied later
+        state.pending_task = None;
+        if !(config.is_changed() || coverage_config.is_changed() || coverage_state.is_changed()) {
+            return;
+        }
+        let Some(result) = coverage_state.result.as_ref() else {
+            clear_vertex_colors(mesh);
+            state.legend = None;
+            return;
+        };
+
+        let metric = coverage_config.metric;
+        let values: Vec<f32> = state
+            .vertex_positions
+            .iter()
+            .map(|vertex_pos| {
+                let surface_point_ecef = bevy_to_ecef_km(vertex_pos.normalize() * EARTH_RADIUS_KM);
+                result
+                    .value_at_ecef(surface_point_ecef, metric)
+                    .map_or(f32::NAN, |v| v as f32)
+            })
+            .collect();
+
+        // Coverage metrics have their own units, so always auto-range them
+        let range = finite_value_range(&values);
+        state.legend = Some(HeatmapLegend {
+            label: metric.label().to_string(),
+            min: range.0,
+            max: range.1,
+        });
+        apply_colors_to_mesh(
+            mesh,
+            &values,
+            range,
+            config.color_alpha,
+            state.color_buffer.as_mut_slice(),
+        );
+        if let Some(material) = materials.get_mut(&material3d.0) {
+            material.base_color.set_alpha(1.0);
+        }
+        return;
+    }
+
+    // Collect current satellite positions in ECEF (restricted to the DOP group if set)
+    let group_filter = config
+        .dop_group
+        .as_deref()
+        .filter(|_| config.metric.is_dop());
+    let satellite_positions_ecef: Vec<DVec3> =
+        collect_satellite_positions_ecef(&satellite_query, group_filter);
 
     if satellite_positions_ecef.is_empty() {
         // No satellites - completely hide the heatmap overlay
@@ -267,27 +463,31 @@ fn update_heatmap_system(
         // Clear vertex colors so they don't interfere
         clear_vertex_colors(mesh);
 
+        state.legend = None;
         state.last_update_instant = Instant::now();
         return;
     }
 
-    // Spawn new async task if ready
+    // Spawn new async task if ready; config edits (e.g. a new metric) refresh immediately
     if state.pending_task.is_none()
-        && state.last_update_instant.elapsed().as_secs_f32() >= config.update_period_s
+        && (config.is_changed()
+            || state.last_update_instant.elapsed().as_secs_f32() >= config.update_period_s)
     {
         let positions = state.vertex_positions.clone();
         let satellites = satellite_positions_ecef.clone();
-        let earth_radius = f64::from(EARTH_RADIUS_KM);
+        let metric = config.metric;
+        let min_elevation_deg = f64::from(config.min_elevation_deg);
 
         let task = ComputeTaskPool::get().spawn(async move {
-            let mut counts = vec![0u32; positions.len()];
-            for (i, vertex_pos) in positions.iter().enumerate() {
-                let surface_point_bevy = vertex_pos.normalize() * EARTH_RADIUS_KM;
-                let surface_point_ecef = bevy_to_ecef_km(surface_point_bevy);
-                counts[i] =
-                    count_visible_satellites(&surface_point_ecef, &satellites, earth_radius);
-            }
-            counts
+            let values = positions
+                .iter()
+                .map(|vertex_pos| {
+                    let surface_point_bevy = vertex_pos.normalize() * EARTH_RADIUS_KM;
+                    let surface_point_ecef = bevy_to_ecef_km(surface_point_bevy);
+                    metric.evaluate(surface_point_ecef, &satellites, min_elevation_deg)
+                })
+                .collect();
+            (metric, values)
         });
 
         state.pending_task = Some(task);
@@ -297,11 +497,29 @@ fn update_heatmap_system(
     // Poll task completion
     if let Some(task) = state.pending_task.take() {
         if task.is_finished() {
-            let counts = block_on(task);
-            state.vertex_counts = counts;
-            let counts = state.vertex_counts.clone();
+            let (metric, mut values) = block_on(task);
+            let range = metric_range(metric, &values, &config);
+            if metric.is_count() {
+                // Zero count should be transparent
+                for value in &mut values {
+                    if *value == 0.0 {
+                        *value = f32::NAN;
+                    }
+                }
+            }
 
-            apply_colors_to_mesh(mesh, &counts, &config, state.color_buffer.as_mut_slice());
+            apply_colors_to_mesh(
+                mesh,
+                &values,
+                range,
+                config.color_alpha,
+                state.color_buffer.as_mut_slice(),
+            );
+            state.legend = Some(HeatmapLegend {
+                label: metric.label().to_string(),
+                min: range.0,
+                max: range.1,
+            });
 
             if let Some(material) = materials.get_mut(&material3d.0) {
                 material.base_color.set_alpha(1.0);
@@ -312,13 +530,15 @@ fn update_heatmap_system(
     }
 }
 
-/// Collect satellite positions in ECEF coordinates
+/// Collect satellite positions in ECEF coordinates, optionally limited to one group
 fn collect_satellite_positions_ecef(
-    satellite_query: &Query<&WorldEcefKm, With<Satellite>>,
+    satellite_query: &Query<(&WorldEcefKm, Option<&SatelliteGroupUrl>), With<Satellite>>,
+    group_filter: Option<&str>,
 ) -> Vec<DVec3> {
     satellite_query
         .iter()
-        .map(|world_ecef| world_ecef.0)
+        .filter(|(_, group)| group_filter.is_none_or(|wanted| group.is_some_and(|g| g.0 == wanted)))
+        .map(|(world_ecef, _)| world_ecef.0)
         .collect()
 }
 
@@ -344,41 +564,61 @@ fn count_visible_satellites(
     visible_count
 }
 
-/// Apply computed colors to mesh vertex colors
+/// Normalization range for a live metric
+fn metric_range(metric: HeatmapMetric, values: &[f32], config: &HeatmapConfig) -> (f32, f32) {
+    match config.range_mode {
+        RangeMode::Auto => {
+            let (min, max) = finite_value_range(values);
+            if metric.is_count() {
+                (min, max.max(1.0)) // Ensure max is at least 1 to avoid division by zero
+            } else {
+                (min, max)
+            }
+        }
+        RangeMode::Fixed => metric.fixed_range(config),
+    }
+}
+
+/// Min/max over the finite values, (0, 1) when there are none
+fn finite_value_range(values: &[f32]) -> (f32, f32) {
+    let (min, max) = values
+        .iter()
+        .filter(|v| v.is_finite())
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| {
+            (lo.min(v), hi.max(v))
+        });
+    if min.is_finite() {
+        (min, max)
+    } else {
+        (0.0, 1.0)
+    }
+}
+
+/// Apply computed colors to mesh vertex colors; non-finite values are transparent
 fn apply_colors_to_mesh(
     mesh: &mut Mesh,
-    vertex_counts: &[u32],
-    config: &HeatmapConfig,
+    values: &[f32],
+    (min_value, max_value): (f32, f32),
+    alpha: f32,
     color_buffer: &mut [[f32; 4]],
 ) {
-    if vertex_counts.is_empty() {
+    if values.is_empty() {
         return;
     }
 
-    // Determine normalization range
-    let (min_count, max_count) = match config.range_mode {
-        RangeMode::Auto => {
-            let min = *vertex_counts.iter().min().unwrap_or(&0);
-            let max = *vertex_counts.iter().max().unwrap_or(&1);
-            (min, max.max(1)) // Ensure max is at least 1 to avoid division by zero
-        }
-        RangeMode::Fixed => (0, config.fixed_max.unwrap_or(20)),
-    };
-
-    // Map counts to colors
-    for (i, &count) in vertex_counts.iter().enumerate() {
-        if count == 0 {
-            // Zero count should be transparent
+    // Map values to colors
+    for (i, &value) in values.iter().enumerate() {
+        if !value.is_finite() {
             color_buffer[i] = [0.0, 0.0, 0.0, 0.0];
         } else {
-            let normalized = if max_count > min_count {
-                (count - min_count) as f32 / (max_count - min_count) as f32
+            let normalized = if max_value > min_value {
+                (value - min_value) / (max_value - min_value)
             } else {
                 0.0
             };
 
             let mut color = turbo_colormap(normalized.clamp(0.0, 1.0));
-            color[3] = config.color_alpha; // Apply alpha
+            color[3] = alpha; // Apply alpha
             color_buffer[i] = color;
         }
     }
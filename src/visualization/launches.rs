@@ -2,8 +2,12 @@
 
 use crate::core::coordinates::Coordinates;
 use crate::core::space::{WorldEcefKm, ecef_to_bevy_km};
-use crate::launch_library::{LaunchLibraryConfig, LaunchLibraryData, LaunchSummary};
+use crate::launch_library::history::{history_range, sim_day};
+use crate::launch_library::{LaunchFilter, LaunchLibraryConfig, LaunchLibraryData, LaunchSummary};
+use crate::orbital::SimulationTime;
 use crate::ui::state::{LaunchLibraryItemKind, LaunchLibrarySelection, LaunchLibraryUiState};
+use crate::visualization::ascent::draw_ascent_trajectories;
+use crate::visualization::stations::draw_crewed_station_halos;
 use bevy::math::DVec3;
 use bevy::mesh::{
     ConeAnchor, ConeMeshBuilder, CylinderAnchor, CylinderMeshBuilder, TorusMeshBuilder,
@@ -27,6 +31,8 @@ const ROCKET_RING_OFFSET_Y: f32 = 2.0;
 const ROCKET_GLOW_RADIUS: f32 = 12.0;
 const ROCKET_GLOW_HEIGHT: f32 = 70.0;
 const ROCKET_SURFACE_OFFSET_KM: f32 = 1.5;
+const LANDING_RING_INNER: f32 = 10.0;
+const LANDING_RING_OUTER: f32 = 13.0;
 
 #[derive(Component, Clone)]
 #[allow(dead_code)]
@@ -40,6 +46,12 @@ pub struct LaunchPadMarker {
     pub next_net: Option<DateTime<Utc>>,
 }
 
+/// Booster or spacecraft landing zone of the launch open in the popup
+#[derive(Component, Clone)]
+pub struct LandingZoneMarker {
+    pub key: String,
+}
+
 #[derive(Resource)]
 struct LaunchPadAssets {
     body_mesh: Handle<Mesh>,
@@ -52,6 +64,8 @@ struct LaunchPadAssets {
     ring_material: Handle<StandardMaterial>,
     ring2_material: Handle<StandardMaterial>,
     glow_material: Handle<StandardMaterial>,
+    landing_mesh: Handle<Mesh>,
+    landing_material: Handle<StandardMaterial>,
 }
 
 #[derive(Component, Clone, Copy)]
@@ -71,9 +85,12 @@ impl Plugin for LaunchesPlugin {
                 Update,
                 (
                     update_launch_pad_markers,
+                    update_landing_zone_markers,
                     sync_launch_pad_visibility,
                     animate_pulse_rings,
                     handle_launch_pad_clicks,
+                    draw_ascent_trajectories,
+                    draw_crewed_station_halos,
                 )
                     .chain(),
             );
@@ -139,6 +156,18 @@ fn setup_launch_pad_assets(
         unlit: true,
         ..default()
     });
+    let landing_mesh = meshes.add(
+        TorusMeshBuilder::new(LANDING_RING_INNER, LANDING_RING_OUTER)
+            .major_resolution(40)
+            .minor_resolution(12),
+    );
+    let landing_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.3, 1.0, 0.5, 0.8),
+        emissive: LinearRgba::new(0.6, 2.0, 0.9, 1.0),
+        alpha_mode: AlphaMode::Add,
+        unlit: true,
+        ..default()
+    });
 
     commands.insert_resource(LaunchPadAssets {
         body_mesh,
@@ -151,21 +180,62 @@ fn setup_launch_pad_assets(
         ring_material,
         ring2_material,
         glow_material,
+        landing_mesh,
+        landing_material,
     });
 }
 
+/// NET range of the launches marked on the globe: the window around the simulated day
+fn marked_range(sim_utc: DateTime<Utc>, window_days: i64) -> (DateTime<Utc>, DateTime<Utc>) {
+    history_range(sim_day(sim_utc), window_days)
+}
+
+/// Upcoming and past launches that pass the filter and fly within `range`
+fn marked_launches<'a>(
+    data: &'a LaunchLibraryData,
+    filter: &'a LaunchFilter,
+    (start, end): (DateTime<Utc>, DateTime<Utc>),
+) -> impl Iterator<Item = (LaunchLibrarySelection, &'a LaunchSummary)> {
+    let list = |kind, launches: &'a [LaunchSummary]| {
+        launches
+            .iter()
+            .enumerate()
+            .map(move |(index, launch)| (LaunchLibrarySelection { kind, index }, launch))
+    };
+    list(LaunchLibraryItemKind::Launch, &data.launches)
+        .chain(list(
+            LaunchLibraryItemKind::PastLaunch,
+            &data.previous_launches,
+        ))
+        .filter(move |(_, launch)| {
+            filter.matches(launch) && launch.net_utc.is_none_or(|net| net >= start && net <= end)
+        })
+}
+
+#[allow(clippy::too_many_arguments)]
 fn update_launch_pad_markers(
     data: Res<LaunchLibraryData>,
     config: Res<LaunchLibraryConfig>,
+    filter: Res<LaunchFilter>,
+    sim_time: Res<SimulationTime>,
     assets: Res<LaunchPadAssets>,
+    mut shown_range: Local<Option<(DateTime<Utc>, DateTime<Utc>)>>,
     mut query: Query<(Entity, &LaunchPadMarker, &mut Transform, &mut Visibility)>,
     mut commands: Commands,
 ) {
-    if !data.is_changed() && !config.is_changed() {
+    // The range only moves when the simulated day does
+    let range = marked_range(sim_time.current_utc, config.window_days);
+    if !data.is_changed()
+        && !config.is_changed()
+        && !filter.is_changed()
+        && *shown_range == Some(range)
+    {
         return;
     }
+    *shown_range = Some(range);
 
-    let pad_markers = build_pad_markers(&data.launches);
+    let pad_markers =
+        build_pad_markers(marked_launches(&data, &filter, range).map(|(_, launch)| launch));
     let mut existing: HashMap<String, Entity> = HashMap::new();
     for (entity, marker, _transform, _visibility) in &mut query {
         existing.insert(marker.pad_key.clone(), entity);
@@ -271,6 +341,75 @@ fn update_launch_pad_markers(
     }
 }
 
+/// Show the landing zones from the selected launch's detail as pulsing rings
+fn update_landing_zone_markers(
+    data: Res<LaunchLibraryData>,
+    config: Res<LaunchLibraryConfig>,
+    launch_ui: Res<LaunchLibraryUiState>,
+    assets: Res<LaunchPadAssets>,
+    query: Query<(Entity, &LandingZoneMarker)>,
+    mut commands: Commands,
+) {
+    if !data.is_changed() && !config.is_changed() && !launch_ui.is_changed() {
+        return;
+    }
+
+    let detail = launch_ui
+        .selection
+        .and_then(|selection| selection.launch(&data))
+        .and_then(|launch| launch.uuid.as_ref())
+        .and_then(|uuid| data.details.get(uuid));
+
+    let mut zones: HashMap<String, DVec3> = HashMap::new();
+    if config.show_pad_markers
+        && let Some(detail) = detail
+    {
+        for landing in &detail.landings {
+            let (Some(lat), Some(lon)) = (landing.lat, landing.lon) else {
+                continue;
+            };
+            let Ok(coords) = Coordinates::from_degrees(lat as f32, lon as f32) else {
+                continue;
+            };
+            zones.insert(
+                format!("{lat:.3}:{lon:.3}"),
+                coords.get_point_on_sphere_ecef_km_dvec(),
+            );
+        }
+    }
+
+    for (entity, marker) in &query {
+        if zones.remove(&marker.key).is_none() {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for (key, ecef) in zones {
+        let transform = marker_transform(ecef_to_bevy_km(ecef), 1);
+        let phase = hash_phase(&key);
+        commands
+            .spawn((
+                transform,
+                WorldEcefKm(ecef),
+                Visibility::Visible,
+                LandingZoneMarker { key },
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Mesh3d(assets.landing_mesh.clone()),
+                    MeshMaterial3d(assets.landing_material.clone()),
+                    Transform::from_translation(Vec3::new(0.0, ROCKET_RING_OFFSET_Y, 0.0)),
+                    PulseRing {
+                        base_scale: 1.0,
+                        speed: 1.6,
+                        amplitude: 0.3,
+                        phase,
+                    },
+                ));
+            });
+    }
+}
+
 fn sync_launch_pad_visibility(
     config: Res<LaunchLibraryConfig>,
     mut query: Query<&mut Visibility, With<LaunchPadMarker>>,
@@ -327,16 +466,21 @@ fn animate_pulse_rings(time: Res<Time>, mut rings: Query<(&PulseRing, &mut Trans
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_launch_pad_clicks(
     mut click_events: MessageReader<Pointer<Click>>,
     markers: Query<&LaunchPadMarker>,
     parents: Query<&ChildOf>,
     data: Res<LaunchLibraryData>,
+    config: Res<LaunchLibraryConfig>,
+    filter: Res<LaunchFilter>,
+    sim_time: Res<SimulationTime>,
     mut launch_ui: ResMut<LaunchLibraryUiState>,
 ) {
-    if data.launches.is_empty() {
+    if data.launches.is_empty() && data.previous_launches.is_empty() {
         return;
     }
+    let range = marked_range(sim_time.current_utc, config.window_days);
 
     for ev in click_events.read() {
         let mut entity = ev.entity;
@@ -352,47 +496,47 @@ fn handle_launch_pad_clicks(
         };
 
         let Some(marker) = marker else { continue };
-        if let Some(index) = find_launch_index_for_marker(marker, &data.launches) {
-            launch_ui.selection = Some(LaunchLibrarySelection {
-                kind: LaunchLibraryItemKind::Launch,
-                index,
-            });
+        if let Some(selection) = find_launch_for_marker(
+            marker,
+            marked_launches(&data, &filter, range),
+            sim_time.current_utc,
+        ) {
+            launch_ui.selection = Some(selection);
         }
     }
 }
 
-fn find_launch_index_for_marker(
+/// The marked launch from the pad closest to the simulated time (one without a NET last)
+fn find_launch_for_marker<'a>(
     marker: &LaunchPadMarker,
-    launches: &[LaunchSummary],
-) -> Option<usize> {
-    let mut best: Option<(usize, DateTime<Utc>)> = None;
-
-    for (idx, launch) in launches.iter().enumerate() {
-        let matches_id =
-            marker.pad_id.is_some() && launch.pad_id.is_some() && marker.pad_id == launch.pad_id;
-        let matches_coords = launch.pad_lat.is_some()
-            && launch.pad_lon.is_some()
-            && ((launch.pad_lat.unwrap() - marker.pad_lat).abs() < 0.01)
-            && ((launch.pad_lon.unwrap() - marker.pad_lon).abs() < 0.01);
-
-        if !(matches_id || matches_coords) {
-            continue;
-        }
-
-        if let Some(net) = launch.net_utc {
-            match best {
-                Some((_, best_net)) if net >= best_net => {}
-                _ => best = Some((idx, net)),
-            }
-        } else if best.is_none() {
-            best = Some((idx, Utc::now()));
-        }
-    }
+    launches: impl IntoIterator<Item = (LaunchLibrarySelection, &'a LaunchSummary)>,
+    sim_utc: DateTime<Utc>,
+) -> Option<LaunchLibrarySelection> {
+    launches
+        .into_iter()
+        .filter(|(_, launch)| launch_at_pad(marker, launch))
+        .min_by_key(|(_, launch)| {
+            launch
+                .net_utc
+                .map_or(i64::MAX, |net| (net - sim_utc).num_seconds().abs())
+        })
+        .map(|(selection, _)| selection)
+}
 
-    best.map(|(idx, _)| idx)
+/// Whether `launch` flies from the pad `marker` stands for
+pub(crate) fn launch_at_pad(marker: &LaunchPadMarker, launch: &LaunchSummary) -> bool {
+    let matches_id =
+        marker.pad_id.is_some() && launch.pad_id.is_some() && marker.pad_id == launch.pad_id;
+    let matches_coords = launch.pad_lat.is_some()
+        && launch.pad_lon.is_some()
+        && ((launch.pad_lat.unwrap() - marker.pad_lat).abs() < 0.01)
+        && ((launch.pad_lon.unwrap() - marker.pad_lon).abs() < 0.01);
+    matches_id || matches_coords
 }
 
-fn build_pad_markers(launches: &[LaunchSummary]) -> Vec<LaunchPadMarker> {
+fn build_pad_markers<'a>(
+    launches: impl IntoIterator<Item = &'a LaunchSummary>,
+) -> Vec<LaunchPadMarker> {
     let mut map: HashMap<String, LaunchPadMarker> = HashMap::new();
 
     for launch in launches {
@@ -6,6 +6,7 @@
 use bevy::prelude::*;
 
 pub mod arrows;
+pub mod ascent;
 pub mod axes;
 pub mod cities;
 pub mod colormaps;
@@ -17,7 +18,10 @@ pub mod heatmap;
 pub mod launches;
 pub mod lighting;
 pub mod moon;
+pub mod planets;
 pub mod sky_material;
+pub mod stars;
+pub mod stations;
 
 pub use arrows::draw_city_to_satellite_arrows;
 #[allow(unused_imports)]
@@ -32,14 +36,20 @@ pub use ground_track::{GroundTrackConfig, GroundTrackPlugin};
 #[allow(unused_imports)]
 pub use ground_track_gizmo::{GroundTrackGizmoConfig, GroundTrackGizmoPlugin};
 #[allow(unused_imports)]
-pub use heatmap::{HeatmapConfig, HeatmapPlugin, RangeMode};
+pub use heatmap::{
+    HeatmapConfig, HeatmapMetric, HeatmapPlugin, HeatmapSource, HeatmapState, RangeMode,
+};
 #[allow(unused_imports)]
 pub use launches::LaunchesPlugin;
 #[allow(unused_imports)]
 pub use lighting::SunLight;
 #[allow(unused_imports)]
 pub use moon::MoonPlugin;
+#[allow(unused_imports)]
+pub use planets::PlanetsPlugin;
 pub use sky_material::SkyMaterialPlugin;
+#[allow(unused_imports)]
+pub use stars::{StarCatalogConfig, StarsPlugin};
 
 /// Plugin for visualization systems
 pub struct VisualizationPlugin;
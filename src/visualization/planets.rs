@@ -0,0 +1,95 @@
+//! Planets drawn as bright points in the sky.
+//!
+//! Planets are effectively at infinity, so each one is placed along its ECEF
+//! direction at a fixed distance from the camera, inside the far plane, and
+//! sized from its apparent magnitude.
+
+use bevy::prelude::*;
+
+use crate::core::space::ecef_to_bevy_km;
+use crate::orbital::ephemeris::{Planet, planet_direction_ecef, planet_position};
+use crate::orbital::{Dut1, SimulationTime};
+
+/// Distance from the camera at which planets are drawn (km)
+const PLANET_DRAW_DISTANCE_KM: f32 = 800_000.0;
+/// Angular radius of a magnitude 0 planet (rad)
+const PLANET_BASE_ANGULAR_RADIUS: f32 = 1.5e-3;
+
+/// Marker component for a planet point.
+#[derive(Component)]
+pub struct PlanetMarker(pub Planet);
+
+/// Plugin for planet rendering.
+pub struct PlanetsPlugin;
+
+impl Plugin for PlanetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_planets).add_systems(
+            Update,
+            update_planet_transforms.after(crate::orbital::advance_simulation_clock),
+        );
+    }
+}
+
+fn planet_color(planet: Planet) -> LinearRgba {
+    match planet {
+        Planet::Mercury => LinearRgba::new(1.6, 1.5, 1.4, 1.0),
+        Planet::Venus => LinearRgba::new(2.4, 2.3, 1.9, 1.0),
+        Planet::Mars => LinearRgba::new(2.2, 0.9, 0.5, 1.0),
+        Planet::Jupiter => LinearRgba::new(2.2, 2.0, 1.7, 1.0),
+        Planet::Saturn => LinearRgba::new(2.0, 1.8, 1.2, 1.0),
+    }
+}
+
+fn spawn_planets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = meshes.add(Sphere::new(1.0).mesh().ico(2).unwrap());
+    for planet in Planet::ALL {
+        let material = materials.add(StandardMaterial {
+            base_color: Color::BLACK,
+            emissive: planet_color(planet),
+            unlit: true,
+            ..default()
+        });
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material),
+            Transform::default(),
+            Visibility::Hidden,
+            PlanetMarker(planet),
+            Name::new(planet.name()),
+        ));
+    }
+}
+
+/// Apparent size factor: brighter planets draw larger, within limits
+fn magnitude_scale(magnitude: f64) -> f32 {
+    (1.0 - 0.15 * magnitude as f32).clamp(0.5, 2.0)
+}
+
+fn update_planet_transforms(
+    sim_time: Res<SimulationTime>,
+    dut1: Res<Dut1>,
+    camera: Query<&GlobalTransform, With<Camera3d>>,
+    mut planets: Query<(&PlanetMarker, &mut Transform, &mut Visibility)>,
+) {
+    let Some(camera) = camera.iter().next() else {
+        return;
+    };
+    let eye = camera.translation();
+    for (marker, mut transform, mut visibility) in &mut planets {
+        let position = planet_position(marker.0, sim_time.current_utc);
+        let direction = planet_direction_ecef(&position, sim_time.current_utc, **dut1);
+        let direction = ecef_to_bevy_km(direction).normalize_or_zero();
+        transform.translation = eye + direction * PLANET_DRAW_DISTANCE_KM;
+        transform.scale = Vec3::splat(
+            PLANET_DRAW_DISTANCE_KM
+                * PLANET_BASE_ANGULAR_RADIUS
+                * magnitude_scale(position.magnitude),
+        );
+        *visibility = Visibility::Visible;
+    }
+}
@@ -0,0 +1,443 @@
+//! Star field from a local catalog file.
+//!
+//! Reads the Yale Bright Star Catalogue (`bsc5.dat`, fixed width) or a CSV
+//! subset of Hipparcos/HYG. Catalog positions are J2000; they are precessed to
+//! the simulation date and the whole field is turned by apparent sidereal time,
+//! so stars sit where they really are relative to Earth. Sizes follow visual
+//! magnitude and colors follow the B-V index.
+
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::core::coordinates::eci_to_ecef_km;
+use crate::core::paths::data_dir;
+use crate::core::space::ecef_to_bevy_km;
+use crate::orbital::ephemeris::{apparent_sidereal_time_rad, precess_from_j2000};
+use crate::orbital::{Dut1, SimulationTime};
+
+/// Distance from the camera at which stars are drawn (km), behind the planets
+const STAR_DRAW_DISTANCE_KM: f32 = 900_000.0;
+/// Angular radius of a magnitude 0 star (rad)
+const STAR_BASE_ANGULAR_RADIUS: f32 = 1.0e-3;
+/// Re-precess the field when the simulation date moves this far
+const PRECESSION_REFRESH_DAYS: i64 = 30;
+
+/// One catalog entry, J2000 equator and equinox
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CatalogStar {
+    pub ra_rad: f64,
+    pub dec_rad: f64,
+    pub vmag: f64,
+    /// B-V color index, if the catalog has one
+    pub bv: Option<f64>,
+}
+
+impl CatalogStar {
+    /// J2000 equatorial unit vector
+    pub fn j2000(&self) -> DVec3 {
+        DVec3::new(
+            self.dec_rad.cos() * self.ra_rad.cos(),
+            self.dec_rad.cos() * self.ra_rad.sin(),
+            self.dec_rad.sin(),
+        )
+    }
+}
+
+/// Parse a catalog, detecting CSV (header line with commas) or BSC5 fixed width
+pub fn parse_star_catalog(text: &str) -> anyhow::Result<Vec<CatalogStar>> {
+    let first = text.lines().find(|line| !line.trim().is_empty());
+    match first {
+        Some(line) if line.contains(',') => parse_star_csv(text),
+        Some(_) => Ok(parse_bsc5(text)),
+        None => anyhow::bail!("star catalog is empty"),
+    }
+}
+
+fn fixed_field(line: &str, start: usize, end: usize) -> Option<f64> {
+    line.get(start - 1..end.min(line.len()))?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Yale Bright Star Catalogue, 5th edition (VizieR V/50 `catalog`)
+///
+/// Entries without a position or magnitude (the few non-stellar objects) are skipped.
+pub fn parse_bsc5(text: &str) -> Vec<CatalogStar> {
+    text.lines()
+        .filter_map(|line| {
+            let ra_h = fixed_field(line, 76, 77)?;
+            let ra_m = fixed_field(line, 78, 79)?;
+            let ra_s = fixed_field(line, 80, 83)?;
+            let dec_sign = if line.get(83..84)? == "-" { -1.0 } else { 1.0 };
+            let dec_d = fixed_field(line, 85, 86)?;
+            let dec_m = fixed_field(line, 87, 88)?;
+            let dec_s = fixed_field(line, 89, 90)?;
+            let vmag = fixed_field(line, 103, 107)?;
+            let ra_deg = 15.0 * (ra_h + ra_m / 60.0 + ra_s / 3600.0);
+            let dec_deg = dec_sign * (dec_d + dec_m / 60.0 + dec_s / 3600.0);
+            Some(CatalogStar {
+                ra_rad: ra_deg.to_radians(),
+                dec_rad: dec_deg.to_radians(),
+                vmag,
+                bv: fixed_field(line, 110, 114),
+            })
+        })
+        .collect()
+}
+
+/// CSV with a header row
+///
+/// Columns are matched case-insensitively: `rarad`/`decrad` (radians, as in HYG)
+/// or `ra`/`dec` (degrees, also `raicrs`/`deicrs`), `vmag`/`mag`/`hpmag`, and
+/// optionally `b-v`/`bv`/`ci`.
+pub fn parse_star_csv(text: &str) -> anyhow::Result<Vec<CatalogStar>> {
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+    let header: Vec<String> = lines
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("star catalog is empty"))?
+        .split(',')
+        .map(|h| h.trim().trim_matches('"').to_ascii_lowercase())
+        .collect();
+    let column = |names: &[&str]| {
+        names
+            .iter()
+            .find_map(|name| header.iter().position(|h| h == name))
+    };
+    let (ra_col, dec_col, radians) = match (column(&["rarad"]), column(&["decrad"])) {
+        (Some(ra), Some(dec)) => (ra, dec, true),
+        _ => (
+            column(&["ra", "raicrs", "ra_deg", "radeg"])
+                .ok_or_else(|| anyhow::anyhow!("star catalog has no RA column"))?,
+            column(&["dec", "deicrs", "dec_deg", "dedeg"])
+                .ok_or_else(|| anyhow::anyhow!("star catalog has no Dec column"))?,
+            false,
+        ),
+    };
+    let mag_col = column(&["vmag", "mag", "hpmag"])
+        .ok_or_else(|| anyhow::anyhow!("star catalog has no magnitude column"))?;
+    let bv_col = column(&["b-v", "bv", "ci"]);
+
+    Ok(lines
+        .filter_map(|line| {
+            let fields: Vec<&str> = line
+                .split(',')
+                .map(|f| f.trim().trim_matches('"'))
+                .collect();
+            let value = |col: usize| fields.get(col)?.parse::<f64>().ok();
+            let (ra, dec) = (value(ra_col)?, value(dec_col)?);
+            let (ra_rad, dec_rad) = if radians {
+                (ra, dec)
+            } else {
+                (ra.to_radians(), dec.to_radians())
+            };
+            Some(CatalogStar {
+                ra_rad,
+                dec_rad,
+                vmag: value(mag_col)?,
+                bv: bv_col.and_then(value),
+            })
+        })
+        .collect())
+}
+
+/// Effective temperature (K) from B-V (Ballesteros 2012)
+pub fn bv_to_temperature_k(bv: f64) -> f64 {
+    let bv = bv.clamp(-0.4, 2.0);
+    4600.0 * (1.0 / (0.92 * bv + 1.7) + 1.0 / (0.92 * bv + 0.62))
+}
+
+/// Approximate sRGB (0..1) of a blackbody at `kelvin`
+pub fn temperature_to_rgb(kelvin: f64) -> [f32; 3] {
+    let t = kelvin.clamp(1000.0, 40_000.0) / 100.0;
+    let red = if t <= 66.0 {
+        255.0
+    } else {
+        329.698_727_446 * (t - 60.0).powf(-0.133_204_759_2)
+    };
+    let green = if t <= 66.0 {
+        99.470_802_586_1 * t.ln() - 161.119_568_166_1
+    } else {
+        288.122_169_528_3 * (t - 60.0).powf(-0.075_514_849_2)
+    };
+    let blue = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.517_731_223_1 * (t - 10.0).ln() - 305.044_792_730_7
+    };
+    [red, green, blue].map(|c| (c.clamp(0.0, 255.0) / 255.0) as f32)
+}
+
+/// Star catalog configuration (`--stars <file>`)
+#[derive(Resource, Clone, Debug)]
+pub struct StarCatalogConfig {
+    /// Catalog file; defaults to `bsc5.dat` in the app data directory
+    pub path: Option<PathBuf>,
+    /// Faintest visual magnitude drawn
+    pub max_magnitude: f64,
+}
+
+impl Default for StarCatalogConfig {
+    fn default() -> Self {
+        Self {
+            path: None,
+            max_magnitude: 6.5,
+        }
+    }
+}
+
+/// Loaded catalog
+#[derive(Resource, Default, Clone, Debug)]
+pub struct StarCatalog {
+    pub stars: Vec<CatalogStar>,
+    pub source: Option<PathBuf>,
+    pub error: Option<String>,
+}
+
+/// Root of the star field; children are positioned in the frame of `precessed_to`
+#[derive(Component)]
+pub struct StarField {
+    precessed_to: DateTime<Utc>,
+}
+
+/// Index into [`StarCatalog::stars`]
+#[derive(Component)]
+pub struct StarMarker(pub usize);
+
+/// Plugin for star catalog rendering.
+pub struct StarsPlugin;
+
+impl Plugin for StarsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StarCatalogConfig>()
+            .init_resource::<StarCatalog>()
+            .add_systems(Startup, (load_star_catalog, spawn_star_field).chain())
+            .add_systems(
+                Update,
+                update_star_field.after(crate::orbital::advance_simulation_clock),
+            );
+    }
+}
+
+fn load_star_catalog(config: Res<StarCatalogConfig>, mut catalog: ResMut<StarCatalog>) {
+    let path = match config.path.clone() {
+        Some(path) => path,
+        None => match data_dir() {
+            Ok(dir) => dir.join("bsc5.dat"),
+            Err(e) => {
+                catalog.error = Some(e.to_string());
+                return;
+            }
+        },
+    };
+    let loaded = fs::read_to_string(&path)
+        .map_err(anyhow::Error::from)
+        .and_then(|body| parse_star_catalog(&body));
+    match loaded {
+        Ok(mut stars) => {
+            stars.retain(|star| star.vmag <= config.max_magnitude);
+            println!(
+                "[STARS] Loaded {} stars from {}",
+                stars.len(),
+                path.display()
+            );
+            catalog.stars = stars;
+            catalog.source = Some(path);
+            catalog.error = None;
+        }
+        Err(e) => {
+            // A missing catalog is normal; the cubemap sky stays as the background
+            if config.path.is_some() {
+                eprintln!("[STARS] Failed to load {}: {}", path.display(), e);
+            }
+            catalog.error = Some(e.to_string());
+        }
+    }
+}
+
+/// Apparent size factor: brighter stars draw larger, within limits
+fn magnitude_scale(vmag: f64) -> f32 {
+    10f32.powf(-0.1 * vmag as f32).clamp(0.2, 1.6)
+}
+
+/// Local position of a star in the field, inertial of date with Bevy axes
+fn star_local_position(star: &CatalogStar, utc: DateTime<Utc>) -> Vec3 {
+    ecef_to_bevy_km(precess_from_j2000(star.j2000(), utc)).normalize_or_zero()
+        * STAR_DRAW_DISTANCE_KM
+}
+
+fn spawn_star_field(
+    mut commands: Commands,
+    catalog: Res<StarCatalog>,
+    sim_time: Res<SimulationTime>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if catalog.stars.is_empty() {
+        return;
+    }
+    let mesh = meshes.add(Sphere::new(1.0).mesh().ico(1).unwrap());
+    // Materials are shared per (color, brightness) bucket
+    let mut material_cache: HashMap<(i32, i32), Handle<StandardMaterial>> = HashMap::new();
+    let utc = sim_time.current_utc;
+
+    commands
+        .spawn((
+            Transform::default(),
+            Visibility::Visible,
+            StarField { precessed_to: utc },
+            Name::new("Star Field"),
+        ))
+        .with_children(|field| {
+            for (index, star) in catalog.stars.iter().enumerate() {
+                let bv_bucket = (star.bv.unwrap_or(0.6).clamp(-0.4, 2.0) * 10.0).round() as i32;
+                let mag_bucket = star.vmag.clamp(-1.5, 7.0).round() as i32;
+                let material = material_cache
+                    .entry((bv_bucket, mag_bucket))
+                    .or_insert_with(|| {
+                        let [r, g, b] =
+                            temperature_to_rgb(bv_to_temperature_k(bv_bucket as f64 / 10.0));
+                        let intensity = 2.5 * 10f32.powf(-0.15 * mag_bucket as f32);
+                        materials.add(StandardMaterial {
+                            base_color: Color::BLACK,
+                            emissive: LinearRgba::new(
+                                r * intensity,
+                                g * intensity,
+                                b * intensity,
+                                1.0,
+                            ),
+                            unlit: true,
+                            ..default()
+                        })
+                    })
+                    .clone();
+                field.spawn((
+                    Mesh3d(mesh.clone()),
+                    MeshMaterial3d(material),
+                    Transform::from_translation(star_local_position(star, utc)).with_scale(
+                        Vec3::splat(
+                            STAR_DRAW_DISTANCE_KM
+                                * STAR_BASE_ANGULAR_RADIUS
+                                * magnitude_scale(star.vmag),
+                        ),
+                    ),
+                    StarMarker(index),
+                ));
+            }
+        });
+}
+
+fn update_star_field(
+    sim_time: Res<SimulationTime>,
+    dut1: Res<Dut1>,
+    catalog: Res<StarCatalog>,
+    camera: Query<&GlobalTransform, With<Camera3d>>,
+    mut fields: Query<(&mut StarField, &mut Transform), Without<StarMarker>>,
+    mut stars: Query<(&StarMarker, &mut Transform), Without<StarField>>,
+) {
+    let Some(camera) = camera.iter().next() else {
+        return;
+    };
+    let utc = sim_time.current_utc;
+    for (mut field, mut transform) in &mut fields {
+        // Inertial-of-date to ECEF is a turn about the pole by apparent sidereal time
+        let x_axis = eci_to_ecef_km(DVec3::X, apparent_sidereal_time_rad(utc, **dut1));
+        transform.rotation = Quat::from_rotation_y(x_axis.y.atan2(x_axis.x) as f32);
+        transform.translation = camera.translation();
+
+        if (utc - field.precessed_to).abs() > Duration::days(PRECESSION_REFRESH_DAYS) {
+            for (marker, mut star_transform) in &mut stars {
+                if let Some(star) = catalog.stars.get(marker.0) {
+                    star_transform.translation = star_local_position(star, utc);
+                }
+            }
+            field.precessed_to = utc;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orbital::ephemeris::equatorial_of_date_to_ecef;
+    use chrono::TimeZone;
+
+    // Two lines of bsc5.dat: Sirius (HR 2491) and Polaris (HR 424)
+    const BSC5_SAMPLE: &str = "\
+2491  9Alp CMaBD-16 1591  48915151881 257I   5423           064044.6-163444064508.9-164258227.22-08.88-1.46   0.00 -0.05 -0.03   A1Vm               -0.553-1.205 +.375-008SBO    13 10.3  11.2AB   4*
+ 424  1Alp UMiBD+88    8   8890   308 257I   5423           013648.0+884554023148.7+891551123.28+26.46 2.02   0.60 +0.38 -0.03
+";
+
+    #[test]
+    fn test_parse_bsc5_fixed_width() {
+        let stars = parse_star_catalog(BSC5_SAMPLE).unwrap();
+        assert_eq!(stars.len(), 2);
+        let sirius = stars[0];
+        // 06h45m08.9s, -16d42'58"
+        assert!((sirius.ra_rad.to_degrees() - 101.287_08).abs() < 1e-4);
+        assert!((sirius.dec_rad.to_degrees() + 16.716_11).abs() < 1e-4);
+        assert_eq!(sirius.vmag, -1.46);
+        assert_eq!(sirius.bv, Some(0.0));
+        let polaris = stars[1];
+        assert!((polaris.dec_rad.to_degrees() - 89.264_17).abs() < 1e-4);
+        assert_eq!(polaris.vmag, 2.02);
+        assert_eq!(polaris.bv, Some(0.6));
+    }
+
+    #[test]
+    fn test_parse_csv_degrees_and_radians() {
+        let degrees =
+            "HIP,RAICRS,DEICRS,Vmag,B-V\n32349,101.28716,-16.71612,-1.44,0.009\n1,2,3,,0.5\n";
+        let stars = parse_star_catalog(degrees).unwrap();
+        // The row without a magnitude is skipped
+        assert_eq!(stars.len(), 1);
+        assert!((stars[0].ra_rad - 101.287_16_f64.to_radians()).abs() < 1e-12);
+        assert_eq!(stars[0].bv, Some(0.009));
+
+        // HYG has `ra` in hours; the radian columns take precedence
+        let hyg = "id,ra,dec,mag,ci,rarad,decrad\n32263,6.752481,-16.716116,-1.44,0.009,1.767791,-0.291751\n";
+        let stars = parse_star_catalog(hyg).unwrap();
+        assert!((stars[0].ra_rad - 1.767_791).abs() < 1e-12);
+        assert!((stars[0].dec_rad + 0.291_751).abs() < 1e-12);
+
+        assert!(parse_star_catalog("name,vmag\nx,1.0\n").is_err());
+        assert!(parse_star_catalog("").is_err());
+    }
+
+    #[test]
+    fn test_star_colors_follow_bv() {
+        // The Sun (B-V 0.65) is about 5800 K
+        assert!((bv_to_temperature_k(0.65) - 5800.0).abs() < 150.0);
+        let [r, _, b] = temperature_to_rgb(bv_to_temperature_k(-0.3));
+        assert!(b >= r, "hot stars are blue");
+        let [r, g, b] = temperature_to_rgb(bv_to_temperature_k(1.6));
+        assert!(r > g && g > b, "cool stars are red");
+    }
+
+    /// Unit direction to a catalog star in ECEF at `utc`
+    fn star_direction_ecef(star: &CatalogStar, utc: DateTime<Utc>, dut1_seconds: f64) -> DVec3 {
+        equatorial_of_date_to_ecef(precess_from_j2000(star.j2000(), utc), utc, dut1_seconds)
+    }
+
+    #[test]
+    fn test_polaris_stays_near_the_pole() {
+        let stars = parse_bsc5(BSC5_SAMPLE);
+        let utc = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let polaris = star_direction_ecef(&stars[1], utc, 0.0);
+        // Within a degree of the ECEF +Z axis, and moving toward the pole until ~2100
+        assert!(polaris.z > 1f64.to_radians().cos());
+        assert!(polaris.z > stars[1].j2000().z);
+        // Sirius turns with sidereal time: 12 sidereal hours later it is on the other side
+        let later = utc + Duration::seconds((0.5 * 86_164.090_5) as i64);
+        let a = star_direction_ecef(&stars[0], utc, 0.0);
+        let b = star_direction_ecef(&stars[0], later, 0.0);
+        assert!((a.z - b.z).abs() < 1e-6);
+        assert!((a.x + b.x).abs() < 1e-3 && (a.y + b.y).abs() < 1e-3);
+    }
+}
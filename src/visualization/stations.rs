@@ -0,0 +1,47 @@
+//! Highlight for crewed space stations
+//!
+//! Stations with a crew on board get a halo around their satellite, found by
+//! NORAD id, when that satellite is loaded.
+
+use bevy::prelude::*;
+
+use crate::launch_library::LaunchLibraryData;
+use crate::launch_library::stations::station_norad_id;
+use crate::satellite::components::Satellite;
+use crate::satellite::resources::NoradIndex;
+
+const HALO_RADIUS_KM: f32 = 140.0;
+const HALO_COLOR: Color = Color::srgba(0.4, 1.0, 0.6, 0.8);
+
+/// Draw a halo around each loaded station that has a crew
+pub fn draw_crewed_station_halos(
+    mut gizmos: Gizmos,
+    data: Res<LaunchLibraryData>,
+    norad_index: Res<NoradIndex>,
+    satellites: Query<&Transform, With<Satellite>>,
+    time: Res<Time>,
+) {
+    // A slow pulse so the halo reads as a highlight rather than an orbit marker
+    let pulse = 1.0 + 0.15 * (time.elapsed_secs() * 2.0).sin();
+    for station in data.stations.iter().filter(|s| !s.crew.is_empty()) {
+        let Some(transform) = station_norad_id(&station.name)
+            .and_then(|norad| norad_index.map.get(&norad))
+            .and_then(|&entity| satellites.get(entity).ok())
+        else {
+            continue;
+        };
+        let position = transform.translation;
+        gizmos.sphere(
+            Isometry3d::from_translation(position),
+            HALO_RADIUS_KM * pulse,
+            HALO_COLOR,
+        );
+        // Ring facing away from the Earth, so the halo stands out edge-on too
+        let normal = Dir3::new(position).unwrap_or(Dir3::Y);
+        gizmos.circle(
+            Isometry3d::new(position, Quat::from_rotation_arc(Vec3::Z, *normal)),
+            HALO_RADIUS_KM * 1.6,
+            HALO_COLOR,
+        );
+    }
+}